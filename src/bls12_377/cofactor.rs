@@ -0,0 +1,99 @@
+/*!
+Cofactor clearing for G1 and G2.
+*/
+
+use crate::{
+    bls12_377::{G1, G2},
+    hash_to_curve::{mul_by_limbs, ClearH},
+};
+
+// G1 cofactor h1 = (x - 1)^2 / 3
+// = 0x170b5d44300000000000000000000000
+const H1: [u64; 2] = [0x0, 0x170b5d4430000000];
+
+// G2 cofactor h2 = (x^8 - 4 x^7 + 5 x^6 - 4 x^4 + 6 x^3 - 4 x^2 - 4 x + 13) / 9
+// = 0x26ba558ae9562addd88d99a6f6a829fbb36b00e1dcc40c8c505634fae2e189d693e8c36676bd09a0f3622fba094800452217cc900000000000000000000001
+const H2: [u64; 8] = [
+    0x1,
+    0x452217cc90000000,
+    0xa0f3622fba094800,
+    0xd693e8c36676bd09,
+    0x8c505634fae2e189,
+    0xfbb36b00e1dcc40c,
+    0xddd88d99a6f6a829,
+    0x26ba558ae9562a,
+];
+
+impl ClearH for G1 {
+    // out = h1 * in
+    fn clear_h(&mut self) {
+        mul_by_limbs(self, &H1);
+    }
+}
+
+impl ClearH for G2 {
+    // out = h2 * in
+    fn clear_h(&mut self) {
+        mul_by_limbs(self, &H2);
+    }
+}
+
+#[test]
+fn test_clear_h() {
+    use crate::{
+        bls12_377::{Fq, Fq2, Fr},
+        hash_to_curve::SvdwMap,
+        CurveAffine, CurveProjective,
+    };
+    use ff::{Field, PrimeField};
+    use rand_core::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // clear_h on an element of the subgroup is multiplication by h1
+    let mut p = G1::random(&mut rng);
+    let mut expect = p;
+    p.clear_h();
+    expect.mul_assign(Fr::from_str("30631250834960419227450344600217059328").unwrap());
+    assert_eq!(p, expect);
+    assert!(p.into_affine().mul(Fr::char()).is_zero());
+
+    // and by h2 mod r
+    let mut p = G2::random(&mut rng);
+    let mut expect = p;
+    p.clear_h();
+    expect.mul_assign(
+        Fr::from_str("587269870971281361413539917442707821409598082120838807553").unwrap(),
+    );
+    assert_eq!(p, expect);
+    assert!(p.into_affine().mul(Fr::char()).is_zero());
+
+    // on the output of the map, which is outside the subgroup, compare with
+    // h1 * P, where h1 < r, and with h2 * P = (h2 / r) * (r * P) + (h2 mod r) * P
+    let h1 = Fr::from_str("30631250834960419227450344600217059328").unwrap();
+    let h2_quo =
+        Fr::from_str("938273527714263380080578412550428706998505953666966461904535972351871811584")
+            .unwrap();
+    let h2_rem = Fr::from_str("587269870971281361413539917442707821409598082120838807553").unwrap();
+    for _ in 0..10 {
+        let p = G1::svdw_map(&Fq::random(&mut rng)).into_affine();
+        assert!(!p.mul(Fr::char()).is_zero());
+        let mut cleared = p.into_projective();
+        cleared.clear_h();
+        assert_eq!(cleared, p.mul(h1));
+        assert!(cleared.into_affine().mul(Fr::char()).is_zero());
+
+        let p = G2::svdw_map(&Fq2::random(&mut rng)).into_affine();
+        let r_p = p.mul(Fr::char());
+        assert!(!r_p.is_zero());
+        let mut expect = r_p.into_affine().mul(h2_quo);
+        expect.add_assign(&p.mul(h2_rem));
+        let mut cleared = p.into_projective();
+        cleared.clear_h();
+        assert_eq!(cleared, expect);
+        assert!(cleared.into_affine().mul(Fr::char()).is_zero());
+    }
+}
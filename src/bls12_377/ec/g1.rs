@@ -0,0 +1,489 @@
+use super::g2::G2Affine;
+use crate::{
+    bls12_377::{Bls12_377, Fq, Fq12, FqRepr, Fr, FrRepr},
+    CurveAffine, CurveProjective, EncodedPoint, Engine, Error, SubgroupCheck,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+curve_impl!(
+    "G1",
+    G1,
+    G1Affine,
+    G1Prepared,
+    Fq,
+    Fr,
+    G1Uncompressed,
+    G1Compressed,
+    G2Affine,
+    Bls12_377,
+    Fq12
+);
+
+#[derive(Copy, Clone)]
+pub struct G1Uncompressed([u8; 96]);
+
+impl AsRef<[u8]> for G1Uncompressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G1Uncompressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G1Uncompressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G1Uncompressed {
+    type Affine = G1Affine;
+
+    fn empty() -> Self {
+        G1Uncompressed([0; 96])
+    }
+    fn size() -> usize {
+        96
+    }
+    fn into_affine(&self) -> Result<G1Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        if !affine.is_on_curve() {
+            Err(Error::NotOnCurve)
+        } else if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G1Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+
+        if copy[0] & (1 << 7) != 0 {
+            // Distinguisher bit is set, but this should be uncompressed!
+            return Err(Error::UnexpectedCompressionMode);
+        }
+
+        if copy[0] & (1 << 6) != 0 {
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
+            // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
+            copy[0] &= 0x3f;
+
+            if copy.iter().all(|b| *b == 0) {
+                Ok(G1Affine::zero())
+            } else {
+                Err(Error::NonZeroInfinity)
+            }
+        } else {
+            if copy[0] & (1 << 5) != 0 {
+                // The bit indicating the y-coordinate should be lexicographically
+                // largest is set, but this is an uncompressed element.
+                return Err(Error::BadFlags);
+            }
+
+            // Unset the three most significant bits.
+            copy[0] &= 0x1f;
+
+            let mut x = FqRepr([0; 6]);
+            let mut y = FqRepr([0; 6]);
+
+            {
+                let mut reader = &copy[..];
+
+                x.read_be(&mut reader).unwrap();
+                y.read_be(&mut reader).unwrap();
+            }
+
+            Ok(G1Affine {
+                x: Fq::from_repr(x)
+                    .map_err(|e| Error::NonCanonicalFieldElement("x coordinate", e))?,
+                y: Fq::from_repr(y)
+                    .map_err(|e| Error::NonCanonicalFieldElement("y coordinate", e))?,
+                infinity: false,
+            })
+        }
+    }
+    fn from_affine(affine: G1Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            // Set the second-most significant bit to indicate this point
+            // is at infinity.
+            res.0[0] |= 1 << 6;
+        } else {
+            let mut writer = &mut res.0[..];
+
+            affine.x.into_repr().write_be(&mut writer).unwrap();
+            affine.y.into_repr().write_be(&mut writer).unwrap();
+        }
+
+        res
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct G1Compressed([u8; 48]);
+
+impl AsRef<[u8]> for G1Compressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G1Compressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G1Compressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G1Compressed {
+    type Affine = G1Affine;
+
+    fn empty() -> Self {
+        G1Compressed([0; 48])
+    }
+    fn size() -> usize {
+        48
+    }
+    fn into_affine(&self) -> Result<G1Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        // NB: Decompression guarantees that it is on the curve already.
+
+        if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G1Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+
+        if copy[0] & (1 << 7) == 0 {
+            // Distinguisher bit isn't set.
+            return Err(Error::UnexpectedCompressionMode);
+        }
+
+        if copy[0] & (1 << 6) != 0 {
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
+            // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
+            copy[0] &= 0x3f;
+
+            if copy.iter().all(|b| *b == 0) {
+                Ok(G1Affine::zero())
+            } else {
+                Err(Error::NonZeroInfinity)
+            }
+        } else {
+            // Determine if the intended y coordinate must be greater
+            // lexicographically.
+            let greatest = copy[0] & (1 << 5) != 0;
+
+            // Unset the three most significant bits.
+            copy[0] &= 0x1f;
+
+            let mut x = FqRepr([0; 6]);
+
+            {
+                let mut reader = &copy[..];
+
+                x.read_be(&mut reader).unwrap();
+            }
+
+            // Interpret as Fq element.
+            let x =
+                Fq::from_repr(x).map_err(|e| Error::NonCanonicalFieldElement("x coordinate", e))?;
+
+            G1Affine::get_point_from_x(x, greatest).ok_or(Error::NotOnCurve)
+        }
+    }
+    fn from_affine(affine: G1Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            // Set the second-most significant bit to indicate this point
+            // is at infinity.
+            res.0[0] |= 1 << 6;
+        } else {
+            {
+                let mut writer = &mut res.0[..];
+
+                affine.x.into_repr().write_be(&mut writer).unwrap();
+            }
+
+            let mut negy = affine.y;
+            negy.negate();
+
+            // Set the third most significant bit if the correct y-coordinate
+            // is lexicographically largest.
+            if affine.y > negy {
+                res.0[0] |= 1 << 5;
+            }
+        }
+
+        // Set highest bit to distinguish this as a compressed element.
+        res.0[0] |= 1 << 7;
+
+        res
+    }
+}
+
+impl G1Affine {
+    fn scale_by_cofactor(&self) -> G1 {
+        // G1 cofactor = (x - 1)^2 / 3  = 30631250834960419227450344600217059328
+        let cofactor = BitIterator::new([0x0, 0x170b5d4430000000]);
+        self.mul_bits(cofactor)
+    }
+
+    fn get_generator() -> Self {
+        G1Affine {
+            x: super::super::fq::G1_GENERATOR_X,
+            y: super::super::fq::G1_GENERATOR_Y,
+            infinity: false,
+        }
+    }
+
+    fn get_coeff_b() -> Fq {
+        super::super::fq::B_COEFF
+    }
+
+    fn perform_pairing(&self, other: &G2Affine) -> Fq12 {
+        super::super::Bls12_377::pairing(*self, *other)
+    }
+}
+
+impl G1 {
+    fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
+        let num_bits = scalar.num_bits() as usize;
+
+        if num_bits >= 130 {
+            4
+        } else if num_bits >= 34 {
+            3
+        } else {
+            2
+        }
+    }
+
+    fn empirical_recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        const RECOMMENDATIONS: [usize; 12] =
+            [1, 3, 7, 20, 43, 120, 273, 563, 1630, 3128, 7933, 62569];
+
+        let mut ret = 4;
+        for r in &RECOMMENDATIONS {
+            if num_scalars > *r {
+                ret += 1;
+            } else {
+                break;
+            }
+        }
+
+        ret
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct G1Prepared(pub(crate) G1Affine);
+
+impl G1Prepared {
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn from_affine(p: G1Affine) -> Self {
+        G1Prepared(p)
+    }
+}
+
+mod subgroup_check {
+
+    use super::G1Affine;
+    #[cfg(test)]
+    use super::G1;
+    use crate::SubgroupCheck;
+    #[cfg(test)]
+    use crate::{CurveAffine, CurveProjective};
+    #[cfg(test)]
+    use rand_core::SeedableRng;
+
+    impl SubgroupCheck for G1Affine {
+        fn in_subgroup(&self) -> bool {
+            self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve()
+        }
+    }
+
+    #[test]
+    fn test_g1_subgroup_check() {
+        use crate::hash_to_curve::ClearH;
+        let mut rng = rand_xorshift::XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..32 {
+            let p = G1::random(&mut rng).into_affine();
+            assert_eq!(
+                p.in_subgroup(),
+                p.is_in_correct_subgroup_assuming_on_curve()
+            );
+
+            let mut pp = p.into_projective();
+            pp.clear_h();
+            let p = pp.into_affine();
+            assert!(p.in_subgroup() && p.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+}
+
+#[test]
+fn g1_generator() {
+    let g1 = G1Affine::one();
+    assert_eq!(
+        g1.x,
+        Fq::from_str("81937999373150964239938255573465948239988671502647976594219695644855304257327692006745978603320413799295628339695").unwrap()
+    );
+    assert_eq!(
+        g1.y,
+        Fq::from_str("241266749859715473739788878240585681733927191168601896383759122102112907357779751001206799952863815012735208165030").unwrap()
+    );
+    assert!(g1.in_subgroup());
+    assert!(!g1.into_projective().is_zero());
+}
+
+#[test]
+fn g1_test_is_valid() {
+    // Reject point on a twist (y^2 = x^3 + 4)
+    {
+        let p = G1Affine {
+            x: Fq::zero(),
+            y: Fq::from_str("2").unwrap(),
+            infinity: false,
+        };
+        assert!(!p.is_on_curve());
+        assert!(!p.in_subgroup());
+    }
+
+    // Reject point of order 3 on the curve
+    {
+        let p = G1Affine {
+            x: Fq::zero(),
+            y: Fq::one(),
+            infinity: false,
+        };
+        assert!(p.is_on_curve());
+        assert!(!p.in_subgroup());
+        match p.into_compressed().into_affine() {
+            Err(Error::NotInSubgroup) => (),
+            _ => panic!("should have rejected point outside the subgroup"),
+        }
+        assert!(p.into_compressed().into_affine_unchecked().is_ok());
+    }
+}
+
+#[test]
+fn test_g1_addition_correctness() {
+    let mut p = G1::one();
+    p.add_assign(
+        &G1Affine {
+            x: Fq::from_str(
+                "142653276895993031000006916266724122521221908004256063457362569275298456307915314952948497516099307719409858077584",
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "124869013296681382405525048387381943745958348199556996371954051753620340892927007930177100403663166477748695189485",
+            )
+            .unwrap(),
+            infinity: false,
+        }
+        .into_projective(),
+    );
+
+    let p = G1Affine::from(p);
+
+    assert_eq!(
+        p,
+        G1Affine {
+            x: Fq::from_str(
+                "176261570884257385822490072081126577831012136425835029348738960656180450073404624791045413530220899834805037231150"
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "198407175183702058016858320272971443103814444082515847676087944564022364364886880454870163762778973932449736087554"
+            )
+            .unwrap(),
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn test_g1_doubling_correctness() {
+    let mut p = G1::one();
+
+    p.double();
+
+    let p = G1Affine::from(p);
+
+    assert_eq!(
+        p,
+        G1Affine {
+            x: Fq::from_str(
+                "142653276895993031000006916266724122521221908004256063457362569275298456307915314952948497516099307719409858077584"
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "124869013296681382405525048387381943745958348199556996371954051753620340892927007930177100403663166477748695189485"
+            )
+            .unwrap(),
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn test_g1_encoding_flags() {
+    // the generator's y-coordinate is the larger of the two
+    let c = G1Affine::one().into_compressed();
+    assert_eq!(c.as_ref()[0] >> 5, 0b101);
+    let mut g = G1Affine::one();
+    g.negate();
+    let c = g.into_compressed();
+    assert_eq!(c.as_ref()[0] >> 5, 0b100);
+    assert_eq!(c.into_affine().unwrap(), g);
+
+    let u = G1Affine::one().into_uncompressed();
+    assert_eq!(u.as_ref()[0] >> 5, 0);
+
+    let z = G1Affine::zero().into_compressed();
+    assert_eq!(z.as_ref()[0], 0b110 << 5);
+    assert!(z.as_ref()[1..].iter().all(|b| *b == 0));
+    let z = G1Affine::zero().into_uncompressed();
+    assert_eq!(z.as_ref()[0], 0b010 << 5);
+    assert!(z.as_ref()[1..].iter().all(|b| *b == 0));
+}
+
+#[test]
+fn g1_curve_tests() {
+    crate::tests::curve::curve_tests::<G1>();
+}
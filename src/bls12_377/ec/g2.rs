@@ -0,0 +1,504 @@
+use super::super::{Bls12_377, Fq, Fq12, Fq2, FqRepr, Fr, FrRepr};
+use super::g1::G1Affine;
+use crate::{CurveAffine, CurveProjective, EncodedPoint, Engine, Error, SubgroupCheck};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+
+curve_impl!(
+    "G2",
+    G2,
+    G2Affine,
+    G2Prepared,
+    Fq2,
+    Fr,
+    G2Uncompressed,
+    G2Compressed,
+    G1Affine,
+    Bls12_377,
+    Fq12
+);
+
+#[derive(Copy, Clone)]
+pub struct G2Uncompressed([u8; 192]);
+
+impl AsRef<[u8]> for G2Uncompressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G2Uncompressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G2Uncompressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G2Uncompressed {
+    type Affine = G2Affine;
+
+    fn empty() -> Self {
+        G2Uncompressed([0; 192])
+    }
+    fn size() -> usize {
+        192
+    }
+    fn into_affine(&self) -> Result<G2Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        if !affine.is_on_curve() {
+            Err(Error::NotOnCurve)
+        } else if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G2Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+
+        if copy[0] & (1 << 7) != 0 {
+            // Distinguisher bit is set, but this should be uncompressed!
+            return Err(Error::UnexpectedCompressionMode);
+        }
+
+        if copy[0] & (1 << 6) != 0 {
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
+            // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
+            copy[0] &= 0x3f;
+
+            if copy.iter().all(|b| *b == 0) {
+                Ok(G2Affine::zero())
+            } else {
+                Err(Error::NonZeroInfinity)
+            }
+        } else {
+            if copy[0] & (1 << 5) != 0 {
+                // The bit indicating the y-coordinate should be lexicographically
+                // largest is set, but this is an uncompressed element.
+                return Err(Error::BadFlags);
+            }
+
+            // Unset the three most significant bits.
+            copy[0] &= 0x1f;
+
+            let mut x_c0 = FqRepr([0; 6]);
+            let mut x_c1 = FqRepr([0; 6]);
+            let mut y_c0 = FqRepr([0; 6]);
+            let mut y_c1 = FqRepr([0; 6]);
+
+            {
+                let mut reader = &copy[..];
+
+                x_c1.read_be(&mut reader).unwrap();
+                x_c0.read_be(&mut reader).unwrap();
+                y_c1.read_be(&mut reader).unwrap();
+                y_c0.read_be(&mut reader).unwrap();
+            }
+
+            Ok(G2Affine {
+                x: Fq2 {
+                    c0: Fq::from_repr(x_c0)
+                        .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c0)", e))?,
+                    c1: Fq::from_repr(x_c1)
+                        .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c1)", e))?,
+                },
+                y: Fq2 {
+                    c0: Fq::from_repr(y_c0)
+                        .map_err(|e| Error::NonCanonicalFieldElement("y coordinate (c0)", e))?,
+                    c1: Fq::from_repr(y_c1)
+                        .map_err(|e| Error::NonCanonicalFieldElement("y coordinate (c1)", e))?,
+                },
+                infinity: false,
+            })
+        }
+    }
+    fn from_affine(affine: G2Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            // Set the second-most significant bit to indicate this point
+            // is at infinity.
+            res.0[0] |= 1 << 6;
+        } else {
+            let mut writer = &mut res.0[..];
+
+            affine.x.c1.into_repr().write_be(&mut writer).unwrap();
+            affine.x.c0.into_repr().write_be(&mut writer).unwrap();
+            affine.y.c1.into_repr().write_be(&mut writer).unwrap();
+            affine.y.c0.into_repr().write_be(&mut writer).unwrap();
+        }
+
+        res
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct G2Compressed([u8; 96]);
+
+impl AsRef<[u8]> for G2Compressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G2Compressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G2Compressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G2Compressed {
+    type Affine = G2Affine;
+
+    fn empty() -> Self {
+        G2Compressed([0; 96])
+    }
+    fn size() -> usize {
+        96
+    }
+    fn into_affine(&self) -> Result<G2Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        // NB: Decompression guarantees that it is on the curve already.
+
+        if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G2Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+
+        if copy[0] & (1 << 7) == 0 {
+            // Distinguisher bit isn't set.
+            return Err(Error::UnexpectedCompressionMode);
+        }
+
+        if copy[0] & (1 << 6) != 0 {
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
+            // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
+            copy[0] &= 0x3f;
+
+            if copy.iter().all(|b| *b == 0) {
+                Ok(G2Affine::zero())
+            } else {
+                Err(Error::NonZeroInfinity)
+            }
+        } else {
+            // Determine if the intended y coordinate must be greater
+            // lexicographically.
+            let greatest = copy[0] & (1 << 5) != 0;
+
+            // Unset the three most significant bits.
+            copy[0] &= 0x1f;
+
+            let mut x_c1 = FqRepr([0; 6]);
+            let mut x_c0 = FqRepr([0; 6]);
+
+            {
+                let mut reader = &copy[..];
+
+                x_c1.read_be(&mut reader).unwrap();
+                x_c0.read_be(&mut reader).unwrap();
+            }
+
+            // Interpret as Fq element.
+            let x = Fq2 {
+                c0: Fq::from_repr(x_c0)
+                    .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c0)", e))?,
+                c1: Fq::from_repr(x_c1)
+                    .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c1)", e))?,
+            };
+
+            G2Affine::get_point_from_x(x, greatest).ok_or(Error::NotOnCurve)
+        }
+    }
+    fn from_affine(affine: G2Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            // Set the second-most significant bit to indicate this point
+            // is at infinity.
+            res.0[0] |= 1 << 6;
+        } else {
+            {
+                let mut writer = &mut res.0[..];
+
+                affine.x.c1.into_repr().write_be(&mut writer).unwrap();
+                affine.x.c0.into_repr().write_be(&mut writer).unwrap();
+            }
+
+            let mut negy = affine.y;
+            negy.negate();
+
+            // Set the third most significant bit if the correct y-coordinate
+            // is lexicographically largest.
+            if affine.y > negy {
+                res.0[0] |= 1 << 5;
+            }
+        }
+
+        // Set highest bit to distinguish this as a compressed element.
+        res.0[0] |= 1 << 7;
+
+        res
+    }
+}
+
+impl G2Affine {
+    fn get_generator() -> Self {
+        G2Affine {
+            x: Fq2 {
+                c0: super::super::fq::G2_GENERATOR_X_C0,
+                c1: super::super::fq::G2_GENERATOR_X_C1,
+            },
+            y: Fq2 {
+                c0: super::super::fq::G2_GENERATOR_Y_C0,
+                c1: super::super::fq::G2_GENERATOR_Y_C1,
+            },
+            infinity: false,
+        }
+    }
+
+    fn get_coeff_b() -> Fq2 {
+        super::super::fq::B_COEFF_FQ2
+    }
+
+    fn scale_by_cofactor(&self) -> G2 {
+        // G2 cofactor = (x^8 - 4 x^7 + 5 x^6 - 4 x^4 + 6 x^3 - 4 x^2 - 4 x + 13) / 9
+        // 0x26ba558ae9562addd88d99a6f6a829fbb36b00e1dcc40c8c505634fae2e189d693e8c36676bd09a0f3622fba094800452217cc900000000000000000000001
+        let cofactor = BitIterator::new([
+            0x1,
+            0x452217cc90000000,
+            0xa0f3622fba094800,
+            0xd693e8c36676bd09,
+            0x8c505634fae2e189,
+            0xfbb36b00e1dcc40c,
+            0xddd88d99a6f6a829,
+            0x26ba558ae9562a,
+        ]);
+        self.mul_bits(cofactor)
+    }
+
+    fn perform_pairing(&self, other: &G1Affine) -> Fq12 {
+        super::super::Bls12_377::pairing(*other, *self)
+    }
+}
+
+impl G2 {
+    fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
+        let num_bits = scalar.num_bits() as usize;
+
+        if num_bits >= 103 {
+            4
+        } else if num_bits >= 37 {
+            3
+        } else {
+            2
+        }
+    }
+
+    fn empirical_recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        const RECOMMENDATIONS: [usize; 11] = [1, 3, 8, 20, 47, 126, 260, 826, 1501, 4555, 84071];
+
+        let mut ret = 4;
+        for r in &RECOMMENDATIONS {
+            if num_scalars > *r {
+                ret += 1;
+            } else {
+                break;
+            }
+        }
+
+        ret
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct G2Prepared {
+    pub(crate) coeffs: Vec<(Fq2, Fq2, Fq2)>,
+    pub(crate) infinity: bool,
+}
+
+mod subgroup_check {
+    use super::G2Affine;
+    #[cfg(test)]
+    use crate::CurveAffine;
+    use crate::SubgroupCheck;
+    #[cfg(test)]
+    use rand_core::SeedableRng;
+
+    impl SubgroupCheck for G2Affine {
+        fn in_subgroup(&self) -> bool {
+            self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve()
+        }
+    }
+
+    #[test]
+    fn test_g2_subgroup_check() {
+        use crate::{bls12_377::G2, hash_to_curve::ClearH, CurveProjective};
+        let mut rng = rand_xorshift::XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..32 {
+            let p = G2::random(&mut rng).into_affine();
+            assert_eq!(
+                p.in_subgroup(),
+                p.is_in_correct_subgroup_assuming_on_curve()
+            );
+
+            let mut pp = p.into_projective();
+            pp.clear_h();
+            let p = pp.into_affine();
+            assert!(p.in_subgroup() && p.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+}
+
+#[test]
+fn g2_generator() {
+    let g2 = G2Affine::one();
+    assert_eq!(
+        g2.x,
+        Fq2 {
+            c0: Fq::from_str("233578398248691099356572568220835526895379068987715365179118596935057653620464273615301663571204657964920925606294").unwrap(),
+            c1: Fq::from_str("140913150380207355837477652521042157274541796891053068589147167627541651775299824604154852141315666357241556069118").unwrap(),
+        }
+    );
+    assert_eq!(
+        g2.y,
+        Fq2 {
+            c0: Fq::from_str("63160294768292073209381361943935198908131692476676907196754037919244929611450776219210369229519898517858833747423").unwrap(),
+            c1: Fq::from_str("149157405641012693445398062341192467754805999074082136895788947234480009303640899064710353187729182149407503257491").unwrap(),
+        }
+    );
+    assert!(g2.in_subgroup());
+    assert!(!g2.into_projective().is_zero());
+}
+
+#[test]
+fn g2_test_is_valid() {
+    // Reject point not on the twist
+    {
+        let p = G2Affine {
+            x: Fq2::one(),
+            y: Fq2::one(),
+            infinity: false,
+        };
+        assert!(!p.is_on_curve());
+        assert!(!p.in_subgroup());
+    }
+
+    // Reject the first point on the twist, which is outside the subgroup until
+    // the cofactor is cleared
+    {
+        let mut x = Fq2::zero();
+        let p = loop {
+            if let Some(p) = G2Affine::get_point_from_x(x, false) {
+                break p;
+            }
+            x.add_assign(&Fq2::one());
+        };
+        assert!(p.is_on_curve());
+        assert!(!p.in_subgroup());
+        match p.into_uncompressed().into_affine() {
+            Err(Error::NotInSubgroup) => (),
+            _ => panic!("should have rejected point outside the subgroup"),
+        }
+
+        let p = G2Affine::from(p.scale_by_cofactor());
+        assert!(!p.is_zero());
+        assert!(p.in_subgroup());
+    }
+}
+
+#[test]
+fn test_g2_addition_correctness() {
+    let mut p = G2::one();
+    p.add_assign(
+        &G2Affine {
+            x: Fq2 {
+                c0: Fq::from_str("219564603530812897662626723532119871126025986455221517258692924146720701350966061579326121792267180830577562566009").unwrap(),
+                c1: Fq::from_str("184624156959816253153212691059252753251414060022257016743873452235807427608305571427957272308982089732460045225308").unwrap(),
+            },
+            y: Fq2 {
+                c0: Fq::from_str("183390023131802300769587703273946623618493162536337034672377497825442095098047279060444611635423015435359400970794").unwrap(),
+                c1: Fq::from_str("137006181065015237533186569405795438258126659550846636415327489699198493880440637650517929480195536789466736841218").unwrap(),
+            },
+            infinity: false,
+        }
+        .into_projective(),
+    );
+
+    let p = G2Affine::from(p);
+
+    assert_eq!(
+        p,
+        G2Affine {
+            x: Fq2 {
+                c0: Fq::from_str("159649057549196860864637737553195631227528009349991199445752144797813068573077993552289147144687717385664482634538").unwrap(),
+                c1: Fq::from_str("63597712832814314652652672875683083446506922663163397586583532404939542939245621845162697609734980114303052592716").unwrap(),
+            },
+            y: Fq2 {
+                c0: Fq::from_str("241574250383758704057811208765719854944757846716618229062262635414448864951095025934719089206230453147502644873626").unwrap(),
+                c1: Fq::from_str("48092082478241333417185156541678277654023974371409175344878855294844626225208225413135239190198220608950275813210").unwrap(),
+            },
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn test_g2_doubling_correctness() {
+    let mut p = G2::one();
+
+    p.double();
+
+    let p = G2Affine::from(p);
+
+    assert_eq!(
+        p,
+        G2Affine {
+            x: Fq2 {
+                c0: Fq::from_str("219564603530812897662626723532119871126025986455221517258692924146720701350966061579326121792267180830577562566009").unwrap(),
+                c1: Fq::from_str("184624156959816253153212691059252753251414060022257016743873452235807427608305571427957272308982089732460045225308").unwrap(),
+            },
+            y: Fq2 {
+                c0: Fq::from_str("183390023131802300769587703273946623618493162536337034672377497825442095098047279060444611635423015435359400970794").unwrap(),
+                c1: Fq::from_str("137006181065015237533186569405795438258126659550846636415327489699198493880440637650517929480195536789466736841218").unwrap(),
+            },
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn g2_curve_tests() {
+    crate::tests::curve::curve_tests::<G2>();
+}
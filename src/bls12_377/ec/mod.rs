@@ -0,0 +1,18 @@
+// Points are encoded as for BLS12-381: big-endian coordinates, with the
+// compression, infinity and sort flags in the three most significant bits of
+// the first byte, which are free because the modulus is below 2^377.
+pub mod g1;
+pub mod g2;
+
+pub use self::g1::*;
+pub use self::g2::*;
+
+#[test]
+fn test_group_defaults() {
+    use crate::{CurveAffine, CurveProjective};
+
+    assert_eq!(G1::default(), G1::zero());
+    assert_eq!(G2::default(), G2::zero());
+    assert_eq!(G1Affine::default(), G1Affine::zero());
+    assert_eq!(G2Affine::default(), G2Affine::zero());
+}
@@ -0,0 +1,568 @@
+use super::fq2::Fq2;
+use crate::{
+    hash_to_field::BaseFromRO,
+    signum::{Sgn0Result, Signum0},
+};
+use digest::generic_array::{typenum::U64, GenericArray};
+use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
+
+// B coefficient of BLS12-377 curve, 1.
+pub const B_COEFF: Fq = Fq(FqRepr([
+    0x2cdffffffffff68,
+    0x51409f837fffffb1,
+    0x9f7db3a98a7d3ff2,
+    0x7b4e97b76e7c6305,
+    0x4cf495bf803c84e8,
+    0x8d6661e2fdf49a,
+]));
+
+// B coefficient of the twist, 1 / u.
+pub const B_COEFF_FQ2: Fq2 = Fq2 {
+    c0: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    c1: Fq(FqRepr([
+        0x8072266666666685,
+        0x8df55926899999a9,
+        0x7fe4561ad64f34cf,
+        0xb95da6d8b6e4f01b,
+        0x4b747cccfc142743,
+        0x39c3fa70f49f43,
+    ])),
+};
+
+// Generator of G1
+// x = 81937999373150964239938255573465948239988671502647976594219695644855304257327692006745978603320413799295628339695
+// y = 241266749859715473739788878240585681733927191168601896383759122102112907357779751001206799952863815012735208165030
+pub const G1_GENERATOR_X: Fq = Fq(FqRepr([
+    0x260f33b9772451f4,
+    0xc54dd773169d5658,
+    0x5c1551c469a510dd,
+    0x761662e4425e1698,
+    0xc97d78cc6f065272,
+    0xa41206b361fd4d,
+]));
+
+pub const G1_GENERATOR_Y: Fq = Fq(FqRepr([
+    0x8193961fb8cb81f3,
+    0x638d4c5f44adb8,
+    0xfafaf3dad4daf54a,
+    0xc27849e2d655cd18,
+    0x2ec3ddb401d52814,
+    0x7da93326303c71,
+]));
+
+// Generator of G2
+// x = 140913150380207355837477652521042157274541796891053068589147167627541651775299824604154852141315666357241556069118*u + 233578398248691099356572568220835526895379068987715365179118596935057653620464273615301663571204657964920925606294
+// y = 149157405641012693445398062341192467754805999074082136895788947234480009303640899064710353187729182149407503257491*u + 63160294768292073209381361943935198908131692476676907196754037919244929611450776219210369229519898517858833747423
+pub const G2_GENERATOR_X_C0: Fq = Fq(FqRepr([
+    0x68904082f268725b,
+    0x668f2ea74f45328b,
+    0xebca7a65802be84f,
+    0x1e1850f4c1ada3e6,
+    0x830dc22d588ef1e9,
+    0x1862a81767c0982,
+]));
+
+pub const G2_GENERATOR_X_C1: Fq = Fq(FqRepr([
+    0x5f02a915c91c7f39,
+    0xf8c553ba388da2a7,
+    0xd51a416dbd198850,
+    0xe943c6f38ae3073a,
+    0xffe24aa8259a4981,
+    0x11853391e73dfdd,
+]));
+
+pub const G2_GENERATOR_Y_C0: Fq = Fq(FqRepr([
+    0xd5b19b897881430f,
+    0x5be9118a5b371ed,
+    0x6063f91f86c131ee,
+    0x3244a61be8f4ec19,
+    0xa02e425b9f9a3a12,
+    0x18af8c04f3360d2,
+]));
+
+pub const G2_GENERATOR_Y_C1: Fq = Fq(FqRepr([
+    0x57601ac71a5b96f5,
+    0xe99acc1714f2440e,
+    0x2339612f10118ea9,
+    0x8321e68a3b1cd722,
+    0x2b543b050cc74917,
+    0x590182b396c112,
+]));
+
+// Coefficients for the Frobenius automorphism.
+pub const FROBENIUS_COEFF_FQ2_C1: [Fq; 2] = [
+    Fq(FqRepr([
+        0x2cdffffffffff68,
+        0x51409f837fffffb1,
+        0x9f7db3a98a7d3ff2,
+        0x7b4e97b76e7c6305,
+        0x4cf495bf803c84e8,
+        0x8d6661e2fdf49a,
+    ])),
+    Fq(FqRepr([
+        0x823ac00000000099,
+        0xc5cabdc0b000004f,
+        0x7f75ae862f8c080d,
+        0x9ed4423b9278b089,
+        0x79467000ec64c452,
+        0x120d3e434c71c50,
+    ])),
+];
+
+// u^((q^i - 1) / 3) for i in 0..6
+pub const FROBENIUS_COEFF_FQ6_C1: [Fq2; 6] = [
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x2cdffffffffff68,
+            0x51409f837fffffb1,
+            0x9f7db3a98a7d3ff2,
+            0x7b4e97b76e7c6305,
+            0x4cf495bf803c84e8,
+            0x8d6661e2fdf49a,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x5892506da58478da,
+            0x133366940ac2a74b,
+            0x9b64a150cdf726cf,
+            0x5cc426090a9c587e,
+            0x5cf848adfdcd640c,
+            0x4702bf3ac02380,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xdacd106da5847973,
+            0xd8fe2454bac2a79a,
+            0x1ada4fd6fd832edc,
+            0xfb9868449d150908,
+            0xd63eb8aeea32285e,
+            0x167d6a36f873fd0,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x823ac00000000099,
+            0xc5cabdc0b000004f,
+            0x7f75ae862f8c080d,
+            0x9ed4423b9278b089,
+            0x79467000ec64c452,
+            0x120d3e434c71c50,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x2c766f925a7b8727,
+            0x3d7f6b0253d58b5,
+            0x838ec0deec122131,
+            0xbd5eb3e9f658bb10,
+            0x6942bd126ed3e52e,
+            0x1673786dd04ed6a,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xaa3baf925a7b868e,
+            0x3e0d38ef753d5865,
+            0x4191258bc861923,
+            0x1e8a71ae63e00a87,
+            0xeffc4d11826f20dc,
+            0x4663a2a83dd119,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+];
+
+// u^((2 q^i - 2) / 3) for i in 0..6
+pub const FROBENIUS_COEFF_FQ6_C2: [Fq2; 6] = [
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x2cdffffffffff68,
+            0x51409f837fffffb1,
+            0x9f7db3a98a7d3ff2,
+            0x7b4e97b76e7c6305,
+            0x4cf495bf803c84e8,
+            0x8d6661e2fdf49a,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xdacd106da5847973,
+            0xd8fe2454bac2a79a,
+            0x1ada4fd6fd832edc,
+            0xfb9868449d150908,
+            0xd63eb8aeea32285e,
+            0x167d6a36f873fd0,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x2c766f925a7b8727,
+            0x3d7f6b0253d58b5,
+            0x838ec0deec122131,
+            0xbd5eb3e9f658bb10,
+            0x6942bd126ed3e52e,
+            0x1673786dd04ed6a,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x2cdffffffffff68,
+            0x51409f837fffffb1,
+            0x9f7db3a98a7d3ff2,
+            0x7b4e97b76e7c6305,
+            0x4cf495bf803c84e8,
+            0x8d6661e2fdf49a,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xdacd106da5847973,
+            0xd8fe2454bac2a79a,
+            0x1ada4fd6fd832edc,
+            0xfb9868449d150908,
+            0xd63eb8aeea32285e,
+            0x167d6a36f873fd0,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x2c766f925a7b8727,
+            0x3d7f6b0253d58b5,
+            0x838ec0deec122131,
+            0xbd5eb3e9f658bb10,
+            0x6942bd126ed3e52e,
+            0x1673786dd04ed6a,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+];
+
+// u^((q^i - 1) / 6) for i in 0..12
+pub const FROBENIUS_COEFF_FQ12_C1: [Fq2; 12] = [
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x2cdffffffffff68,
+            0x51409f837fffffb1,
+            0x9f7db3a98a7d3ff2,
+            0x7b4e97b76e7c6305,
+            0x4cf495bf803c84e8,
+            0x8d6661e2fdf49a,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x6ec47a04a3f7ca9e,
+            0xa42e0cb968c1fa44,
+            0x578d5187fbd2bd23,
+            0x930eeb0ac79dd4bd,
+            0xa24883de1e09a9ee,
+            0xdaa7058067d46f,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x5892506da58478da,
+            0x133366940ac2a74b,
+            0x9b64a150cdf726cf,
+            0x5cc426090a9c587e,
+            0x5cf848adfdcd640c,
+            0x4702bf3ac02380,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x982c13d9d084771f,
+            0xfd49de0c6da34a32,
+            0x61a530d183ab0e53,
+            0xdf8fe44106dd9879,
+            0x40f29b58d88472bc,
+            0x158723199046d5d,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xdacd106da5847973,
+            0xd8fe2454bac2a79a,
+            0x1ada4fd6fd832edc,
+            0xfb9868449d150908,
+            0xd63eb8aeea32285e,
+            0x167d6a36f873fd0,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x296799d52c8cac81,
+            0x591bd15304e14fee,
+            0xa17df4987d85130,
+            0x4c80f9363f3fc3bc,
+            0x9eaa177aba7ac8ce,
+            0x7dcb2c189c98ed,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x823ac00000000099,
+            0xc5cabdc0b000004f,
+            0x7f75ae862f8c080d,
+            0x9ed4423b9278b089,
+            0x79467000ec64c452,
+            0x120d3e434c71c50,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x164445fb5c083563,
+            0x72dd508ac73e05bc,
+            0xc76610a7be368adc,
+            0x8713eee839573ed1,
+            0x23f281e24e979f4c,
+            0xd39340975d3c7b,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x2c766f925a7b8727,
+            0x3d7f6b0253d58b5,
+            0x838ec0deec122131,
+            0xbd5eb3e9f658bb10,
+            0x6942bd126ed3e52e,
+            0x1673786dd04ed6a,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xecdcac262f7b88e2,
+            0x19c17f37c25cb5cd,
+            0xbd4e315e365e39ac,
+            0x3a92f5b1fa177b15,
+            0x85486a67941cd67e,
+            0x55c8147ec0a38d,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xaa3baf925a7b868e,
+            0x3e0d38ef753d5865,
+            0x4191258bc861923,
+            0x1e8a71ae63e00a87,
+            0xeffc4d11826f20dc,
+            0x4663a2a83dd119,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x5ba1262ad3735380,
+            0xbdef8bf12b1eb012,
+            0x14db82e63230f6cf,
+            0xcda1e0bcc1b54fd3,
+            0x2790ee45b226806c,
+            0x1306f19ff2877fd,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+    },
+];
+
+#[derive(PrimeField, Zeroize)]
+#[PrimeFieldModulus = "258664426012969094010652733694893533536393512754914660539884262666720468348340822774968888139573360124440321458177"]
+#[PrimeFieldGenerator = "15"]
+pub struct Fq(pub(super) FqRepr);
+
+/// # Safety
+pub const unsafe fn transmute(r: FqRepr) -> Fq {
+    Fq(r)
+}
+
+impl BaseFromRO for Fq {
+    type BaseLength = U64;
+
+    // Convert an output keying material to an Fq element
+    // the input generic array is gauranteed to have 64 bytes
+    fn from_okm(okm: &GenericArray<u8, U64>) -> Fq {
+        const F_2_256: Fq = Fq(FqRepr([
+            0x7ad989e964bf4bc5,
+            0x506bfea62ba9792a,
+            0x9bcbd51fe77a6419,
+            0xf3686e651b6a0c02,
+            0xa530f99c54c84d81,
+            0x9772b5cbb75e07,
+        ]));
+
+        // unwraps are safe here: we only use 32 bytes at a time, which is strictly less than p
+        let mut repr = FqRepr::default();
+        let mut buf = [0u8; 48];
+        buf[16..].copy_from_slice(&okm[..32]);
+        repr.read_be(&buf[..]).unwrap();
+        let mut elm = Fq::from_repr(repr).unwrap();
+        elm.mul_assign(&F_2_256);
+
+        buf[16..].copy_from_slice(&okm[32..]);
+        repr.read_be(&buf[..]).unwrap();
+        let elm2 = Fq::from_repr(repr).unwrap();
+        elm.add_assign(&elm2);
+        elm
+    }
+}
+
+impl Signum0 for Fq {
+    // returns the sign of a center lifted element over the integer ring
+    fn sgn0(&self) -> Sgn0Result {
+        if self.into_repr().0[0] & 1 == 1 {
+            Sgn0Result::Negative
+        } else {
+            Sgn0Result::NonNegative
+        }
+    }
+}
+
+#[test]
+fn test_b_coeff() {
+    assert_eq!(Fq::from_repr(FqRepr::from(1)).unwrap(), B_COEFF);
+
+    let mut b = B_COEFF_FQ2;
+    b.mul_assign(&Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::one(),
+    });
+    assert_eq!(b, Fq2::one());
+}
+
+#[test]
+fn test_frob_coeffs() {
+    // u^2 = -5
+    let mut nqr = Fq::from_repr(FqRepr::from(5)).unwrap();
+    nqr.negate();
+
+    // (q - 1) / 2
+    let q_m1_over2 = [
+        0x4284600000000000,
+        0xb85aea218000000,
+        0x8f79b117dd04a400,
+        0x8d116cf9807a89c7,
+        0x631d82e03650a49d,
+        0xd71d230be28875,
+    ];
+
+    assert_eq!(FROBENIUS_COEFF_FQ2_C1[0], Fq::one());
+    assert_eq!(FROBENIUS_COEFF_FQ2_C1[1], nqr.pow(q_m1_over2));
+
+    // u^((q^i - 1) / 6) determines the other coefficients
+    let mut xi_pow = Fq2::one();
+    for i in 0..12 {
+        let coeff = FROBENIUS_COEFF_FQ12_C1[i];
+        assert_eq!(coeff, xi_pow);
+        if i < 6 {
+            let mut c = coeff;
+            c.square();
+            assert_eq!(FROBENIUS_COEFF_FQ6_C1[i], c);
+            c.square();
+            assert_eq!(FROBENIUS_COEFF_FQ6_C2[i], c);
+        }
+
+        // u^((q^(i+1) - 1) / 6) = (u^((q^i - 1) / 6))^q * u^((q - 1) / 6)
+        xi_pow.frobenius_map(1);
+        xi_pow.mul_assign(&FROBENIUS_COEFF_FQ12_C1[1]);
+    }
+
+    // (u^((q - 1) / 6))^6 = u^(q - 1) = (-5)^((q - 1) / 2)
+    let mut c = FROBENIUS_COEFF_FQ12_C1[1];
+    c = c.pow([6]);
+    assert_eq!(
+        c,
+        Fq2 {
+            c0: nqr.pow(q_m1_over2),
+            c1: Fq::zero(),
+        }
+    );
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    use ff::SqrtField;
+
+    assert_eq!(Fq::S, 46);
+    assert_eq!(
+        Fq::multiplicative_generator(),
+        Fq::from_repr(FqRepr::from(15)).unwrap()
+    );
+    assert_eq!(
+        Fq::multiplicative_generator().pow([
+            0x7510c00000021423,
+            0x88bee82520005c2d,
+            0x67cc03d44e3c7bcd,
+            0x1701b28524ec688b,
+            0xe9185f1443ab18ec,
+            0x6b8
+        ]),
+        Fq::root_of_unity()
+    );
+    assert_eq!(Fq::root_of_unity().pow([1 << Fq::S]), Fq::one());
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fq_from_okm() {
+    // 2^256 * head + tail
+    let mut okm = [0u8; 64];
+    okm[31] = 1;
+    okm[63] = 5;
+    let mut expect = Fq::from_repr(FqRepr([0, 0, 0, 0, 1, 0])).unwrap();
+    expect.add_assign(&Fq::from_repr(FqRepr::from(5)).unwrap());
+    assert_eq!(Fq::from_okm(GenericArray::from_slice(&okm[..])), expect);
+
+    // all ones is 2^512 - 1 mod q
+    let okm = [0xffu8; 64];
+    assert_eq!(
+        Fq::from_okm(GenericArray::from_slice(&okm[..])),
+        Fq::from_str(
+            "182092876608940012711595214563085269948755507054538144544185039252216080194758592100182947116766058128310756585745"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn fq_field_tests() {
+    crate::tests::field::random_field_tests::<Fq>();
+    crate::tests::field::random_sqrt_tests::<Fq>();
+    crate::tests::field::random_frobenius_tests::<Fq, _>(Fq::char(), 13);
+    crate::tests::field::from_str_tests::<Fq>();
+}
+
+#[test]
+fn test_fq_ordering() {
+    // FqRepr's ordering is well-tested, but we still need to make sure the Fq
+    // elements aren't being compared in Montgomery form.
+    for i in 0..100 {
+        assert!(
+            Fq::from_repr(FqRepr::from(i + 1)).unwrap() > Fq::from_repr(FqRepr::from(i)).unwrap()
+        );
+    }
+}
+
+#[test]
+fn fq_repr_tests() {
+    crate::tests::repr::random_repr_tests::<Fq, FqRepr>();
+}
@@ -0,0 +1,256 @@
+use super::fq::{Fq, FROBENIUS_COEFF_FQ2_C1};
+use crate::{
+    hash_to_field::{BaseFromRO, FromRO},
+    tower::{QuadraticExtParameters, QuadraticExtension},
+};
+use digest::generic_array::{
+    typenum::{U128, U64},
+    GenericArray,
+};
+use ff::{Field, SqrtField};
+
+/// Multiply an element of Fq by the quadratic nonresidue u^2 = -5.
+#[inline(always)]
+fn mul_fq_by_nonresidue(fe: &Fq) -> Fq {
+    let mut tmp = *fe;
+    tmp.double();
+    tmp.double();
+    tmp.add_assign(fe);
+    tmp.negate();
+    tmp
+}
+
+/// Parameters of Fq2 = Fq[u] / (u^2 + 5).
+#[derive(Copy, Clone, Debug)]
+pub struct Fq2Parameters;
+
+impl QuadraticExtParameters for Fq2Parameters {
+    type BaseField = Fq;
+
+    const NAME: &'static str = "Fq2";
+    const VAR: &'static str = "u";
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &mut Fq) {
+        *fe = mul_fq_by_nonresidue(fe);
+    }
+
+    #[inline(always)]
+    fn mul_by_frobenius_coeff(c1: &mut Fq, power: usize) {
+        c1.mul_assign(&FROBENIUS_COEFF_FQ2_C1[power % 2]);
+    }
+}
+
+/// An element of Fq2, represented by c0 + c1 * u.
+pub type Fq2 = QuadraticExtension<Fq2Parameters>;
+
+impl Fq2 {
+    /// Multiply this element by the cubic and quadratic nonresidue u.
+    pub fn mul_by_nonresidue(&mut self) {
+        // (c0 + c1 * u) * u = -5 * c1 + c0 * u
+        let t0 = self.c0;
+        self.c0 = mul_fq_by_nonresidue(&self.c1);
+        self.c1 = t0;
+    }
+}
+
+impl SqrtField for Fq2 {
+    fn legendre(&self) -> ::ff::LegendreSymbol {
+        self.norm().legendre()
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // Complex method, Algorithm 8, https://eprint.iacr.org/2012/685.pdf
+        // q = 1 mod 4 here, so the faster Algorithm 9 does not apply.
+
+        if self.c1.is_zero() {
+            // c0 is a square in Fq, or else c0 / u^2 is
+            return match self.c0.sqrt() {
+                Some(c0) => Some(Fq2 { c0, c1: Fq::zero() }),
+                None => {
+                    let u2_inv = mul_fq_by_nonresidue(&Fq::one()).inverse().unwrap();
+                    let mut tmp = self.c0;
+                    tmp.mul_assign(&u2_inv);
+                    tmp.sqrt().map(|c1| Fq2 { c0: Fq::zero(), c1 })
+                }
+            };
+        }
+
+        // alpha = sqrt(c0^2 - u^2 * c1^2)
+        let alpha = self.norm().sqrt()?;
+        let mut two_inv = Fq::one();
+        two_inv.double();
+        let two_inv = two_inv.inverse().unwrap();
+
+        // delta = (c0 + alpha) / 2 or (c0 - alpha) / 2, whichever is square
+        let mut delta = self.c0;
+        delta.add_assign(&alpha);
+        delta.mul_assign(&two_inv);
+        let c0 = match delta.sqrt() {
+            Some(c0) => c0,
+            None => {
+                delta = self.c0;
+                delta.sub_assign(&alpha);
+                delta.mul_assign(&two_inv);
+                delta.sqrt()?
+            }
+        };
+
+        // c1 = self.c1 / (2 * c0)
+        let mut c1 = c0;
+        c1.double();
+        c1 = c1.inverse()?;
+        c1.mul_assign(&self.c1);
+
+        Some(Fq2 { c0, c1 })
+    }
+}
+
+/// Fq2 implementation: hash to two elemnts of Fq and combine.
+impl FromRO for Fq2 {
+    type Length = U128;
+
+    fn from_ro(okm: &GenericArray<u8, U128>) -> Fq2 {
+        let c0 = Fq::from_okm(GenericArray::<u8, U64>::from_slice(&okm[..64]));
+        let c1 = Fq::from_okm(GenericArray::<u8, U64>::from_slice(&okm[64..]));
+        Fq2 { c0, c1 }
+    }
+}
+
+#[cfg(test)]
+use rand_core::SeedableRng;
+
+#[test]
+fn test_fq2_ordering() {
+    use core::cmp::Ordering;
+
+    let mut a = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::zero(),
+    };
+
+    let mut b = a;
+
+    assert!(a.cmp(&b) == Ordering::Equal);
+    b.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Equal);
+    b.c1.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c1.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Greater);
+    b.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Equal);
+}
+
+#[test]
+fn test_fq2_basics() {
+    assert_eq!(
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::zero(),
+        },
+        Fq2::zero()
+    );
+    assert_eq!(
+        Fq2 {
+            c0: Fq::one(),
+            c1: Fq::zero(),
+        },
+        Fq2::one()
+    );
+    assert!(Fq2::zero().is_zero());
+    assert!(!Fq2::one().is_zero());
+    assert!(!Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::one(),
+    }
+    .is_zero());
+}
+
+#[test]
+fn test_fq2_legendre() {
+    use ff::LegendreSymbol::*;
+
+    assert_eq!(Zero, Fq2::zero().legendre());
+    // -1 is already a square in Fq
+    let mut m1 = Fq2::one();
+    m1.negate();
+    assert_eq!(QuadraticResidue, m1.legendre());
+    m1.mul_by_nonresidue();
+    assert_eq!(QuadraticNonResidue, m1.legendre());
+}
+
+#[test]
+fn test_fq2_mul_nonresidue() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    let nqr = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::one(),
+    };
+
+    for _ in 0..1000 {
+        let mut a = Fq2::random(&mut rng);
+        let mut b = a;
+        a.mul_by_nonresidue();
+        b.mul_assign(&nqr);
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq2_sqrt_base_field() {
+    use ff::PrimeField;
+
+    // 15 is not a square in Fq, so its square root is a multiple of u
+    for a in &["4", "15"] {
+        let a = Fq2 {
+            c0: Fq::from_str(a).unwrap(),
+            c1: Fq::zero(),
+        };
+        let mut b = a.sqrt().unwrap();
+        b.square();
+        assert_eq!(a, b);
+    }
+    let a = Fq2 {
+        c0: Fq::from_str("15").unwrap(),
+        c1: Fq::zero(),
+    };
+    assert!(a.sqrt().unwrap().c0.is_zero());
+}
+
+#[test]
+fn test_fq2_sgn0() {
+    use crate::signum::{Sgn0Result, Signum0};
+
+    let mut a = Fq2::one();
+    assert_eq!(a.sgn0(), Sgn0Result::Negative);
+    a.negate();
+    assert_eq!(a.sgn0(), Sgn0Result::NonNegative);
+
+    // sign of c1 is used when c0 is zero
+    let mut a = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::one(),
+    };
+    assert_eq!(a.sgn0(), Sgn0Result::Negative);
+    a.negate();
+    assert_eq!(a.sgn0(), Sgn0Result::NonNegative);
+    assert_eq!(Fq2::zero().sgn0(), Sgn0Result::NonNegative);
+}
+
+#[test]
+fn fq2_field_tests() {
+    use ff::PrimeField;
+
+    crate::tests::field::random_field_tests::<Fq2>();
+    crate::tests::field::random_sqrt_tests::<Fq2>();
+    crate::tests::field::random_frobenius_tests::<Fq2, _>(super::fq::Fq::char(), 13);
+}
@@ -0,0 +1,111 @@
+use crate::{hash_to_field::BaseFromRO, hash_to_scalar::FromBytesWide};
+use digest::generic_array::{typenum::U48, GenericArray};
+use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
+
+#[derive(PrimeField, Zeroize)]
+#[PrimeFieldModulus = "8444461749428370424248824938781546531375899335154063827935233455917409239041"]
+#[PrimeFieldGenerator = "22"]
+pub struct Fr(FrRepr);
+
+/// set the default value for Fr to 0
+impl ::core::default::Default for Fr {
+    fn default() -> Self {
+        Fr::zero()
+    }
+}
+
+/// # Safety
+pub const unsafe fn transmute(r: FrRepr) -> Fr {
+    Fr(r)
+}
+
+// 2^192 in Montgomery form
+const F_2_192: Fr = Fr(FrRepr([
+    0x164e7d1ca58769c3u64,
+    0xe05cf9c05772b267u64,
+    0xb36f4a504642c78du64,
+    0x54197d589f85ef6u64,
+]));
+
+impl BaseFromRO for Fr {
+    type BaseLength = U48;
+
+    fn from_okm(okm: &GenericArray<u8, U48>) -> Fr {
+        // unwraps are safe here: we only use 24 bytes at a time, which is strictly less than r
+        let mut repr = FrRepr::default();
+        let mut buf = [0u8; 32];
+        buf[8..].copy_from_slice(&okm[..24]);
+        repr.read_be(&buf[..]).unwrap();
+        let mut elm = Fr::from_repr(repr).unwrap();
+        elm.mul_assign(&F_2_192);
+
+        buf[8..].copy_from_slice(&okm[24..]);
+        repr.read_be(&buf[..]).unwrap();
+        elm.add_assign(&Fr::from_repr(repr).unwrap());
+        elm
+    }
+}
+
+impl FromBytesWide for Fr {
+    fn from_bytes_wide(bytes: &[u8; 64]) -> Fr {
+        // unwraps are safe here: we use at most 24 bytes at a time, which is strictly less than r
+        let mut repr = FrRepr::default();
+        let mut buf = [0u8; 32];
+        buf[16..].copy_from_slice(&bytes[..16]);
+        repr.read_be(&buf[..]).unwrap();
+        let mut elm = Fr::from_repr(repr).unwrap();
+
+        for chunk in bytes[16..].chunks(24) {
+            elm.mul_assign(&F_2_192);
+            buf[8..].copy_from_slice(chunk);
+            repr.read_be(&buf[..]).unwrap();
+            elm.add_assign(&Fr::from_repr(repr).unwrap());
+        }
+        elm
+    }
+}
+
+#[test]
+fn test_fr_from_bytes_wide() {
+    assert_eq!(
+        Fr::from_bytes_wide(&[0xff; 64]),
+        Fr::from_str("508595941311779472113692600146818027278633330499214071737745792929336755578")
+            .unwrap()
+    );
+    assert!(Fr::from_bytes_wide(&[0; 64]).is_zero());
+}
+
+#[test]
+fn test_fr_root_of_unity() {
+    use ff::SqrtField;
+
+    assert_eq!(Fr::S, 47);
+    assert_eq!(
+        Fr::multiplicative_generator(),
+        Fr::from_repr(FrRepr::from(22)).unwrap()
+    );
+    assert_eq!(
+        Fr::multiplicative_generator().pow([
+            0xedfda00000021423,
+            0x9a3cb86f6002b354,
+            0xcabd34594aacc168,
+            0x2556
+        ]),
+        Fr::root_of_unity()
+    );
+    assert_eq!(Fr::root_of_unity().pow([1 << Fr::S]), Fr::one());
+    assert!(Fr::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn fr_field_tests() {
+    crate::tests::field::random_field_tests::<Fr>();
+    crate::tests::field::random_sqrt_tests::<Fr>();
+    crate::tests::field::random_frobenius_tests::<Fr, _>(Fr::char(), 13);
+    crate::tests::field::from_str_tests::<Fr>();
+}
+
+#[test]
+fn fr_repr_tests() {
+    crate::tests::repr::random_repr_tests::<Fr, FrRepr>();
+}
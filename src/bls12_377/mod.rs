@@ -0,0 +1,422 @@
+/*!
+The BLS12-377 pairing-friendly curve, whose scalar field has 2-adicity 47 and
+whose base field is the scalar field of BW6-761, for recursive proof systems.
+
+The curve is y^2 = x^3 + 1 over Fq, with the D-type sextic twist
+y^2 = x^3 + 1 / u over Fq2 = Fq[u] / (u^2 + 5). The pairing is the optimal
+ate pairing, with the Miller loop run over the bits of x = 0x8508c00000000001.
+Hashing to the curve uses the Shallue-van de Woestijne map, as the curves have
+j-invariant 0 and no isogeny is needed.
+*/
+
+mod cofactor;
+mod ec;
+mod fq;
+mod fq12;
+mod fq2;
+mod fq6;
+mod fr;
+mod svdw_map;
+
+pub use self::ec::{
+    G1Affine, G1Compressed, G1Prepared, G1Uncompressed, G2Affine, G2Compressed, G2Prepared,
+    G2Uncompressed, G1, G2,
+};
+pub use self::fq::{Fq, FqRepr};
+pub use self::fq12::{Fq12, Fq12Parameters};
+pub use self::fq2::{Fq2, Fq2Parameters};
+pub use self::fq6::{Fq6, Fq6Parameters};
+pub use self::fr::{Fr, FrRepr};
+
+pub mod transmute {
+    pub use super::ec::g1::transmute_affine as g1_affine;
+    pub use super::ec::g1::transmute_projective as g1_projective;
+    pub use super::ec::g2::transmute_affine as g2_affine;
+    pub use super::ec::g2::transmute_projective as g2_projective;
+    pub use super::fq::transmute as fq;
+    pub use super::fr::transmute as fr;
+}
+
+use super::{CurveAffine, Engine};
+
+use ff::{BitIterator, Field, ScalarEngine};
+
+// The BLS parameter x for BLS12-377 is 0x8508c00000000001
+const BLS_X: u64 = 0x8508c00000000001;
+
+#[derive(Clone, Debug)]
+pub struct Bls12_377;
+
+impl ScalarEngine for Bls12_377 {
+    type Fr = Fr;
+}
+
+impl Engine for Bls12_377 {
+    type G1 = G1;
+    type G1Affine = G1Affine;
+    type G2 = G2;
+    type G2Affine = G2Affine;
+    type Fq = Fq;
+    type Fqe = Fq2;
+    type Fqk = Fq12;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<
+            Item = &'a (
+                &'a <Self::G1Affine as CurveAffine>::Prepared,
+                &'a <Self::G2Affine as CurveAffine>::Prepared,
+            ),
+        >,
+    {
+        let mut pairs = vec![];
+        for &(p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, q.coeffs.iter()));
+            }
+        }
+
+        // Twisting isomorphism from E to E'
+        fn ell(f: &mut Fq12, coeffs: &(Fq2, Fq2, Fq2), p: &G1Affine) {
+            let mut c0 = coeffs.0;
+            let mut c1 = coeffs.1;
+
+            c0.c0.mul_assign(&p.y);
+            c0.c1.mul_assign(&p.y);
+
+            c1.c0.mul_assign(&p.x);
+            c1.c1.mul_assign(&p.x);
+
+            // Sparse multiplication in Fq12
+            f.mul_by_034(&c0, &c1, &coeffs.2);
+        }
+
+        let mut f = Fq12::one();
+
+        let mut found_one = false;
+        for i in BitIterator::new(&[BLS_X]) {
+            if !found_one {
+                found_one = i;
+                continue;
+            }
+
+            f.square();
+
+            for &mut (p, ref mut coeffs) in &mut pairs {
+                ell(&mut f, coeffs.next().unwrap(), &p.0);
+            }
+
+            if i {
+                for &mut (p, ref mut coeffs) in &mut pairs {
+                    ell(&mut f, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        f
+    }
+
+    fn final_exponentiation(r: &Fq12) -> Option<Fq12> {
+        let mut f1 = *r;
+        f1.conjugate();
+
+        match r.inverse() {
+            Some(mut f2) => {
+                // Easy part: r^((q^6 - 1) * (q^2 + 1))
+                let mut r = f1;
+                r.mul_assign(&f2);
+                f2 = r;
+                r.frobenius_map(2);
+                r.mul_assign(&f2);
+
+                fn exp_by_x(f: &Fq12) -> Fq12 {
+                    f.pow([BLS_X])
+                }
+
+                // Hard part: r^(3 (q^4 - q^2 + 1) / r), using
+                // 3 (q^4 - q^2 + 1) / r = 3 + (x - 1)^2 (x + q) (x^2 + q^2 - 1),
+                // Algorithm 1 of https://eprint.iacr.org/2020/875. Unlike the chain
+                // for BLS12-381 this does not need x to be even.
+                let mut y0 = r;
+                y0.square();
+                let mut y1 = exp_by_x(&r);
+                let mut y2 = r;
+                y2.conjugate();
+                y1.mul_assign(&y2);
+                y2 = exp_by_x(&y1);
+                y1.conjugate();
+                y1.mul_assign(&y2);
+                y2 = exp_by_x(&y1);
+                y1.frobenius_map(1);
+                y1.mul_assign(&y2);
+                r.mul_assign(&y0);
+                y0 = exp_by_x(&y1);
+                y2 = exp_by_x(&y0);
+                y0 = y1;
+                y0.frobenius_map(2);
+                y1.conjugate();
+                y1.mul_assign(&y2);
+                y1.mul_assign(&y0);
+                r.mul_assign(&y1);
+
+                Some(r)
+            }
+            None => None,
+        }
+    }
+}
+
+impl G2Prepared {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine) -> Self {
+        if q.is_zero() {
+            return G2Prepared {
+                coeffs: vec![],
+                infinity: true,
+            };
+        }
+
+        fn doubling_step(r: &mut G2) -> (Fq2, Fq2, Fq2) {
+            // Adaptation of Algorithm 26, https://eprint.iacr.org/2010/354.pdf
+            let mut tmp0 = r.x;
+            tmp0.square();
+
+            let mut tmp1 = r.y;
+            tmp1.square();
+
+            let mut tmp2 = tmp1;
+            tmp2.square();
+
+            let mut tmp3 = tmp1;
+            tmp3.add_assign(&r.x);
+            tmp3.square();
+            tmp3.sub_assign(&tmp0);
+            tmp3.sub_assign(&tmp2);
+            tmp3.double();
+
+            let mut tmp4 = tmp0;
+            tmp4.double();
+            tmp4.add_assign(&tmp0);
+
+            let mut tmp6 = r.x;
+            tmp6.add_assign(&tmp4);
+
+            let mut tmp5 = tmp4;
+            tmp5.square();
+
+            let mut zsquared = r.z;
+            zsquared.square();
+
+            r.x = tmp5;
+            r.x.sub_assign(&tmp3);
+            r.x.sub_assign(&tmp3);
+
+            r.z.add_assign(&r.y);
+            r.z.square();
+            r.z.sub_assign(&tmp1);
+            r.z.sub_assign(&zsquared);
+
+            r.y = tmp3;
+            r.y.sub_assign(&r.x);
+            r.y.mul_assign(&tmp4);
+
+            tmp2.double();
+            tmp2.double();
+            tmp2.double();
+
+            r.y.sub_assign(&tmp2);
+
+            tmp3 = tmp4;
+            tmp3.mul_assign(&zsquared);
+            tmp3.double();
+            tmp3.negate();
+
+            tmp6.square();
+            tmp6.sub_assign(&tmp0);
+            tmp6.sub_assign(&tmp5);
+
+            tmp1.double();
+            tmp1.double();
+
+            tmp6.sub_assign(&tmp1);
+
+            tmp0 = r.z;
+            tmp0.mul_assign(&zsquared);
+            tmp0.double();
+
+            (tmp0, tmp3, tmp6)
+        }
+
+        fn addition_step(r: &mut G2, q: &G2Affine) -> (Fq2, Fq2, Fq2) {
+            // Adaptation of Algorithm 27, https://eprint.iacr.org/2010/354.pdf
+            let mut zsquared = r.z;
+            zsquared.square();
+
+            let mut ysquared = q.y;
+            ysquared.square();
+
+            let mut t0 = zsquared;
+            t0.mul_assign(&q.x);
+
+            let mut t1 = q.y;
+            t1.add_assign(&r.z);
+            t1.square();
+            t1.sub_assign(&ysquared);
+            t1.sub_assign(&zsquared);
+            t1.mul_assign(&zsquared);
+
+            let mut t2 = t0;
+            t2.sub_assign(&r.x);
+
+            let mut t3 = t2;
+            t3.square();
+
+            let mut t4 = t3;
+            t4.double();
+            t4.double();
+
+            let mut t5 = t4;
+            t5.mul_assign(&t2);
+
+            let mut t6 = t1;
+            t6.sub_assign(&r.y);
+            t6.sub_assign(&r.y);
+
+            let mut t9 = t6;
+            t9.mul_assign(&q.x);
+
+            let mut t7 = t4;
+            t7.mul_assign(&r.x);
+
+            r.x = t6;
+            r.x.square();
+            r.x.sub_assign(&t5);
+            r.x.sub_assign(&t7);
+            r.x.sub_assign(&t7);
+
+            r.z.add_assign(&t2);
+            r.z.square();
+            r.z.sub_assign(&zsquared);
+            r.z.sub_assign(&t3);
+
+            let mut t10 = q.y;
+            t10.add_assign(&r.z);
+
+            let mut t8 = t7;
+            t8.sub_assign(&r.x);
+            t8.mul_assign(&t6);
+
+            t0 = r.y;
+            t0.mul_assign(&t5);
+            t0.double();
+
+            r.y = t8;
+            r.y.sub_assign(&t0);
+
+            t10.square();
+            t10.sub_assign(&ysquared);
+
+            let mut ztsquared = r.z;
+            ztsquared.square();
+
+            t10.sub_assign(&ztsquared);
+
+            t9.double();
+            t9.sub_assign(&t10);
+
+            t10 = r.z;
+            t10.double();
+
+            t6.negate();
+
+            t1 = t6;
+            t1.double();
+
+            (t10, t1, t9)
+        }
+
+        let mut coeffs = vec![];
+        let mut r: G2 = q.into();
+
+        let mut found_one = false;
+        for i in BitIterator::new([BLS_X]) {
+            if !found_one {
+                found_one = i;
+                continue;
+            }
+
+            coeffs.push(doubling_step(&mut r));
+
+            if i {
+                coeffs.push(addition_step(&mut r, &q));
+            }
+        }
+
+        G2Prepared {
+            coeffs,
+            infinity: false,
+        }
+    }
+}
+
+#[test]
+fn bls12_377_engine_tests() {
+    crate::tests::engine::engine_tests::<Bls12_377>();
+}
+
+#[test]
+fn test_final_exponentiation() {
+    use ff::PrimeField;
+    use rand_core::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // 3 (q^4 - q^2 + 1) / r
+    let hard_part = [
+        0x3,
+        0x8a442f9920000000,
+        0x3a42c0cbe68b3800,
+        0x54d289e23b0cd396,
+        0xdee3c5e1efd2ee76,
+        0x7795ade9113d3e31,
+        0x360e2bd1e9ef628,
+        0x238bda81dcfd878b,
+        0xa0ff59ff6e58f1,
+        0x75ccf89bfa1ed210,
+        0xf6754bee3d52ea18,
+        0x4fa899c878786041,
+        0xfdc6fc6858837cb3,
+        0x6531c5fd5c369f8c,
+        0x4fda0f88c67119e6,
+        0x3ceca8d4866ab171,
+        0xb1c15defcda8f16f,
+        0x106357ab04cf4b3d,
+        0x165e87883281639d,
+        0x148244aca56,
+    ];
+
+    for _ in 0..5 {
+        let f = Fq12::random(&mut rng);
+
+        // f^((q^6 - 1) * (q^2 + 1))
+        let mut easy = f;
+        easy.conjugate();
+        easy.mul_assign(&f.inverse().unwrap());
+        let mut tmp = easy;
+        tmp.frobenius_map(2);
+        easy.mul_assign(&tmp);
+
+        let expected = easy.pow(hard_part);
+        let result = Bls12_377::final_exponentiation(&f).unwrap();
+        assert_eq!(result, expected);
+        assert_eq!(result.pow(Fr::char()), Fq12::one());
+    }
+
+    assert!(Bls12_377::final_exponentiation(&Fq12::zero()).is_none());
+}
@@ -0,0 +1,62 @@
+/*!
+Constants for the Shallue-van de Woestijne map for G1
+*/
+
+use crate::bls12_377::{fq::B_COEFF, Fq, FqRepr, G1};
+use crate::hash_to_curve::svdw::{svdw_help, SvdwConsts, SvdwMap};
+use ff::Field;
+
+// c1 = g(Z) = 2 for Z = 1
+pub(super) const SVDW_C1: Fq = Fq(FqRepr([
+    0x59bfffffffffed0,
+    0xa2813f06ffffff62,
+    0x3efb675314fa7fe4,
+    0xf69d2f6edcf8c60b,
+    0x99e92b7f007909d0,
+    0x11accc3c5fbe934,
+]));
+
+// c2 = -Z / 2
+pub(super) const SVDW_C2: Fq = Fq(FqRepr([
+    0x3a1c0000000004d,
+    0xee6b0d8270000028,
+    0x4f34885af4caa806,
+    0xdc7b8e1749b6e20c,
+    0x9fc0bae0ac8306c6,
+    0x16787152646169d,
+]));
+
+// c3 = sqrt(-g(Z) * 3 Z^2), with sgn0(c3) = 0
+pub(super) const SVDW_C3: Fq = Fq(FqRepr([
+    0x1d310ec61bb69c79,
+    0xfedd1500ad6fa28b,
+    0xbf158fb55d2e5c06,
+    0xb72c352759ed109e,
+    0x35fc81c3418e226c,
+    0x2a7002fe17a55d,
+]));
+
+// c4 = -4 g(Z) / (3 Z^2)
+pub(super) const SVDW_C4: Fq = Fq(FqRepr([
+    0xa9e65555555556ec,
+    0xf0b8285195555628,
+    0xd54aa3d0dc13b579,
+    0x2f5ce35adaa5bcaf,
+    0x906d2301e58aff38,
+    0xc4920317b6df9d,
+]));
+
+impl SvdwMap for G1 {
+    fn svdw_map(u: &Fq) -> G1 {
+        let consts = SvdwConsts {
+            b: B_COEFF,
+            z: Fq::one(),
+            c1: SVDW_C1,
+            c2: SVDW_C2,
+            c3: SVDW_C3,
+            c4: SVDW_C4,
+        };
+        let (x, y) = svdw_help(u, &consts);
+        G1 { x, y, z: Fq::one() }
+    }
+}
@@ -0,0 +1,112 @@
+/*!
+Constants for the Shallue-van de Woestijne map for G2
+*/
+
+use crate::bls12_377::{fq::B_COEFF_FQ2, Fq, Fq2, FqRepr, G2};
+use crate::hash_to_curve::svdw::{svdw_help, SvdwConsts, SvdwMap};
+use ff::Field;
+
+// Z = 2
+pub(super) const SVDW_Z: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0x59bfffffffffed0,
+        0xa2813f06ffffff62,
+        0x3efb675314fa7fe4,
+        0xf69d2f6edcf8c60b,
+        0x99e92b7f007909d0,
+        0x11accc3c5fbe934,
+    ])),
+    c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+};
+
+// c1 = g(Z) = 8 + 1 / u
+pub(super) const SVDW_C1: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0xc5e7ffffffffb3e,
+        0x5bee41939ffffd87,
+        0xbe06d8ecdfd76f92,
+        0xa62f09d571f8f10e,
+        0xdb2ea27b28a194cd,
+        0x10ebe82e86582fc,
+    ])),
+    c1: Fq(FqRepr([
+        0x8072266666666685,
+        0x8df55926899999a9,
+        0x7fe4561ad64f34cf,
+        0xb95da6d8b6e4f01b,
+        0x4b747cccfc142743,
+        0x39c3fa70f49f43,
+    ])),
+};
+
+// c2 = -Z / 2
+pub(super) const SVDW_C2: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0x823ac00000000099,
+        0xc5cabdc0b000004f,
+        0x7f75ae862f8c080d,
+        0x9ed4423b9278b089,
+        0x79467000ec64c452,
+        0x120d3e434c71c50,
+    ])),
+    c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0, 0x0, 0x0])),
+};
+
+// c3 = sqrt(-g(Z) * 3 Z^2), with sgn0(c3) = 0
+pub(super) const SVDW_C3: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0xf2e4443443a239a0,
+        0xb1706a328d2d4baa,
+        0xd408869c3d3950ef,
+        0x8c5b740d00dc2848,
+        0x9c5df20787ab65b2,
+        0x11985aa9c872bda,
+    ])),
+    c1: Fq(FqRepr([
+        0x977c4ed70661597a,
+        0xed9b151a15f45247,
+        0xf71665a9a86de5ac,
+        0x62b3059de3585834,
+        0x87d05ad0803bd9ea,
+        0x110a4dcdaecddb4,
+    ])),
+};
+
+// c4 = -4 g(Z) / (3 Z^2)
+pub(super) const SVDW_C4: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0xa9e65555555556ec,
+        0xf0b8285195555628,
+        0xd54aa3d0dc13b579,
+        0x2f5ce35adaa5bcaf,
+        0x906d2301e58aff38,
+        0xc4920317b6df9d,
+    ])),
+    c1: Fq(FqRepr([
+        0x56dcddddddddddd4,
+        0x2db2015f37777772,
+        0x8a5a595c4be8b110,
+        0x2041bbb36e056126,
+        0x7e422da67ad9b5fd,
+        0x7c276e8cf025e2,
+    ])),
+};
+
+impl SvdwMap for G2 {
+    fn svdw_map(u: &Fq2) -> G2 {
+        let consts = SvdwConsts {
+            b: B_COEFF_FQ2,
+            z: SVDW_Z,
+            c1: SVDW_C1,
+            c2: SVDW_C2,
+            c3: SVDW_C3,
+            c4: SVDW_C4,
+        };
+        let (x, y) = svdw_help(u, &consts);
+        G2 {
+            x,
+            y,
+            z: Fq2::one(),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+/*!
+Shallue-van de Woestijne maps for G1 and G2
+see: section 6.6.1 of https://www.rfc-editor.org/rfc/rfc9380
+
+Both curves have A = 0, so the simplified SWU map would need an isogeny; the
+SvdW map works on the curves directly. The map itself is in hash_to_curve::svdw.
+*/
+
+mod g1;
+mod g2;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,237 @@
+/*!
+Tests for the Shallue-van de Woestijne map
+*/
+
+use crate::{
+    bls12_377::{
+        fq::{B_COEFF, B_COEFF_FQ2},
+        Fq, Fq2, G1, G2,
+    },
+    hash_to_curve::{
+        svdw::{eval_g, SvdwMap},
+        HashToCurve,
+    },
+    signum::{Sgn0Result, Signum0},
+    CurveAffine, CurveProjective, EncodedPoint,
+};
+use ff::{Field, PrimeField, SqrtField};
+use rand_core::SeedableRng;
+
+/// check the map constants against their definitions
+fn check_consts<F: SqrtField + Signum0>(b: &F, z: &F, c1: &F, c2: &F, c3: &F, c4: &F) {
+    let gz = eval_g(z, b);
+    assert_eq!(*c1, gz);
+
+    // 2 * c2 = -Z
+    let mut tmp = *c2;
+    tmp.double();
+    tmp.add_assign(z);
+    assert!(tmp.is_zero());
+
+    // c3^2 = -3 Z^2 g(Z), sgn0(c3) = 0
+    let mut z2 = *z;
+    z2.square();
+    let mut m3z2gz = gz;
+    m3z2gz.mul_assign(&z2);
+    let tmp = m3z2gz;
+    m3z2gz.double();
+    m3z2gz.add_assign(&tmp);
+    m3z2gz.negate();
+    let mut tmp = *c3;
+    tmp.square();
+    assert_eq!(tmp, m3z2gz);
+    assert_eq!(c3.sgn0(), Sgn0Result::NonNegative);
+
+    // 3 Z^2 * c4 = -4 g(Z)
+    let mut tmp = *c4;
+    tmp.mul_assign(&z2);
+    let t = tmp;
+    tmp.double();
+    tmp.add_assign(&t);
+    let mut m4gz = gz;
+    m4gz.double();
+    m4gz.double();
+    m4gz.negate();
+    assert_eq!(tmp, m4gz);
+}
+
+#[test]
+fn test_svdw_consts() {
+    {
+        use super::g1::{SVDW_C1, SVDW_C2, SVDW_C3, SVDW_C4};
+        check_consts(&B_COEFF, &Fq::one(), &SVDW_C1, &SVDW_C2, &SVDW_C3, &SVDW_C4);
+        assert_eq!(SVDW_C1, Fq::from_str("2").unwrap());
+    }
+    {
+        use super::g2::{SVDW_C1, SVDW_C2, SVDW_C3, SVDW_C4, SVDW_Z};
+        check_consts(
+            &B_COEFF_FQ2,
+            &SVDW_Z,
+            &SVDW_C1,
+            &SVDW_C2,
+            &SVDW_C3,
+            &SVDW_C4,
+        );
+        assert_eq!(
+            SVDW_Z,
+            Fq2 {
+                c0: Fq::from_str("2").unwrap(),
+                c1: Fq::zero(),
+            }
+        );
+    }
+}
+
+#[test]
+fn test_svdw_g1() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // exceptional case: zero
+    let p = G1::svdw_map(&Fq::zero()).into_affine();
+    assert!(!p.is_zero());
+    assert!(p.into_uncompressed().into_affine_unchecked().is_ok());
+
+    for _ in 0..100 {
+        let u = Fq::random(&mut rng);
+        let p = G1::svdw_map(&u).into_affine();
+        let (x, y) = p.as_tuple();
+        assert_eq!(u.sgn0(), y.sgn0());
+        let mut y2 = *y;
+        y2.square();
+        assert_eq!(y2, eval_g(x, &B_COEFF));
+    }
+}
+
+#[test]
+fn test_svdw_g2() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // exceptional case: zero
+    let p = G2::svdw_map(&Fq2::zero()).into_affine();
+    assert!(!p.is_zero());
+    assert!(p.into_uncompressed().into_affine_unchecked().is_ok());
+
+    for _ in 0..100 {
+        let u = Fq2::random(&mut rng);
+        let p = G2::svdw_map(&u).into_affine();
+        let (x, y) = p.as_tuple();
+        assert_eq!(u.sgn0(), y.sgn0());
+        let mut y2 = *y;
+        y2.square();
+        assert_eq!(y2, eval_g(x, &B_COEFF_FQ2));
+    }
+}
+
+#[test]
+fn test_hash_to_curve_bls12_377() {
+    use crate::{hash_to_field::ExpandMsgXmd, SubgroupCheck};
+    use sha2::Sha256;
+
+    const DST: &[u8] = b"pairing-plus-bls12-377-test";
+    for msg in &[&b""[..], b"abc", b"abcdef0123456789"] {
+        let p = <G1 as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(msg, DST).into_affine();
+        assert!(!p.is_zero() && p.in_subgroup());
+        let q = <G1 as HashToCurve<ExpandMsgXmd<Sha256>>>::encode_to_curve(msg, DST).into_affine();
+        assert!(!q.is_zero() && q.in_subgroup());
+        assert_ne!(p, q);
+
+        let p = <G2 as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(msg, DST).into_affine();
+        assert!(!p.is_zero() && p.in_subgroup());
+        let q = <G2 as HashToCurve<ExpandMsgXmd<Sha256>>>::encode_to_curve(msg, DST).into_affine();
+        assert!(!q.is_zero() && q.in_subgroup());
+        assert_ne!(p, q);
+    }
+}
+
+/// Known-answer tests in the format of appendix J of RFC 9380, which has no
+/// BLS12-377 suites. The vectors were computed with an independent
+/// implementation of sections 5.2, 6.6.1 and 7 of the RFC, with Z = 1 for G1
+/// and Z = 2 for G2 as chosen by find_z_svdw in appendix H.1, and with
+/// clear_cofactor the multiplication by h1 and h2. Points are in the
+/// uncompressed encoding of this crate.
+type Xmd = crate::hash_to_field::ExpandMsgXmd<sha2::Sha256>;
+
+fn check_vectors<P: CurveProjective>(map: impl Fn(&[u8]) -> P, tests: &[(&[u8], &str)]) {
+    for (msg, p) in tests {
+        let a = map(msg).into_affine().into_uncompressed();
+        assert_eq!(hex::encode(a.as_ref()), *p);
+    }
+}
+
+#[test]
+fn hash_to_curve_g1_ro() {
+    //suite   = BLS12377G1_XMD:SHA-256_SVDW_RO_
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12377G1_XMD:SHA-256_SVDW_RO_";
+    let tests: [(&[u8], &str); 5] = [
+        (b"", "01a20a6dd2456b9a27408be3d2e4c3064e22e3bb6b9efc134cb6911101fb834cc7ba5987aec2016db155e1456b82f5870120aa3a3ac3d9ae8003d5fa20750f3564867fde3b6df00abff35602d9a5bdfeb01e3fe813538b42e7da96a727c1663e"),
+        (b"abc", "00c0b87c9ee74d7cccfb61b55e1370e753aba30353983e13c656ed1a141d50239b31ac2cb0992e872ec87f22df84622300a3a30c2e3bccf580637bc077fe3f145f96e6771affe49ca489691ff34e5569135aecdad526d5390381d56b9c0d6ade"),
+        (b"abcdef0123456789", "00ee7e4e53846bb77118eab0dbfe812518b3bd86a4757dff63ad52d10d7551b5b38ad45a18bf1dcd89bc06615ef8337e017362a9ccaa3ca9ae4dafe77e478c72092bfb5b4b3bd4d141406e296dcfb3575ccd1c470b6d03fb5685dd1addaeaa2e"),
+        (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", "0151b85e64a5a42ee9c18c687ba20ad4039e7edd57c497f35ebdba80a630529cd62771e709e976c179080fdbd1fd3cf5010ff1e1e185410bd3f606d21f513aded9d9519c82311a649b7706202c34b2a9a862f173c7d871cf437b2502260b4bfd"),
+        (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "004c517ba6da82d7584948074bedc4d15a005a181815bd1096ea4186e8dba93ea3f7d73a1037358697d01843c80886490035eb601b380344f5afc7db4feffbeb33b7b438d50f73ac9e3f65e65a3f83ea50c05a2e519019fb03d7a3804b5f0fa3"),
+    ];
+
+    check_vectors(
+        |msg| <G1 as HashToCurve<Xmd>>::hash_to_curve(msg, DST),
+        &tests,
+    );
+}
+
+#[test]
+fn encode_to_curve_g1() {
+    //suite   = BLS12377G1_XMD:SHA-256_SVDW_NU_
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12377G1_XMD:SHA-256_SVDW_NU_";
+    let tests: [(&[u8], &str); 5] = [
+        (b"", "013137d6778520b50da38db5dc6a990ddee0d2cde9bead03b58d5a4239ce417bbf4479b4ff07b42fc9db84f2a0ea9d78014f28c8146b88f404fc0ecd18101dd3bc089513a8de3e644fb403f092f67d54e9ce6cfd885e8105b38939a3a10d9fc0"),
+        (b"abc", "00c2d5bb14432eab9b9ed681bfd13ca7c2a85717a86d47e73a6349a66dcd2fbf43fd44eeb526e96774d291d0a50999050179bc5c6aeba4f57d50a42538c7b9ca42180a56f4bfe90adf666732331ce78fa5faa9ca8310d98b16c7ad76f96b6fd8"),
+        (b"abcdef0123456789", "01a5887680b266acafff257a086d3f9cd01ff36c3a49db5f5a01804343eff9819d14280cc1cb423a7619d9553a5d399900624225e6966883c40c36048fb77bae6d3fdc9d558e194b7d460e21e0fd95572c00b8a6eb4ba8d152b36cacc105ef46"),
+        (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", "006bda77a236a33808b48e93ed7adc77f15b995b9a895ed14a694af355b96093fcc0aa83f32aa1829424b07f3060e47f010a02cff7bdf08164cf0445845b02819a765f900961a08b992f252a93c4160f9f29f9036b7f82f28b2029dc8b7fc6b1"),
+        (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "013be62b6c09d069c5b17841e34666a59325924cb49c38eb67c2df05fa764006bddd226834b4222c54c8555737f9a13a009f17046e0b69a0f252ae427c3986adc5abe672495d1463e941bbe6b5cf8444cb2e1df4d0d8fce24d80d801f0c64da2"),
+    ];
+
+    check_vectors(
+        |msg| <G1 as HashToCurve<Xmd>>::encode_to_curve(msg, DST),
+        &tests,
+    );
+}
+
+#[test]
+fn hash_to_curve_g2_ro() {
+    //suite   = BLS12377G2_XMD:SHA-256_SVDW_RO_
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12377G2_XMD:SHA-256_SVDW_RO_";
+    let tests: [(&[u8], &str); 5] = [
+        (b"", "0125ef404cf0a66d95869504a439965ebcb725a22393a093f50de5fc1b6e69ba053ff500df2c07d53875a3126b6151bf013d9c1193c5367b0b78d4b5b7268bc8ed6b75ac5d5540e19e42d56282391053510e3ea0c58fdfc301d10d12bfa49490003430d1d577bfd8e55de683b54dda580c5f9d0b944a37f00bc42911cdf1f7ed50a438006f29501e2d974ba0c53afd620011b7fc212d5c2408ffe70bb24fd1eb5b201242b385c902679b485a147546fef8e60a4f5662ac4f0ce922e7949e8864"),
+        (b"abc", "006676579e387eff12096c48f970524d712025d85323bb52eda48c544c409ee06b06116cbcc4b7df090cb710377684e40143107d0ea42beaf68c42888fc1d3a5574dd399398e305700228e812fb356ecd12b684f5a6b9b6e937a13b2eab387d000359b8986624633a39142678aa424ab1e842a6b3f91a47580f4e40f7b484ae10694c4105e2d4a0d470b4a4b5476de5301464232b4e762e817f89040f63ce1018c45ac5a7c8997ba2fe1ebda282ba74f317c94fc3e1545a680f41e996b9b0fed"),
+        (b"abcdef0123456789", "00c04776870d3dca452f0b7ee3b8657401f97fcae3e13505ecfed1953d3ad0ab83f8e6a5c388db0f32e04af1e1ec9acf003d891ebfa0eab4144ced7e96c478edeabf36a632e0ad6c728524abb3ef6d0c5bc39fefd2b9916b3164935abb99e50a0004ab76bd62f778a00da10d604ef700f8917a5091903801203a9309fda0fb1a3682a0c5147c964f4b82def0bdb4eccd0091a118ccb013df60932342093b37009558691515da21fd4c0a6579f3d738b1a6fbdc9effd239b3eee7e45645adb9c8"),
+        (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", "012016bdff8160088990fcf6002cdc17ef1e5f15306c4fcc01267662e8b3d2e081d3fb424054f61c1853a92681d146b800d034bf013ca64d765a9083cb0b1369fea66eb20ac8ac4418d1dcb4e9628c55017eddffb3cb27cac2e4c0a8b0ff0493017c677a0382d388bc5301a699ccce8fe9e044215ad06dd7efef4a8d7f5a703f7cf2b1c5f2b5ab8e35c45ad1d965f2130183852a34b4f60e5f552be541a298437dfdfc42ffc8b94fc4de222625a6fc5abf309f87ffc091e7d9edf32ab18df9b8"),
+        (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "00b4bbf482afdda97ab2e3cb702494a2797ccb75e70b23b1c4a467cd52c63fb5031f39915e65b3ce14c7beb816a8d4c400262f168c563f1e4e1e5d6497f71e03643a30f2b68b56853892d8b491dc23343af43b60c53e72daa768b004a21abbf20157cb69272af4c2f68b91bf90bb28648f350de981054f78d5642f1cafe283122b3c5febb2034cb9485d3e6b4498e608019944501ab628a974f09898e63a79a7b22aadc520f44e0391bf62060391bbd16c0bafbb278daec82e0c56b97054f383"),
+    ];
+
+    check_vectors(
+        |msg| <G2 as HashToCurve<Xmd>>::hash_to_curve(msg, DST),
+        &tests,
+    );
+}
+
+#[test]
+fn encode_to_curve_g2() {
+    //suite   = BLS12377G2_XMD:SHA-256_SVDW_NU_
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BLS12377G2_XMD:SHA-256_SVDW_NU_";
+    let tests: [(&[u8], &str); 5] = [
+        (b"", "00811c109a55840f18336c806878ec6a0c22023d4f03223c27efbeb17d1a6656174dee1101fa7154cb1bb2c0356949ee00eaab5dbf286080da64fba9616fe33ec286da0ec39dfc6975d60de4e533218aa9e1dd3c39e3b379b657e76875979efc013cfbcf1abc527d779cf46631ae7ca37c73ab0e840b0a21c4ffd40f2bf188be269b93f438cfa79aa20ff71c600acb41008d917fa2cfb1eac4b2f66526dc96373e843f81ad1ec40e015720af72c352386c5ae8474fdc8ea5d62dcc28f2a98bb3"),
+        (b"abc", "017a0be2769c7cf8159adcc92ca04e7f3d41b0d40c806259f69318fcdd16ac430e7a9e14e7c22bab94c5856d57dc3cde005bb0749418489f38c961a724cc95982ef25f612cfbf792def4f415079478f1cf602cb5b937228d5a275dff7dc2544500aed7acac70f57ac80f3e75d36adc9c0106bf8e0d1b57eb111b7ab47b3355c3c8c5522d9b8f6d9c5206367c0795340e0064b3792467172662ce0d7f42bc7e097aab0b24c0c3fea5b7e1e479912b70eaf2494ff2655375168817ac345693e70d"),
+        (b"abcdef0123456789", "01287473bb6c5075068effd7c995e689dea9369bf9088cb1f98a7369dfbfa8a975630efee8ecaa98d13a3535eea606ba00ba6fa9c463cc0e342a374e776f3f738f549d5570fc3648fa8e5db1d668b698a966d653a69eb89c1f7fd5e5a03b4e2d000123d90e3fd6dba8038fe88dafaffdcce327d82d8d25a8933c1336b440bf7c14461afb602ba01bf922f08342867a2f018e98b4c940bc5b549b23d736a0541798de9ba96a97a9a56e41146c8fae636bb58bfcc88e01ad6d05de215bd6447ddf"),
+        (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", "0194a4c8923f3f9938a985695ba1354645d046165cee83ed44e40af4794352d55db0475438f22fee927dc53cadc2201901867d5dadab9a31f4198ed310b7a837c3046eb16ccaa955ecdcfcea0d4cd9bf1bb49e866ee2c9d93b99b05a90007f0a00f55da9294f1a8d862414b8ac889b6f2987d0d4b0cc07768d58adeb17e5e9597ed11828eb68646d061c1a6297ea60b6008d65be3dc171bc2cc1571d641a65e21a53526732311c469461610872e98d81688d18346c1f5c74c6141060605e964e"),
+        (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "00553298c4ce3ba5ddf810060973283765d9ae5c3448dfee2d36a3e621b5e58b51b5500029ae1874b1f3907da3470eeb0165114c739251f84f8832aa262df93c2a9021960820a8a8ce0ee57b69181b32960dfd1f9ca59bb08f811ed1a96639350073560a134e31a36445839e1e241fd2c8e64ad7e11eaa2eadcce3d5c36582d19a673621f83bbf125edccfed34eca1d400c7b838cdd905fde7cb0d0577f41b4b0bb6046de682165ca3aa3eb5a4d6b2479d309a8f0501b73232834a9d764d25cd"),
+    ];
+
+    check_vectors(
+        |msg| <G2 as HashToCurve<Xmd>>::encode_to_curve(msg, DST),
+        &tests,
+    );
+}
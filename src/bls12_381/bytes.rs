@@ -0,0 +1,253 @@
+/*!
+Fixed-size byte encodings of BLS12-381 field elements and points.
+
+Prime field elements are encoded as their canonical representative, in
+either byte order. Extension field elements are the concatenation of their
+coefficients in the order c0, c1 (and c2), recursively, so the big-endian
+encoding of an `Fq12` matches the one written by `SerDes`. Points use the
+ZCash compressed and uncompressed formats of `EncodedPoint`.
+*/
+
+use super::{
+    Fq, Fq12, Fq2, Fq6, FqRepr, Fr, FrRepr, G1Affine, G1Compressed, G1Uncompressed, G2Affine,
+    G2Compressed, G2Uncompressed,
+};
+use crate::{CurveAffine, EncodedPoint, Error};
+use ff::{PrimeField, PrimeFieldRepr};
+
+macro_rules! prime_field_bytes_impl {
+    ($field:ident, $repr:ident, $len:expr) => {
+        impl $field {
+            /// Big-endian encoding of the canonical representative.
+            pub fn to_bytes_be(&self) -> [u8; $len] {
+                let mut bytes = [0u8; $len];
+                // cannot fail: the buffer has the size of the representation
+                self.into_repr().write_be(&mut bytes[..]).unwrap();
+                bytes
+            }
+
+            /// Little-endian encoding of the canonical representative.
+            pub fn to_bytes_le(&self) -> [u8; $len] {
+                let mut bytes = [0u8; $len];
+                self.into_repr().write_le(&mut bytes[..]).unwrap();
+                bytes
+            }
+
+            /// Decodes a big-endian canonical representative. Fails if it is
+            /// not less than the modulus.
+            pub fn from_bytes_be(bytes: &[u8; $len]) -> Result<Self, Error> {
+                let mut repr = $repr::default();
+                repr.read_be(&bytes[..]).unwrap();
+                $field::from_repr(repr)
+                    .map_err(|e| Error::NonCanonicalFieldElement(stringify!($field), e))
+            }
+
+            /// Decodes a little-endian canonical representative. Fails if it
+            /// is not less than the modulus.
+            pub fn from_bytes_le(bytes: &[u8; $len]) -> Result<Self, Error> {
+                let mut repr = $repr::default();
+                repr.read_le(&bytes[..]).unwrap();
+                $field::from_repr(repr)
+                    .map_err(|e| Error::NonCanonicalFieldElement(stringify!($field), e))
+            }
+        }
+    };
+}
+
+prime_field_bytes_impl!(Fr, FrRepr, 32);
+prime_field_bytes_impl!(Fq, FqRepr, 48);
+
+macro_rules! ext_field_bytes_impl {
+    ($field:ident, $base:ident, $base_len:expr, $len:expr, [$($c:ident),+]) => {
+        impl $field {
+            /// Big-endian encoding: the coefficients in order, each big-endian.
+            pub fn to_bytes_be(&self) -> [u8; $len] {
+                let mut bytes = [0u8; $len];
+                let mut chunks = bytes.chunks_mut($base_len);
+                $(chunks.next().unwrap().copy_from_slice(&self.$c.to_bytes_be());)+
+                bytes
+            }
+
+            /// Little-endian encoding: the coefficients in order, each
+            /// little-endian.
+            pub fn to_bytes_le(&self) -> [u8; $len] {
+                let mut bytes = [0u8; $len];
+                let mut chunks = bytes.chunks_mut($base_len);
+                $(chunks.next().unwrap().copy_from_slice(&self.$c.to_bytes_le());)+
+                bytes
+            }
+
+            /// Decodes the output of `to_bytes_be`. Fails if a coefficient is
+            /// not canonical.
+            pub fn from_bytes_be(bytes: &[u8; $len]) -> Result<Self, Error> {
+                let mut chunks = bytes.chunks($base_len);
+                Ok($field {
+                    $($c: $base::from_bytes_be(&sub_array(chunks.next().unwrap()))?,)+
+                })
+            }
+
+            /// Decodes the output of `to_bytes_le`. Fails if a coefficient is
+            /// not canonical.
+            pub fn from_bytes_le(bytes: &[u8; $len]) -> Result<Self, Error> {
+                let mut chunks = bytes.chunks($base_len);
+                Ok($field {
+                    $($c: $base::from_bytes_le(&sub_array(chunks.next().unwrap()))?,)+
+                })
+            }
+        }
+    };
+}
+
+/// Copies a chunk of an encoding into an array of the coefficient size.
+fn sub_array<const N: usize>(chunk: &[u8]) -> [u8; N] {
+    let mut a = [0u8; N];
+    a.copy_from_slice(chunk);
+    a
+}
+
+ext_field_bytes_impl!(Fq2, Fq, 48, 96, [c0, c1]);
+ext_field_bytes_impl!(Fq6, Fq2, 96, 288, [c0, c1, c2]);
+ext_field_bytes_impl!(Fq12, Fq6, 288, 576, [c0, c1]);
+
+macro_rules! point_bytes_impl {
+    ($affine:ident, $compressed:ident, $compressed_len:expr, $uncompressed:ident, $uncompressed_len:expr) => {
+        impl $affine {
+            /// The compressed encoding of the point.
+            pub fn to_compressed_bytes(&self) -> [u8; $compressed_len] {
+                sub_array(self.into_compressed().as_ref())
+            }
+
+            /// The uncompressed encoding of the point.
+            pub fn to_uncompressed_bytes(&self) -> [u8; $uncompressed_len] {
+                sub_array(self.into_uncompressed().as_ref())
+            }
+
+            /// Decodes a compressed point, checking that it is on the curve
+            /// and in the prime-order subgroup.
+            pub fn from_compressed_bytes(bytes: &[u8; $compressed_len]) -> Result<Self, Error> {
+                let mut encoded = $compressed::empty();
+                encoded.as_mut().copy_from_slice(bytes);
+                encoded.into_affine()
+            }
+
+            /// Decodes an uncompressed point, checking that it is on the
+            /// curve and in the prime-order subgroup.
+            pub fn from_uncompressed_bytes(bytes: &[u8; $uncompressed_len]) -> Result<Self, Error> {
+                let mut encoded = $uncompressed::empty();
+                encoded.as_mut().copy_from_slice(bytes);
+                encoded.into_affine()
+            }
+        }
+    };
+}
+
+point_bytes_impl!(G1Affine, G1Compressed, 48, G1Uncompressed, 96);
+point_bytes_impl!(G2Affine, G2Compressed, 96, G2Uncompressed, 192);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_rng;
+    use crate::CurveProjective;
+    use crate::{bls12_381::G1, bls12_381::G2};
+    use ff::Field;
+
+    #[test]
+    fn test_prime_field_bytes() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let a = Fr::random(&mut rng);
+            let be = a.to_bytes_be();
+            let mut le = a.to_bytes_le();
+            assert_eq!(Fr::from_bytes_be(&be).unwrap(), a);
+            assert_eq!(Fr::from_bytes_le(&le).unwrap(), a);
+            le.reverse();
+            assert_eq!(le, be);
+
+            let b = Fq::random(&mut rng);
+            assert_eq!(Fq::from_bytes_be(&b.to_bytes_be()).unwrap(), b);
+            assert_eq!(Fq::from_bytes_le(&b.to_bytes_le()).unwrap(), b);
+        }
+
+        let mut one = [0u8; 32];
+        one[31] = 1;
+        assert_eq!(Fr::one().to_bytes_be(), one);
+
+        // the modulus is not canonical
+        let mut modulus = [0u8; 48];
+        Fq::char().write_be(&mut modulus[..]).unwrap();
+        assert!(Fq::from_bytes_be(&modulus).is_err());
+        assert!(Fq::from_bytes_be(&[0xff; 48]).is_err());
+        assert!(Fr::from_bytes_le(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn test_ext_field_bytes() {
+        let mut rng = test_rng();
+        for _ in 0..20 {
+            let a = Fq2::random(&mut rng);
+            let bytes = a.to_bytes_be();
+            assert_eq!(&bytes[..48], &a.c0.to_bytes_be()[..]);
+            assert_eq!(&bytes[48..], &a.c1.to_bytes_be()[..]);
+            assert_eq!(Fq2::from_bytes_be(&bytes).unwrap(), a);
+            assert_eq!(Fq2::from_bytes_le(&a.to_bytes_le()).unwrap(), a);
+
+            let b = Fq12::random(&mut rng);
+            let bytes = b.to_bytes_be();
+            assert_eq!(&bytes[..96], &b.c0.c0.to_bytes_be()[..]);
+            assert_eq!(&bytes[480..], &b.c1.c2.to_bytes_be()[..]);
+            assert_eq!(Fq12::from_bytes_be(&bytes).unwrap(), b);
+            assert_eq!(Fq12::from_bytes_le(&b.to_bytes_le()).unwrap(), b);
+        }
+
+        let mut bytes = Fq12::one().to_bytes_be();
+        bytes[288] = 0xff;
+        assert!(Fq12::from_bytes_be(&bytes).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_bytes_match_serdes() {
+        use crate::serdes::SerDes;
+
+        let mut rng = test_rng();
+        let a = Fq12::random(&mut rng);
+        let mut buf = vec![];
+        a.serialize(&mut buf, true).unwrap();
+        assert_eq!(&buf[..], &a.to_bytes_be()[..]);
+
+        let b = Fr::random(&mut rng);
+        let mut buf = vec![];
+        b.serialize(&mut buf, true).unwrap();
+        assert_eq!(&buf[..], &b.to_bytes_be()[..]);
+    }
+
+    #[test]
+    fn test_point_bytes() {
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            let p = G1::random(&mut rng).into_affine();
+            let q = G2::random(&mut rng).into_affine();
+
+            let bytes = p.to_compressed_bytes();
+            assert_eq!(&bytes[..], p.into_compressed().as_ref());
+            assert_eq!(G1Affine::from_compressed_bytes(&bytes).unwrap(), p);
+            let bytes = p.to_uncompressed_bytes();
+            assert_eq!(G1Affine::from_uncompressed_bytes(&bytes).unwrap(), p);
+
+            let bytes = q.to_compressed_bytes();
+            assert_eq!(G2Affine::from_compressed_bytes(&bytes).unwrap(), q);
+            let bytes = q.to_uncompressed_bytes();
+            assert_eq!(&bytes[..], q.into_uncompressed().as_ref());
+            assert_eq!(G2Affine::from_uncompressed_bytes(&bytes).unwrap(), q);
+        }
+
+        let zero = G1Affine::zero().to_compressed_bytes();
+        assert_eq!(
+            G1Affine::from_compressed_bytes(&zero).unwrap(),
+            G1Affine::zero()
+        );
+        assert!(G1Affine::from_compressed_bytes(&[0u8; 48]).is_err());
+        assert!(G2Affine::from_uncompressed_bytes(&[0xff; 192]).is_err());
+    }
+}
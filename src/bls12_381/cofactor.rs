@@ -13,6 +13,7 @@ Cofactor clearing for G1 and G2.
 
 use crate::{
     bls12_381::{G1, G2},
+    hash_to_curve::ClearH,
     CurveProjective,
 };
 
@@ -438,12 +439,6 @@ fn chain_h2_eff<PtT: CurveProjective>(tmpvar1: &mut PtT, tmpvar0: &PtT) {
     tmpvar1.sub_assign(&tmpvar2);
 }
 
-/// Trait implementing cofactor clearing for projective coords
-pub trait ClearH: CurveProjective {
-    /// Clear the cofactor in-place
-    fn clear_h(&mut self);
-}
-
 impl ClearH for G1 {
     // h_eff = 1 - z, therefore
     // out = in * chain_z(in)
@@ -472,8 +467,8 @@ impl ClearH for G2 {
 /// Tests for cofactor clearing
 #[cfg(test)]
 mod tests {
-    use super::ClearH;
     use crate::bls12_381::{Fq, Fq2, FqRepr, FrRepr, G1, G2};
+    use crate::hash_to_curve::ClearH;
     use ff::PrimeField;
     //    use rand::{thread_rng, Rand};
     use crate::CurveProjective;
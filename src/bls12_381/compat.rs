@@ -0,0 +1,1002 @@
+/*!
+Implementations of the zkcrypto `ff` 0.13, `group` 0.13 and `pairing` 0.23
+traits for BLS12-381.
+
+These live alongside the legacy traits of this crate, so `Fr`, `Fq`, the
+groups and `Bls12` can be handed to libraries written against the modern
+API. Both sets of traits have methods with the same names, so import the
+modern traits only where they are used (or call them with qualified
+paths).
+
+Encodings follow the zkcrypto `bls12_381` crate: `Fr` is 32 bytes
+little-endian, `Fq` is 48 bytes big-endian, and points use this crate's
+(ZCash-compatible) compressed and uncompressed formats. `Gt` is written
+additively, as the `group` API expects.
+
+Unlike the zkcrypto implementations, operations are not constant time
+except for equality checks and conditional selection.
+*/
+
+use super::{
+    Bls12, Fq12, Fr, G1Affine, G1Compressed, G1Prepared, G1Uncompressed, G2Affine, G2Compressed,
+    G2Prepared, G2Uncompressed, G1, G2,
+};
+use crate::{CurveAffine, CurveProjective, EncodedPoint, Engine};
+use alloc::vec::Vec;
+use ff::{Field, PrimeField};
+use group::{prime::PrimeCurveAffine, Curve, Group, GroupEncoding, UncompressedEncoding};
+use rand_core06::RngCore;
+use subtle::{Choice, CtOption};
+
+/// Adapts a `rand_core` 0.6 RNG for the legacy `random` functions.
+pub(crate) struct RngCompat<R: RngCore>(R);
+
+impl<R: RngCore> rand_core::RngCore for RngCompat<R> {
+    fn next_u32(&mut self) -> u32 {
+        self.0.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.0.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.0.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+/// Samples a legacy field element or group element with a `rand_core` 0.6 RNG.
+pub(crate) fn random_compat<T, R: RngCore>(rng: R, f: impl FnOnce(&mut RngCompat<R>) -> T) -> T {
+    f(&mut RngCompat(rng))
+}
+
+/// Byte encoding of `Fq` for `ff13::PrimeField`: 48 bytes, big-endian.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FqBytes(pub [u8; 48]);
+
+impl Default for FqBytes {
+    fn default() -> Self {
+        FqBytes([0u8; 48])
+    }
+}
+
+impl AsRef<[u8]> for FqBytes {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for FqBytes {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+/// Implements the `ff13` traits, and the operator traits they require, for
+/// a prime field derived with `ff::PrimeField`. This must be invoked in the
+/// module that defines the field, since it uses the private representation
+/// and the constants generated by the derive.
+macro_rules! ff13_prime_field_impl {
+    (
+        $field:ident,
+        $repr:ident,
+        $limbs:expr,
+        $bytes:ty,
+        $read:ident,
+        $write:ident,
+        modulus = $modulus:expr,
+        two_inv = $two_inv:expr,
+        root_of_unity_inv = $root_of_unity_inv:expr,
+        delta = $delta:expr $(,)?
+    ) => {
+        impl ::subtle::ConstantTimeEq for $field {
+            fn ct_eq(&self, other: &Self) -> ::subtle::Choice {
+                ::subtle::ConstantTimeEq::ct_eq(
+                    AsRef::<[u64]>::as_ref(&self.0),
+                    AsRef::<[u64]>::as_ref(&other.0),
+                )
+            }
+        }
+
+        impl ::subtle::ConditionallySelectable for $field {
+            fn conditional_select(a: &Self, b: &Self, choice: ::subtle::Choice) -> Self {
+                let mut limbs = [0u64; $limbs];
+                for ((l, a), b) in limbs.iter_mut().zip((a.0).0.iter()).zip((b.0).0.iter()) {
+                    *l = u64::conditional_select(a, b, choice);
+                }
+                $field($repr(limbs))
+            }
+        }
+
+        impl From<u64> for $field {
+            fn from(v: u64) -> Self {
+                <$field as ::ff::PrimeField>::from_repr($repr::from(v)).unwrap()
+            }
+        }
+
+        impl ::core::ops::Neg for $field {
+            type Output = $field;
+
+            fn neg(mut self) -> $field {
+                ::ff::Field::negate(&mut self);
+                self
+            }
+        }
+
+        ff13_prime_field_impl!(@binop $field, Add, add, AddAssign, add_assign);
+        ff13_prime_field_impl!(@binop $field, Sub, sub, SubAssign, sub_assign);
+        ff13_prime_field_impl!(@binop $field, Mul, mul, MulAssign, mul_assign);
+
+        impl ::core::iter::Sum for $field {
+            fn sum<I: Iterator<Item = $field>>(iter: I) -> $field {
+                iter.fold(<$field as ::ff::Field>::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl<'a> ::core::iter::Sum<&'a $field> for $field {
+            fn sum<I: Iterator<Item = &'a $field>>(iter: I) -> $field {
+                iter.fold(<$field as ::ff::Field>::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl ::core::iter::Product for $field {
+            fn product<I: Iterator<Item = $field>>(iter: I) -> $field {
+                iter.fold(<$field as ::ff::Field>::one(), |acc, x| acc * x)
+            }
+        }
+
+        impl<'a> ::core::iter::Product<&'a $field> for $field {
+            fn product<I: Iterator<Item = &'a $field>>(iter: I) -> $field {
+                iter.fold(<$field as ::ff::Field>::one(), |acc, x| acc * x)
+            }
+        }
+
+        impl ::ff13::Field for $field {
+            const ZERO: Self = $field($repr([0u64; $limbs]));
+            const ONE: Self = $field(R);
+
+            fn random(rng: impl ::rand_core06::RngCore) -> Self {
+                crate::bls12_381::compat::random_compat(rng, |rng| {
+                    <$field as ::ff::Field>::random(rng)
+                })
+            }
+
+            fn square(&self) -> Self {
+                let mut tmp = *self;
+                ::ff::Field::square(&mut tmp);
+                tmp
+            }
+
+            fn double(&self) -> Self {
+                let mut tmp = *self;
+                ::ff::Field::double(&mut tmp);
+                tmp
+            }
+
+            fn invert(&self) -> ::subtle::CtOption<Self> {
+                match ::ff::Field::inverse(self) {
+                    Some(inv) => ::subtle::CtOption::new(inv, ::subtle::Choice::from(1)),
+                    None => ::subtle::CtOption::new(
+                        <$field as ::ff13::Field>::ZERO,
+                        ::subtle::Choice::from(0),
+                    ),
+                }
+            }
+
+            fn sqrt(&self) -> ::subtle::CtOption<Self> {
+                match ::ff::SqrtField::sqrt(self) {
+                    Some(root) => ::subtle::CtOption::new(root, ::subtle::Choice::from(1)),
+                    None => ::subtle::CtOption::new(
+                        <$field as ::ff13::Field>::ZERO,
+                        ::subtle::Choice::from(0),
+                    ),
+                }
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> (::subtle::Choice, Self) {
+                ::ff13::helpers::sqrt_ratio_generic(num, div)
+            }
+        }
+
+        impl ::ff13::PrimeField for $field {
+            type Repr = $bytes;
+
+            fn from_repr(bytes: $bytes) -> ::subtle::CtOption<Self> {
+                use ::ff::PrimeFieldRepr;
+
+                let mut repr = $repr::default();
+                let res = repr
+                    .$read(AsRef::<[u8]>::as_ref(&bytes))
+                    .ok()
+                    .and_then(|_| <$field as ::ff::PrimeField>::from_repr(repr).ok());
+                match res {
+                    Some(fe) => ::subtle::CtOption::new(fe, ::subtle::Choice::from(1)),
+                    None => ::subtle::CtOption::new(
+                        <$field as ::ff13::Field>::ZERO,
+                        ::subtle::Choice::from(0),
+                    ),
+                }
+            }
+
+            fn to_repr(&self) -> $bytes {
+                use ::ff::PrimeFieldRepr;
+
+                let mut bytes = <$bytes>::default();
+                ::ff::PrimeField::into_repr(self)
+                    .$write(AsMut::<[u8]>::as_mut(&mut bytes))
+                    .unwrap();
+                bytes
+            }
+
+            fn is_odd(&self) -> ::subtle::Choice {
+                use ::ff::PrimeFieldRepr;
+
+                ::subtle::Choice::from(::ff::PrimeField::into_repr(self).is_odd() as u8)
+            }
+
+            const MODULUS: &'static str = $modulus;
+            const NUM_BITS: u32 = <$field as ::ff::PrimeField>::NUM_BITS;
+            const CAPACITY: u32 = <$field as ::ff::PrimeField>::CAPACITY;
+            const TWO_INV: Self = $field($repr($two_inv));
+            const MULTIPLICATIVE_GENERATOR: Self = $field(GENERATOR);
+            const S: u32 = S;
+            const ROOT_OF_UNITY: Self = $field(ROOT_OF_UNITY);
+            const ROOT_OF_UNITY_INV: Self = $field($repr($root_of_unity_inv));
+            const DELTA: Self = $field($repr($delta));
+        }
+    };
+
+    (@binop $field:ident, $op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident) => {
+        impl<'a> ::core::ops::$assign<&'a $field> for $field {
+            fn $assign_fn(&mut self, rhs: &'a $field) {
+                ::ff::Field::$assign_fn(self, rhs);
+            }
+        }
+
+        impl ::core::ops::$assign<$field> for $field {
+            fn $assign_fn(&mut self, rhs: $field) {
+                ::ff::Field::$assign_fn(self, &rhs);
+            }
+        }
+
+        impl<'a> ::core::ops::$op<&'a $field> for $field {
+            type Output = $field;
+
+            fn $op_fn(mut self, rhs: &'a $field) -> $field {
+                ::ff::Field::$assign_fn(&mut self, rhs);
+                self
+            }
+        }
+
+        impl ::core::ops::$op<$field> for $field {
+            type Output = $field;
+
+            fn $op_fn(mut self, rhs: $field) -> $field {
+                ::ff::Field::$assign_fn(&mut self, &rhs);
+                self
+            }
+        }
+    };
+}
+
+/// Implements the `group` traits, and the operator traits they require, for
+/// a pair of projective and affine curve types.
+macro_rules! group_impl {
+    ($projective:ident, $affine:ident, $compressed:ident, $uncompressed:ident) => {
+        impl Default for $compressed {
+            fn default() -> Self {
+                Self::empty()
+            }
+        }
+
+        impl Default for $uncompressed {
+            fn default() -> Self {
+                Self::empty()
+            }
+        }
+
+        impl ::core::ops::Neg for $projective {
+            type Output = $projective;
+
+            fn neg(mut self) -> $projective {
+                CurveProjective::negate(&mut self);
+                self
+            }
+        }
+
+        impl ::core::ops::Neg for $affine {
+            type Output = $affine;
+
+            fn neg(mut self) -> $affine {
+                CurveAffine::negate(&mut self);
+                self
+            }
+        }
+
+        group_impl!(@binop $projective, $projective, Add, add, AddAssign, add_assign, add_assign);
+        group_impl!(@binop $projective, $projective, Sub, sub, SubAssign, sub_assign, sub_assign);
+        group_impl!(@binop $projective, $affine, Add, add, AddAssign, add_assign, add_assign_mixed);
+        group_impl!(@binop $projective, $affine, Sub, sub, SubAssign, sub_assign, sub_assign_mixed);
+
+        impl<'a> ::core::ops::MulAssign<&'a Fr> for $projective {
+            fn mul_assign(&mut self, rhs: &'a Fr) {
+                CurveProjective::mul_assign(self, *rhs);
+            }
+        }
+
+        impl ::core::ops::MulAssign<Fr> for $projective {
+            fn mul_assign(&mut self, rhs: Fr) {
+                CurveProjective::mul_assign(self, rhs);
+            }
+        }
+
+        impl<'a> ::core::ops::Mul<&'a Fr> for $projective {
+            type Output = $projective;
+
+            fn mul(mut self, rhs: &'a Fr) -> $projective {
+                CurveProjective::mul_assign(&mut self, *rhs);
+                self
+            }
+        }
+
+        impl ::core::ops::Mul<Fr> for $projective {
+            type Output = $projective;
+
+            fn mul(mut self, rhs: Fr) -> $projective {
+                CurveProjective::mul_assign(&mut self, rhs);
+                self
+            }
+        }
+
+        impl<'a> ::core::ops::Mul<&'a Fr> for $affine {
+            type Output = $projective;
+
+            fn mul(self, rhs: &'a Fr) -> $projective {
+                CurveAffine::mul(&self, *rhs)
+            }
+        }
+
+        impl ::core::ops::Mul<Fr> for $affine {
+            type Output = $projective;
+
+            fn mul(self, rhs: Fr) -> $projective {
+                CurveAffine::mul(&self, rhs)
+            }
+        }
+
+        impl ::core::iter::Sum for $projective {
+            fn sum<I: Iterator<Item = $projective>>(iter: I) -> $projective {
+                iter.fold(CurveProjective::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl<'a> ::core::iter::Sum<&'a $projective> for $projective {
+            fn sum<I: Iterator<Item = &'a $projective>>(iter: I) -> $projective {
+                iter.fold(CurveProjective::zero(), |acc, x| acc + x)
+            }
+        }
+
+        impl Group for $projective {
+            type Scalar = Fr;
+
+            fn random(rng: impl RngCore) -> Self {
+                random_compat(rng, |rng| <$projective as CurveProjective>::random(rng))
+            }
+
+            fn identity() -> Self {
+                CurveProjective::zero()
+            }
+
+            fn generator() -> Self {
+                CurveProjective::one()
+            }
+
+            fn is_identity(&self) -> Choice {
+                Choice::from(CurveProjective::is_zero(self) as u8)
+            }
+
+            fn double(&self) -> Self {
+                let mut tmp = *self;
+                CurveProjective::double(&mut tmp);
+                tmp
+            }
+        }
+
+        impl Curve for $projective {
+            type AffineRepr = $affine;
+
+            fn batch_normalize(p: &[Self], q: &mut [$affine]) {
+                assert_eq!(p.len(), q.len());
+
+                let mut p = p.to_vec();
+                <$projective as CurveProjective>::batch_normalization(&mut p);
+                for (p, q) in p.iter().zip(q.iter_mut()) {
+                    *q = CurveProjective::into_affine(p);
+                }
+            }
+
+            fn to_affine(&self) -> $affine {
+                CurveProjective::into_affine(self)
+            }
+        }
+
+        impl group::prime::PrimeGroup for $projective {}
+
+        impl group::prime::PrimeCurve for $projective {
+            type Affine = $affine;
+        }
+
+        impl GroupEncoding for $projective {
+            type Repr = $compressed;
+
+            fn from_bytes(bytes: &$compressed) -> CtOption<Self> {
+                let res = bytes.into_affine().map(|p| CurveAffine::into_projective(&p));
+                decoded(res, CurveProjective::zero())
+            }
+
+            fn from_bytes_unchecked(bytes: &$compressed) -> CtOption<Self> {
+                let res = bytes
+                    .into_affine_unchecked()
+                    .map(|p| CurveAffine::into_projective(&p));
+                decoded(res, CurveProjective::zero())
+            }
+
+            fn to_bytes(&self) -> $compressed {
+                CurveAffine::into_compressed(&CurveProjective::into_affine(self))
+            }
+        }
+
+        impl PrimeCurveAffine for $affine {
+            type Scalar = Fr;
+            type Curve = $projective;
+
+            fn identity() -> Self {
+                CurveAffine::zero()
+            }
+
+            fn generator() -> Self {
+                CurveAffine::one()
+            }
+
+            fn is_identity(&self) -> Choice {
+                Choice::from(CurveAffine::is_zero(self) as u8)
+            }
+
+            fn to_curve(&self) -> $projective {
+                CurveAffine::into_projective(self)
+            }
+        }
+
+        impl GroupEncoding for $affine {
+            type Repr = $compressed;
+
+            fn from_bytes(bytes: &$compressed) -> CtOption<Self> {
+                decoded(bytes.into_affine(), CurveAffine::zero())
+            }
+
+            fn from_bytes_unchecked(bytes: &$compressed) -> CtOption<Self> {
+                decoded(bytes.into_affine_unchecked(), CurveAffine::zero())
+            }
+
+            fn to_bytes(&self) -> $compressed {
+                CurveAffine::into_compressed(self)
+            }
+        }
+
+        impl UncompressedEncoding for $affine {
+            type Uncompressed = $uncompressed;
+
+            fn from_uncompressed(bytes: &$uncompressed) -> CtOption<Self> {
+                decoded(bytes.into_affine(), CurveAffine::zero())
+            }
+
+            fn from_uncompressed_unchecked(bytes: &$uncompressed) -> CtOption<Self> {
+                decoded(bytes.into_affine_unchecked(), CurveAffine::zero())
+            }
+
+            fn to_uncompressed(&self) -> $uncompressed {
+                CurveAffine::into_uncompressed(self)
+            }
+        }
+    };
+
+    (@binop $projective:ident, $rhs:ident, $op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident, $legacy_fn:ident) => {
+        impl<'a> ::core::ops::$assign<&'a $rhs> for $projective {
+            fn $assign_fn(&mut self, rhs: &'a $rhs) {
+                CurveProjective::$legacy_fn(self, rhs);
+            }
+        }
+
+        impl ::core::ops::$assign<$rhs> for $projective {
+            fn $assign_fn(&mut self, rhs: $rhs) {
+                CurveProjective::$legacy_fn(self, &rhs);
+            }
+        }
+
+        impl<'a> ::core::ops::$op<&'a $rhs> for $projective {
+            type Output = $projective;
+
+            fn $op_fn(mut self, rhs: &'a $rhs) -> $projective {
+                CurveProjective::$legacy_fn(&mut self, rhs);
+                self
+            }
+        }
+
+        impl ::core::ops::$op<$rhs> for $projective {
+            type Output = $projective;
+
+            fn $op_fn(mut self, rhs: $rhs) -> $projective {
+                CurveProjective::$legacy_fn(&mut self, &rhs);
+                self
+            }
+        }
+    };
+}
+
+/// Converts the result of decoding a point into a CtOption, which needs a
+/// placeholder value for the failure case.
+fn decoded<G, E>(res: Result<G, E>, placeholder: G) -> CtOption<G> {
+    match res {
+        Ok(p) => CtOption::new(p, Choice::from(1)),
+        Err(_) => CtOption::new(placeholder, Choice::from(0)),
+    }
+}
+
+group_impl!(G1, G1Affine, G1Compressed, G1Uncompressed);
+group_impl!(G2, G2Affine, G2Compressed, G2Uncompressed);
+
+/// An element of the target group of the pairing, written additively.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Gt(Fq12);
+
+impl From<Gt> for Fq12 {
+    fn from(gt: Gt) -> Fq12 {
+        gt.0
+    }
+}
+
+impl ::core::ops::Neg for Gt {
+    type Output = Gt;
+
+    fn neg(mut self) -> Gt {
+        // elements of Gt are unitary, so the inverse is the conjugate
+        self.0.conjugate();
+        self
+    }
+}
+
+impl<'a> ::core::ops::AddAssign<&'a Gt> for Gt {
+    fn add_assign(&mut self, rhs: &'a Gt) {
+        self.0.mul_assign(&rhs.0);
+    }
+}
+
+impl<'a> ::core::ops::SubAssign<&'a Gt> for Gt {
+    fn sub_assign(&mut self, rhs: &'a Gt) {
+        *self += &-*rhs;
+    }
+}
+
+impl<'a> ::core::ops::MulAssign<&'a Fr> for Gt {
+    fn mul_assign(&mut self, rhs: &'a Fr) {
+        self.0 = self.0.pow(rhs.into_repr());
+    }
+}
+
+/// Derives the by-value and non-assigning operators from the by-reference
+/// assigning operators.
+macro_rules! gt_ops_impl {
+    ($rhs:ident, $op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident) => {
+        impl ::core::ops::$assign<$rhs> for Gt {
+            fn $assign_fn(&mut self, rhs: $rhs) {
+                ::core::ops::$assign::$assign_fn(self, &rhs);
+            }
+        }
+
+        impl<'a> ::core::ops::$op<&'a $rhs> for Gt {
+            type Output = Gt;
+
+            fn $op_fn(mut self, rhs: &'a $rhs) -> Gt {
+                ::core::ops::$assign::$assign_fn(&mut self, rhs);
+                self
+            }
+        }
+
+        impl ::core::ops::$op<$rhs> for Gt {
+            type Output = Gt;
+
+            fn $op_fn(mut self, rhs: $rhs) -> Gt {
+                ::core::ops::$assign::$assign_fn(&mut self, &rhs);
+                self
+            }
+        }
+    };
+}
+
+gt_ops_impl!(Gt, Add, add, AddAssign, add_assign);
+gt_ops_impl!(Gt, Sub, sub, SubAssign, sub_assign);
+gt_ops_impl!(Fr, Mul, mul, MulAssign, mul_assign);
+
+impl ::core::iter::Sum for Gt {
+    fn sum<I: Iterator<Item = Gt>>(iter: I) -> Gt {
+        iter.fold(Gt::identity(), |acc, x| acc + x)
+    }
+}
+
+impl<'a> ::core::iter::Sum<&'a Gt> for Gt {
+    fn sum<I: Iterator<Item = &'a Gt>>(iter: I) -> Gt {
+        iter.fold(Gt::identity(), |acc, x| acc + x)
+    }
+}
+
+impl Group for Gt {
+    type Scalar = Fr;
+
+    fn random(rng: impl RngCore) -> Self {
+        Gt::generator() * <Fr as ff13::Field>::random(rng)
+    }
+
+    fn identity() -> Self {
+        Gt(Fq12::one())
+    }
+
+    /// Returns e(G1::generator(), G2::generator()). This computes a pairing
+    /// on each call.
+    fn generator() -> Self {
+        Gt(Bls12::pairing(G1Affine::one(), G2Affine::one()))
+    }
+
+    fn is_identity(&self) -> Choice {
+        Choice::from((self.0 == Fq12::one()) as u8)
+    }
+
+    fn double(&self) -> Self {
+        let mut tmp = self.0;
+        tmp.square();
+        Gt(tmp)
+    }
+}
+
+/// The output of a multi-Miller loop, before the final exponentiation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MillerLoopResult(Fq12);
+
+impl Default for MillerLoopResult {
+    fn default() -> Self {
+        MillerLoopResult(Fq12::one())
+    }
+}
+
+impl<'a> ::core::ops::AddAssign<&'a MillerLoopResult> for MillerLoopResult {
+    fn add_assign(&mut self, rhs: &'a MillerLoopResult) {
+        self.0.mul_assign(&rhs.0);
+    }
+}
+
+impl ::core::ops::AddAssign for MillerLoopResult {
+    fn add_assign(&mut self, rhs: MillerLoopResult) {
+        *self += &rhs;
+    }
+}
+
+impl<'a> ::core::ops::Add<&'a MillerLoopResult> for MillerLoopResult {
+    type Output = MillerLoopResult;
+
+    fn add(mut self, rhs: &'a MillerLoopResult) -> MillerLoopResult {
+        self += rhs;
+        self
+    }
+}
+
+impl ::core::ops::Add for MillerLoopResult {
+    type Output = MillerLoopResult;
+
+    fn add(mut self, rhs: MillerLoopResult) -> MillerLoopResult {
+        self += &rhs;
+        self
+    }
+}
+
+impl pairing::MillerLoopResult for MillerLoopResult {
+    type Gt = Gt;
+
+    fn final_exponentiation(&self) -> Gt {
+        // the output of a Miller loop is a product of nonzero line functions
+        Gt(Bls12::final_exponentiation(&self.0).expect("Miller loop output is nonzero"))
+    }
+}
+
+impl From<G2Affine> for G2Prepared {
+    fn from(p: G2Affine) -> G2Prepared {
+        p.prepare()
+    }
+}
+
+impl pairing::PairingCurveAffine for G1Affine {
+    type Pair = G2Affine;
+    type PairingResult = Gt;
+
+    fn pairing_with(&self, other: &G2Affine) -> Gt {
+        Gt(Bls12::pairing(*self, *other))
+    }
+}
+
+impl pairing::PairingCurveAffine for G2Affine {
+    type Pair = G1Affine;
+    type PairingResult = Gt;
+
+    fn pairing_with(&self, other: &G1Affine) -> Gt {
+        Gt(Bls12::pairing(*other, *self))
+    }
+}
+
+impl pairing::Engine for Bls12 {
+    type Fr = Fr;
+    type G1 = G1;
+    type G1Affine = G1Affine;
+    type G2 = G2;
+    type G2Affine = G2Affine;
+    type Gt = Gt;
+
+    fn pairing(p: &G1Affine, q: &G2Affine) -> Gt {
+        Gt(<Bls12 as Engine>::pairing(*p, *q))
+    }
+}
+
+impl pairing::MultiMillerLoop for Bls12 {
+    type G2Prepared = G2Prepared;
+    type Result = MillerLoopResult;
+
+    fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> MillerLoopResult {
+        let g1: Vec<G1Prepared> = terms.iter().map(|(p, _)| p.prepare()).collect();
+        let pairs: Vec<(&G1Prepared, &G2Prepared)> = g1
+            .iter()
+            .zip(terms.iter())
+            .map(|(p, (_, q))| (p, *q))
+            .collect();
+        MillerLoopResult(<Bls12 as Engine>::miller_loop(pairs.iter()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::Fq;
+    use crate::SqrtField;
+    use ff::PrimeFieldRepr;
+    use group::prime::PrimeCurve;
+    use pairing::{MillerLoopResult as _, MultiMillerLoop};
+
+    /// Adapts the legacy test RNG to `rand_core` 0.6.
+    struct TestRng(rand_xorshift::XorShiftRng);
+
+    impl RngCore for TestRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::RngCore::next_u32(&mut self.0)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::RngCore::next_u64(&mut self.0)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            rand_core::RngCore::fill_bytes(&mut self.0, dest)
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core06::Error> {
+            rand_core::RngCore::fill_bytes(&mut self.0, dest);
+            Ok(())
+        }
+    }
+
+    fn test_rng() -> TestRng {
+        TestRng(crate::tests::test_rng())
+    }
+
+    fn pow2<F: ff13::Field>(mut x: F, k: u32) -> F {
+        for _ in 0..k {
+            x = x.square();
+        }
+        x
+    }
+
+    fn check_constants<F: ff13::PrimeField + PrimeField>() {
+        assert_eq!(F::ZERO, <F as Field>::zero());
+        assert_eq!(F::ONE, <F as Field>::one());
+        assert_eq!(F::TWO_INV + F::TWO_INV, F::ONE);
+        assert_eq!(F::ROOT_OF_UNITY * F::ROOT_OF_UNITY_INV, F::ONE);
+        assert_eq!(pow2(F::ROOT_OF_UNITY, <F as ff13::PrimeField>::S), F::ONE);
+        assert!(pow2(F::ROOT_OF_UNITY, <F as ff13::PrimeField>::S - 1) != F::ONE);
+        assert_eq!(
+            pow2(F::MULTIPLICATIVE_GENERATOR, <F as ff13::PrimeField>::S),
+            F::DELTA
+        );
+        assert_eq!(
+            <F as ff13::PrimeField>::NUM_BITS,
+            <F as PrimeField>::NUM_BITS
+        );
+    }
+
+    fn check_field_ops<F: ff13::PrimeField + Field + SqrtField>() {
+        let mut rng = test_rng();
+        for _ in 0..100 {
+            let a = <F as ff13::Field>::random(&mut rng);
+            let b = <F as ff13::Field>::random(&mut rng);
+
+            let mut t = a;
+            Field::add_assign(&mut t, &b);
+            assert_eq!(a + b, t);
+            let mut t = a;
+            Field::sub_assign(&mut t, &b);
+            assert_eq!(a - b, t);
+            let mut t = a;
+            Field::mul_assign(&mut t, &b);
+            assert_eq!(a * b, t);
+            assert_eq!(-a + a, F::ZERO);
+            assert_eq!(ff13::Field::double(&a), a + a);
+            assert_eq!(ff13::Field::square(&a), a * a);
+            assert_eq!(a * a.invert().unwrap(), F::ONE);
+            assert_eq!([a, b].iter().sum::<F>(), a + b);
+            assert_eq!([a, b].iter().product::<F>(), a * b);
+
+            let sq = ff13::Field::square(&a);
+            let root = ff13::Field::sqrt(&sq).unwrap();
+            assert!(root == a || root == -a);
+            let (is_square, root) = F::sqrt_ratio(&(sq * b), &b);
+            assert!(bool::from(is_square));
+            assert!(root == a || root == -a);
+
+            assert_eq!(F::from_repr(a.to_repr()).unwrap(), a);
+            assert!(bool::from(a.ct_eq(&a)));
+            assert!(!bool::from(a.ct_eq(&b)));
+            assert_eq!(F::conditional_select(&a, &b, Choice::from(0)), a);
+            assert_eq!(F::conditional_select(&a, &b, Choice::from(1)), b);
+        }
+        assert!(bool::from(F::ZERO.invert().is_none()));
+        assert_eq!(F::from(3u64), F::ONE + F::ONE + F::ONE);
+        assert!(bool::from(F::ONE.is_odd()));
+        assert!(!bool::from(F::from(2u64).is_odd()));
+    }
+
+    #[test]
+    fn test_fr_compat() {
+        check_constants::<Fr>();
+        check_field_ops::<Fr>();
+
+        // little-endian encoding of the canonical representation
+        let x = <Fr as ff13::Field>::random(&mut test_rng());
+        let mut bytes = [0u8; 32];
+        x.into_repr().write_le(&mut bytes[..]).unwrap();
+        assert_eq!(ff13::PrimeField::to_repr(&x), bytes);
+        assert_eq!(ff13::PrimeField::to_repr(&<Fr as ff13::Field>::ONE), {
+            let mut one = [0u8; 32];
+            one[0] = 1;
+            one
+        });
+
+        // the modulus does not decode
+        let mut modulus = [0u8; 32];
+        Fr::char().write_le(&mut modulus[..]).unwrap();
+        assert!(bool::from(
+            <Fr as ff13::PrimeField>::from_repr(modulus).is_none()
+        ));
+    }
+
+    #[test]
+    fn test_fq_compat() {
+        check_constants::<Fq>();
+        check_field_ops::<Fq>();
+
+        // big-endian encoding of the canonical representation
+        let x = <Fq as ff13::Field>::random(&mut test_rng());
+        let mut bytes = [0u8; 48];
+        x.into_repr().write_be(&mut bytes[..]).unwrap();
+        assert_eq!(ff13::PrimeField::to_repr(&x), FqBytes(bytes));
+
+        let mut modulus = FqBytes::default();
+        Fq::char().write_be(&mut modulus.0[..]).unwrap();
+        assert!(bool::from(
+            <Fq as ff13::PrimeField>::from_repr(modulus).is_none()
+        ));
+    }
+
+    fn check_group<G, A>()
+    where
+        G: PrimeCurve<Scalar = Fr, Affine = A>
+            + GroupEncoding<Repr = A::Repr>
+            + CurveProjective<Scalar = Fr>,
+        A: PrimeCurveAffine<Scalar = Fr, Curve = G>
+            + GroupEncoding
+            + UncompressedEncoding
+            + PartialEq,
+    {
+        let mut rng = test_rng();
+        for _ in 0..10 {
+            let a = <G as Group>::random(&mut rng);
+            let b = <G as Group>::random(&mut rng);
+            let s = <Fr as ff13::Field>::random(&mut rng);
+
+            let mut t = a;
+            CurveProjective::add_assign(&mut t, &b);
+            assert_eq!(a + b, t);
+            assert_eq!(a + b.to_affine(), t);
+            assert_eq!(a - b + b, a);
+            assert_eq!(a - b.to_affine() + b, a);
+            assert_eq!(-a + a, G::identity());
+            assert_eq!(Group::double(&a), a + a);
+            assert_eq!([a, b].iter().sum::<G>(), a + b);
+
+            let mut t = a;
+            CurveProjective::mul_assign(&mut t, s);
+            assert_eq!(a * s, t);
+            assert_eq!(a.to_affine() * s, t);
+            assert_eq!((-a.to_affine()).to_curve(), -a);
+
+            let bytes = a.to_bytes();
+            assert_eq!(G::from_bytes(&bytes).unwrap(), a);
+            assert_eq!(A::from_bytes(&bytes).unwrap(), a.to_affine());
+            let bytes = a.to_affine().to_uncompressed();
+            assert_eq!(A::from_uncompressed(&bytes).unwrap(), a.to_affine());
+
+            let points = [a, b, G::identity()];
+            let mut affine = [A::identity(); 3];
+            G::batch_normalize(&points, &mut affine);
+            for (p, q) in points.iter().zip(affine.iter()) {
+                assert_eq!(p.to_affine(), *q);
+            }
+        }
+
+        assert!(bool::from(G::identity().is_identity()));
+        assert!(!bool::from(G::generator().is_identity()));
+        assert!(bool::from(A::identity().is_identity()));
+        assert_eq!(A::generator().to_curve(), G::generator());
+
+        // an invalid encoding is rejected
+        let mut bytes = G::generator().to_bytes();
+        bytes.as_mut()[0] ^= 0x80;
+        assert!(bool::from(G::from_bytes(&bytes).is_none()));
+    }
+
+    #[test]
+    fn test_g1_compat() {
+        check_group::<G1, G1Affine>();
+    }
+
+    #[test]
+    fn test_g2_compat() {
+        check_group::<G2, G2Affine>();
+    }
+
+    #[test]
+    fn test_engine_compat() {
+        let mut rng = test_rng();
+        let a = <Fr as ff13::Field>::random(&mut rng);
+        let b = <Fr as ff13::Field>::random(&mut rng);
+        let p = (G1::generator() * a).to_affine();
+        let q = (G2::generator() * b).to_affine();
+
+        let gt = <Bls12 as pairing::Engine>::pairing(&p, &q);
+        assert_eq!(gt, Gt::generator() * (a * b));
+        assert_eq!(pairing::PairingCurveAffine::pairing_with(&p, &q), gt);
+        assert_eq!(pairing::PairingCurveAffine::pairing_with(&q, &p), gt);
+        assert_eq!(gt - gt, Gt::identity());
+        assert_eq!(-gt + gt, Gt::identity());
+        assert_eq!(Group::double(&gt), gt + gt);
+        assert!(bool::from(Gt::identity().is_identity()));
+
+        let r = G1Affine::generator();
+        let s = G2Affine::generator();
+        let terms = [(&p, &G2Prepared::from(q)), (&r, &G2Prepared::from(s))];
+        let res = Bls12::multi_miller_loop(&terms).final_exponentiation();
+        assert_eq!(res, gt + Gt::generator());
+        assert_eq!(
+            Bls12::multi_miller_loop(&[]).final_exponentiation(),
+            Gt::identity()
+        );
+    }
+}
@@ -1,10 +1,11 @@
 use super::g2::G2Affine;
 use crate::{
     bls12_381::{Bls12, Fq, Fq12, FqRepr, Fr, FrRepr},
-    CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError, SubgroupCheck,
+    CurveAffine, CurveProjective, EncodedPoint, Engine, Error, SubgroupCheck,
 };
+use alloc::vec::Vec;
+use core::fmt;
 use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
-use std::fmt;
 curve_impl!(
     "G1",
     G1,
@@ -14,7 +15,9 @@ curve_impl!(
     Fr,
     G1Uncompressed,
     G1Compressed,
-    G2Affine
+    G2Affine,
+    Bls12,
+    Fq12
 );
 
 #[derive(Copy, Clone)]
@@ -47,42 +50,45 @@ impl EncodedPoint for G1Uncompressed {
     fn size() -> usize {
         96
     }
-    fn into_affine(&self) -> Result<G1Affine, GroupDecodingError> {
+    fn into_affine(&self) -> Result<G1Affine, Error> {
         let affine = self.into_affine_unchecked()?;
 
         if !affine.is_on_curve() {
-            Err(GroupDecodingError::NotOnCurve)
+            Err(Error::NotOnCurve)
         } else if !affine.in_subgroup() {
-            Err(GroupDecodingError::NotInSubgroup)
+            Err(Error::NotInSubgroup)
         } else {
             Ok(affine)
         }
     }
-    fn into_affine_unchecked(&self) -> Result<G1Affine, GroupDecodingError> {
+    fn into_affine_unchecked(&self) -> Result<G1Affine, Error> {
         // Create a copy of this representation.
         let mut copy = self.0;
 
         if copy[0] & (1 << 7) != 0 {
             // Distinguisher bit is set, but this should be uncompressed!
-            return Err(GroupDecodingError::UnexpectedCompressionMode);
+            return Err(Error::UnexpectedCompressionMode);
         }
 
         if copy[0] & (1 << 6) != 0 {
-            // This is the point at infinity, which means that if we mask away
-            // the first two bits, the entire representation should consist
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
             // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
             copy[0] &= 0x3f;
 
             if copy.iter().all(|b| *b == 0) {
                 Ok(G1Affine::zero())
             } else {
-                Err(GroupDecodingError::UnexpectedInformation)
+                Err(Error::NonZeroInfinity)
             }
         } else {
             if copy[0] & (1 << 5) != 0 {
                 // The bit indicating the y-coordinate should be lexicographically
                 // largest is set, but this is an uncompressed element.
-                return Err(GroupDecodingError::UnexpectedInformation);
+                return Err(Error::BadFlags);
             }
 
             // Unset the three most significant bits.
@@ -100,9 +106,9 @@ impl EncodedPoint for G1Uncompressed {
 
             Ok(G1Affine {
                 x: Fq::from_repr(x)
-                    .map_err(|e| GroupDecodingError::CoordinateDecodingError("x coordinate", e))?,
+                    .map_err(|e| Error::NonCanonicalFieldElement("x coordinate", e))?,
                 y: Fq::from_repr(y)
-                    .map_err(|e| GroupDecodingError::CoordinateDecodingError("y coordinate", e))?,
+                    .map_err(|e| Error::NonCanonicalFieldElement("y coordinate", e))?,
                 infinity: false,
             })
         }
@@ -155,36 +161,39 @@ impl EncodedPoint for G1Compressed {
     fn size() -> usize {
         48
     }
-    fn into_affine(&self) -> Result<G1Affine, GroupDecodingError> {
+    fn into_affine(&self) -> Result<G1Affine, Error> {
         let affine = self.into_affine_unchecked()?;
 
         // NB: Decompression guarantees that it is on the curve already.
 
         if !affine.in_subgroup() {
-            Err(GroupDecodingError::NotInSubgroup)
+            Err(Error::NotInSubgroup)
         } else {
             Ok(affine)
         }
     }
-    fn into_affine_unchecked(&self) -> Result<G1Affine, GroupDecodingError> {
+    fn into_affine_unchecked(&self) -> Result<G1Affine, Error> {
         // Create a copy of this representation.
         let mut copy = self.0;
 
         if copy[0] & (1 << 7) == 0 {
             // Distinguisher bit isn't set.
-            return Err(GroupDecodingError::UnexpectedCompressionMode);
+            return Err(Error::UnexpectedCompressionMode);
         }
 
         if copy[0] & (1 << 6) != 0 {
-            // This is the point at infinity, which means that if we mask away
-            // the first two bits, the entire representation should consist
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
             // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
             copy[0] &= 0x3f;
 
             if copy.iter().all(|b| *b == 0) {
                 Ok(G1Affine::zero())
             } else {
-                Err(GroupDecodingError::UnexpectedInformation)
+                Err(Error::NonZeroInfinity)
             }
         } else {
             // Determine if the intended y coordinate must be greater
@@ -203,10 +212,10 @@ impl EncodedPoint for G1Compressed {
             }
 
             // Interpret as Fq element.
-            let x = Fq::from_repr(x)
-                .map_err(|e| GroupDecodingError::CoordinateDecodingError("x coordinate", e))?;
+            let x =
+                Fq::from_repr(x).map_err(|e| Error::NonCanonicalFieldElement("x coordinate", e))?;
 
-            G1Affine::get_point_from_x(x, greatest).ok_or(GroupDecodingError::NotOnCurve)
+            G1Affine::get_point_from_x(x, greatest).ok_or(Error::NotOnCurve)
         }
     }
     fn from_affine(affine: G1Affine) -> Self {
@@ -326,7 +335,7 @@ mod subgroup_check {
 
     #[test]
     fn test_g1_subgroup_check() {
-        use crate::bls12_381::ClearH;
+        use crate::hash_to_curve::ClearH;
         let mut rng = rand_xorshift::XorShiftRng::from_seed([
             0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
             0xbc, 0xe5,
@@ -1,10 +1,9 @@
 use super::super::{Bls12, Fq, Fq12, Fq2, FqRepr, Fr, FrRepr};
 use super::g1::G1Affine;
-use crate::{
-    CurveAffine, CurveProjective, EncodedPoint, Engine, GroupDecodingError, SubgroupCheck,
-};
+use crate::{CurveAffine, CurveProjective, EncodedPoint, Engine, Error, SubgroupCheck};
+use alloc::vec::Vec;
+use core::fmt;
 use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
-use std::fmt;
 
 curve_impl!(
     "G2",
@@ -15,7 +14,9 @@ curve_impl!(
     Fr,
     G2Uncompressed,
     G2Compressed,
-    G1Affine
+    G1Affine,
+    Bls12,
+    Fq12
 );
 
 #[derive(Copy, Clone)]
@@ -48,42 +49,45 @@ impl EncodedPoint for G2Uncompressed {
     fn size() -> usize {
         192
     }
-    fn into_affine(&self) -> Result<G2Affine, GroupDecodingError> {
+    fn into_affine(&self) -> Result<G2Affine, Error> {
         let affine = self.into_affine_unchecked()?;
 
         if !affine.is_on_curve() {
-            Err(GroupDecodingError::NotOnCurve)
+            Err(Error::NotOnCurve)
         } else if !affine.in_subgroup() {
-            Err(GroupDecodingError::NotInSubgroup)
+            Err(Error::NotInSubgroup)
         } else {
             Ok(affine)
         }
     }
-    fn into_affine_unchecked(&self) -> Result<G2Affine, GroupDecodingError> {
+    fn into_affine_unchecked(&self) -> Result<G2Affine, Error> {
         // Create a copy of this representation.
         let mut copy = self.0;
 
         if copy[0] & (1 << 7) != 0 {
             // Distinguisher bit is set, but this should be uncompressed!
-            return Err(GroupDecodingError::UnexpectedCompressionMode);
+            return Err(Error::UnexpectedCompressionMode);
         }
 
         if copy[0] & (1 << 6) != 0 {
-            // This is the point at infinity, which means that if we mask away
-            // the first two bits, the entire representation should consist
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
             // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
             copy[0] &= 0x3f;
 
             if copy.iter().all(|b| *b == 0) {
                 Ok(G2Affine::zero())
             } else {
-                Err(GroupDecodingError::UnexpectedInformation)
+                Err(Error::NonZeroInfinity)
             }
         } else {
             if copy[0] & (1 << 5) != 0 {
                 // The bit indicating the y-coordinate should be lexicographically
                 // largest is set, but this is an uncompressed element.
-                return Err(GroupDecodingError::UnexpectedInformation);
+                return Err(Error::BadFlags);
             }
 
             // Unset the three most significant bits.
@@ -105,20 +109,16 @@ impl EncodedPoint for G2Uncompressed {
 
             Ok(G2Affine {
                 x: Fq2 {
-                    c0: Fq::from_repr(x_c0).map_err(|e| {
-                        GroupDecodingError::CoordinateDecodingError("x coordinate (c0)", e)
-                    })?,
-                    c1: Fq::from_repr(x_c1).map_err(|e| {
-                        GroupDecodingError::CoordinateDecodingError("x coordinate (c1)", e)
-                    })?,
+                    c0: Fq::from_repr(x_c0)
+                        .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c0)", e))?,
+                    c1: Fq::from_repr(x_c1)
+                        .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c1)", e))?,
                 },
                 y: Fq2 {
-                    c0: Fq::from_repr(y_c0).map_err(|e| {
-                        GroupDecodingError::CoordinateDecodingError("y coordinate (c0)", e)
-                    })?,
-                    c1: Fq::from_repr(y_c1).map_err(|e| {
-                        GroupDecodingError::CoordinateDecodingError("y coordinate (c1)", e)
-                    })?,
+                    c0: Fq::from_repr(y_c0)
+                        .map_err(|e| Error::NonCanonicalFieldElement("y coordinate (c0)", e))?,
+                    c1: Fq::from_repr(y_c1)
+                        .map_err(|e| Error::NonCanonicalFieldElement("y coordinate (c1)", e))?,
                 },
                 infinity: false,
             })
@@ -174,36 +174,39 @@ impl EncodedPoint for G2Compressed {
     fn size() -> usize {
         96
     }
-    fn into_affine(&self) -> Result<G2Affine, GroupDecodingError> {
+    fn into_affine(&self) -> Result<G2Affine, Error> {
         let affine = self.into_affine_unchecked()?;
 
         // NB: Decompression guarantees that it is on the curve already.
 
         if !affine.in_subgroup() {
-            Err(GroupDecodingError::NotInSubgroup)
+            Err(Error::NotInSubgroup)
         } else {
             Ok(affine)
         }
     }
-    fn into_affine_unchecked(&self) -> Result<G2Affine, GroupDecodingError> {
+    fn into_affine_unchecked(&self) -> Result<G2Affine, Error> {
         // Create a copy of this representation.
         let mut copy = self.0;
 
         if copy[0] & (1 << 7) == 0 {
             // Distinguisher bit isn't set.
-            return Err(GroupDecodingError::UnexpectedCompressionMode);
+            return Err(Error::UnexpectedCompressionMode);
         }
 
         if copy[0] & (1 << 6) != 0 {
-            // This is the point at infinity, which means that if we mask away
-            // the first two bits, the entire representation should consist
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
             // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
             copy[0] &= 0x3f;
 
             if copy.iter().all(|b| *b == 0) {
                 Ok(G2Affine::zero())
             } else {
-                Err(GroupDecodingError::UnexpectedInformation)
+                Err(Error::NonZeroInfinity)
             }
         } else {
             // Determine if the intended y coordinate must be greater
@@ -225,15 +228,13 @@ impl EncodedPoint for G2Compressed {
 
             // Interpret as Fq element.
             let x = Fq2 {
-                c0: Fq::from_repr(x_c0).map_err(|e| {
-                    GroupDecodingError::CoordinateDecodingError("x coordinate (c0)", e)
-                })?,
-                c1: Fq::from_repr(x_c1).map_err(|e| {
-                    GroupDecodingError::CoordinateDecodingError("x coordinate (c1)", e)
-                })?,
+                c0: Fq::from_repr(x_c0)
+                    .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c0)", e))?,
+                c1: Fq::from_repr(x_c1)
+                    .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c1)", e))?,
             };
 
-            G2Affine::get_point_from_x(x, greatest).ok_or(GroupDecodingError::NotOnCurve)
+            G2Affine::get_point_from_x(x, greatest).ok_or(Error::NotOnCurve)
         }
     }
     fn from_affine(affine: G2Affine) -> Self {
@@ -362,10 +363,7 @@ mod subgroup_check {
 
     #[test]
     fn test_g2_subgroup_check() {
-        use crate::{
-            bls12_381::{ClearH, G2},
-            CurveProjective,
-        };
+        use crate::{bls12_381::G2, hash_to_curve::ClearH, CurveProjective};
         let mut rng = rand_xorshift::XorShiftRng::from_seed([
             0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
             0xbc, 0xe5,
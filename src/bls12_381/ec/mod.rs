@@ -8,7 +8,38 @@ macro_rules! curve_impl {
         $scalarfield:ident,
         $uncompressed:ident,
         $compressed:ident,
-        $pairing:ident
+        $pairing:ident,
+        $engine:ident,
+        $pairingresult:ident
+    ) => {
+        curve_impl!(
+            $name,
+            $projective,
+            $affine,
+            $prepared,
+            $basefield,
+            $scalarfield,
+            $uncompressed,
+            $compressed,
+            $pairing,
+            $engine,
+            $pairingresult,
+            precomp_impl
+        );
+    };
+    (
+        $name:expr,
+        $projective:ident,
+        $affine:ident,
+        $prepared:ident,
+        $basefield:ident,
+        $scalarfield:ident,
+        $uncompressed:ident,
+        $compressed:ident,
+        $pairing:ident,
+        $engine:ident,
+        $pairingresult:ident,
+        $precomp:ident
     ) => {
         #[derive(Copy, Clone, PartialEq, Eq, Debug, Zeroize)]
         pub struct $affine {
@@ -23,21 +54,21 @@ macro_rules! curve_impl {
         }
 
         // set the default values for the group elements to 0s
-        impl ::std::default::Default for $affine {
+        impl ::core::default::Default for $affine {
             fn default() -> Self {
                 $affine::zero()
             }
         }
 
         // set the default values for the group elements to 0s
-        impl ::std::default::Default for $projective {
+        impl ::core::default::Default for $projective {
             fn default() -> Self {
                 $projective::zero()
             }
         }
 
-        impl ::std::fmt::Display for $affine {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        impl ::core::fmt::Display for $affine {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                 if self.infinity {
                     write!(f, "{}(Infinity)", $name)
                 } else {
@@ -63,8 +94,8 @@ macro_rules! curve_impl {
             $projective { x, y, z }
         }
 
-        impl ::std::fmt::Display for $projective {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        impl ::core::fmt::Display for $projective {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                 write!(f, "{}", self.into_affine())
             }
         }
@@ -170,7 +201,7 @@ macro_rules! curve_impl {
         }
 
         impl CurveAffine for $affine {
-            type Engine = Bls12;
+            type Engine = $engine;
             type Scalar = $scalarfield;
             type Base = $basefield;
             type Prepared = $prepared;
@@ -178,7 +209,7 @@ macro_rules! curve_impl {
             type Uncompressed = $uncompressed;
             type Compressed = $compressed;
             type Pair = $pairing;
-            type PairingResult = Fq12;
+            type PairingResult = $pairingresult;
 
             fn zero() -> Self {
                 $affine {
@@ -227,137 +258,7 @@ macro_rules! curve_impl {
                 (&mut self.x, &mut self.y)
             }
 
-            // pre[0] becomes (2^64) * self, pre[1]  becomes (2^128) * self, and pre[2] (becomes 2^196) * self
-            fn precomp_3(&self, pre: &mut [Self]) {
-                // TODO: check if pre has the right length?
-                // TODO: possible optimization: convert 3 points into affine jointly by doing a single inversion, rather than separately
-                // In fact, this function gets called multiple times, and the conversions to affine could be cheaper if done together
-                let mut p = self.into_projective();
-                for i in 0..3 {
-                    for _ in 0..64 {
-                        p.double();
-                    }
-                    pre[i] = p.into_affine();
-                }
-            }
-
-            // Expects pre[0] = (2^64) * self, pre[1] = (2^128) * self, pre[2] = (2^192) * self
-            fn mul_precomp_3<S: Into<<Self::Scalar as PrimeField>::Repr>>(
-                &self,
-                other: S,
-                pre: &[Self],
-            ) -> $projective {
-                // TODO: we may decide we should clear memory, such as the old self
-                // and the precomp array.
-                // For now, none of the other functions do that, either.
-
-                // Interleaved window technique: deal with each of the four words of the scalar in parallel
-                // TODO: possible optimization: maybe convert precomp to affine using a single inversion and about 16 multiplications?
-
-                let mut precomp = Vec::with_capacity(16);
-                precomp.push(Self::Projective::zero()); // 0000 - 0*self
-                precomp.push(self.into_projective()); // 0001 - 1*self
-                precomp.push(pre[0].into_projective()); // 0010 - (2^64)*self
-                precomp.push(precomp[2]);
-                precomp[3].add_assign_mixed(self); // 0011 - (2^64+1)*self
-                precomp.push(pre[1].into_projective()); // 0100 - (2^128)*self
-                precomp.push(precomp[4]);
-                precomp[5].add_assign_mixed(self); // 0101 - (2^128+1)*self
-                precomp.push(precomp[2]);
-                precomp[6].add_assign_mixed(&pre[1]); // 0110 - (2^128+2^64)*self
-                precomp.push(precomp[6]);
-                precomp[7].add_assign_mixed(self); // 0111 - (2^128+2^64+1)*self
-                precomp.push(pre[2].into_projective()); // 1000  - (2^192)*self
-                for i in 9..16 {
-                    precomp.push(precomp[i - 8]);
-                    precomp[i].add_assign_mixed(&pre[2]); // 1001 trough 1111 -- 2^192*self + ...
-                }
-
-                let repr = other.into();
-                let bits: &[u64; 4] = &repr.0;
-                let mut nibble = (bits[3] >> 60) & 8;
-                nibble |= (bits[2] >> 61) & 4;
-                nibble |= (bits[1] >> 62) & 2;
-                nibble |= (bits[0] >> 63) & 1;
-                let mut res = precomp[nibble as usize];
-
-                for i in (0..63).rev() {
-                    res.double();
-                    nibble = ((bits[3] >> i) << 3) & 8; // can't shift by i-3 because it can be negative
-                    nibble |= ((bits[2] >> i) << 2) & 4;
-                    nibble |= ((bits[1] >> i) << 1) & 2;
-                    nibble |= (bits[0] >> i) & 1;
-                    res.add_assign(&precomp[nibble as usize]);
-                }
-                res
-            }
-
-            // pre[i] becomes (\sum_{b such that bth bit of i is 1} 2^{32i}) * self for i in 0..25
-            fn precomp_256(&self, pre: &mut [Self]) {
-                // TODO: check if pre has the right length?
-                // TODO: possible optimization: convert 256 points into affine jointly by doing a single inversion, rather than separately
-                // In fact, this function gets called multiple times, and the conversions to affine could be cheaper if done together
-
-                pre[0] = Self::zero();
-                let mut piece_length = 1;
-                let mut power_of_2_times_self = self.into_projective(); // power_of_2_times_self = 2^{32*piece_length} * self
-                while piece_length <= 128 {
-                    // operate in pieces of length 1, 2, 4, 8, 16, 32, 64, 128
-                    pre[piece_length] = power_of_2_times_self.into_affine();
-                    for i in 1..piece_length {
-                        pre[i + piece_length] = pre[i];
-                        let mut temp = pre[i].into_projective();
-                        temp.add_assign_mixed(&pre[piece_length]);
-                        pre[i + piece_length] = temp.into_affine();
-                    }
-                    if piece_length < 128 {
-                        for _ in 0..32 {
-                            power_of_2_times_self.double();
-                        }
-                    }
-                    piece_length *= 2;
-                }
-            }
-
-            // Expects pre[i] = (\sum_{b such that bth bit of i is 1} 2^{32i}) * self for i in 0..256
-            // pre can be obtained by calling precomp_256
-            fn mul_precomp_256<S: Into<<Self::Scalar as PrimeField>::Repr>>(
-                &self,
-                other: S,
-                pre: &[Self],
-            ) -> $projective {
-                // TODO: we may decide we should clear memory, such as the old self
-                // and the precomp array.
-                // For now, none of the other functions do that, either.
-
-                // Interleaved window technique: deal with each of the 8 32-bit chunks words of the scalar in parallel
-                let repr = other.into();
-                let bits: &[u64; 4] = &repr.0; // Not using as_ref here, to ensure a compile-time error if repr not [u64; 4]
-
-                let mut byte = (bits[3] >> 56) & 128;
-                byte |= (bits[3] >> 25) & 64;
-                byte |= (bits[2] >> 58) & 32;
-                byte |= (bits[2] >> 27) & 16;
-                byte |= (bits[1] >> 60) & 8;
-                byte |= (bits[1] >> 29) & 4;
-                byte |= (bits[0] >> 62) & 2;
-                byte |= (bits[0] >> 31) & 1;
-                let mut res = pre[byte as usize].into_projective();
-
-                for i in (0..31).rev() {
-                    res.double();
-                    byte = (bits[3] >> (i + 25)) & 128;
-                    byte |= ((bits[3] >> i) << 6) & 64; // can't shift by i-6 because it can be negative
-                    byte |= (bits[2] >> (i + 27)) & 32;
-                    byte |= ((bits[2] >> i) << 4) & 16;
-                    byte |= (bits[1] >> (i + 29)) & 8;
-                    byte |= ((bits[1] >> i) << 2) & 4;
-                    byte |= (bits[0] >> (i + 31)) & 2;
-                    byte |= (bits[0] >> i) & 1;
-                    res.add_assign_mixed(&pre[byte as usize]);
-                }
-                res
-            }
+            $precomp!($projective);
 
             // TODO: may want to look at http://cacr.uwaterloo.ca/techreports/2001/corr2001-41.ps;
             // TODO: may want to look at algorithms in Relic https://github.com/relic-toolkit/relic
@@ -409,6 +310,7 @@ macro_rules! curve_impl {
             // This function estimates the number of mixed (projective+affine) and projective additions
             // to well within 1%, and overall running time for G1 to within 3%, at least on one particular machine
             // for num_components < 10000.
+            #[cfg(feature = "std")]
             fn find_pippinger_window_via_estimate(num_components: usize) -> usize {
                 let n_components = num_components as f64;
                 let affine_time = 768.0; // This is from emprirical time (in ns) for a G1 mixed addition
@@ -642,7 +544,7 @@ macro_rules! curve_impl {
         // impl Rand for $projective {}
 
         impl CurveProjective for $projective {
-            type Engine = Bls12;
+            type Engine = $engine;
             type Scalar = $scalarfield;
             type Base = $basefield;
             type Affine = $affine;
@@ -1075,6 +977,145 @@ macro_rules! curve_impl {
     };
 }
 
+// The precomputed multiples of a point and the multiplications that use them,
+// for curves whose scalars are [u64; 4], which the interleaved windows cover
+// exactly.
+macro_rules! precomp_impl {
+    ($projective:ident) => {
+        // pre[0] becomes (2^64) * self, pre[1]  becomes (2^128) * self, and pre[2] (becomes 2^196) * self
+        fn precomp_3(&self, pre: &mut [Self]) {
+            // TODO: check if pre has the right length?
+            // TODO: possible optimization: convert 3 points into affine jointly by doing a single inversion, rather than separately
+            // In fact, this function gets called multiple times, and the conversions to affine could be cheaper if done together
+            let mut p = self.into_projective();
+            for i in 0..3 {
+                for _ in 0..64 {
+                    p.double();
+                }
+                pre[i] = p.into_affine();
+            }
+        }
+
+        // pre[i] becomes (\sum_{b such that bth bit of i is 1} 2^{32i}) * self for i in 0..25
+        fn precomp_256(&self, pre: &mut [Self]) {
+            // TODO: check if pre has the right length?
+            // TODO: possible optimization: convert 256 points into affine jointly by doing a single inversion, rather than separately
+            // In fact, this function gets called multiple times, and the conversions to affine could be cheaper if done together
+
+            pre[0] = Self::zero();
+            let mut piece_length = 1;
+            let mut power_of_2_times_self = self.into_projective(); // power_of_2_times_self = 2^{32*piece_length} * self
+            while piece_length <= 128 {
+                // operate in pieces of length 1, 2, 4, 8, 16, 32, 64, 128
+                pre[piece_length] = power_of_2_times_self.into_affine();
+                for i in 1..piece_length {
+                    pre[i + piece_length] = pre[i];
+                    let mut temp = pre[i].into_projective();
+                    temp.add_assign_mixed(&pre[piece_length]);
+                    pre[i + piece_length] = temp.into_affine();
+                }
+                if piece_length < 128 {
+                    for _ in 0..32 {
+                        power_of_2_times_self.double();
+                    }
+                }
+                piece_length *= 2;
+            }
+        }
+
+        // Expects pre[0] = (2^64) * self, pre[1] = (2^128) * self, pre[2] = (2^192) * self
+        fn mul_precomp_3<S: Into<<Self::Scalar as PrimeField>::Repr>>(
+            &self,
+            other: S,
+            pre: &[Self],
+        ) -> $projective {
+            // TODO: we may decide we should clear memory, such as the old self
+            // and the precomp array.
+            // For now, none of the other functions do that, either.
+
+            // Interleaved window technique: deal with each of the four words of the scalar in parallel
+            // TODO: possible optimization: maybe convert precomp to affine using a single inversion and about 16 multiplications?
+
+            let mut precomp = Vec::with_capacity(16);
+            precomp.push(Self::Projective::zero()); // 0000 - 0*self
+            precomp.push(self.into_projective()); // 0001 - 1*self
+            precomp.push(pre[0].into_projective()); // 0010 - (2^64)*self
+            precomp.push(precomp[2]);
+            precomp[3].add_assign_mixed(self); // 0011 - (2^64+1)*self
+            precomp.push(pre[1].into_projective()); // 0100 - (2^128)*self
+            precomp.push(precomp[4]);
+            precomp[5].add_assign_mixed(self); // 0101 - (2^128+1)*self
+            precomp.push(precomp[2]);
+            precomp[6].add_assign_mixed(&pre[1]); // 0110 - (2^128+2^64)*self
+            precomp.push(precomp[6]);
+            precomp[7].add_assign_mixed(self); // 0111 - (2^128+2^64+1)*self
+            precomp.push(pre[2].into_projective()); // 1000  - (2^192)*self
+            for i in 9..16 {
+                precomp.push(precomp[i - 8]);
+                precomp[i].add_assign_mixed(&pre[2]); // 1001 trough 1111 -- 2^192*self + ...
+            }
+
+            let repr = other.into();
+            let bits: &[u64; 4] = &repr.0;
+            let mut nibble = (bits[3] >> 60) & 8;
+            nibble |= (bits[2] >> 61) & 4;
+            nibble |= (bits[1] >> 62) & 2;
+            nibble |= (bits[0] >> 63) & 1;
+            let mut res = precomp[nibble as usize];
+
+            for i in (0..63).rev() {
+                res.double();
+                nibble = ((bits[3] >> i) << 3) & 8; // can't shift by i-3 because it can be negative
+                nibble |= ((bits[2] >> i) << 2) & 4;
+                nibble |= ((bits[1] >> i) << 1) & 2;
+                nibble |= (bits[0] >> i) & 1;
+                res.add_assign(&precomp[nibble as usize]);
+            }
+            res
+        }
+
+        // Expects pre[i] = (\sum_{b such that bth bit of i is 1} 2^{32i}) * self for i in 0..256
+        // pre can be obtained by calling precomp_256
+        fn mul_precomp_256<S: Into<<Self::Scalar as PrimeField>::Repr>>(
+            &self,
+            other: S,
+            pre: &[Self],
+        ) -> $projective {
+            // TODO: we may decide we should clear memory, such as the old self
+            // and the precomp array.
+            // For now, none of the other functions do that, either.
+
+            // Interleaved window technique: deal with each of the 8 32-bit chunks words of the scalar in parallel
+            let repr = other.into();
+            let bits: &[u64; 4] = &repr.0; // Not using as_ref here, to ensure a compile-time error if repr not [u64; 4]
+
+            let mut byte = (bits[3] >> 56) & 128;
+            byte |= (bits[3] >> 25) & 64;
+            byte |= (bits[2] >> 58) & 32;
+            byte |= (bits[2] >> 27) & 16;
+            byte |= (bits[1] >> 60) & 8;
+            byte |= (bits[1] >> 29) & 4;
+            byte |= (bits[0] >> 62) & 2;
+            byte |= (bits[0] >> 31) & 1;
+            let mut res = pre[byte as usize].into_projective();
+
+            for i in (0..31).rev() {
+                res.double();
+                byte = (bits[3] >> (i + 25)) & 128;
+                byte |= ((bits[3] >> i) << 6) & 64; // can't shift by i-6 because it can be negative
+                byte |= (bits[2] >> (i + 27)) & 32;
+                byte |= ((bits[2] >> i) << 4) & 16;
+                byte |= (bits[1] >> (i + 29)) & 8;
+                byte |= ((bits[1] >> i) << 2) & 4;
+                byte |= (bits[0] >> (i + 31)) & 2;
+                byte |= (bits[0] >> i) & 1;
+                res.add_assign_mixed(&pre[byte as usize]);
+            }
+            res
+        }
+    };
+}
+
 pub mod g1;
 pub mod g2;
 
@@ -0,0 +1,28 @@
+/*!
+Elligator Squared parameters for G1
+*/
+
+use super::{fq_order, write_uniform_fq, ElligatorParams};
+use crate::bls12_381::{isogeny, osswu_map, Fq, G1};
+use alloc::vec::Vec;
+use rand_core::RngCore;
+
+impl ElligatorParams for G1 {
+    const ELLP_A: Fq = osswu_map::g1::ELLP_A;
+    const ELLP_B: Fq = osswu_map::g1::ELLP_B;
+    const XI: Fq = osswu_map::g1::XI;
+    const XNUM: &'static [Fq] = &isogeny::g1::XNUM;
+    const XDEN: &'static [Fq] = &isogeny::g1::XDEN;
+    const YNUM: &'static [Fq] = &isogeny::g1::YNUM;
+    const YDEN: &'static [Fq] = &isogeny::g1::YDEN;
+    // at most 11 isogeny preimages, each with at most 4 OSSWU preimages
+    const MAX_PREIMAGES: usize = 44;
+
+    fn field_order() -> Vec<u64> {
+        fq_order()
+    }
+
+    fn write_uniform<R: RngCore>(u: &Fq, rng: &mut R, out: &mut Vec<u8>) {
+        write_uniform_fq(u, rng, out);
+    }
+}
@@ -0,0 +1,30 @@
+/*!
+Elligator Squared parameters for G2
+*/
+
+use super::{fq2_order, write_uniform_fq, ElligatorParams};
+use crate::bls12_381::{isogeny, osswu_map, Fq2, G2};
+use alloc::vec::Vec;
+use rand_core::RngCore;
+
+impl ElligatorParams for G2 {
+    const ELLP_A: Fq2 = osswu_map::g2::ELLP_A;
+    const ELLP_B: Fq2 = osswu_map::g2::ELLP_B;
+    const XI: Fq2 = osswu_map::g2::XI;
+    const XNUM: &'static [Fq2] = &isogeny::g2::XNUM;
+    const XDEN: &'static [Fq2] = &isogeny::g2::XDEN;
+    const YNUM: &'static [Fq2] = &isogeny::g2::YNUM;
+    const YDEN: &'static [Fq2] = &isogeny::g2::YDEN;
+    // at most 3 isogeny preimages, each with at most 4 OSSWU preimages
+    const MAX_PREIMAGES: usize = 12;
+
+    fn field_order() -> Vec<u64> {
+        fq2_order()
+    }
+
+    // same layout as Fq2::from_ro: c0 followed by c1
+    fn write_uniform<R: RngCore>(u: &Fq2, rng: &mut R, out: &mut Vec<u8>) {
+        write_uniform_fq(&u.c0, rng, out);
+        write_uniform_fq(&u.c1, rng, out);
+    }
+}
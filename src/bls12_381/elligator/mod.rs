@@ -0,0 +1,411 @@
+/*!
+Elligator Squared encoding of G1 and G2 points as uniformly random strings,
+built on the inverse of the optimized simplified SWU map plus isogeny.
+see: https://eprint.iacr.org/2014/043
+
+Encoding a point P samples (u1, u2) uniformly among all pairs with
+
+```text
+isogeny_map(osswu_map(u1)) + isogeny_map(osswu_map(u2)) == P
+```
+
+and decoding evaluates the left-hand side. Each u is then serialized as a
+64-byte integer that is uniform modulo p, so the encoding of a point is
+statistically close to a uniformly random string.
+*/
+
+mod g1;
+mod g2;
+mod poly;
+#[cfg(test)]
+mod tests;
+
+use super::{Fq, FqRepr, IsogenyMap, OSSWUMap};
+use crate::hash_to_field::FromRO;
+use crate::signum::Signum0;
+use crate::CurveProjective;
+use alloc::vec::Vec;
+use digest::generic_array::{typenum::Unsigned, GenericArray};
+use ff::{Field, PrimeField, SqrtField};
+use rand_core::RngCore;
+
+/// Alias for the coordinate type corresponding to a CurveProjective type
+type CoordT<PtT> = <PtT as CurveProjective>::Base;
+
+/// Constants describing the OSSWU map and isogeny for a curve
+pub(crate) trait ElligatorParams: OSSWUMap + IsogenyMap
+where
+    CoordT<Self>: SqrtField + Signum0 + FromRO,
+{
+    /// A coefficient of the isogenous curve E'
+    const ELLP_A: CoordT<Self>;
+    /// B coefficient of the isogenous curve E'
+    const ELLP_B: CoordT<Self>;
+    /// The constant Z (xi) of the OSSWU map
+    const XI: CoordT<Self>;
+    /// Coefficients of the isogeny map polynomials, in ascending order of degree
+    const XNUM: &'static [CoordT<Self>];
+    const XDEN: &'static [CoordT<Self>];
+    const YNUM: &'static [CoordT<Self>];
+    const YDEN: &'static [CoordT<Self>];
+    /// Upper bound on the number of preimages of a point under the map to curve
+    const MAX_PREIMAGES: usize;
+
+    /// Number of elements in the coordinate field, as little-endian u64 limbs
+    fn field_order() -> Vec<u64>;
+
+    /// Serialize a coordinate as a uniformly random string of <CoordT as FromRO>::Length bytes
+    fn write_uniform<R: RngCore>(u: &CoordT<Self>, rng: &mut R, out: &mut Vec<u8>);
+}
+
+/// Elligator Squared encoding of curve points
+pub trait ElligatorSquared: CurveProjective {
+    /// Find every u such that isogeny_map(osswu_map(u)) equals this point.
+    /// Returns an empty vector for the point at infinity.
+    fn map_to_curve_preimages(&self) -> Vec<Self::Base>;
+
+    /// Sample a uniformly random pair (u1, u2) that decodes to this point
+    fn elligator_squared_encode<R: RngCore>(&self, rng: &mut R) -> [Self::Base; 2];
+
+    /// Decode (u1, u2) to isogeny_map(osswu_map(u1)) + isogeny_map(osswu_map(u2)).
+    ///
+    /// The result is only guaranteed to be in the prime-order subgroup if
+    /// (u1, u2) was produced by encoding a point of the subgroup.
+    fn elligator_squared_decode(u: &[Self::Base; 2]) -> Self;
+
+    /// Encode this point as a string of encoded_len() bytes that is
+    /// statistically close to uniform
+    fn to_uniform_bytes<R: RngCore>(&self, rng: &mut R) -> Vec<u8>;
+
+    /// Decode a string produced by to_uniform_bytes. Any string of
+    /// encoded_len() bytes decodes to some point; returns None otherwise.
+    fn from_uniform_bytes(bytes: &[u8]) -> Option<Self>;
+
+    /// The length in bytes of a uniform encoding
+    fn encoded_len() -> usize;
+}
+
+impl<PtT> ElligatorSquared for PtT
+where
+    PtT: ElligatorParams,
+    CoordT<PtT>: SqrtField + Signum0 + FromRO,
+{
+    fn map_to_curve_preimages(&self) -> Vec<CoordT<PtT>> {
+        if self.is_zero() {
+            return vec![];
+        }
+
+        // deterministic randomness for root finding; it does not affect the output
+        let mut rng = SplitMix64(0x5eed_e111_6a70_5eed);
+        let mut ret = vec![];
+        for (x, y) in iso_preimages::<PtT, _>(self, &mut rng) {
+            for u in osswu_preimages::<PtT>(&x, &y) {
+                if !ret.contains(&u) {
+                    ret.push(u);
+                }
+            }
+        }
+        ret
+    }
+
+    fn elligator_squared_encode<R: RngCore>(&self, rng: &mut R) -> [CoordT<PtT>; 2] {
+        loop {
+            let u1 = CoordT::<PtT>::random(rng);
+            let mut q = *self;
+            q.sub_assign(&map_to_curve::<PtT>(&u1));
+
+            // accept with probability (number of preimages) / MAX_PREIMAGES
+            let preimages = q.map_to_curve_preimages();
+            let idx = uniform_below(rng, PtT::MAX_PREIMAGES);
+            if idx < preimages.len() {
+                return [u1, preimages[idx]];
+            }
+        }
+    }
+
+    fn elligator_squared_decode(u: &[CoordT<PtT>; 2]) -> PtT {
+        let mut p = map_to_curve::<PtT>(&u[0]);
+        p.add_assign(&map_to_curve::<PtT>(&u[1]));
+        p
+    }
+
+    fn to_uniform_bytes<R: RngCore>(&self, rng: &mut R) -> Vec<u8> {
+        let u = self.elligator_squared_encode(rng);
+        let mut ret = Vec::with_capacity(Self::encoded_len());
+        PtT::write_uniform(&u[0], rng, &mut ret);
+        PtT::write_uniform(&u[1], rng, &mut ret);
+        ret
+    }
+
+    fn from_uniform_bytes(bytes: &[u8]) -> Option<PtT> {
+        if bytes.len() != Self::encoded_len() {
+            return None;
+        }
+        let (b1, b2) = bytes.split_at(bytes.len() / 2);
+        let u1 = CoordT::<PtT>::from_ro(GenericArray::from_slice(b1));
+        let u2 = CoordT::<PtT>::from_ro(GenericArray::from_slice(b2));
+        Some(Self::elligator_squared_decode(&[u1, u2]))
+    }
+
+    fn encoded_len() -> usize {
+        2 * <CoordT<PtT> as FromRO>::Length::to_usize()
+    }
+}
+
+/// Evaluate the OSSWU map followed by the isogeny
+fn map_to_curve<PtT: OSSWUMap + IsogenyMap>(u: &CoordT<PtT>) -> PtT {
+    let mut p = PtT::osswu_map(u);
+    p.isogeny_map();
+    p
+}
+
+/// Find the affine points (x', y') of E' that the isogeny maps to p
+fn iso_preimages<PtT, R>(p: &PtT, rng: &mut R) -> Vec<(CoordT<PtT>, CoordT<PtT>)>
+where
+    PtT: ElligatorParams,
+    CoordT<PtT>: SqrtField + Signum0 + FromRO,
+    R: RngCore,
+{
+    let (x, y) = {
+        let (x, y, z) = p.as_tuple();
+        let zinv = z.inverse().unwrap();
+        let mut zinv2 = zinv;
+        zinv2.square();
+        let mut x = *x;
+        x.mul_assign(&zinv2);
+        let mut y = *y;
+        y.mul_assign(&zinv2);
+        y.mul_assign(&zinv);
+        (x, y)
+    };
+
+    // x' is a root of XNUM(x') - x * XDEN(x')
+    let mut f = PtT::XNUM.to_vec();
+    for (idx, c) in PtT::XDEN.iter().enumerate() {
+        let mut tmp = *c;
+        tmp.mul_assign(&x);
+        f[idx].sub_assign(&tmp);
+    }
+
+    let mut ret = vec![];
+    for xp in poly::roots(&f, &PtT::field_order(), rng) {
+        // y = y' * YNUM(x') / YDEN(x')
+        let ynum = poly::eval(PtT::YNUM, &xp);
+        if ynum.is_zero() {
+            continue;
+        }
+        let mut yp = poly::eval(PtT::YDEN, &xp);
+        yp.mul_assign(&y);
+        yp.mul_assign(&ynum.inverse().unwrap());
+        ret.push((xp, yp));
+    }
+    ret
+}
+
+/// Roots of a w^2 + b w + c
+fn quadratic_roots<F: SqrtField>(a: &F, b: &F, c: &F) -> Vec<F> {
+    if a.is_zero() {
+        return vec![];
+    }
+    let mut disc = *b;
+    disc.square();
+    let mut four_ac = *a;
+    four_ac.mul_assign(c);
+    four_ac.double();
+    four_ac.double();
+    disc.sub_assign(&four_ac);
+
+    let sqrt_disc = match disc.sqrt() {
+        Some(s) => s,
+        None => return vec![],
+    };
+    let mut two_a_inv = *a;
+    two_a_inv.double();
+    let two_a_inv = two_a_inv.inverse().unwrap();
+
+    let mut ret = Vec::with_capacity(2);
+    for s in [sqrt_disc, {
+        let mut tmp = sqrt_disc;
+        tmp.negate();
+        tmp
+    }]
+    .iter()
+    {
+        let mut w = *s;
+        w.sub_assign(b);
+        w.mul_assign(&two_a_inv);
+        if !ret.contains(&w) {
+            ret.push(w);
+        }
+    }
+    ret
+}
+
+/// Find every u such that osswu_map(u) is the point (x, y) of E'
+fn osswu_preimages<PtT>(x: &CoordT<PtT>, y: &CoordT<PtT>) -> Vec<CoordT<PtT>>
+where
+    PtT: ElligatorParams,
+    CoordT<PtT>: SqrtField + Signum0 + FromRO,
+{
+    // With w = xi u^2 and c = -B / A, the map outputs either
+    //   x0 = c (1 + 1 / (w^2 + w))         if g(x0) is square, or
+    //   x1 = w x0 = c (w^2 + w + 1) / (w + 1)  otherwise,
+    // and u = 0 or xi u^2 = -1 is mapped to x0 = B / (xi A).
+    let c = {
+        let mut tmp = PtT::ELLP_A.inverse().unwrap();
+        tmp.mul_assign(&PtT::ELLP_B);
+        tmp.negate();
+        tmp
+    };
+    let mut c_minus_x = c;
+    c_minus_x.sub_assign(x);
+
+    let mut ws = vec![];
+    // x = x0: w^2 + w - c / (x - c) = 0
+    if !c_minus_x.is_zero() {
+        let mut nd = c_minus_x.inverse().unwrap();
+        nd.mul_assign(&c); // -c / (x - c)
+        ws.extend(quadratic_roots(
+            &CoordT::<PtT>::one(),
+            &CoordT::<PtT>::one(),
+            &nd,
+        ));
+    }
+    // the exceptional case w^2 + w = 0 also yields x0 = B / (xi A)
+    ws.push({
+        let mut tmp = CoordT::<PtT>::one();
+        tmp.negate();
+        tmp
+    });
+    // x = x1: c w^2 + (c - x) w + (c - x) = 0
+    ws.extend(quadratic_roots(&c, &c_minus_x, &c_minus_x));
+
+    let mut candidates = vec![CoordT::<PtT>::zero()];
+    let xi_inv = PtT::XI.inverse().unwrap();
+    for w in ws {
+        let mut usq = w;
+        usq.mul_assign(&xi_inv);
+        if let Some(mut u) = usq.sqrt() {
+            // sgn0(u) must equal sgn0(y)
+            let sgn0_y_xor_u = y.sgn0() ^ u.sgn0();
+            u.negate_if(sgn0_y_xor_u);
+            candidates.push(u);
+        }
+    }
+
+    // keep only the candidates that actually map to (x, y)
+    let mut ret = vec![];
+    for u in candidates {
+        let p = PtT::osswu_map(&u);
+        let (px, py, pz) = p.as_tuple();
+        let mut z2 = *pz;
+        z2.square();
+        let mut xz2 = *x;
+        xz2.mul_assign(&z2);
+        let mut yz3 = *y;
+        yz3.mul_assign(&z2);
+        yz3.mul_assign(pz);
+        if !pz.is_zero() && *px == xz2 && *py == yz3 && !ret.contains(&u) {
+            ret.push(u);
+        }
+    }
+    ret
+}
+
+/// Sample an integer uniformly from [0, n) by rejection
+fn uniform_below<R: RngCore>(rng: &mut R, n: usize) -> usize {
+    let n = n as u64;
+    let bound = u64::MAX - (u64::MAX % n);
+    loop {
+        let v = rng.next_u64();
+        if v < bound {
+            return (v % n) as usize;
+        }
+    }
+}
+
+/// floor(2^512 / p) for the BLS12-381 base field
+const LIFT_BOUND: [u64; 3] = [0x28101b0cc7a6ba29, 0xd835d2f3cc9e45ce, 0x9];
+
+/// Write u + k p as a 64-byte big-endian integer, where k is uniform in
+/// [0, floor(2^512 / p)). The result is within statistical distance 2^-131
+/// of a uniformly random 64-byte string, and Fq::from_okm recovers u.
+fn write_uniform_fq<R: RngCore>(u: &Fq, rng: &mut R, out: &mut Vec<u8>) {
+    // sample k < LIFT_BOUND by rejection; LIFT_BOUND has 132 bits
+    let k = loop {
+        let k = [rng.next_u64(), rng.next_u64(), rng.next_u64() & 0xf];
+        if (k[2], k[1], k[0]) < (LIFT_BOUND[2], LIFT_BOUND[1], LIFT_BOUND[0]) {
+            break k;
+        }
+    };
+
+    // v = u + k * p
+    let p = Fq::char();
+    let mut v = [0u64; 8];
+    v[..6].copy_from_slice(u.into_repr().as_ref());
+    for (idx, kval) in k.iter().enumerate() {
+        let mut carry = 0u128;
+        for (jdx, pval) in p.as_ref().iter().enumerate() {
+            let tmp = (*kval as u128) * (*pval as u128) + (v[idx + jdx] as u128) + carry;
+            v[idx + jdx] = tmp as u64;
+            carry = tmp >> 64;
+        }
+        for vval in v[idx + 6..].iter_mut() {
+            let tmp = (*vval as u128) + carry;
+            *vval = tmp as u64;
+            carry = tmp >> 64;
+        }
+    }
+
+    for vval in v.iter().rev() {
+        out.extend_from_slice(&vval.to_be_bytes());
+    }
+}
+
+/// The order of the BLS12-381 base field, as little-endian u64 limbs
+fn fq_order() -> Vec<u64> {
+    Fq::char().as_ref().to_vec()
+}
+
+/// The order of the quadratic extension of the BLS12-381 base field
+fn fq2_order() -> Vec<u64> {
+    let p: FqRepr = Fq::char();
+    let p = p.as_ref();
+    let mut ret = vec![0u64; 2 * p.len()];
+    for (idx, a) in p.iter().enumerate() {
+        let mut carry = 0u128;
+        for (jdx, b) in p.iter().enumerate() {
+            let tmp = (*a as u128) * (*b as u128) + (ret[idx + jdx] as u128) + carry;
+            ret[idx + jdx] = tmp as u64;
+            carry = tmp >> 64;
+        }
+        ret[idx + p.len()] = carry as u64;
+    }
+    ret
+}
+
+/// A small deterministic generator for the randomness used in root finding
+struct SplitMix64(u64);
+
+impl RngCore for SplitMix64 {
+    fn next_u32(&mut self) -> u32 {
+        self.next_u64() as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        rand_core::impls::fill_bytes_via_next(self, dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
@@ -0,0 +1,228 @@
+/*!
+Dense univariate polynomials over a finite field, with just enough
+arithmetic to find the roots of a polynomial (Cantor-Zassenhaus).
+
+Polynomials are stored as coefficient vectors in ascending order of degree.
+*/
+
+use alloc::vec::Vec;
+use ff::Field;
+use rand_core::RngCore;
+
+/// Remove leading zero coefficients
+fn trim<F: Field>(a: &mut Vec<F>) {
+    while let Some(c) = a.last() {
+        if c.is_zero() {
+            a.pop();
+        } else {
+            break;
+        }
+    }
+}
+
+/// Scale a nonzero polynomial so that its leading coefficient is 1
+fn make_monic<F: Field>(a: &mut Vec<F>) {
+    trim(a);
+    if let Some(lc) = a.last() {
+        let lc_inv = lc.inverse().unwrap();
+        for c in a.iter_mut() {
+            c.mul_assign(&lc_inv);
+        }
+    }
+}
+
+/// Compute (a / m, a mod m) for nonzero m
+fn divrem<F: Field>(a: &[F], m: &[F]) -> (Vec<F>, Vec<F>) {
+    let mut rem = a.to_vec();
+    trim(&mut rem);
+    if rem.len() < m.len() {
+        return (vec![], rem);
+    }
+
+    let lc = m.last().unwrap();
+    let lc_inv = if *lc == F::one() {
+        F::one()
+    } else {
+        lc.inverse().unwrap()
+    };
+    let mut quo = vec![F::zero(); rem.len() - m.len() + 1];
+    for idx in (0..quo.len()).rev() {
+        let mut coeff = rem[idx + m.len() - 1];
+        coeff.mul_assign(&lc_inv);
+        quo[idx] = coeff;
+        for (jdx, mval) in m.iter().enumerate() {
+            let mut tmp = *mval;
+            tmp.mul_assign(&coeff);
+            rem[idx + jdx].sub_assign(&tmp);
+        }
+    }
+    trim(&mut rem);
+    (quo, rem)
+}
+
+/// Compute a * b
+fn mul<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut prod = vec![F::zero(); a.len() + b.len() - 1];
+    for (idx, aval) in a.iter().enumerate() {
+        for (jdx, bval) in b.iter().enumerate() {
+            let mut tmp = *aval;
+            tmp.mul_assign(bval);
+            prod[idx + jdx].add_assign(&tmp);
+        }
+    }
+    prod
+}
+
+/// Compute a * b mod m
+fn mulmod<F: Field>(a: &[F], b: &[F], m: &[F]) -> Vec<F> {
+    divrem(&mul(a, b), m).1
+}
+
+/// Compute base ^ exp mod m, where exp is given as little-endian u64 limbs
+fn powmod<F: Field>(base: &[F], exp: &[u64], m: &[F]) -> Vec<F> {
+    let base = divrem(base, m).1;
+    let mut ret = vec![F::one()];
+    for limb in exp.iter().rev() {
+        for bit in (0..64).rev() {
+            ret = mulmod(&ret, &ret, m);
+            if (limb >> bit) & 1 == 1 {
+                ret = mulmod(&ret, &base, m);
+            }
+        }
+    }
+    ret
+}
+
+/// Monic greatest common divisor of a and b
+fn gcd<F: Field>(a: &[F], b: &[F]) -> Vec<F> {
+    let mut a = a.to_vec();
+    let mut b = b.to_vec();
+    trim(&mut a);
+    trim(&mut b);
+    while !b.is_empty() {
+        let r = divrem(&a, &b).1;
+        a = b;
+        b = r;
+    }
+    make_monic(&mut a);
+    a
+}
+
+/// Subtract 1 from an odd multiprecision integer and halve it
+fn minus_one_over_two(q: &[u64]) -> Vec<u64> {
+    let mut ret = q.to_vec();
+    ret[0] &= !1u64;
+    for idx in 0..ret.len() {
+        ret[idx] >>= 1;
+        if idx + 1 < ret.len() {
+            ret[idx] |= ret[idx + 1] << 63;
+        }
+    }
+    ret
+}
+
+/// Split a monic product of distinct linear factors into its roots
+fn split<F: Field, R: RngCore>(g: Vec<F>, q_m1_over2: &[u64], rng: &mut R, out: &mut Vec<F>) {
+    match g.len() {
+        0 | 1 => (),
+        2 => {
+            let mut root = g[0];
+            root.negate();
+            out.push(root);
+        }
+        _ => loop {
+            // gcd(g, (x + delta)^((q - 1) / 2) - 1) is a nontrivial factor of g
+            // with probability about 1/2
+            let mut t = powmod(&[F::random(rng), F::one()], q_m1_over2, &g);
+            if t.is_empty() {
+                continue;
+            }
+            t[0].sub_assign(&F::one());
+            let d = gcd(&g, &t);
+            if d.len() > 1 && d.len() < g.len() {
+                let (mut h, _) = divrem(&g, &d);
+                make_monic(&mut h);
+                split(d, q_m1_over2, rng, out);
+                split(h, q_m1_over2, rng, out);
+                return;
+            }
+        },
+    }
+}
+
+/// Evaluate a polynomial at x by Horner's rule
+pub(super) fn eval<F: Field>(a: &[F], x: &F) -> F {
+    let mut ret = F::zero();
+    for coeff in a.iter().rev() {
+        ret.mul_assign(x);
+        ret.add_assign(coeff);
+    }
+    ret
+}
+
+/// Find all roots of f in a field with q elements, q odd, where q is given
+/// as little-endian u64 limbs. Roots are returned without multiplicity.
+pub(super) fn roots<F: Field, R: RngCore>(f: &[F], q: &[u64], rng: &mut R) -> Vec<F> {
+    let mut f = f.to_vec();
+    make_monic(&mut f);
+    if f.len() < 2 {
+        return vec![];
+    }
+
+    // gcd(f, x^q - x) is the product of the distinct linear factors of f
+    let mut xq = powmod(&[F::zero(), F::one()], q, &f);
+    xq.resize(core::cmp::max(xq.len(), 2), F::zero());
+    xq[1].sub_assign(&F::one());
+    let g = gcd(&f, &xq);
+
+    let mut ret = Vec::with_capacity(g.len().saturating_sub(1));
+    split(g, &minus_one_over_two(q), rng, &mut ret);
+    ret
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::Fq;
+    use ff::PrimeField;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn test_poly_roots() {
+        let mut rng = rand_xorshift::XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        let q = Fq::char();
+
+        let linear = |r: &Fq| {
+            let mut neg_r = *r;
+            neg_r.negate();
+            vec![neg_r, Fq::one()]
+        };
+
+        for num_roots in 0..6 {
+            // f = (x - r_0) ... (x - r_{n-1}) (x^2 + 1); x^2 + 1 is irreducible since p = 3 mod 4
+            let expect: Vec<Fq> = (0..num_roots).map(|_| Fq::random(&mut rng)).collect();
+            let mut prod = vec![Fq::one(), Fq::zero(), Fq::one()];
+            for r in &expect {
+                prod = mul(&prod, &linear(r));
+            }
+            // a repeated root must not change the result
+            if let Some(r) = expect.first() {
+                prod = mul(&prod, &linear(r));
+            }
+
+            let mut found = roots(&prod, q.as_ref(), &mut rng);
+            assert_eq!(found.len(), expect.len());
+            for r in &expect {
+                assert!(eval(&prod, r).is_zero());
+                let pos = found.iter().position(|s| s == r).unwrap();
+                found.remove(pos);
+            }
+        }
+    }
+}
@@ -0,0 +1,81 @@
+/*!
+Tests for Elligator Squared encoding
+*/
+
+use super::{map_to_curve, ElligatorSquared};
+use crate::bls12_381::{Fq, Fq2, G1, G2};
+use crate::{tests::test_rng, CurveProjective, SubgroupCheck};
+use ff::Field;
+
+#[test]
+fn test_preimages_g1() {
+    let mut rng = test_rng();
+    assert!(G1::zero().map_to_curve_preimages().is_empty());
+    for _ in 0..2 {
+        let u = Fq::random(&mut rng);
+        let p = map_to_curve::<G1>(&u);
+        let preimages = p.map_to_curve_preimages();
+        assert!(preimages.contains(&u));
+        for v in preimages {
+            assert_eq!(map_to_curve::<G1>(&v), p);
+        }
+    }
+}
+
+#[test]
+fn test_preimages_g2() {
+    let mut rng = test_rng();
+    assert!(G2::zero().map_to_curve_preimages().is_empty());
+    for _ in 0..2 {
+        let u = Fq2::random(&mut rng);
+        let p = map_to_curve::<G2>(&u);
+        let preimages = p.map_to_curve_preimages();
+        assert!(preimages.contains(&u));
+        for v in preimages {
+            assert_eq!(map_to_curve::<G2>(&v), p);
+        }
+    }
+}
+
+#[test]
+fn test_elligator_squared_g1() {
+    let mut rng = test_rng();
+    let p = G1::random(&mut rng);
+    let u = p.elligator_squared_encode(&mut rng);
+    assert_eq!(G1::elligator_squared_decode(&u), p);
+
+    let bytes = p.to_uniform_bytes(&mut rng);
+    assert_eq!(bytes.len(), G1::encoded_len());
+    assert_eq!(bytes.len(), 128);
+    let q = G1::from_uniform_bytes(&bytes).unwrap();
+    assert_eq!(q, p);
+    assert!(q.into_affine().in_subgroup());
+    assert_eq!(G1::from_uniform_bytes(&[0u8; 127]), None);
+}
+
+#[test]
+fn test_elligator_squared_g2() {
+    let mut rng = test_rng();
+    for _ in 0..2 {
+        let p = G2::random(&mut rng);
+        let u = p.elligator_squared_encode(&mut rng);
+        assert_eq!(G2::elligator_squared_decode(&u), p);
+
+        let bytes = p.to_uniform_bytes(&mut rng);
+        assert_eq!(bytes.len(), G2::encoded_len());
+        assert_eq!(bytes.len(), 256);
+        let q = G2::from_uniform_bytes(&bytes).unwrap();
+        assert_eq!(q, p);
+        assert!(q.into_affine().in_subgroup());
+    }
+    assert_eq!(G2::from_uniform_bytes(&[0u8; 255]), None);
+}
+
+#[test]
+fn test_elligator_squared_zero() {
+    let mut rng = test_rng();
+    let u = G1::zero().elligator_squared_encode(&mut rng);
+    assert!(G1::elligator_squared_decode(&u).is_zero());
+    let u = G2::zero().elligator_squared_encode(&mut rng);
+    assert!(G2::elligator_squared_decode(&u).is_zero());
+}
@@ -0,0 +1,610 @@
+/*!
+Conversions between the point encodings used by other BLS12-381
+implementations.
+
+`EncodedPoint` and `SerDes` speak the ZCash format, which is also the format
+of the IETF pairing-friendly-curves draft (Appendix C). `PointFormat` names
+it together with the EIP-2537 and arkworks formats, and `G1Affine` and
+`G2Affine` can be encoded to and decoded from any of them. Decoding always
+checks that the point is on the curve and in the prime-order subgroup.
+
+Coordinates of G2 points are elements c0 + c1 * u of Fq2. ZCash writes c1
+before c0, while EIP-2537 and arkworks write c0 first.
+*/
+
+use super::{G1Affine, G2Affine};
+use crate::{CurveAffine, EncodedPoint, Error};
+use alloc::vec::Vec;
+use ff::PrimeFieldDecodingError;
+
+/// Size of an Fq element in the ZCash and arkworks formats.
+const FQ_LEN: usize = 48;
+
+/// Size of an Fq element in EIP-2537, which pads it to 64 bytes.
+const EIP2537_FQ_LEN: usize = 64;
+
+// ZCash flags, in the most significant bits of the first byte.
+const ZCASH_COMPRESSED: u8 = 1 << 7;
+const ZCASH_INFINITY: u8 = 1 << 6;
+const ZCASH_GREATEST: u8 = 1 << 5;
+
+// arkworks flags, in the most significant bits of the last byte. arkworks
+// calls the larger y `YIsNegative`.
+const ARK_Y_IS_LARGER: u8 = 1 << 7;
+const ARK_INFINITY: u8 = 1 << 6;
+
+/// An encoding of BLS12-381 points.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum PointFormat {
+    /// The ZCash compressed format of `EncodedPoint`: the big-endian x
+    /// coordinate, with flags for compression, infinity and the larger y in
+    /// the three most significant bits. Also the IETF draft's compressed
+    /// format.
+    ZcashCompressed,
+    /// The ZCash uncompressed format: big-endian x and y, with the infinity
+    /// flag in the second most significant bit. Also the IETF draft's
+    /// uncompressed format.
+    ZcashUncompressed,
+    /// The EIP-2537 precompile format: big-endian x and y, each Fq element
+    /// left-padded with zeros to 64 bytes. The point at infinity is all
+    /// zeros.
+    Eip2537,
+    /// The arkworks 0.3 compressed format: the little-endian x coordinate,
+    /// with the infinity flag and a flag for the larger y (`YIsNegative`)
+    /// in the two most significant bits of the last byte.
+    ArkworksCompressed,
+    /// The arkworks 0.3 uncompressed format: little-endian x and y, with the
+    /// infinity flag in the second most significant bit of the last byte.
+    /// The point at infinity is written as (0, 1) with that flag.
+    ArkworksUncompressed,
+}
+
+impl PointFormat {
+    /// The number of Fq elements in an encoding of a point whose coordinates
+    /// have `degree` coefficients.
+    fn num_coeffs(self, degree: usize) -> usize {
+        match self {
+            PointFormat::ZcashCompressed | PointFormat::ArkworksCompressed => degree,
+            _ => 2 * degree,
+        }
+    }
+
+    fn len(self, degree: usize) -> usize {
+        match self {
+            PointFormat::Eip2537 => EIP2537_FQ_LEN * self.num_coeffs(degree),
+            _ => FQ_LEN * self.num_coeffs(degree),
+        }
+    }
+
+    /// The size of an encoded G1 point.
+    pub fn g1_len(self) -> usize {
+        self.len(1)
+    }
+
+    /// The size of an encoded G2 point.
+    pub fn g2_len(self) -> usize {
+        self.len(2)
+    }
+
+    fn is_compressed(self) -> bool {
+        self == PointFormat::ZcashCompressed || self == PointFormat::ArkworksCompressed
+    }
+}
+
+/// Splits a ZCash encoding into its flags and its big-endian Fq
+/// coefficients, in the order c0, c1.
+fn split_zcash(bytes: &[u8], degree: usize) -> (u8, Vec<[u8; FQ_LEN]>) {
+    let flags = bytes[0] & (ZCASH_COMPRESSED | ZCASH_INFINITY | ZCASH_GREATEST);
+    let mut coeffs: Vec<[u8; FQ_LEN]> = bytes.chunks(FQ_LEN).map(fq_array).collect();
+    coeffs[0][0] &= !flags;
+    if degree == 2 {
+        for pair in coeffs.chunks_mut(2) {
+            pair.swap(0, 1);
+        }
+    }
+    (flags, coeffs)
+}
+
+/// The inverse of `split_zcash`.
+fn join_zcash(flags: u8, mut coeffs: Vec<[u8; FQ_LEN]>, degree: usize) -> Vec<u8> {
+    if degree == 2 {
+        for pair in coeffs.chunks_mut(2) {
+            pair.swap(0, 1);
+        }
+    }
+    let mut bytes = coeffs.concat();
+    bytes[0] |= flags;
+    bytes
+}
+
+fn fq_array(chunk: &[u8]) -> [u8; FQ_LEN] {
+    let mut a = [0u8; FQ_LEN];
+    a.copy_from_slice(chunk);
+    a
+}
+
+/// Concatenates the coefficients, each reversed to little-endian.
+fn to_le(coeffs: &[[u8; FQ_LEN]]) -> Vec<u8> {
+    coeffs
+        .iter()
+        .flat_map(|c| c.iter().rev().cloned())
+        .collect()
+}
+
+/// Splits little-endian coefficients into big-endian ones.
+fn from_le(bytes: &[u8]) -> Vec<[u8; FQ_LEN]> {
+    bytes
+        .chunks(FQ_LEN)
+        .map(|c| {
+            let mut a = fq_array(c);
+            a.reverse();
+            a
+        })
+        .collect()
+}
+
+/// The arkworks uncompressed encoding of the point at infinity.
+fn ark_uncompressed_infinity(degree: usize) -> Vec<u8> {
+    let mut bytes = vec![0u8; 2 * degree * FQ_LEN];
+    // y = 1
+    bytes[degree * FQ_LEN] = 1;
+    let last = bytes.len() - 1;
+    bytes[last] |= ARK_INFINITY;
+    bytes
+}
+
+fn encode<A: CurveAffine>(p: &A, format: PointFormat, degree: usize) -> Vec<u8> {
+    let zcash = if format.is_compressed() {
+        p.into_compressed().as_ref().to_vec()
+    } else {
+        p.into_uncompressed().as_ref().to_vec()
+    };
+    let (flags, coeffs) = split_zcash(&zcash, degree);
+    let infinity = flags & ZCASH_INFINITY != 0;
+
+    match format {
+        PointFormat::ZcashCompressed | PointFormat::ZcashUncompressed => zcash,
+        PointFormat::Eip2537 => coeffs
+            .iter()
+            .flat_map(|c| {
+                [0u8; EIP2537_FQ_LEN - FQ_LEN]
+                    .iter()
+                    .chain(c.iter())
+                    .cloned()
+            })
+            .collect(),
+        PointFormat::ArkworksCompressed => {
+            let mut bytes = to_le(&coeffs);
+            let last = bytes.len() - 1;
+            if infinity {
+                bytes[last] |= ARK_INFINITY;
+            } else if flags & ZCASH_GREATEST != 0 {
+                bytes[last] |= ARK_Y_IS_LARGER;
+            }
+            bytes
+        }
+        PointFormat::ArkworksUncompressed if infinity => ark_uncompressed_infinity(degree),
+        PointFormat::ArkworksUncompressed => to_le(&coeffs),
+    }
+}
+
+fn decode<A: CurveAffine>(bytes: &[u8], format: PointFormat, degree: usize) -> Result<A, Error> {
+    let expected = format.len(degree);
+    if bytes.len() != expected {
+        return Err(Error::UnexpectedLength {
+            expected,
+            actual: bytes.len(),
+        });
+    }
+
+    let zcash = match format {
+        PointFormat::ZcashCompressed | PointFormat::ZcashUncompressed => bytes.to_vec(),
+        PointFormat::Eip2537 => {
+            if bytes.iter().all(|b| *b == 0) {
+                return Ok(A::zero());
+            }
+            let mut coeffs = vec![];
+            for chunk in bytes.chunks(EIP2537_FQ_LEN) {
+                let (padding, c) = chunk.split_at(EIP2537_FQ_LEN - FQ_LEN);
+                if padding.iter().any(|b| *b != 0) {
+                    return Err(Error::NonCanonicalFieldElement(
+                        "coordinate",
+                        PrimeFieldDecodingError::NotInField("nonzero padding".into()),
+                    ));
+                }
+                coeffs.push(fq_array(c));
+            }
+            join_zcash(0, coeffs, degree)
+        }
+        PointFormat::ArkworksCompressed => {
+            let last = bytes.len() - 1;
+            let flags = bytes[last] & (ARK_Y_IS_LARGER | ARK_INFINITY);
+            let mut bytes = bytes.to_vec();
+            bytes[last] &= !flags;
+
+            let zcash_flags = match flags {
+                ARK_INFINITY => ZCASH_COMPRESSED | ZCASH_INFINITY,
+                ARK_Y_IS_LARGER => ZCASH_COMPRESSED | ZCASH_GREATEST,
+                0 => ZCASH_COMPRESSED,
+                _ => return Err(Error::BadFlags),
+            };
+            join_zcash(zcash_flags, from_le(&bytes), degree)
+        }
+        PointFormat::ArkworksUncompressed => {
+            let last = bytes.len() - 1;
+            if bytes[last] & ARK_Y_IS_LARGER != 0 {
+                return Err(Error::BadFlags);
+            }
+            if bytes[last] & ARK_INFINITY != 0 {
+                return if bytes == &ark_uncompressed_infinity(degree)[..] {
+                    Ok(A::zero())
+                } else {
+                    Err(Error::NonZeroInfinity)
+                };
+            }
+            join_zcash(0, from_le(bytes), degree)
+        }
+    };
+
+    if format.is_compressed() {
+        let mut encoded = A::Compressed::empty();
+        encoded.as_mut().copy_from_slice(&zcash);
+        encoded.into_affine()
+    } else {
+        let mut encoded = A::Uncompressed::empty();
+        encoded.as_mut().copy_from_slice(&zcash);
+        encoded.into_affine()
+    }
+}
+
+impl G1Affine {
+    /// Encodes the point in the given format.
+    pub fn to_format(&self, format: PointFormat) -> Vec<u8> {
+        encode(self, format, 1)
+    }
+
+    /// Decodes a point in the given format, checking that it is on the curve
+    /// and in the prime-order subgroup.
+    pub fn from_format(bytes: &[u8], format: PointFormat) -> Result<Self, Error> {
+        decode(bytes, format, 1)
+    }
+}
+
+impl G2Affine {
+    /// Encodes the point in the given format.
+    pub fn to_format(&self, format: PointFormat) -> Vec<u8> {
+        encode(self, format, 2)
+    }
+
+    /// Decodes a point in the given format, checking that it is on the curve
+    /// and in the prime-order subgroup.
+    pub fn from_format(bytes: &[u8], format: PointFormat) -> Result<Self, Error> {
+        decode(bytes, format, 2)
+    }
+}
+
+/// Re-encodes a G1 point from one format to another, validating it.
+pub fn convert_g1(bytes: &[u8], from: PointFormat, to: PointFormat) -> Result<Vec<u8>, Error> {
+    G1Affine::from_format(bytes, from).map(|p| p.to_format(to))
+}
+
+/// Re-encodes a G2 point from one format to another, validating it.
+pub fn convert_g2(bytes: &[u8], from: PointFormat, to: PointFormat) -> Result<Vec<u8>, Error> {
+    G2Affine::from_format(bytes, from).map(|p| p.to_format(to))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::{G1, G2};
+    use crate::CurveProjective;
+    use rand_core::SeedableRng;
+
+    const FORMATS: [PointFormat; 5] = [
+        PointFormat::ZcashCompressed,
+        PointFormat::ZcashUncompressed,
+        PointFormat::Eip2537,
+        PointFormat::ArkworksCompressed,
+        PointFormat::ArkworksUncompressed,
+    ];
+
+    // Coordinates of the generators, from the IETF pairing-friendly-curves
+    // draft.
+    const G1_X: &str = "17f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb";
+    const G1_Y: &str = "08b3f481e3aaa0f1a09e30ed741d8ae4fcf5e095d5d00af600db18cb2c04b3edd03cc744a2888ae40caa232946c5e7e1";
+    const G2_X_C0: &str = "024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8";
+    const G2_X_C1: &str = "13e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e";
+    const G2_Y_C0: &str = "0ce5d527727d6e118cc9cdc6da2e351aadfd9baa8cbdd3a76d429a695160d12c923ac9cc3baca289e193548608b82801";
+    const G2_Y_C1: &str = "0606c4a02ea734cc32acd2b02bc28b99cb3e287e85a763af267492ab572e99ab3f370d275cec1da1aaa9075ff05f79be";
+
+    // In the arkworks compressed vectors x is little-endian, and the larger
+    // y sets 0x80 in the last byte (`YIsNegative`). The G1 generator's is
+    // the encoding written by ark-bls12-381 0.3.
+
+    fn unhex(s: &str) -> Vec<u8> {
+        hex::decode(s).unwrap()
+    }
+
+    fn le(s: &str) -> Vec<u8> {
+        let mut v = unhex(s);
+        v.reverse();
+        v
+    }
+
+    const PAD: &str = "00000000000000000000000000000000";
+
+    // The points p1 and p2 and the sums g1 + p1 and g2 + p2, from the
+    // bls_g1add_g1+p1 and bls_g2add_g2+p2 cases of the EIP-2537 precompile
+    // test suite.
+    const P1_X: &str = "112b98340eee2777cc3c14163dea3ec97977ac3dc5c70da32e6e87578f44912e902ccef9efe28d4a78b8999dfbca9426";
+    const P1_Y: &str = "186b28d92356c4dfec4b5201ad099dbdede3781f8998ddf929b4cd7756192185ca7b8f4ef7088f813270ac3d48868a21";
+    const G1_P1_X: &str = "0a40300ce2dec9888b60690e9a41d3004fda4886854573974fab73b046d3147ba5b7a5bde85279ffede1b45b3918d82d";
+    const G1_P1_Y: &str = "06d3d887e9f53b9ec4eb6cedf5607226754b07c01ace7834f57f3e7315faefb739e59018e22c492006190fba4a870025";
+    const P2_X_C0: &str = "103121a2ceaae586d240843a398967325f8eb5a93e8fea99b62b9f88d8556c80dd726a4b30e84a36eeabaf3592937f27";
+    const P2_X_C1: &str = "086b990f3da2aeac0a36143b7d7c824428215140db1bb859338764cb58458f081d92664f9053b50b3fbd2e4723121b68";
+    const P2_Y_C0: &str = "0f9e7ba9a86a8f7624aa2b42dcc8772e1af4ae115685e60abc2c9b90242167acef3d0be4050bf935eed7c3b6fc7ba77e";
+    const P2_Y_C1: &str = "0d22c3652d0dc6f0fc9316e14268477c2049ef772e852108d269d9c38dba1d4802e8dae479818184c08f9a569d878451";
+    const G2_P2_X_C0: &str = "0b54a8a7b08bd6827ed9a797de216b8c9057b3a9ca93e2f88e7f04f19accc42da90d883632b9ca4dc38d013f71ede4db";
+    const G2_P2_X_C1: &str = "077eba4eecf0bd764dce8ed5f45040dd8f3b3427cb35230509482c14651713282946306247866dfe39a8e33016fcbe52";
+    const G2_P2_Y_C0: &str = "14e60a76a29ef85cbd69f251b9f29147b67cfe3ed2823d3f9776b3a0efd2731941d47436dc6d2b58d9e65f8438bad073";
+    const G2_P2_Y_C1: &str = "1586c3c910d95754fef7a732df78e279c3d37431c6a2b77e67a00c7c130a8fcd4d19f159cbeb997a178108fffffcbd20";
+
+    #[test]
+    fn test_g1_generator_vectors() {
+        let g = G1Affine::one();
+
+        // ZCash / IETF: the generator has the smaller y
+        let zcash = unhex("97f1d3a73197d7942695638c4fa9ac0fc3688c4f9774b905a14e3a3f171bac586c55e83ff97a1aeffb3af00adb22c6bb");
+        assert_eq!(g.to_format(PointFormat::ZcashCompressed), zcash);
+        assert_eq!(
+            g.to_format(PointFormat::ZcashUncompressed),
+            unhex(&[G1_X, G1_Y].concat())
+        );
+
+        // EIP-2537
+        let eip = unhex(&[PAD, G1_X, PAD, G1_Y].concat());
+        assert_eq!(g.to_format(PointFormat::Eip2537), eip);
+
+        // arkworks: the generator has the smaller y, so no flag is set
+        let ark = unhex("bbc622db0af03afbef1a7af93fe8556c58ac1b173f3a4ea105b974974f8c68c30faca94f8c63952694d79731a7d3f117");
+        assert_eq!(g.to_format(PointFormat::ArkworksCompressed), ark);
+        let ark = [le(G1_X), le(G1_Y)].concat();
+        assert_eq!(g.to_format(PointFormat::ArkworksUncompressed), ark);
+
+        for &format in FORMATS.iter() {
+            let bytes = g.to_format(format);
+            assert_eq!(bytes.len(), format.g1_len());
+            assert_eq!(G1Affine::from_format(&bytes, format).unwrap(), g);
+        }
+    }
+
+    #[test]
+    fn test_g2_generator_vectors() {
+        let g = G2Affine::one();
+
+        let zcash = unhex("93e02b6052719f607dacd3a088274f65596bd0d09920b61ab5da61bbdc7f5049334cf11213945d57e5ac7d055d042b7e024aa2b2f08f0a91260805272dc51051c6e47ad4fa403b02b4510b647ae3d1770bac0326a805bbefd48056c8c121bdb8");
+        assert_eq!(g.to_format(PointFormat::ZcashCompressed), zcash);
+        assert_eq!(
+            g.to_format(PointFormat::ZcashUncompressed),
+            unhex(&[G2_X_C1, G2_X_C0, G2_Y_C1, G2_Y_C0].concat())
+        );
+
+        let eip = unhex(&[PAD, G2_X_C0, PAD, G2_X_C1, PAD, G2_Y_C0, PAD, G2_Y_C1].concat());
+        assert_eq!(g.to_format(PointFormat::Eip2537), eip);
+
+        let ark = unhex("b8bd21c1c85680d4efbb05a82603ac0b77d1e37a640b51b4023b40fad47ae4c65110c52d27050826910a8ff0b2a24a027e2b045d057dace5575d941312f14c3349507fdcbb61dab51ab62099d0d06b59654f2788a0d3ac7d609f7152602be013");
+        assert_eq!(g.to_format(PointFormat::ArkworksCompressed), ark);
+        let ark = [le(G2_X_C0), le(G2_X_C1), le(G2_Y_C0), le(G2_Y_C1)].concat();
+        assert_eq!(g.to_format(PointFormat::ArkworksUncompressed), ark);
+
+        for &format in FORMATS.iter() {
+            let bytes = g.to_format(format);
+            assert_eq!(bytes.len(), format.g2_len());
+            assert_eq!(G2Affine::from_format(&bytes, format).unwrap(), g);
+        }
+    }
+
+    #[test]
+    fn test_eip2537_add_vectors() {
+        let g1 = unhex(&[PAD, G1_X, PAD, G1_Y].concat());
+        let p1 = unhex(&[PAD, P1_X, PAD, P1_Y].concat());
+        let mut sum = G1Affine::from_format(&g1, PointFormat::Eip2537)
+            .unwrap()
+            .into_projective();
+        sum.add_assign_mixed(&G1Affine::from_format(&p1, PointFormat::Eip2537).unwrap());
+        assert_eq!(
+            sum.into_affine().to_format(PointFormat::Eip2537),
+            unhex(&[PAD, G1_P1_X, PAD, G1_P1_Y].concat())
+        );
+
+        let g2 = unhex(&[PAD, G2_X_C0, PAD, G2_X_C1, PAD, G2_Y_C0, PAD, G2_Y_C1].concat());
+        let p2 = unhex(&[PAD, P2_X_C0, PAD, P2_X_C1, PAD, P2_Y_C0, PAD, P2_Y_C1].concat());
+        let mut sum = G2Affine::from_format(&g2, PointFormat::Eip2537)
+            .unwrap()
+            .into_projective();
+        sum.add_assign_mixed(&G2Affine::from_format(&p2, PointFormat::Eip2537).unwrap());
+        assert_eq!(
+            sum.into_affine().to_format(PointFormat::Eip2537),
+            unhex(&[PAD, G2_P2_X_C0, PAD, G2_P2_X_C1, PAD, G2_P2_Y_C0, PAD, G2_P2_Y_C1].concat())
+        );
+    }
+
+    #[test]
+    fn test_g1_point_vectors() {
+        let eip = unhex(&[PAD, P1_X, PAD, P1_Y].concat());
+        let p = G1Affine::from_format(&eip, PointFormat::Eip2537).unwrap();
+        assert_ne!(p, G1Affine::one());
+
+        // p1 has the larger y, which sets the arkworks flag
+        let zcash = unhex("b12b98340eee2777cc3c14163dea3ec97977ac3dc5c70da32e6e87578f44912e902ccef9efe28d4a78b8999dfbca9426");
+        let vectors = [
+            (PointFormat::ZcashCompressed, zcash),
+            (
+                PointFormat::ZcashUncompressed,
+                unhex(&[P1_X, P1_Y].concat()),
+            ),
+            (PointFormat::Eip2537, eip),
+            (
+                PointFormat::ArkworksCompressed,
+                unhex("2694cafb9d99b8784a8de2eff9ce2c902e91448f57876e2ea30dc7c53dac7779c93eea3d16143ccc7727ee0e34982b91"),
+            ),
+            (
+                PointFormat::ArkworksUncompressed,
+                [le(P1_X), le(P1_Y)].concat(),
+            ),
+        ];
+        for (format, bytes) in vectors.iter() {
+            assert_eq!(p.to_format(*format), *bytes);
+            assert_eq!(G1Affine::from_format(bytes, *format).unwrap(), p);
+        }
+    }
+
+    #[test]
+    fn test_g2_point_vectors() {
+        let eip = unhex(&[PAD, P2_X_C0, PAD, P2_X_C1, PAD, P2_Y_C0, PAD, P2_Y_C1].concat());
+        let p = G2Affine::from_format(&eip, PointFormat::Eip2537).unwrap();
+        assert_ne!(p, G2Affine::one());
+
+        // p2 has the larger y, which sets the arkworks flag
+        let zcash = unhex("a86b990f3da2aeac0a36143b7d7c824428215140db1bb859338764cb58458f081d92664f9053b50b3fbd2e4723121b68103121a2ceaae586d240843a398967325f8eb5a93e8fea99b62b9f88d8556c80dd726a4b30e84a36eeabaf3592937f27");
+        let vectors = [
+            (PointFormat::ZcashCompressed, zcash),
+            (
+                PointFormat::ZcashUncompressed,
+                unhex(&[P2_X_C1, P2_X_C0, P2_Y_C1, P2_Y_C0].concat()),
+            ),
+            (PointFormat::Eip2537, eip),
+            (
+                PointFormat::ArkworksCompressed,
+                unhex("277f939235afabee364ae8304b6a72dd806c55d8889f2bb699ea8f3ea9b58e5f326789393a8440d286e5aacea2213110681b1223472ebd3f0bb553904f66921d088f4558cb64873359b81bdb4051212844827c7d3b14360aacaea23d0f996b88"),
+            ),
+            (
+                PointFormat::ArkworksUncompressed,
+                [le(P2_X_C0), le(P2_X_C1), le(P2_Y_C0), le(P2_Y_C1)].concat(),
+            ),
+        ];
+        for (format, bytes) in vectors.iter() {
+            assert_eq!(p.to_format(*format), *bytes);
+            assert_eq!(G2Affine::from_format(bytes, *format).unwrap(), p);
+        }
+    }
+
+    #[test]
+    fn test_infinity_vectors() {
+        let zero = G1Affine::zero();
+        let mut zcash = vec![0u8; 48];
+        zcash[0] = 0xc0;
+        assert_eq!(zero.to_format(PointFormat::ZcashCompressed), zcash);
+        let mut zcash = vec![0u8; 96];
+        zcash[0] = 0x40;
+        assert_eq!(zero.to_format(PointFormat::ZcashUncompressed), zcash);
+        assert_eq!(zero.to_format(PointFormat::Eip2537), vec![0u8; 128]);
+        let mut ark = vec![0u8; 48];
+        ark[47] = 0x40;
+        assert_eq!(zero.to_format(PointFormat::ArkworksCompressed), ark);
+        let mut ark = vec![0u8; 96];
+        ark[48] = 1;
+        ark[95] = 0x40;
+        assert_eq!(zero.to_format(PointFormat::ArkworksUncompressed), ark);
+
+        let zero2 = G2Affine::zero();
+        let mut zcash = vec![0u8; 96];
+        zcash[0] = 0xc0;
+        assert_eq!(zero2.to_format(PointFormat::ZcashCompressed), zcash);
+        let mut zcash = vec![0u8; 192];
+        zcash[0] = 0x40;
+        assert_eq!(zero2.to_format(PointFormat::ZcashUncompressed), zcash);
+        assert_eq!(zero2.to_format(PointFormat::Eip2537), vec![0u8; 256]);
+        let mut ark = vec![0u8; 96];
+        ark[95] = 0x40;
+        assert_eq!(zero2.to_format(PointFormat::ArkworksCompressed), ark);
+        // y = 1 + 0 * u
+        let mut ark = vec![0u8; 192];
+        ark[96] = 1;
+        ark[191] = 0x40;
+        assert_eq!(zero2.to_format(PointFormat::ArkworksUncompressed), ark);
+
+        for &format in FORMATS.iter() {
+            let bytes = zero.to_format(format);
+            assert!(G1Affine::from_format(&bytes, format).unwrap().is_zero());
+            let bytes = G2Affine::zero().to_format(format);
+            assert!(G2Affine::from_format(&bytes, format).unwrap().is_zero());
+        }
+    }
+
+    #[test]
+    fn test_conversions() {
+        let mut rng = rand_xorshift::XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..10 {
+            let p = G1::random(&mut rng).into_affine();
+            let q = G2::random(&mut rng).into_affine();
+            for &from in FORMATS.iter() {
+                for &to in FORMATS.iter() {
+                    assert_eq!(
+                        convert_g1(&p.to_format(from), from, to).unwrap(),
+                        p.to_format(to)
+                    );
+                    assert_eq!(
+                        convert_g2(&q.to_format(from), from, to).unwrap(),
+                        q.to_format(to)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_encodings() {
+        let g = G1Affine::one();
+
+        match G1Affine::from_format(&[0u8; 47], PointFormat::ZcashCompressed) {
+            Err(Error::UnexpectedLength {
+                expected: 48,
+                actual: 47,
+            }) => (),
+            _ => panic!("expected a length error"),
+        }
+
+        let mut eip = g.to_format(PointFormat::Eip2537);
+        eip[0] = 1;
+        match G1Affine::from_format(&eip, PointFormat::Eip2537) {
+            Err(Error::NonCanonicalFieldElement(..)) => (),
+            _ => panic!("expected nonzero padding to be rejected"),
+        }
+
+        let mut ark = g.to_format(PointFormat::ArkworksCompressed);
+        ark[47] |= 0xc0;
+        match G1Affine::from_format(&ark, PointFormat::ArkworksCompressed) {
+            Err(Error::BadFlags) => (),
+            _ => panic!("expected both flags to be rejected"),
+        }
+
+        let mut ark = g.to_format(PointFormat::ArkworksUncompressed);
+        ark[95] |= 0x80;
+        match G1Affine::from_format(&ark, PointFormat::ArkworksUncompressed) {
+            Err(Error::BadFlags) => (),
+            _ => panic!("expected a sign flag on an uncompressed point to be rejected"),
+        }
+
+        let mut ark = G1Affine::zero().to_format(PointFormat::ArkworksUncompressed);
+        ark[0] = 1;
+        match G1Affine::from_format(&ark, PointFormat::ArkworksUncompressed) {
+            Err(Error::NonZeroInfinity) => (),
+            _ => panic!("expected a nonzero point at infinity to be rejected"),
+        }
+
+        // flipping the sign gives the negation
+        let mut ark = g.to_format(PointFormat::ArkworksCompressed);
+        ark[47] ^= 0x80;
+        let mut neg = g;
+        neg.negate();
+        assert_eq!(
+            G1Affine::from_format(&ark, PointFormat::ArkworksCompressed).unwrap(),
+            neg
+        );
+    }
+}
@@ -5,7 +5,6 @@ use crate::{
 };
 use digest::generic_array::{typenum::U64, GenericArray};
 use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
-use std::io::{Cursor, Read};
 
 // B coefficient of BLS12-381 curve, 4.
 pub const B_COEFF: Fq = Fq(FqRepr([
@@ -470,6 +469,47 @@ pub const unsafe fn transmute(r: FqRepr) -> Fq {
     Fq(r)
 }
 
+/// set the default value for Fq to 0
+impl ::core::default::Default for Fq {
+    fn default() -> Self {
+        Fq::zero()
+    }
+}
+
+ff13_prime_field_impl!(
+    Fq,
+    FqRepr,
+    6,
+    super::compat::FqBytes,
+    read_be,
+    write_be,
+    modulus = "0x1a0111ea397fe69a4b1ba7b6434bacd764774b84f38512bf6730d2a0f6b0f6241eabfffeb153ffffb9feffffffffaaab",
+    two_inv = [
+        0x1804000000015554,
+        0x855000053ab00001,
+        0x633cb57c253c276f,
+        0x6e22d1ec31ebb502,
+        0xd3916126f2d14ca2,
+        0x17fbb8571a006596,
+    ],
+    root_of_unity_inv = [
+        0x43f5fffffffcaaae,
+        0x32b7fff2ed47fffd,
+        0x07e83a49a2e99d69,
+        0xeca8f3318332bb7a,
+        0xef148d1ea0f4c069,
+        0x040ab3263eff0206,
+    ],
+    delta = [
+        0xaa270000000cfff3,
+        0x53cc0032fc34000a,
+        0x478fe97a6b0a807f,
+        0xb1d37ebee6ba24d7,
+        0x8ec9733bbf78ab2f,
+        0x09d645513d83de7e,
+    ],
+);
+
 impl BaseFromRO for Fq {
     type BaseLength = U64;
 
@@ -488,13 +528,14 @@ impl BaseFromRO for Fq {
 
         // unwraps are safe here: we only use 32 bytes at a time, which is strictly less than p
         let mut repr = FqRepr::default();
-        repr.read_be(Cursor::new([0; 16]).chain(Cursor::new(&okm[..32])))
-            .unwrap();
+        let mut buf = [0u8; 48];
+        buf[16..].copy_from_slice(&okm[..32]);
+        repr.read_be(&buf[..]).unwrap();
         let mut elm = Fq::from_repr(repr).unwrap();
         elm.mul_assign(&F_2_256);
 
-        repr.read_be(Cursor::new([0; 16]).chain(Cursor::new(&okm[32..])))
-            .unwrap();
+        buf[16..].copy_from_slice(&okm[32..]);
+        repr.read_be(&buf[..]).unwrap();
         let elm2 = Fq::from_repr(repr).unwrap();
         elm.add_assign(&elm2);
         elm
@@ -1243,7 +1284,7 @@ use rand_core::SeedableRng;
 
 #[test]
 fn test_fq_repr_ordering() {
-    use std::cmp::Ordering;
+    use core::cmp::Ordering;
 
     fn assert_equality(a: FqRepr, b: FqRepr) {
         assert_eq!(a, b);
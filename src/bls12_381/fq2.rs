@@ -1,47 +1,48 @@
 use super::fq::{Fq, FROBENIUS_COEFF_FQ2_C1, NEGATIVE_ONE};
 use crate::{
     hash_to_field::{BaseFromRO, FromRO},
-    signum::{Sgn0Result, Signum0},
+    tower::{QuadraticExtParameters, QuadraticExtension},
 };
 use digest::generic_array::{
     typenum::{U128, U64},
     GenericArray,
 };
 use ff::{Field, SqrtField};
-use std::cmp::Ordering;
 
-/// An element of Fq2, represented by c0 + c1 * u.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Zeroize)]
-pub struct Fq2 {
-    pub c0: Fq,
-    pub c1: Fq,
-}
+/// Parameters of Fq2 = Fq[u] / (u^2 + 1).
+#[derive(Copy, Clone, Debug)]
+pub struct Fq2Parameters;
+
+impl QuadraticExtParameters for Fq2Parameters {
+    type BaseField = Fq;
+
+    const NAME: &'static str = "Fq2";
+    const VAR: &'static str = "u";
 
-impl ::std::fmt::Display for Fq2 {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
-        write!(f, "Fq2({} + {} * u)", self.c0, self.c1)
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &mut Fq) {
+        fe.negate();
     }
-}
 
-/// `Fq2` elements are ordered lexicographically.
-impl Ord for Fq2 {
     #[inline(always)]
-    fn cmp(&self, other: &Fq2) -> Ordering {
-        match self.c1.cmp(&other.c1) {
-            Ordering::Greater => Ordering::Greater,
-            Ordering::Less => Ordering::Less,
-            Ordering::Equal => self.c0.cmp(&other.c0),
-        }
+    fn add_mul_by_nonresidue(acc: &mut Fq, fe: &Fq) {
+        acc.sub_assign(fe);
     }
-}
 
-impl PartialOrd for Fq2 {
     #[inline(always)]
-    fn partial_cmp(&self, other: &Fq2) -> Option<Ordering> {
-        Some(self.cmp(other))
+    fn sub_mul_by_nonresidue(acc: &mut Fq, fe: &Fq) {
+        acc.add_assign(fe);
+    }
+
+    #[inline(always)]
+    fn mul_by_frobenius_coeff(c1: &mut Fq, power: usize) {
+        c1.mul_assign(&FROBENIUS_COEFF_FQ2_C1[power % 2]);
     }
 }
 
+/// An element of Fq2, represented by c0 + c1 * u.
+pub type Fq2 = QuadraticExtension<Fq2Parameters>;
+
 impl Fq2 {
     /// Multiply this element by the cubic and quadratic nonresidue 1 + u.
     pub fn mul_by_nonresidue(&mut self) {
@@ -49,117 +50,6 @@ impl Fq2 {
         self.c0.sub_assign(&self.c1);
         self.c1.add_assign(&t0);
     }
-
-    /// Norm of Fq2 as extension field in i over Fq
-    pub fn norm(&self) -> Fq {
-        let mut t0 = self.c0;
-        let mut t1 = self.c1;
-        t0.square();
-        t1.square();
-        t1.add_assign(&t0);
-
-        t1
-    }
-}
-
-impl Field for Fq2 {
-    fn random<R: rand_core::RngCore + ?std::marker::Sized>(rng: &mut R) -> Self {
-        Fq2 {
-            c0: Fq::random(rng),
-            c1: Fq::random(rng),
-        }
-    }
-    fn zero() -> Self {
-        Fq2 {
-            c0: Fq::zero(),
-            c1: Fq::zero(),
-        }
-    }
-
-    fn one() -> Self {
-        Fq2 {
-            c0: Fq::one(),
-            c1: Fq::zero(),
-        }
-    }
-
-    fn is_zero(&self) -> bool {
-        self.c0.is_zero() && self.c1.is_zero()
-    }
-
-    fn square(&mut self) {
-        let mut ab = self.c0;
-        ab.mul_assign(&self.c1);
-        let mut c0c1 = self.c0;
-        c0c1.add_assign(&self.c1);
-        let mut c0 = self.c1;
-        c0.negate();
-        c0.add_assign(&self.c0);
-        c0.mul_assign(&c0c1);
-        c0.sub_assign(&ab);
-        self.c1 = ab;
-        self.c1.add_assign(&ab);
-        c0.add_assign(&ab);
-        self.c0 = c0;
-    }
-
-    fn double(&mut self) {
-        self.c0.double();
-        self.c1.double();
-    }
-
-    fn negate(&mut self) {
-        self.c0.negate();
-        self.c1.negate();
-    }
-
-    fn add_assign(&mut self, other: &Self) {
-        self.c0.add_assign(&other.c0);
-        self.c1.add_assign(&other.c1);
-    }
-
-    fn sub_assign(&mut self, other: &Self) {
-        self.c0.sub_assign(&other.c0);
-        self.c1.sub_assign(&other.c1);
-    }
-
-    fn mul_assign(&mut self, other: &Self) {
-        let mut aa = self.c0;
-        aa.mul_assign(&other.c0);
-        let mut bb = self.c1;
-        bb.mul_assign(&other.c1);
-        let mut o = other.c0;
-        o.add_assign(&other.c1);
-        self.c1.add_assign(&self.c0);
-        self.c1.mul_assign(&o);
-        self.c1.sub_assign(&aa);
-        self.c1.sub_assign(&bb);
-        self.c0 = aa;
-        self.c0.sub_assign(&bb);
-    }
-
-    fn inverse(&self) -> Option<Self> {
-        let mut t1 = self.c1;
-        t1.square();
-        let mut t0 = self.c0;
-        t0.square();
-        t0.add_assign(&t1);
-        t0.inverse().map(|t| {
-            let mut tmp = Fq2 {
-                c0: self.c0,
-                c1: self.c1,
-            };
-            tmp.c0.mul_assign(&t);
-            tmp.c1.mul_assign(&t);
-            tmp.c1.negate();
-
-            tmp
-        })
-    }
-
-    fn frobenius_map(&mut self, power: usize) {
-        self.c1.mul_assign(&FROBENIUS_COEFF_FQ2_C1[power % 2]);
-    }
 }
 
 impl SqrtField for Fq2 {
@@ -235,19 +125,10 @@ impl FromRO for Fq2 {
     }
 }
 
-impl Signum0 for Fq2 {
-    fn sgn0(&self) -> Sgn0Result {
-        let Fq2 { c0, c1 } = self;
-        if c0.is_zero() {
-            c1.sgn0()
-        } else {
-            c0.sgn0()
-        }
-    }
-}
-
 #[test]
 fn test_fq2_ordering() {
+    use core::cmp::Ordering;
+
     let mut a = Fq2 {
         c0: Fq::zero(),
         c1: Fq::zero(),
@@ -1221,6 +1102,7 @@ fn test_fq2_hash_to_field_xmd_sha256() {
 #[test]
 fn test_fq2_sgn0() {
     use super::fq::P_M1_OVER2;
+    use crate::signum::{Sgn0Result, Signum0};
 
     assert_eq!(Fq2::zero().sgn0(), Sgn0Result::NonNegative);
     assert_eq!(Fq2::one().sgn0(), Sgn0Result::Negative);
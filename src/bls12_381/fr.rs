@@ -1,7 +1,6 @@
-use crate::hash_to_field::BaseFromRO;
+use crate::{hash_to_field::BaseFromRO, hash_to_scalar::FromBytesWide};
 use digest::generic_array::{typenum::U48, GenericArray};
 use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
-use std::io::{Cursor, Read};
 
 #[derive(PrimeField, Zeroize)]
 #[PrimeFieldModulus = "52435875175126190479447740508185965837690552500527637822603658699938581184513"]
@@ -9,7 +8,7 @@ use std::io::{Cursor, Read};
 pub struct Fr(FrRepr);
 
 /// set the default value for Fr to 0
-impl ::std::default::Default for Fr {
+impl ::core::default::Default for Fr {
     fn default() -> Self {
         Fr::zero()
     }
@@ -20,31 +19,80 @@ pub const unsafe fn transmute(r: FrRepr) -> Fr {
     Fr(r)
 }
 
+ff13_prime_field_impl!(
+    Fr,
+    FrRepr,
+    4,
+    [u8; 32],
+    read_le,
+    write_le,
+    modulus = "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+    two_inv = [
+        0x00000000ffffffff,
+        0xac425bfd0001a401,
+        0xccc627f7f65e27fa,
+        0x0c1258acd66282b7,
+    ],
+    root_of_unity_inv = [
+        0x4256481adcf3219a,
+        0x45f37b7f96b6cad3,
+        0xf9c3f1d75f7a3b27,
+        0x2d2fc049658afd43,
+    ],
+    delta = [
+        0x70e310d3d146f96a,
+        0x4b64c08919e299e6,
+        0x51e114186a8b970d,
+        0x6185d06627c067cb,
+    ],
+);
+
+// 2^192 in Montgomery form
+const F_2_192: Fr = Fr(FrRepr([
+    0x59476ebc41b4528fu64,
+    0xc5a30cb243fcc152u64,
+    0x2b34e63940ccbd72u64,
+    0x1e179025ca247088u64,
+]));
+
 impl BaseFromRO for Fr {
     type BaseLength = U48;
 
     fn from_okm(okm: &GenericArray<u8, U48>) -> Fr {
-        const F_2_192: Fr = Fr(FrRepr([
-            0x59476ebc41b4528fu64,
-            0xc5a30cb243fcc152u64,
-            0x2b34e63940ccbd72u64,
-            0x1e179025ca247088u64,
-        ]));
-
         // unwraps are safe here: we only use 24 bytes at a time, which is strictly less than p
         let mut repr = FrRepr::default();
-        repr.read_be(Cursor::new([0; 8]).chain(Cursor::new(&okm[..24])))
-            .unwrap();
+        let mut buf = [0u8; 32];
+        buf[8..].copy_from_slice(&okm[..24]);
+        repr.read_be(&buf[..]).unwrap();
         let mut elm = Fr::from_repr(repr).unwrap();
         elm.mul_assign(&F_2_192);
 
-        repr.read_be(Cursor::new([0; 8]).chain(Cursor::new(&okm[24..])))
-            .unwrap();
+        buf[8..].copy_from_slice(&okm[24..]);
+        repr.read_be(&buf[..]).unwrap();
         elm.add_assign(&Fr::from_repr(repr).unwrap());
         elm
     }
 }
 
+impl FromBytesWide for Fr {
+    fn from_bytes_wide(bytes: &[u8; 64]) -> Fr {
+        // unwraps are safe here: we use at most 24 bytes at a time, which is strictly less than p
+        let mut repr = FrRepr::default();
+        let mut buf = [0u8; 32];
+        buf[16..].copy_from_slice(&bytes[..16]);
+        repr.read_be(&buf[..]).unwrap();
+        let mut elm = Fr::from_repr(repr).unwrap();
+
+        for chunk in bytes[16..].chunks(24) {
+            elm.mul_assign(&F_2_192);
+            buf[8..].copy_from_slice(chunk);
+            repr.read_be(&buf[..]).unwrap();
+            elm.add_assign(&Fr::from_repr(repr).unwrap());
+        }
+        elm
+    }
+}
+
 #[cfg(test)]
 use rand_core::SeedableRng;
 
@@ -52,7 +100,7 @@ use rand_core::SeedableRng;
 fn test_fr_repr_ordering() {
     fn assert_equality(a: FrRepr, b: FrRepr) {
         assert_eq!(a, b);
-        assert!(a.cmp(&b) == ::std::cmp::Ordering::Equal);
+        assert!(a.cmp(&b) == ::core::cmp::Ordering::Equal);
     }
 
     fn assert_lt(a: FrRepr, b: FrRepr) {
@@ -6,7 +6,7 @@ use super::{eval_iso, IsogenyMap};
 use crate::bls12_381::{Fq, FqRepr, G1};
 
 /// Coefficients of the 11-isogeny x map's numerator
-const XNUM: [Fq; 12] = [
+pub(crate) const XNUM: [Fq; 12] = [
     Fq(FqRepr([
         0x4d18b6f3af00131cu64,
         0x19fa219793fee28cu64,
@@ -106,7 +106,7 @@ const XNUM: [Fq; 12] = [
 ];
 
 /// Coefficients of the 11-isogeny x map's denominator
-const XDEN: [Fq; 11] = [
+pub(crate) const XDEN: [Fq; 11] = [
     Fq(FqRepr([
         0xb962a077fdb0f945u64,
         0xa6a9740fefda13a0u64,
@@ -198,7 +198,7 @@ const XDEN: [Fq; 11] = [
 ];
 
 /// Coefficients of the 11-isogeny y map's numerator
-const YNUM: [Fq; 16] = [
+pub(crate) const YNUM: [Fq; 16] = [
     Fq(FqRepr([
         0x2b567ff3e2837267u64,
         0x1d4d9e57b958a767u64,
@@ -330,7 +330,7 @@ const YNUM: [Fq; 16] = [
 ];
 
 /// Coefficients of the 11-isogeny y map's denominator
-const YDEN: [Fq; 16] = [
+pub(crate) const YDEN: [Fq; 16] = [
     Fq(FqRepr([
         0xeb6c359d47e52b1cu64,
         0x18ef5f8a10634d60u64,
@@ -6,7 +6,7 @@ use super::{eval_iso, IsogenyMap};
 use crate::bls12_381::{Fq, Fq2, FqRepr, G2};
 
 /// Coefficients of the 3-isogeny x map's numerator
-const XNUM: [Fq2; 4] = [
+pub(crate) const XNUM: [Fq2; 4] = [
     Fq2 {
         c0: Fq(FqRepr([
             0x47f671c71ce05e62u64,
@@ -82,7 +82,7 @@ const XNUM: [Fq2; 4] = [
 ];
 
 /// Coefficients of the 3-isogeny x map's denominator
-const XDEN: [Fq2; 3] = [
+pub(crate) const XDEN: [Fq2; 3] = [
     Fq2 {
         c0: Fq(FqRepr([
             0x0000000000000000u64,
@@ -140,7 +140,7 @@ const XDEN: [Fq2; 3] = [
 ];
 
 /// Coefficients of the 3-isogeny y map's numerator
-const YNUM: [Fq2; 4] = [
+pub(crate) const YNUM: [Fq2; 4] = [
     Fq2 {
         c0: Fq(FqRepr([
             0x96d8f684bdfc77beu64,
@@ -216,7 +216,7 @@ const YNUM: [Fq2; 4] = [
 ];
 
 /// Coefficients of the 3-isogeny y map's denominator
-const YDEN: [Fq2; 4] = [
+pub(crate) const YDEN: [Fq2; 4] = [
     Fq2 {
         c0: Fq(FqRepr([
             0x0162fffffa765adfu64,
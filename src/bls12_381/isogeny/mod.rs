@@ -2,8 +2,8 @@
 Isogenies E' -> E and E2' -> E2 for OSSWU map.
 */
 
-mod g1;
-mod g2;
+pub(super) mod g1;
+pub(super) mod g2;
 #[cfg(test)]
 mod tests;
 
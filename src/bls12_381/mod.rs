@@ -1,5 +1,11 @@
+mod bytes;
 mod cofactor;
+#[macro_use]
+mod compat;
+#[macro_use]
 mod ec;
+mod elligator;
+mod format;
 mod fq;
 mod fq12;
 mod fq2;
@@ -11,15 +17,17 @@ mod osswu_map;
 #[cfg(test)]
 mod tests;
 
-pub(crate) use self::cofactor::ClearH;
+pub use self::compat::{FqBytes, Gt, MillerLoopResult};
 pub use self::ec::{
     G1Affine, G1Compressed, G1Prepared, G1Uncompressed, G2Affine, G2Compressed, G2Prepared,
     G2Uncompressed, G1, G2,
 };
+pub use self::elligator::ElligatorSquared;
+pub use self::format::{convert_g1, convert_g2, PointFormat};
 pub use self::fq::{Fq, FqRepr};
-pub use self::fq12::Fq12;
-pub use self::fq2::Fq2;
-pub use self::fq6::Fq6;
+pub use self::fq12::{Fq12, Fq12Parameters};
+pub use self::fq2::{Fq2, Fq2Parameters};
+pub use self::fq6::{Fq6, Fq6Parameters};
 pub use self::fr::{Fr, FrRepr};
 pub(crate) use self::isogeny::IsogenyMap;
 pub(crate) use self::osswu_map::OSSWUMap;
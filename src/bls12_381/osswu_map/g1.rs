@@ -8,7 +8,7 @@ use crate::bls12_381::{Fq, FqRepr, G1};
 use crate::signum::Signum0;
 use ff::Field;
 
-pub(super) const ELLP_A: Fq = Fq(FqRepr([
+pub(crate) const ELLP_A: Fq = Fq(FqRepr([
     0x2f65aa0e9af5aa51u64,
     0x86464c2d1e8416c3u64,
     0xb85ce591b7bd31e2u64,
@@ -17,7 +17,7 @@ pub(super) const ELLP_A: Fq = Fq(FqRepr([
     0x155455c3e5071d85u64,
 ]));
 
-pub(super) const ELLP_B: Fq = Fq(FqRepr([
+pub(crate) const ELLP_B: Fq = Fq(FqRepr([
     0xfb996971fe22a1e0u64,
     0x9aa93eb35b742d6fu64,
     0x8c476013de99c5c4u64,
@@ -26,7 +26,7 @@ pub(super) const ELLP_B: Fq = Fq(FqRepr([
     0x06824061418a386bu64,
 ]));
 
-const XI: Fq = Fq(FqRepr([
+pub(crate) const XI: Fq = Fq(FqRepr([
     0x886c00000023ffdcu64,
     0xf70008d3090001du64,
     0x77672417ed5828c3u64,
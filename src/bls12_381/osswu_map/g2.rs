@@ -8,7 +8,7 @@ use crate::bls12_381::{Fq, Fq2, FqRepr, G2};
 use crate::signum::Signum0;
 use ff::Field;
 
-pub(super) const ELLP_A: Fq2 = Fq2 {
+pub(crate) const ELLP_A: Fq2 = Fq2 {
     c0: Fq(FqRepr([
         0x0000000000000000u64,
         0x0000000000000000u64,
@@ -27,7 +27,7 @@ pub(super) const ELLP_A: Fq2 = Fq2 {
     ])),
 };
 
-pub(super) const ELLP_B: Fq2 = Fq2 {
+pub(crate) const ELLP_B: Fq2 = Fq2 {
     c0: Fq(FqRepr([
         0x22ea00000cf89db2u64,
         0x6ec832df71380aa4u64,
@@ -46,7 +46,7 @@ pub(super) const ELLP_B: Fq2 = Fq2 {
     ])),
 };
 
-const XI: Fq2 = Fq2 {
+pub(crate) const XI: Fq2 = Fq2 {
     c0: Fq(FqRepr([
         0x87ebfffffff9555cu64,
         0x656fffe5da8ffffau64,
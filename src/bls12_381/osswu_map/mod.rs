@@ -4,8 +4,8 @@ see: https://eprint.iacr.org/2019/403
 */
 
 mod chain;
-mod g1;
-mod g2;
+pub(super) mod g1;
+pub(super) mod g2;
 #[cfg(test)]
 mod tests;
 
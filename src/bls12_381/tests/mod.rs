@@ -153,7 +153,7 @@ fn test_g1_uncompressed_invalid_vectors() {
         {
             let mut z = z;
             z.as_mut()[0] |= 0b1000_0000;
-            if let Err(GroupDecodingError::UnexpectedCompressionMode) = z.into_affine() {
+            if let Err(Error::UnexpectedCompressionMode) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because we expected an uncompressed point");
@@ -163,7 +163,7 @@ fn test_g1_uncompressed_invalid_vectors() {
         {
             let mut z = z;
             z.as_mut()[0] |= 0b0010_0000;
-            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+            if let Err(Error::BadFlags) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because the parity bit should not be set if the point is at infinity");
@@ -173,7 +173,7 @@ fn test_g1_uncompressed_invalid_vectors() {
         for i in 0..G1Uncompressed::size() {
             let mut z = z;
             z.as_mut()[i] |= 0b0000_0001;
-            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+            if let Err(Error::NonZeroInfinity) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because the coordinates should be zeroes at the point at infinity");
@@ -186,7 +186,7 @@ fn test_g1_uncompressed_invalid_vectors() {
     {
         let mut o = o;
         o.as_mut()[0] |= 0b1000_0000;
-        if let Err(GroupDecodingError::UnexpectedCompressionMode) = o.into_affine() {
+        if let Err(Error::UnexpectedCompressionMode) = o.into_affine() {
             // :)
         } else {
             panic!("should have rejected the point because we expected an uncompressed point");
@@ -199,7 +199,7 @@ fn test_g1_uncompressed_invalid_vectors() {
         let mut o = o;
         m.write_be(&mut o.as_mut()[0..]).unwrap();
 
-        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+        if let Err(Error::NonCanonicalFieldElement(coordinate, _)) = o.into_affine() {
             assert_eq!(coordinate, "x coordinate");
         } else {
             panic!("should have rejected the point")
@@ -210,7 +210,7 @@ fn test_g1_uncompressed_invalid_vectors() {
         let mut o = o;
         m.write_be(&mut o.as_mut()[48..]).unwrap();
 
-        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+        if let Err(Error::NonCanonicalFieldElement(coordinate, _)) = o.into_affine() {
             assert_eq!(coordinate, "y coordinate");
         } else {
             panic!("should have rejected the point")
@@ -223,7 +223,7 @@ fn test_g1_uncompressed_invalid_vectors() {
         let mut o = o;
         m.write_be(&mut o.as_mut()[0..]).unwrap();
 
-        if let Err(GroupDecodingError::NotOnCurve) = o.into_affine() {
+        if let Err(Error::NotOnCurve) = o.into_affine() {
             // :)
         } else {
             panic!("should have rejected the point because it isn't on the curve")
@@ -245,7 +245,7 @@ fn test_g1_uncompressed_invalid_vectors() {
                 x.into_repr().write_be(&mut o.as_mut()[0..]).unwrap();
                 y.into_repr().write_be(&mut o.as_mut()[48..]).unwrap();
 
-                if let Err(GroupDecodingError::NotInSubgroup) = o.into_affine() {
+                if let Err(Error::NotInSubgroup) = o.into_affine() {
                     break;
                 } else {
                     panic!(
@@ -267,7 +267,7 @@ fn test_g2_uncompressed_invalid_vectors() {
         {
             let mut z = z;
             z.as_mut()[0] |= 0b1000_0000;
-            if let Err(GroupDecodingError::UnexpectedCompressionMode) = z.into_affine() {
+            if let Err(Error::UnexpectedCompressionMode) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because we expected an uncompressed point");
@@ -277,7 +277,7 @@ fn test_g2_uncompressed_invalid_vectors() {
         {
             let mut z = z;
             z.as_mut()[0] |= 0b0010_0000;
-            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+            if let Err(Error::BadFlags) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because the parity bit should not be set if the point is at infinity");
@@ -287,7 +287,7 @@ fn test_g2_uncompressed_invalid_vectors() {
         for i in 0..G2Uncompressed::size() {
             let mut z = z;
             z.as_mut()[i] |= 0b0000_0001;
-            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+            if let Err(Error::NonZeroInfinity) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because the coordinates should be zeroes at the point at infinity");
@@ -300,7 +300,7 @@ fn test_g2_uncompressed_invalid_vectors() {
     {
         let mut o = o;
         o.as_mut()[0] |= 0b1000_0000;
-        if let Err(GroupDecodingError::UnexpectedCompressionMode) = o.into_affine() {
+        if let Err(Error::UnexpectedCompressionMode) = o.into_affine() {
             // :)
         } else {
             panic!("should have rejected the point because we expected an uncompressed point");
@@ -313,7 +313,7 @@ fn test_g2_uncompressed_invalid_vectors() {
         let mut o = o;
         m.write_be(&mut o.as_mut()[0..]).unwrap();
 
-        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+        if let Err(Error::NonCanonicalFieldElement(coordinate, _)) = o.into_affine() {
             assert_eq!(coordinate, "x coordinate (c1)");
         } else {
             panic!("should have rejected the point")
@@ -324,7 +324,7 @@ fn test_g2_uncompressed_invalid_vectors() {
         let mut o = o;
         m.write_be(&mut o.as_mut()[48..]).unwrap();
 
-        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+        if let Err(Error::NonCanonicalFieldElement(coordinate, _)) = o.into_affine() {
             assert_eq!(coordinate, "x coordinate (c0)");
         } else {
             panic!("should have rejected the point")
@@ -335,7 +335,7 @@ fn test_g2_uncompressed_invalid_vectors() {
         let mut o = o;
         m.write_be(&mut o.as_mut()[96..]).unwrap();
 
-        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+        if let Err(Error::NonCanonicalFieldElement(coordinate, _)) = o.into_affine() {
             assert_eq!(coordinate, "y coordinate (c1)");
         } else {
             panic!("should have rejected the point")
@@ -346,7 +346,7 @@ fn test_g2_uncompressed_invalid_vectors() {
         let mut o = o;
         m.write_be(&mut o.as_mut()[144..]).unwrap();
 
-        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+        if let Err(Error::NonCanonicalFieldElement(coordinate, _)) = o.into_affine() {
             assert_eq!(coordinate, "y coordinate (c0)");
         } else {
             panic!("should have rejected the point")
@@ -360,7 +360,7 @@ fn test_g2_uncompressed_invalid_vectors() {
         m.write_be(&mut o.as_mut()[0..]).unwrap();
         m.write_be(&mut o.as_mut()[48..]).unwrap();
 
-        if let Err(GroupDecodingError::NotOnCurve) = o.into_affine() {
+        if let Err(Error::NotOnCurve) = o.into_affine() {
             // :)
         } else {
             panic!("should have rejected the point because it isn't on the curve")
@@ -387,7 +387,7 @@ fn test_g2_uncompressed_invalid_vectors() {
                 y.c1.into_repr().write_be(&mut o.as_mut()[96..]).unwrap();
                 y.c0.into_repr().write_be(&mut o.as_mut()[144..]).unwrap();
 
-                if let Err(GroupDecodingError::NotInSubgroup) = o.into_affine() {
+                if let Err(Error::NotInSubgroup) = o.into_affine() {
                     break;
                 } else {
                     panic!(
@@ -409,7 +409,7 @@ fn test_g1_compressed_invalid_vectors() {
         {
             let mut z = z;
             z.as_mut()[0] &= 0b0111_1111;
-            if let Err(GroupDecodingError::UnexpectedCompressionMode) = z.into_affine() {
+            if let Err(Error::UnexpectedCompressionMode) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because we expected a compressed point");
@@ -419,7 +419,7 @@ fn test_g1_compressed_invalid_vectors() {
         {
             let mut z = z;
             z.as_mut()[0] |= 0b0010_0000;
-            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+            if let Err(Error::BadFlags) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because the parity bit should not be set if the point is at infinity");
@@ -429,7 +429,7 @@ fn test_g1_compressed_invalid_vectors() {
         for i in 0..G1Compressed::size() {
             let mut z = z;
             z.as_mut()[i] |= 0b0000_0001;
-            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+            if let Err(Error::NonZeroInfinity) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because the coordinates should be zeroes at the point at infinity");
@@ -442,7 +442,7 @@ fn test_g1_compressed_invalid_vectors() {
     {
         let mut o = o;
         o.as_mut()[0] &= 0b0111_1111;
-        if let Err(GroupDecodingError::UnexpectedCompressionMode) = o.into_affine() {
+        if let Err(Error::UnexpectedCompressionMode) = o.into_affine() {
             // :)
         } else {
             panic!("should have rejected the point because we expected a compressed point");
@@ -456,7 +456,7 @@ fn test_g1_compressed_invalid_vectors() {
         m.write_be(&mut o.as_mut()[0..]).unwrap();
         o.as_mut()[0] |= 0b1000_0000;
 
-        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+        if let Err(Error::NonCanonicalFieldElement(coordinate, _)) = o.into_affine() {
             assert_eq!(coordinate, "x coordinate");
         } else {
             panic!("should have rejected the point")
@@ -479,7 +479,7 @@ fn test_g1_compressed_invalid_vectors() {
                 x.into_repr().write_be(&mut o.as_mut()[0..]).unwrap();
                 o.as_mut()[0] |= 0b1000_0000;
 
-                if let Err(GroupDecodingError::NotOnCurve) = o.into_affine() {
+                if let Err(Error::NotOnCurve) = o.into_affine() {
                     break;
                 } else {
                     panic!("should have rejected the point because it isn't on the curve")
@@ -503,7 +503,7 @@ fn test_g1_compressed_invalid_vectors() {
                 x.into_repr().write_be(&mut o.as_mut()[0..]).unwrap();
                 o.as_mut()[0] |= 0b1000_0000;
 
-                if let Err(GroupDecodingError::NotInSubgroup) = o.into_affine() {
+                if let Err(Error::NotInSubgroup) = o.into_affine() {
                     break;
                 } else {
                     panic!(
@@ -525,7 +525,7 @@ fn test_g2_compressed_invalid_vectors() {
         {
             let mut z = z;
             z.as_mut()[0] &= 0b0111_1111;
-            if let Err(GroupDecodingError::UnexpectedCompressionMode) = z.into_affine() {
+            if let Err(Error::UnexpectedCompressionMode) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because we expected a compressed point");
@@ -535,7 +535,7 @@ fn test_g2_compressed_invalid_vectors() {
         {
             let mut z = z;
             z.as_mut()[0] |= 0b0010_0000;
-            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+            if let Err(Error::BadFlags) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because the parity bit should not be set if the point is at infinity");
@@ -545,7 +545,7 @@ fn test_g2_compressed_invalid_vectors() {
         for i in 0..G2Compressed::size() {
             let mut z = z;
             z.as_mut()[i] |= 0b0000_0001;
-            if let Err(GroupDecodingError::UnexpectedInformation) = z.into_affine() {
+            if let Err(Error::NonZeroInfinity) = z.into_affine() {
                 // :)
             } else {
                 panic!("should have rejected the point because the coordinates should be zeroes at the point at infinity");
@@ -558,7 +558,7 @@ fn test_g2_compressed_invalid_vectors() {
     {
         let mut o = o;
         o.as_mut()[0] &= 0b0111_1111;
-        if let Err(GroupDecodingError::UnexpectedCompressionMode) = o.into_affine() {
+        if let Err(Error::UnexpectedCompressionMode) = o.into_affine() {
             // :)
         } else {
             panic!("should have rejected the point because we expected a compressed point");
@@ -572,7 +572,7 @@ fn test_g2_compressed_invalid_vectors() {
         m.write_be(&mut o.as_mut()[0..]).unwrap();
         o.as_mut()[0] |= 0b1000_0000;
 
-        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+        if let Err(Error::NonCanonicalFieldElement(coordinate, _)) = o.into_affine() {
             assert_eq!(coordinate, "x coordinate (c1)");
         } else {
             panic!("should have rejected the point")
@@ -584,7 +584,7 @@ fn test_g2_compressed_invalid_vectors() {
         m.write_be(&mut o.as_mut()[48..]).unwrap();
         o.as_mut()[0] |= 0b1000_0000;
 
-        if let Err(GroupDecodingError::CoordinateDecodingError(coordinate, _)) = o.into_affine() {
+        if let Err(Error::NonCanonicalFieldElement(coordinate, _)) = o.into_affine() {
             assert_eq!(coordinate, "x coordinate (c0)");
         } else {
             panic!("should have rejected the point")
@@ -614,7 +614,7 @@ fn test_g2_compressed_invalid_vectors() {
                 x.c0.into_repr().write_be(&mut o.as_mut()[48..]).unwrap();
                 o.as_mut()[0] |= 0b1000_0000;
 
-                if let Err(GroupDecodingError::NotOnCurve) = o.into_affine() {
+                if let Err(Error::NotOnCurve) = o.into_affine() {
                     break;
                 } else {
                     panic!("should have rejected the point because it isn't on the curve")
@@ -645,7 +645,7 @@ fn test_g2_compressed_invalid_vectors() {
                 x.c0.into_repr().write_be(&mut o.as_mut()[48..]).unwrap();
                 o.as_mut()[0] |= 0b1000_0000;
 
-                if let Err(GroupDecodingError::NotInSubgroup) = o.into_affine() {
+                if let Err(Error::NotInSubgroup) = o.into_affine() {
                     break;
                 } else {
                     panic!(
@@ -0,0 +1,84 @@
+/*!
+Cofactor clearing for G1 and G2.
+*/
+
+use crate::{
+    bn256::{G1, G2},
+    hash_to_curve::{mul_by_limbs, ClearH},
+};
+
+// G2 cofactor h2 = 2p - r
+// = 0x30644e72e131a029b85045b68181585e06ceecda572a2489345f2299c0f9fa8d
+const H2: [u64; 4] = [
+    0x345f2299c0f9fa8d,
+    0x6ceecda572a2489,
+    0xb85045b68181585e,
+    0x30644e72e131a029,
+];
+
+impl ClearH for G1 {
+    // G1 has prime order, so there is nothing to clear
+    fn clear_h(&mut self) {}
+}
+
+impl ClearH for G2 {
+    // out = h2 * in
+    fn clear_h(&mut self) {
+        mul_by_limbs(self, &H2);
+    }
+}
+
+#[test]
+fn test_clear_h() {
+    use crate::{
+        bn256::{Fq, Fq2, Fr},
+        hash_to_curve::SvdwMap,
+        CurveAffine, CurveProjective,
+    };
+    use ff::{BitIterator, Field, PrimeField};
+    use rand_core::SeedableRng;
+
+    // double-and-add over the bits of a repr
+    fn mul_by_repr<R: AsRef<[u64]>>(p: &G2, repr: &R) -> G2 {
+        let mut res = G2::zero();
+        for bit in BitIterator::new(repr) {
+            res.double();
+            if bit {
+                res.add_assign(p);
+            }
+        }
+        res
+    }
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // clear_h on an element of the subgroup is multiplication by h2 mod r = 2 * (p - r)
+    let mut p = G2::random(&mut rng);
+    let mut expect = p;
+    p.clear_h();
+    expect.mul_assign(Fr::from_str("295893513763578637981667416138835425932").unwrap());
+    assert_eq!(p, expect);
+    assert!(p.into_affine().mul(Fr::char()).is_zero());
+
+    // on the output of the map, which is outside the subgroup, compare with
+    // h2 * P computed as 2 * (p * P) - r * P
+    for _ in 0..10 {
+        let p = G2::svdw_map(&Fq2::random(&mut rng));
+        assert!(!p.into_affine().mul(Fr::char()).is_zero());
+        let mut expect = mul_by_repr(&p, &Fq::char());
+        expect.double();
+        expect.sub_assign(&mul_by_repr(&p, &Fr::char()));
+        let mut cleared = p;
+        cleared.clear_h();
+        assert_eq!(cleared, expect);
+        assert!(cleared.into_affine().mul(Fr::char()).is_zero());
+    }
+
+    let mut q = G1::random(&mut rng);
+    let expect = q;
+    q.clear_h();
+    assert_eq!(q, expect);
+}
@@ -0,0 +1,481 @@
+use super::g2::G2Affine;
+use crate::{
+    bn256::{Bn256, Fq, Fq12, FqRepr, Fr, FrRepr},
+    CurveAffine, CurveProjective, EncodedPoint, Engine, Error, SubgroupCheck,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+curve_impl!(
+    "G1",
+    G1,
+    G1Affine,
+    G1Prepared,
+    Fq,
+    Fr,
+    G1Uncompressed,
+    G1Compressed,
+    G2Affine,
+    Bn256,
+    Fq12
+);
+
+#[derive(Copy, Clone)]
+pub struct G1Uncompressed([u8; 64]);
+
+impl AsRef<[u8]> for G1Uncompressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G1Uncompressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G1Uncompressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G1Uncompressed {
+    type Affine = G1Affine;
+
+    fn empty() -> Self {
+        G1Uncompressed([0; 64])
+    }
+    fn size() -> usize {
+        64
+    }
+    fn into_affine(&self) -> Result<G1Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        if !affine.is_on_curve() {
+            Err(Error::NotOnCurve)
+        } else if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G1Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+
+        match copy[0] & super::FLAG_MASK {
+            super::FLAG_UNCOMPRESSED => {
+                let mut x = FqRepr([0; 4]);
+                let mut y = FqRepr([0; 4]);
+
+                {
+                    let mut reader = &copy[..];
+
+                    x.read_be(&mut reader).unwrap();
+                    y.read_be(&mut reader).unwrap();
+                }
+
+                Ok(G1Affine {
+                    x: Fq::from_repr(x)
+                        .map_err(|e| Error::NonCanonicalFieldElement("x coordinate", e))?,
+                    y: Fq::from_repr(y)
+                        .map_err(|e| Error::NonCanonicalFieldElement("y coordinate", e))?,
+                    infinity: false,
+                })
+            }
+            super::FLAG_INFINITY => {
+                // This is the point at infinity, which means that if we mask away
+                // the flag bits, the entire representation should consist
+                // of zeroes.
+                copy[0] &= !super::FLAG_MASK;
+
+                if copy.iter().all(|b| *b == 0) {
+                    Ok(G1Affine::zero())
+                } else {
+                    Err(Error::NonZeroInfinity)
+                }
+            }
+            // One of the compressed flags is set, but this should be uncompressed!
+            _ => Err(Error::UnexpectedCompressionMode),
+        }
+    }
+    fn from_affine(affine: G1Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            res.0[0] |= super::FLAG_INFINITY;
+        } else {
+            let mut writer = &mut res.0[..];
+
+            affine.x.into_repr().write_be(&mut writer).unwrap();
+            affine.y.into_repr().write_be(&mut writer).unwrap();
+        }
+
+        res
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct G1Compressed([u8; 32]);
+
+impl AsRef<[u8]> for G1Compressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G1Compressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G1Compressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G1Compressed {
+    type Affine = G1Affine;
+
+    fn empty() -> Self {
+        G1Compressed([0; 32])
+    }
+    fn size() -> usize {
+        32
+    }
+    fn into_affine(&self) -> Result<G1Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        // NB: Decompression guarantees that it is on the curve already.
+
+        if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G1Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+        let flags = copy[0] & super::FLAG_MASK;
+        copy[0] &= !super::FLAG_MASK;
+
+        match flags {
+            super::FLAG_COMPRESSED_SMALLEST | super::FLAG_COMPRESSED_LARGEST => {
+                // Determine if the intended y coordinate must be greater
+                // lexicographically.
+                let greatest = flags == super::FLAG_COMPRESSED_LARGEST;
+
+                let mut x = FqRepr([0; 4]);
+
+                {
+                    let mut reader = &copy[..];
+
+                    x.read_be(&mut reader).unwrap();
+                }
+
+                // Interpret as Fq element.
+                let x = Fq::from_repr(x)
+                    .map_err(|e| Error::NonCanonicalFieldElement("x coordinate", e))?;
+
+                G1Affine::get_point_from_x(x, greatest).ok_or(Error::NotOnCurve)
+            }
+            super::FLAG_INFINITY => {
+                if copy.iter().all(|b| *b == 0) {
+                    Ok(G1Affine::zero())
+                } else {
+                    Err(Error::NonZeroInfinity)
+                }
+            }
+            // No flag is set, so this is not a compressed element.
+            _ => Err(Error::UnexpectedCompressionMode),
+        }
+    }
+    fn from_affine(affine: G1Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            res.0[0] |= super::FLAG_INFINITY;
+        } else {
+            {
+                let mut writer = &mut res.0[..];
+
+                affine.x.into_repr().write_be(&mut writer).unwrap();
+            }
+
+            let mut negy = affine.y;
+            negy.negate();
+
+            if affine.y > negy {
+                res.0[0] |= super::FLAG_COMPRESSED_LARGEST;
+            } else {
+                res.0[0] |= super::FLAG_COMPRESSED_SMALLEST;
+            }
+        }
+
+        res
+    }
+}
+
+impl G1Affine {
+    fn scale_by_cofactor(&self) -> G1 {
+        // G1 has cofactor 1
+        self.into_projective()
+    }
+
+    fn get_generator() -> Self {
+        G1Affine {
+            x: super::super::fq::G1_GENERATOR_X,
+            y: super::super::fq::G1_GENERATOR_Y,
+            infinity: false,
+        }
+    }
+
+    fn get_coeff_b() -> Fq {
+        super::super::fq::B_COEFF
+    }
+
+    fn perform_pairing(&self, other: &G2Affine) -> Fq12 {
+        super::super::Bn256::pairing(*self, *other)
+    }
+}
+
+impl G1 {
+    fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
+        let num_bits = scalar.num_bits() as usize;
+
+        if num_bits >= 130 {
+            4
+        } else if num_bits >= 34 {
+            3
+        } else {
+            2
+        }
+    }
+
+    fn empirical_recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        const RECOMMENDATIONS: [usize; 12] =
+            [1, 3, 7, 20, 43, 120, 273, 563, 1630, 3128, 7933, 62569];
+
+        let mut ret = 4;
+        for r in &RECOMMENDATIONS {
+            if num_scalars > *r {
+                ret += 1;
+            } else {
+                break;
+            }
+        }
+
+        ret
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct G1Prepared(pub(crate) G1Affine);
+
+impl G1Prepared {
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn from_affine(p: G1Affine) -> Self {
+        G1Prepared(p)
+    }
+}
+
+impl SubgroupCheck for G1Affine {
+    fn in_subgroup(&self) -> bool {
+        self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve()
+    }
+}
+
+#[test]
+fn g1_generator() {
+    use SqrtField;
+
+    let mut x = Fq::zero();
+    let mut i = 0;
+    loop {
+        // y^2 = x^3 + b
+        let mut rhs = x;
+        rhs.square();
+        rhs.mul_assign(&x);
+        rhs.add_assign(&G1Affine::get_coeff_b());
+
+        if let Some(y) = rhs.sqrt() {
+            let yrepr = y.into_repr();
+            let mut negy = y;
+            negy.negate();
+            let negyrepr = negy.into_repr();
+
+            let p = G1Affine {
+                x,
+                y: if yrepr < negyrepr { y } else { negy },
+                infinity: false,
+            };
+            // The curve has prime order, so every point is in the subgroup.
+            assert!(p.in_subgroup());
+
+            let g1 = p.scale_by_cofactor();
+            assert!(!g1.is_zero());
+            assert_eq!(i, 1);
+            let g1 = G1Affine::from(g1);
+
+            assert_eq!(g1, G1Affine::one());
+            break;
+        }
+
+        i += 1;
+        x.add_assign(&Fq::one());
+    }
+}
+
+#[test]
+fn g1_test_is_valid() {
+    // Reject point on a twist (y^2 = x^3 + 4)
+    {
+        let p = G1Affine {
+            x: Fq::zero(),
+            y: Fq::from_str("2").unwrap(),
+            infinity: false,
+        };
+        assert!(!p.is_on_curve());
+        assert!(!p.in_subgroup());
+    }
+
+    // Reject coordinates that are not field elements
+    {
+        let mut enc = G1Affine::one().into_uncompressed();
+        for b in enc.as_mut()[..32].iter_mut() {
+            *b = 0xff;
+        }
+        enc.as_mut()[0] &= !super::FLAG_MASK;
+        match enc.into_affine() {
+            Err(Error::NonCanonicalFieldElement("x coordinate", _)) => (),
+            _ => panic!("should have rejected x coordinate"),
+        }
+    }
+}
+
+#[test]
+fn test_g1_addition_correctness() {
+    let mut p = G1::one();
+    p.add_assign(
+        &G1Affine {
+            x: Fq::from_str(
+                "1368015179489954701390400359078579693043519447331113978918064868415326638035",
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "9918110051302171585080402603319702774565515993150576347155970296011118125764",
+            )
+            .unwrap(),
+            infinity: false,
+        }
+        .into_projective(),
+    );
+
+    let p = G1Affine::from(p);
+
+    assert_eq!(
+        p,
+        G1Affine {
+            x: Fq::from_str(
+                "3353031288059533942658390886683067124040920775575537747144343083137631628272"
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "19321533766552368860946552437480515441416830039777911637913418824951667761761"
+            )
+            .unwrap(),
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn test_g1_doubling_correctness() {
+    let mut p = G1::one();
+
+    p.double();
+
+    let p = G1Affine::from(p);
+
+    assert_eq!(
+        p,
+        G1Affine {
+            x: Fq::from_str(
+                "1368015179489954701390400359078579693043519447331113978918064868415326638035"
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "9918110051302171585080402603319702774565515993150576347155970296011118125764"
+            )
+            .unwrap(),
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn test_g1_encoding_flags() {
+    // generator: x = 1, y = 2 is the smaller of the two y-coordinates
+    let c = G1Affine::one().into_compressed();
+    assert_eq!(
+        c.as_ref()[0] & super::FLAG_MASK,
+        super::FLAG_COMPRESSED_SMALLEST
+    );
+    assert_eq!(c.as_ref()[31], 1);
+    let mut g = G1Affine::one();
+    g.negate();
+    let c = g.into_compressed();
+    assert_eq!(
+        c.as_ref()[0] & super::FLAG_MASK,
+        super::FLAG_COMPRESSED_LARGEST
+    );
+    assert_eq!(c.into_affine().unwrap(), g);
+
+    let u = G1Affine::one().into_uncompressed();
+    assert_eq!(u.as_ref()[0] & super::FLAG_MASK, super::FLAG_UNCOMPRESSED);
+    assert_eq!(u.as_ref()[31], 1);
+    assert_eq!(u.as_ref()[63], 2);
+
+    // infinity
+    let z = G1Affine::zero().into_compressed();
+    assert_eq!(z.as_ref()[0], super::FLAG_INFINITY);
+    assert!(z.as_ref()[1..].iter().all(|b| *b == 0));
+    let z = G1Affine::zero().into_uncompressed();
+    assert_eq!(z.as_ref()[0], super::FLAG_INFINITY);
+    assert!(z.as_ref()[1..].iter().all(|b| *b == 0));
+
+    // wrong compression mode
+    let mut c = G1Affine::one().into_compressed();
+    c.as_mut()[0] &= !super::FLAG_MASK;
+    match c.into_affine() {
+        Err(Error::UnexpectedCompressionMode) => (),
+        _ => panic!("should have rejected compression mode"),
+    }
+    let mut u = G1Affine::one().into_uncompressed();
+    u.as_mut()[0] |= super::FLAG_COMPRESSED_SMALLEST;
+    match u.into_affine() {
+        Err(Error::UnexpectedCompressionMode) => (),
+        _ => panic!("should have rejected compression mode"),
+    }
+
+    // infinity with nonzero payload
+    let mut z = G1Affine::zero().into_compressed();
+    z.as_mut()[31] = 1;
+    match z.into_affine() {
+        Err(Error::NonZeroInfinity) => (),
+        _ => panic!("should have rejected point at infinity"),
+    }
+}
+
+#[test]
+fn g1_curve_tests() {
+    crate::tests::curve::curve_tests::<G1>();
+}
@@ -0,0 +1,540 @@
+use super::super::{Bn256, Fq, Fq12, Fq2, FqRepr, Fr, FrRepr};
+use super::g1::G1Affine;
+use crate::{CurveAffine, CurveProjective, EncodedPoint, Engine, Error, SubgroupCheck};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+
+curve_impl!(
+    "G2",
+    G2,
+    G2Affine,
+    G2Prepared,
+    Fq2,
+    Fr,
+    G2Uncompressed,
+    G2Compressed,
+    G1Affine,
+    Bn256,
+    Fq12
+);
+
+#[derive(Copy, Clone)]
+pub struct G2Uncompressed([u8; 128]);
+
+impl AsRef<[u8]> for G2Uncompressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G2Uncompressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G2Uncompressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G2Uncompressed {
+    type Affine = G2Affine;
+
+    fn empty() -> Self {
+        G2Uncompressed([0; 128])
+    }
+    fn size() -> usize {
+        128
+    }
+    fn into_affine(&self) -> Result<G2Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        if !affine.is_on_curve() {
+            Err(Error::NotOnCurve)
+        } else if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G2Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+
+        match copy[0] & super::FLAG_MASK {
+            super::FLAG_UNCOMPRESSED => {
+                let mut x_c0 = FqRepr([0; 4]);
+                let mut x_c1 = FqRepr([0; 4]);
+                let mut y_c0 = FqRepr([0; 4]);
+                let mut y_c1 = FqRepr([0; 4]);
+
+                {
+                    let mut reader = &copy[..];
+
+                    x_c1.read_be(&mut reader).unwrap();
+                    x_c0.read_be(&mut reader).unwrap();
+                    y_c1.read_be(&mut reader).unwrap();
+                    y_c0.read_be(&mut reader).unwrap();
+                }
+
+                Ok(G2Affine {
+                    x: Fq2 {
+                        c0: Fq::from_repr(x_c0)
+                            .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c0)", e))?,
+                        c1: Fq::from_repr(x_c1)
+                            .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c1)", e))?,
+                    },
+                    y: Fq2 {
+                        c0: Fq::from_repr(y_c0)
+                            .map_err(|e| Error::NonCanonicalFieldElement("y coordinate (c0)", e))?,
+                        c1: Fq::from_repr(y_c1)
+                            .map_err(|e| Error::NonCanonicalFieldElement("y coordinate (c1)", e))?,
+                    },
+                    infinity: false,
+                })
+            }
+            super::FLAG_INFINITY => {
+                // This is the point at infinity, which means that if we mask away
+                // the flag bits, the entire representation should consist
+                // of zeroes.
+                copy[0] &= !super::FLAG_MASK;
+
+                if copy.iter().all(|b| *b == 0) {
+                    Ok(G2Affine::zero())
+                } else {
+                    Err(Error::NonZeroInfinity)
+                }
+            }
+            // One of the compressed flags is set, but this should be uncompressed!
+            _ => Err(Error::UnexpectedCompressionMode),
+        }
+    }
+    fn from_affine(affine: G2Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            res.0[0] |= super::FLAG_INFINITY;
+        } else {
+            let mut writer = &mut res.0[..];
+
+            affine.x.c1.into_repr().write_be(&mut writer).unwrap();
+            affine.x.c0.into_repr().write_be(&mut writer).unwrap();
+            affine.y.c1.into_repr().write_be(&mut writer).unwrap();
+            affine.y.c0.into_repr().write_be(&mut writer).unwrap();
+        }
+
+        res
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct G2Compressed([u8; 64]);
+
+impl AsRef<[u8]> for G2Compressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G2Compressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G2Compressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G2Compressed {
+    type Affine = G2Affine;
+
+    fn empty() -> Self {
+        G2Compressed([0; 64])
+    }
+    fn size() -> usize {
+        64
+    }
+    fn into_affine(&self) -> Result<G2Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        // NB: Decompression guarantees that it is on the curve already.
+
+        if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G2Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+        let flags = copy[0] & super::FLAG_MASK;
+        copy[0] &= !super::FLAG_MASK;
+
+        match flags {
+            super::FLAG_COMPRESSED_SMALLEST | super::FLAG_COMPRESSED_LARGEST => {
+                // Determine if the intended y coordinate must be greater
+                // lexicographically.
+                let greatest = flags == super::FLAG_COMPRESSED_LARGEST;
+
+                let mut x_c1 = FqRepr([0; 4]);
+                let mut x_c0 = FqRepr([0; 4]);
+
+                {
+                    let mut reader = &copy[..];
+
+                    x_c1.read_be(&mut reader).unwrap();
+                    x_c0.read_be(&mut reader).unwrap();
+                }
+
+                // Interpret as Fq element.
+                let x = Fq2 {
+                    c0: Fq::from_repr(x_c0)
+                        .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c0)", e))?,
+                    c1: Fq::from_repr(x_c1)
+                        .map_err(|e| Error::NonCanonicalFieldElement("x coordinate (c1)", e))?,
+                };
+
+                G2Affine::get_point_from_x(x, greatest).ok_or(Error::NotOnCurve)
+            }
+            super::FLAG_INFINITY => {
+                if copy.iter().all(|b| *b == 0) {
+                    Ok(G2Affine::zero())
+                } else {
+                    Err(Error::NonZeroInfinity)
+                }
+            }
+            // No flag is set, so this is not a compressed element.
+            _ => Err(Error::UnexpectedCompressionMode),
+        }
+    }
+    fn from_affine(affine: G2Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            res.0[0] |= super::FLAG_INFINITY;
+        } else {
+            {
+                let mut writer = &mut res.0[..];
+
+                affine.x.c1.into_repr().write_be(&mut writer).unwrap();
+                affine.x.c0.into_repr().write_be(&mut writer).unwrap();
+            }
+
+            let mut negy = affine.y;
+            negy.negate();
+
+            if affine.y > negy {
+                res.0[0] |= super::FLAG_COMPRESSED_LARGEST;
+            } else {
+                res.0[0] |= super::FLAG_COMPRESSED_SMALLEST;
+            }
+        }
+
+        res
+    }
+}
+
+impl G2Affine {
+    fn get_generator() -> Self {
+        G2Affine {
+            x: Fq2 {
+                c0: super::super::fq::G2_GENERATOR_X_C0,
+                c1: super::super::fq::G2_GENERATOR_X_C1,
+            },
+            y: Fq2 {
+                c0: super::super::fq::G2_GENERATOR_Y_C0,
+                c1: super::super::fq::G2_GENERATOR_Y_C1,
+            },
+            infinity: false,
+        }
+    }
+
+    fn get_coeff_b() -> Fq2 {
+        super::super::fq::B_COEFF_FQ2
+    }
+
+    fn scale_by_cofactor(&self) -> G2 {
+        // G2 cofactor = 2p - r
+        // 0x30644e72e131a029b85045b68181585e06ceecda572a2489345f2299c0f9fa8d
+        let cofactor = BitIterator::new([
+            0x345f2299c0f9fa8d,
+            0x6ceecda572a2489,
+            0xb85045b68181585e,
+            0x30644e72e131a029,
+        ]);
+        self.mul_bits(cofactor)
+    }
+
+    fn perform_pairing(&self, other: &G1Affine) -> Fq12 {
+        super::super::Bn256::pairing(*other, *self)
+    }
+}
+
+impl G2 {
+    fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
+        let num_bits = scalar.num_bits() as usize;
+
+        if num_bits >= 103 {
+            4
+        } else if num_bits >= 37 {
+            3
+        } else {
+            2
+        }
+    }
+
+    fn empirical_recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        const RECOMMENDATIONS: [usize; 11] = [1, 3, 8, 20, 47, 126, 260, 826, 1501, 4555, 84071];
+
+        let mut ret = 4;
+        for r in &RECOMMENDATIONS {
+            if num_scalars > *r {
+                ret += 1;
+            } else {
+                break;
+            }
+        }
+
+        ret
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct G2Prepared {
+    pub(crate) coeffs: Vec<(Fq2, Fq2, Fq2)>,
+    pub(crate) infinity: bool,
+}
+
+mod subgroup_check {
+    use super::G2Affine;
+    #[cfg(test)]
+    use crate::CurveAffine;
+    use crate::SubgroupCheck;
+    #[cfg(test)]
+    use rand_core::SeedableRng;
+
+    impl SubgroupCheck for G2Affine {
+        fn in_subgroup(&self) -> bool {
+            self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve()
+        }
+    }
+
+    #[test]
+    fn test_g2_subgroup_check() {
+        use crate::{bn256::Fq2, hash_to_curve::ClearH, CurveProjective};
+        use ff::Field;
+        let mut rng = rand_xorshift::XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..32 {
+            let p = loop {
+                let x = Fq2::random(&mut rng);
+                if let Some(p) = G2Affine::get_point_from_x(x, false) {
+                    break p;
+                }
+            };
+            assert!(p.is_on_curve());
+            assert!(!p.in_subgroup());
+
+            let mut pp = p.into_projective();
+            pp.clear_h();
+            let p = pp.into_affine();
+            assert!(p.in_subgroup() && p.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+}
+
+#[test]
+fn g2_generator() {
+    let g2 = G2Affine::one();
+    assert!(g2.is_on_curve());
+    assert!(g2.in_subgroup());
+    assert!(g2.mul(Fr::char()).is_zero());
+    assert!(!g2.mul(Fr::one()).is_zero());
+}
+
+#[test]
+fn g2_test_is_valid() {
+    // Reject point on the curve y^2 = x^3 + 3 over Fq2, which is not the twist
+    {
+        let p = G2Affine {
+            x: Fq2::one(),
+            y: Fq2 {
+                c0: Fq::from_str("2").unwrap(),
+                c1: Fq::zero(),
+            },
+            infinity: false,
+        };
+        assert!(!p.is_on_curve());
+        assert!(!p.in_subgroup());
+    }
+
+    // Reject point in an invalid subgroup
+    {
+        let mut x = Fq2::zero();
+        let p = loop {
+            if let Some(p) = G2Affine::get_point_from_x(x, false) {
+                break p;
+            }
+            x.add_assign(&Fq2::one());
+        };
+        assert!(p.is_on_curve());
+        assert!(!p.in_subgroup());
+        assert!(G2Affine::from(p.scale_by_cofactor()).in_subgroup());
+
+        match p.into_uncompressed().into_affine() {
+            Err(Error::NotInSubgroup) => (),
+            _ => panic!("should have rejected point outside the subgroup"),
+        }
+        match p.into_compressed().into_affine() {
+            Err(Error::NotInSubgroup) => (),
+            _ => panic!("should have rejected point outside the subgroup"),
+        }
+    }
+}
+
+#[test]
+fn test_g2_addition_correctness() {
+    let mut p = G2::one();
+    p.add_assign(
+        &G2Affine {
+            x: Fq2 {
+                c0: Fq::from_str(
+                    "18029695676650738226693292988307914797657423701064905010927197838374790804409",
+                )
+                .unwrap(),
+                c1: Fq::from_str(
+                    "14583779054894525174450323658765874724019480979794335525732096752006891875705",
+                )
+                .unwrap(),
+            },
+            y: Fq2 {
+                c0: Fq::from_str(
+                    "2140229616977736810657479771656733941598412651537078903776637920509952744750",
+                )
+                .unwrap(),
+                c1: Fq::from_str(
+                    "11474861747383700316476719153975578001603231366361248090558603872215261634898",
+                )
+                .unwrap(),
+            },
+            infinity: false,
+        }
+        .into_projective(),
+    );
+
+    let p = G2Affine::from(p);
+
+    assert_eq!(
+        p,
+        G2Affine {
+            x: Fq2 {
+                c0: Fq::from_str(
+                    "2725019753478801796453339367788033689375851816420509565303521482350756874229"
+                )
+                .unwrap(),
+                c1: Fq::from_str(
+                    "7273165102799931111715871471550377909735733521218303035754523677688038059653"
+                )
+                .unwrap(),
+            },
+            y: Fq2 {
+                c0: Fq::from_str(
+                    "2512659008974376214222774206987427162027254181373325676825515531566330959255"
+                )
+                .unwrap(),
+                c1: Fq::from_str(
+                    "957874124722006818841961785324909313781880061366718538693995380805373202866"
+                )
+                .unwrap(),
+            },
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn test_g2_doubling_correctness() {
+    let mut p = G2::one();
+
+    p.double();
+
+    let p = G2Affine::from(p);
+
+    assert_eq!(
+        p,
+        G2Affine {
+            x: Fq2 {
+                c0: Fq::from_str(
+                    "18029695676650738226693292988307914797657423701064905010927197838374790804409"
+                )
+                .unwrap(),
+                c1: Fq::from_str(
+                    "14583779054894525174450323658765874724019480979794335525732096752006891875705"
+                )
+                .unwrap(),
+            },
+            y: Fq2 {
+                c0: Fq::from_str(
+                    "2140229616977736810657479771656733941598412651537078903776637920509952744750"
+                )
+                .unwrap(),
+                c1: Fq::from_str(
+                    "11474861747383700316476719153975578001603231366361248090558603872215261634898"
+                )
+                .unwrap(),
+            },
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn test_g2_encoding_flags() {
+    let g = G2Affine::one();
+    let c = g.into_compressed();
+    let mut negy = g.y;
+    negy.negate();
+    let expected = if g.y > negy {
+        super::FLAG_COMPRESSED_LARGEST
+    } else {
+        super::FLAG_COMPRESSED_SMALLEST
+    };
+    assert_eq!(c.as_ref()[0] & super::FLAG_MASK, expected);
+    assert_eq!(c.into_affine().unwrap(), g);
+
+    // x.c1 is written first
+    let mut c1 = FqRepr([0; 4]);
+    {
+        let mut reader = c.as_ref();
+        c1.read_be(&mut reader).unwrap();
+    }
+    c1.as_mut()[3] &= !((super::FLAG_MASK as u64) << 56);
+    assert_eq!(Fq::from_repr(c1).unwrap(), g.x.c1);
+
+    let z = G2Affine::zero().into_compressed();
+    assert_eq!(z.as_ref()[0], super::FLAG_INFINITY);
+    let z = G2Affine::zero().into_uncompressed();
+    assert_eq!(z.as_ref()[0], super::FLAG_INFINITY);
+
+    let mut u = g.into_uncompressed();
+    u.as_mut()[0] |= super::FLAG_COMPRESSED_LARGEST;
+    match u.into_affine() {
+        Err(Error::UnexpectedCompressionMode) => (),
+        _ => panic!("should have rejected compression mode"),
+    }
+}
+
+#[test]
+fn g2_curve_tests() {
+    crate::tests::curve::curve_tests::<G2>();
+}
@@ -0,0 +1,24 @@
+// Points are encoded as in gnark-crypto: the two most significant bits of
+// the first byte carry the flags below, which fit because the modulus is
+// below 2^254. Coordinates are big-endian, with c1 before c0 for Fq2.
+const FLAG_MASK: u8 = 0b11 << 6;
+const FLAG_UNCOMPRESSED: u8 = 0b00 << 6;
+const FLAG_INFINITY: u8 = 0b01 << 6;
+const FLAG_COMPRESSED_SMALLEST: u8 = 0b10 << 6;
+const FLAG_COMPRESSED_LARGEST: u8 = 0b11 << 6;
+
+pub mod g1;
+pub mod g2;
+
+pub use self::g1::*;
+pub use self::g2::*;
+
+#[test]
+fn test_group_defaults() {
+    use crate::{CurveAffine, CurveProjective};
+
+    assert_eq!(G1::default(), G1::zero());
+    assert_eq!(G2::default(), G2::zero());
+    assert_eq!(G1Affine::default(), G1Affine::zero());
+    assert_eq!(G2Affine::default(), G2Affine::zero());
+}
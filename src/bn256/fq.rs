@@ -0,0 +1,594 @@
+use super::fq2::Fq2;
+use crate::{
+    hash_to_field::BaseFromRO,
+    signum::{Sgn0Result, Signum0},
+};
+use digest::generic_array::{typenum::U48, GenericArray};
+use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
+
+// B coefficient of BN254 curve, 3.
+pub const B_COEFF: Fq = Fq(FqRepr([
+    0x7a17caa950ad28d7,
+    0x1f6ac17ae15521b9,
+    0x334bea4e696bd284,
+    0x2a1f6744ce179d8e,
+]));
+
+// B coefficient of the twist, 3 / (9 + u).
+pub const B_COEFF_FQ2: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0x3bf938e377b802a8,
+        0x20b1b273633535d,
+        0x26b7edf049755260,
+        0x2514c6324384a86d,
+    ])),
+    c1: Fq(FqRepr([
+        0x38e7ecccd1dcff67,
+        0x65f0b37d93ce0d3e,
+        0xd749d0dd22ac00aa,
+        0x141b9ce4a688d4d,
+    ])),
+};
+
+// Generator of G1
+// x = 1
+// y = 2
+pub const G1_GENERATOR_X: Fq = Fq(FqRepr([
+    0xd35d438dc58f0d9d,
+    0xa78eb28f5c70b3d,
+    0x666ea36f7879462c,
+    0xe0a77c19a07df2f,
+]));
+
+pub const G1_GENERATOR_Y: Fq = Fq(FqRepr([
+    0xa6ba871b8b1e1b3a,
+    0x14f1d651eb8e167b,
+    0xccdd46def0f28c58,
+    0x1c14ef83340fbe5e,
+]));
+
+// Generator of G2
+// x = 11559732032986387107991004021392285783925812861821192530917403151452391805634*u + 10857046999023057135944570762232829481370756359578518086990519993285655852781
+// y = 4082367875863433681332203403145435568316851327593401208105741076214120093531*u + 8495653923123431417604973247489272438418190587263600148770280649306958101930
+pub const G2_GENERATOR_X_C0: Fq = Fq(FqRepr([
+    0x8e83b5d102bc2026,
+    0xdceb1935497b0172,
+    0xfbb8264797811adf,
+    0x19573841af96503b,
+]));
+
+pub const G2_GENERATOR_X_C1: Fq = Fq(FqRepr([
+    0xafb4737da84c6140,
+    0x6043dd5a5802d8c4,
+    0x9e950fc52a02f86,
+    0x14fef0833aea7b6b,
+]));
+
+pub const G2_GENERATOR_Y_C0: Fq = Fq(FqRepr([
+    0x619dfa9d886be9f6,
+    0xfe7fd297f59e9b78,
+    0xff9e1a62231b7dfe,
+    0x28fd7eebae9e4206,
+]));
+
+pub const G2_GENERATOR_Y_C1: Fq = Fq(FqRepr([
+    0x64095b56c71856ee,
+    0xdc57f922327d3cbb,
+    0x55f935be33351076,
+    0xda4a0e693fd6482,
+]));
+
+// Coefficients for the Frobenius automorphism.
+pub const FROBENIUS_COEFF_FQ2_C1: [Fq; 2] = [
+    Fq(FqRepr([
+        0xd35d438dc58f0d9d,
+        0xa78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0xe0a77c19a07df2f,
+    ])),
+    Fq(FqRepr([
+        0x68c3488912edefaa,
+        0x8d087f6872aabf4f,
+        0x51e1a24709081231,
+        0x2259d6b14729c0fa,
+    ])),
+];
+
+// (9 + u)^((q^i - 1) / 3) for i in 0..6
+pub const FROBENIUS_COEFF_FQ6_C1: [Fq2; 6] = [
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xd35d438dc58f0d9d,
+            0xa78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0xe0a77c19a07df2f,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xb5773b104563ab30,
+            0x347f91c8a9aa6454,
+            0x7a007127242e0991,
+            0x1956bcd8118214ec,
+        ])),
+        c1: Fq(FqRepr([
+            0x6e849f1ea0aa4757,
+            0xaa1c7b6d89f89141,
+            0xb6e713cdfae0ca3a,
+            0x26694fbb4e82ebc3,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x3350c88e13e80b9c,
+            0x7dce557cdb5e56b9,
+            0x6001b4b8b615564a,
+            0x2682e617020217e0,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xc9af22f716ad6bad,
+            0xb311782a4aa662b2,
+            0x19eeaf64e248c7f4,
+            0x20273e77e3439f82,
+        ])),
+        c1: Fq(FqRepr([
+            0xacc02860f7ce93ac,
+            0x3933d5817ba76b4c,
+            0x69e6188b446c8467,
+            0xa46036d4417cc55,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x71930c11d782e155,
+            0xa6bb947cffbe3323,
+            0xaa303344d4741444,
+            0x2c3b3f0d26594943,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xf91aba2654e8e3b1,
+            0x4771cb2fdc92ce12,
+            0xdcb16ae0fc8bdf35,
+            0x274aa195cd9d8be4,
+        ])),
+        c1: Fq(FqRepr([
+            0x5cfc50ae18811f8b,
+            0x4bb28433cb43988c,
+            0x4fd35f13c3b56219,
+            0x301949bd2fc8883a,
+        ])),
+    },
+];
+
+// (9 + u)^((2 q^i - 2) / 3) for i in 0..6
+pub const FROBENIUS_COEFF_FQ6_C2: [Fq2; 6] = [
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xd35d438dc58f0d9d,
+            0xa78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0xe0a77c19a07df2f,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x7361d77f843abe92,
+            0xa5bb2bd3273411fb,
+            0x9c941f314b3e2399,
+            0x15df9cddbb9fd3ec,
+        ])),
+        c1: Fq(FqRepr([
+            0x5dddfd154bd8c949,
+            0x62cb29a5a4445b60,
+            0x37bc870a0c7dd2b9,
+            0x24830a9d3171f0fd,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x71930c11d782e155,
+            0xa6bb947cffbe3323,
+            0xaa303344d4741444,
+            0x2c3b3f0d26594943,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x448a93a57b6762df,
+            0xbfd62df528fdeadf,
+            0xd858f5d00e9bd47a,
+            0x6b03d4d3476ec58,
+        ])),
+        c1: Fq(FqRepr([
+            0x2b19daf4bcc936d1,
+            0xa1a54e7a56f4299f,
+            0xb533eee05adeaef1,
+            0x170c812b84dda0b2,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x3350c88e13e80b9c,
+            0x7dce557cdb5e56b9,
+            0x6001b4b8b615564a,
+            0x2682e617020217e0,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x843420f1d8dadbd6,
+            0x31f010c9183fcdb2,
+            0x436330b527a76049,
+            0x13d47447f11adfe4,
+        ])),
+        c1: Fq(FqRepr([
+            0xef494023a857fa74,
+            0x2a925d02d5ab101a,
+            0x83b015829ba62f10,
+            0x2539111d0c13aea3,
+        ])),
+    },
+];
+
+// (9 + u)^((q^i - 1) / 6) for i in 0..12
+pub const FROBENIUS_COEFF_FQ12_C1: [Fq2; 12] = [
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xd35d438dc58f0d9d,
+            0xa78eb28f5c70b3d,
+            0x666ea36f7879462c,
+            0xe0a77c19a07df2f,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xaf9ba69633144907,
+            0xca6b1d7387afb78a,
+            0x11bded5ef08a2087,
+            0x2f34d751a1f3a7c,
+        ])),
+        c1: Fq(FqRepr([
+            0xa222ae234c492d72,
+            0xd00f02a4565de15b,
+            0xdc2ff3a253dfc926,
+            0x10a75716b3899551,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xca8d800500fa1bf2,
+            0xf0c5d61468b39769,
+            0xe201271ad0d4418,
+            0x4290f65bad856e6,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x365316184e46d97d,
+            0xaf7129ed4c96d9f,
+            0x659da72fca1009b5,
+            0x8116d8983a20d23,
+        ])),
+        c1: Fq(FqRepr([
+            0xb1df4af7c39c1939,
+            0x3d9f02878a73bf7f,
+            0x9b2220928caf0ae0,
+            0x26684515eff054a6,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x3350c88e13e80b9c,
+            0x7dce557cdb5e56b9,
+            0x6001b4b8b615564a,
+            0x2682e617020217e0,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x86b76f821b329076,
+            0x408bf52b4d19b614,
+            0x53dfb9d0d985e92d,
+            0x51e20146982d2a7,
+        ])),
+        c1: Fq(FqRepr([
+            0xfbc9cd47752ebc7,
+            0x6d8fffe33415de24,
+            0xbef22cf038cf41b9,
+            0x15c0edff3c66bf54,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x68c3488912edefaa,
+            0x8d087f6872aabf4f,
+            0x51e1a24709081231,
+            0x2259d6b14729c0fa,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x8c84e580a568b440,
+            0xcd164d1de0c21302,
+            0xa692585790f737d5,
+            0x2d7100fdc71265ad,
+        ])),
+        c1: Fq(FqRepr([
+            0x99fdddf38c33cfd5,
+            0xc77267ed1213e931,
+            0xdc2052142da18f36,
+            0x1fbcf75c2da80ad7,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x71930c11d782e155,
+            0xa6bb947cffbe3323,
+            0xaa303344d4741444,
+            0x2c3b3f0d26594943,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x5cd75fe8a3623ca,
+            0x8c8a57f293a85cee,
+            0x52b29e86b7714ea8,
+            0x2852e0e95d8f9306,
+        ])),
+        c1: Fq(FqRepr([
+            0x8a41411f14e0e40e,
+            0x59e26809ddfe0b0d,
+            0x1d2e2523f4d24d7d,
+            0x9fc095cf1414b83,
+        ])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0x8cfc388c494f1ab,
+            0x19b315148d1373d4,
+            0x584e90fdcb6c0213,
+            0x9e1685bdf2f8849,
+        ])),
+        c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+    },
+    Fq2 {
+        c0: Fq(FqRepr([
+            0xb5691c94bd4a6cd1,
+            0x56f575661b581478,
+            0x64708be5a7fb6f30,
+            0x2b462e5e77aecd82,
+        ])),
+        c1: Fq(FqRepr([
+            0x2c63ef42612a1180,
+            0x29f16aae345bec69,
+            0xf95e18c648b216a4,
+            0x1aa36073a4cae0d4,
+        ])),
+    },
+];
+
+// (9 + u)^((q - 1) / 2)
+pub const XI_TO_Q_MINUS_1_OVER_2: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0xe4bbdd0c2936b629,
+        0xbb30f162e133bacb,
+        0x31a9d1b6f9645366,
+        0x253570bea500f8dd,
+    ])),
+    c1: Fq(FqRepr([
+        0xa1d77ce45ffe77c7,
+        0x7affd117826d1db,
+        0x6d16bd27bb7edc6b,
+        0x2c87200285defecc,
+    ])),
+};
+
+// -((2**256) mod q) mod q
+pub const NEGATIVE_ONE: Fq = Fq(FqRepr([
+    0x68c3488912edefaa,
+    0x8d087f6872aabf4f,
+    0x51e1a24709081231,
+    0x2259d6b14729c0fa,
+]));
+
+#[derive(PrimeField, Zeroize)]
+#[PrimeFieldModulus = "21888242871839275222246405745257275088696311157297823662689037894645226208583"]
+#[PrimeFieldGenerator = "3"]
+pub struct Fq(pub(super) FqRepr);
+
+/// # Safety
+pub const unsafe fn transmute(r: FqRepr) -> Fq {
+    Fq(r)
+}
+
+impl BaseFromRO for Fq {
+    type BaseLength = U48;
+
+    // Convert an output keying material to an Fq element
+    // the input generic array is gauranteed to have 48 bytes
+    fn from_okm(okm: &GenericArray<u8, U48>) -> Fq {
+        const F_2_192: Fq = Fq(FqRepr([
+            0xd9e291c2cdd22cd6u64,
+            0xc722ccf2a40f0271u64,
+            0xa49e35d611a2ac87u64,
+            0x2e1043978c993ec8u64,
+        ]));
+
+        // unwraps are safe here: we only use 24 bytes at a time, which is strictly less than p
+        let mut repr = FqRepr::default();
+        let mut buf = [0u8; 32];
+        buf[8..].copy_from_slice(&okm[..24]);
+        repr.read_be(&buf[..]).unwrap();
+        let mut elm = Fq::from_repr(repr).unwrap();
+        elm.mul_assign(&F_2_192);
+
+        buf[8..].copy_from_slice(&okm[24..]);
+        repr.read_be(&buf[..]).unwrap();
+        let elm2 = Fq::from_repr(repr).unwrap();
+        elm.add_assign(&elm2);
+        elm
+    }
+}
+
+impl Signum0 for Fq {
+    // returns the sign of a center lifted element over the integer ring
+    fn sgn0(&self) -> Sgn0Result {
+        if self.into_repr().0[0] & 1 == 1 {
+            Sgn0Result::Negative
+        } else {
+            Sgn0Result::NonNegative
+        }
+    }
+}
+
+#[test]
+fn test_b_coeff() {
+    assert_eq!(Fq::from_repr(FqRepr::from(3)).unwrap(), B_COEFF);
+
+    let mut b = B_COEFF_FQ2;
+    b.mul_assign(&Fq2 {
+        c0: Fq::from_repr(FqRepr::from(9)).unwrap(),
+        c1: Fq::one(),
+    });
+    assert_eq!(
+        b,
+        Fq2 {
+            c0: B_COEFF,
+            c1: Fq::zero(),
+        }
+    );
+}
+
+#[test]
+fn test_frob_coeffs() {
+    let mut nqr = Fq::one();
+    nqr.negate();
+
+    // (q - 1) / 2
+    let q_m1_over2 = [
+        0x9e10460b6c3e7ea3,
+        0xcbc0b548b438e546,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ];
+
+    assert_eq!(FROBENIUS_COEFF_FQ2_C1[0], Fq::one());
+    assert_eq!(FROBENIUS_COEFF_FQ2_C1[1], nqr.pow(q_m1_over2));
+
+    let nqr = Fq2 {
+        c0: Fq::from_repr(FqRepr::from(9)).unwrap(),
+        c1: Fq::one(),
+    };
+    assert_eq!(XI_TO_Q_MINUS_1_OVER_2, nqr.pow(q_m1_over2));
+
+    // xi^((q^i - 1) / 6) determines the other coefficients
+    let mut xi_pow = Fq2::one();
+    for i in 0..12 {
+        let coeff = FROBENIUS_COEFF_FQ12_C1[i];
+        assert_eq!(coeff, xi_pow);
+        if i < 6 {
+            let mut c = coeff;
+            c.square();
+            assert_eq!(FROBENIUS_COEFF_FQ6_C1[i], c);
+            c.square();
+            assert_eq!(FROBENIUS_COEFF_FQ6_C2[i], c);
+        }
+
+        // xi^((q^(i+1) - 1) / 6) = (xi^((q^i - 1) / 6))^q * xi^((q - 1) / 6)
+        xi_pow.frobenius_map(1);
+        xi_pow.mul_assign(&FROBENIUS_COEFF_FQ12_C1[1]);
+    }
+
+    let mut c = FROBENIUS_COEFF_FQ12_C1[1];
+    c = c.pow([6]);
+    assert_eq!(
+        c,
+        nqr.pow([
+            0x3c208c16d87cfd46,
+            0x97816a916871ca8d,
+            0xb85045b68181585d,
+            0x30644e72e131a029
+        ])
+    );
+}
+
+#[test]
+fn test_neg_one() {
+    let mut o = Fq::one();
+    o.negate();
+
+    assert_eq!(NEGATIVE_ONE, o);
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    use ff::SqrtField;
+
+    assert_eq!(Fq::S, 1);
+    assert_eq!(
+        Fq::multiplicative_generator(),
+        Fq::from_repr(FqRepr::from(3)).unwrap()
+    );
+    assert_eq!(Fq::root_of_unity(), NEGATIVE_ONE);
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn test_fq_from_okm() {
+    // 2^192 * head + tail
+    let mut okm = [0u8; 48];
+    okm[23] = 1;
+    okm[47] = 5;
+    let mut expect = Fq::from_repr(FqRepr([0, 0, 0, 1])).unwrap();
+    expect.add_assign(&Fq::from_repr(FqRepr::from(5)).unwrap());
+    assert_eq!(Fq::from_okm(GenericArray::from_slice(&okm[..])), expect);
+
+    // all ones is 2^384 - 1 mod q
+    let okm = [0xffu8; 48];
+    assert_eq!(
+        Fq::from_okm(GenericArray::from_slice(&okm[..])),
+        Fq::from_str(
+            "19955747995551142847684105936715069082057687757382501343901258828998203168489"
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn fq_field_tests() {
+    crate::tests::field::random_field_tests::<Fq>();
+    crate::tests::field::random_sqrt_tests::<Fq>();
+    crate::tests::field::random_frobenius_tests::<Fq, _>(Fq::char(), 13);
+    crate::tests::field::from_str_tests::<Fq>();
+}
+
+#[test]
+fn test_fq_ordering() {
+    // FqRepr's ordering is well-tested, but we still need to make sure the Fq
+    // elements aren't being compared in Montgomery form.
+    for i in 0..100 {
+        assert!(
+            Fq::from_repr(FqRepr::from(i + 1)).unwrap() > Fq::from_repr(FqRepr::from(i)).unwrap()
+        );
+    }
+}
+
+#[test]
+fn fq_repr_tests() {
+    crate::tests::repr::random_repr_tests::<Fq, FqRepr>();
+}
@@ -0,0 +1,75 @@
+use super::fq::FROBENIUS_COEFF_FQ12_C1;
+#[cfg(test)]
+use super::fq2::Fq2;
+use super::fq6::Fq6;
+use crate::tower::{QuadraticExtParameters, QuadraticExtension};
+use ff::Field;
+
+/// Parameters of Fq12 = Fq6[w] / (w^2 - v).
+#[derive(Copy, Clone, Debug)]
+pub struct Fq12Parameters;
+
+impl QuadraticExtParameters for Fq12Parameters {
+    type BaseField = Fq6;
+
+    const NAME: &'static str = "Fq12";
+    const VAR: &'static str = "w";
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &mut Fq6) {
+        fe.mul_by_nonresidue();
+    }
+
+    #[inline(always)]
+    fn mul_by_frobenius_coeff(c1: &mut Fq6, power: usize) {
+        c1.c0.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
+        c1.c1.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
+        c1.c2.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
+    }
+}
+
+/// An element of Fq12, represented by c0 + c1 * w.
+pub type Fq12 = QuadraticExtension<Fq12Parameters>;
+
+#[cfg(test)]
+use rand_core::SeedableRng;
+//use rand::{SeedableRng, XorShiftRng};
+
+#[test]
+fn test_fq12_mul_by_034() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..1000 {
+        let c0 = Fq2::random(&mut rng);
+        let c3 = Fq2::random(&mut rng);
+        let c4 = Fq2::random(&mut rng);
+        let mut a = Fq12::random(&mut rng);
+        let mut b = a;
+
+        a.mul_by_034(&c0, &c3, &c4);
+        b.mul_assign(&Fq12 {
+            c0: Fq6 {
+                c0,
+                c1: Fq2::zero(),
+                c2: Fq2::zero(),
+            },
+            c1: Fq6 {
+                c0: c3,
+                c1: c4,
+                c2: Fq2::zero(),
+            },
+        });
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn fq12_field_tests() {
+    use ff::PrimeField;
+
+    crate::tests::field::random_field_tests::<Fq12>();
+    crate::tests::field::random_frobenius_tests::<Fq12, _>(super::fq::Fq::char(), 13);
+}
@@ -0,0 +1,253 @@
+use super::fq::{Fq, FROBENIUS_COEFF_FQ2_C1, NEGATIVE_ONE};
+use crate::{
+    hash_to_field::{BaseFromRO, FromRO},
+    tower::{QuadraticExtParameters, QuadraticExtension},
+};
+use digest::generic_array::{
+    typenum::{U48, U96},
+    GenericArray,
+};
+use ff::{Field, SqrtField};
+
+/// Parameters of Fq2 = Fq[u] / (u^2 + 1).
+#[derive(Copy, Clone, Debug)]
+pub struct Fq2Parameters;
+
+impl QuadraticExtParameters for Fq2Parameters {
+    type BaseField = Fq;
+
+    const NAME: &'static str = "Fq2";
+    const VAR: &'static str = "u";
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &mut Fq) {
+        fe.negate();
+    }
+
+    #[inline(always)]
+    fn add_mul_by_nonresidue(acc: &mut Fq, fe: &Fq) {
+        acc.sub_assign(fe);
+    }
+
+    #[inline(always)]
+    fn sub_mul_by_nonresidue(acc: &mut Fq, fe: &Fq) {
+        acc.add_assign(fe);
+    }
+
+    #[inline(always)]
+    fn mul_by_frobenius_coeff(c1: &mut Fq, power: usize) {
+        c1.mul_assign(&FROBENIUS_COEFF_FQ2_C1[power % 2]);
+    }
+}
+
+/// An element of Fq2, represented by c0 + c1 * u.
+pub type Fq2 = QuadraticExtension<Fq2Parameters>;
+
+impl Fq2 {
+    /// Multiply this element by the cubic and quadratic nonresidue 9 + u.
+    pub fn mul_by_nonresidue(&mut self) {
+        // (c0 + c1 * u) * (9 + u) = (9 * c0 - c1) + (c0 + 9 * c1) * u
+        let t0 = self.c0;
+        let t1 = self.c1;
+
+        self.c0.double();
+        self.c0.double();
+        self.c0.double();
+        self.c0.add_assign(&t0);
+        self.c0.sub_assign(&t1);
+
+        self.c1.double();
+        self.c1.double();
+        self.c1.double();
+        self.c1.add_assign(&t1);
+        self.c1.add_assign(&t0);
+    }
+}
+
+impl SqrtField for Fq2 {
+    fn legendre(&self) -> ::ff::LegendreSymbol {
+        self.norm().legendre()
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        // Algorithm 9, https://eprint.iacr.org/2012/685.pdf
+
+        if self.is_zero() {
+            Some(Self::zero())
+        } else {
+            // a1 = self^((q - 3) / 4)
+            let mut a1 = self.pow([
+                0x4f082305b61f3f51,
+                0x65e05aa45a1c72a3,
+                0x6e14116da0605617,
+                0xc19139cb84c680a,
+            ]);
+            let mut alpha = a1;
+            alpha.square();
+            alpha.mul_assign(self);
+            let mut a0 = alpha;
+            a0.frobenius_map(1);
+            a0.mul_assign(&alpha);
+
+            let neg1 = Fq2 {
+                c0: NEGATIVE_ONE,
+                c1: Fq::zero(),
+            };
+
+            if a0 == neg1 {
+                None
+            } else {
+                a1.mul_assign(self);
+
+                if alpha == neg1 {
+                    a1.mul_assign(&Fq2 {
+                        c0: Fq::zero(),
+                        c1: Fq::one(),
+                    });
+                } else {
+                    alpha.add_assign(&Fq2::one());
+                    // alpha = alpha^((q - 1) / 2)
+                    alpha = alpha.pow([
+                        0x9e10460b6c3e7ea3,
+                        0xcbc0b548b438e546,
+                        0xdc2822db40c0ac2e,
+                        0x183227397098d014,
+                    ]);
+                    a1.mul_assign(&alpha);
+                }
+
+                Some(a1)
+            }
+        }
+    }
+}
+
+/// Fq2 implementation: hash to two elemnts of Fq and combine.
+impl FromRO for Fq2 {
+    type Length = U96;
+
+    fn from_ro(okm: &GenericArray<u8, U96>) -> Fq2 {
+        let c0 = Fq::from_okm(GenericArray::<u8, U48>::from_slice(&okm[..48]));
+        let c1 = Fq::from_okm(GenericArray::<u8, U48>::from_slice(&okm[48..]));
+        Fq2 { c0, c1 }
+    }
+}
+
+#[cfg(test)]
+use rand_core::SeedableRng;
+
+#[test]
+fn test_fq2_ordering() {
+    use core::cmp::Ordering;
+
+    let mut a = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::zero(),
+    };
+
+    let mut b = a;
+
+    assert!(a.cmp(&b) == Ordering::Equal);
+    b.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Equal);
+    b.c1.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Less);
+    a.c1.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Greater);
+    b.c0.add_assign(&Fq::one());
+    assert!(a.cmp(&b) == Ordering::Equal);
+}
+
+#[test]
+fn test_fq2_basics() {
+    assert_eq!(
+        Fq2 {
+            c0: Fq::zero(),
+            c1: Fq::zero(),
+        },
+        Fq2::zero()
+    );
+    assert_eq!(
+        Fq2 {
+            c0: Fq::one(),
+            c1: Fq::zero(),
+        },
+        Fq2::one()
+    );
+    assert!(Fq2::zero().is_zero());
+    assert!(!Fq2::one().is_zero());
+    assert!(!Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::one(),
+    }
+    .is_zero());
+}
+
+#[test]
+fn test_fq2_legendre() {
+    use ff::LegendreSymbol::*;
+
+    assert_eq!(Zero, Fq2::zero().legendre());
+    // i^2 = -1
+    let mut m1 = Fq2::one();
+    m1.negate();
+    assert_eq!(QuadraticResidue, m1.legendre());
+    m1.mul_by_nonresidue();
+    assert_eq!(QuadraticNonResidue, m1.legendre());
+}
+
+#[test]
+fn test_fq2_mul_nonresidue() {
+    use ff::PrimeField;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    let nqr = Fq2 {
+        c0: Fq::from_str("9").unwrap(),
+        c1: Fq::one(),
+    };
+
+    for _ in 0..1000 {
+        let mut a = Fq2::random(&mut rng);
+        let mut b = a;
+        a.mul_by_nonresidue();
+        b.mul_assign(&nqr);
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq2_sgn0() {
+    use crate::signum::{Sgn0Result, Signum0};
+
+    let mut a = Fq2::one();
+    assert_eq!(a.sgn0(), Sgn0Result::Negative);
+    a.negate();
+    assert_eq!(a.sgn0(), Sgn0Result::NonNegative);
+
+    // sign of c1 is used when c0 is zero
+    let mut a = Fq2 {
+        c0: Fq::zero(),
+        c1: Fq::one(),
+    };
+    assert_eq!(a.sgn0(), Sgn0Result::Negative);
+    a.negate();
+    assert_eq!(a.sgn0(), Sgn0Result::NonNegative);
+    assert_eq!(Fq2::zero().sgn0(), Sgn0Result::NonNegative);
+}
+
+#[test]
+fn fq2_field_tests() {
+    use ff::PrimeField;
+
+    crate::tests::field::random_field_tests::<Fq2>();
+    crate::tests::field::random_sqrt_tests::<Fq2>();
+    crate::tests::field::random_frobenius_tests::<Fq2, _>(super::fq::Fq::char(), 13);
+}
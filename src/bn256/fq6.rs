@@ -0,0 +1,108 @@
+use super::fq::{FROBENIUS_COEFF_FQ6_C1, FROBENIUS_COEFF_FQ6_C2};
+use super::fq2::Fq2;
+use crate::tower::{CubicExtParameters, CubicExtension};
+use ff::Field;
+
+/// Parameters of Fq6 = Fq2[v] / (v^3 - (u + 9)).
+#[derive(Copy, Clone, Debug)]
+pub struct Fq6Parameters;
+
+impl CubicExtParameters for Fq6Parameters {
+    type BaseField = Fq2;
+
+    const NAME: &'static str = "Fq6";
+    const VAR: &'static str = "v";
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &mut Fq2) {
+        fe.mul_by_nonresidue();
+    }
+
+    #[inline(always)]
+    fn mul_by_frobenius_coeffs(c1: &mut Fq2, c2: &mut Fq2, power: usize) {
+        c1.mul_assign(&FROBENIUS_COEFF_FQ6_C1[power % 6]);
+        c2.mul_assign(&FROBENIUS_COEFF_FQ6_C2[power % 6]);
+    }
+}
+
+/// An element of Fq6, represented by c0 + c1 * v + c2 * v^(2).
+pub type Fq6 = CubicExtension<Fq6Parameters>;
+
+#[cfg(test)]
+use rand_core::SeedableRng;
+//use rand::{SeedableRng, XorShiftRng};
+
+#[test]
+fn test_fq6_mul_nonresidue() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    let nqr = Fq6 {
+        c0: Fq2::zero(),
+        c1: Fq2::one(),
+        c2: Fq2::zero(),
+    };
+
+    for _ in 0..1000 {
+        let mut a = Fq6::random(&mut rng);
+        let mut b = a;
+        a.mul_by_nonresidue();
+        b.mul_assign(&nqr);
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq6_mul_by_1() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..1000 {
+        let c1 = Fq2::random(&mut rng);
+        let mut a = Fq6::random(&mut rng);
+        let mut b = a;
+
+        a.mul_by_1(&c1);
+        b.mul_assign(&Fq6 {
+            c0: Fq2::zero(),
+            c1,
+            c2: Fq2::zero(),
+        });
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq6_mul_by_01() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..1000 {
+        let c0 = Fq2::random(&mut rng);
+        let c1 = Fq2::random(&mut rng);
+        let mut a = Fq6::random(&mut rng);
+        let mut b = a;
+
+        a.mul_by_01(&c0, &c1);
+        b.mul_assign(&Fq6 {
+            c0,
+            c1,
+            c2: Fq2::zero(),
+        });
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn fq6_field_tests() {
+    use ff::PrimeField;
+
+    crate::tests::field::random_field_tests::<Fq6>();
+    crate::tests::field::random_frobenius_tests::<Fq6, _>(super::fq::Fq::char(), 13);
+}
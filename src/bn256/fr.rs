@@ -0,0 +1,111 @@
+use crate::{hash_to_field::BaseFromRO, hash_to_scalar::FromBytesWide};
+use digest::generic_array::{typenum::U48, GenericArray};
+use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
+
+#[derive(PrimeField, Zeroize)]
+#[PrimeFieldModulus = "21888242871839275222246405745257275088548364400416034343698204186575808495617"]
+#[PrimeFieldGenerator = "7"]
+pub struct Fr(FrRepr);
+
+/// set the default value for Fr to 0
+impl ::core::default::Default for Fr {
+    fn default() -> Self {
+        Fr::zero()
+    }
+}
+
+/// # Safety
+pub const unsafe fn transmute(r: FrRepr) -> Fr {
+    Fr(r)
+}
+
+// 2^192 in Montgomery form
+const F_2_192: Fr = Fr(FrRepr([
+    0x5665c3b5c177f51au64,
+    0xe7f02ade75c713u64,
+    0xb09192e52f747168u64,
+    0x621c0bbcccdc65du64,
+]));
+
+impl BaseFromRO for Fr {
+    type BaseLength = U48;
+
+    fn from_okm(okm: &GenericArray<u8, U48>) -> Fr {
+        // unwraps are safe here: we only use 24 bytes at a time, which is strictly less than r
+        let mut repr = FrRepr::default();
+        let mut buf = [0u8; 32];
+        buf[8..].copy_from_slice(&okm[..24]);
+        repr.read_be(&buf[..]).unwrap();
+        let mut elm = Fr::from_repr(repr).unwrap();
+        elm.mul_assign(&F_2_192);
+
+        buf[8..].copy_from_slice(&okm[24..]);
+        repr.read_be(&buf[..]).unwrap();
+        elm.add_assign(&Fr::from_repr(repr).unwrap());
+        elm
+    }
+}
+
+impl FromBytesWide for Fr {
+    fn from_bytes_wide(bytes: &[u8; 64]) -> Fr {
+        // unwraps are safe here: we use at most 24 bytes at a time, which is strictly less than r
+        let mut repr = FrRepr::default();
+        let mut buf = [0u8; 32];
+        buf[16..].copy_from_slice(&bytes[..16]);
+        repr.read_be(&buf[..]).unwrap();
+        let mut elm = Fr::from_repr(repr).unwrap();
+
+        for chunk in bytes[16..].chunks(24) {
+            elm.mul_assign(&F_2_192);
+            buf[8..].copy_from_slice(chunk);
+            repr.read_be(&buf[..]).unwrap();
+            elm.add_assign(&Fr::from_repr(repr).unwrap());
+        }
+        elm
+    }
+}
+
+#[test]
+fn test_fr_from_bytes_wide() {
+    assert_eq!(
+        Fr::from_bytes_wide(&[0xff; 64]),
+        Fr::from_str("944936681149208446651664254269745548490766851729442924617792859073125903782")
+            .unwrap()
+    );
+    assert!(Fr::from_bytes_wide(&[0; 64]).is_zero());
+}
+
+#[test]
+fn test_fr_root_of_unity() {
+    use ff::SqrtField;
+
+    assert_eq!(Fr::S, 28);
+    assert_eq!(
+        Fr::multiplicative_generator(),
+        Fr::from_repr(FrRepr::from(7)).unwrap()
+    );
+    assert_eq!(
+        Fr::multiplicative_generator().pow([
+            0x9b9709143e1f593f,
+            0x181585d2833e8487,
+            0x131a029b85045b68,
+            0x30644e72e
+        ]),
+        Fr::root_of_unity()
+    );
+    assert_eq!(Fr::root_of_unity().pow([1 << Fr::S]), Fr::one());
+    assert!(Fr::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn fr_field_tests() {
+    crate::tests::field::random_field_tests::<Fr>();
+    crate::tests::field::random_sqrt_tests::<Fr>();
+    crate::tests::field::random_frobenius_tests::<Fr, _>(Fr::char(), 13);
+    crate::tests::field::from_str_tests::<Fr>();
+}
+
+#[test]
+fn fr_repr_tests() {
+    crate::tests::repr::random_repr_tests::<Fr, FrRepr>();
+}
@@ -0,0 +1,593 @@
+/*!
+The BN254 (alt_bn128) pairing-friendly curve, as used by the Ethereum
+precompiles and gnark-crypto.
+
+The curve is y^2 = x^3 + 3 over Fq, with the D-type sextic twist
+y^2 = x^3 + 3 / (9 + u) over Fq2. The pairing is the optimal ate pairing,
+with the Miller loop run over the NAF of 6u + 2 for u = 4965661367192848881.
+*/
+
+mod cofactor;
+mod ec;
+mod fq;
+mod fq12;
+mod fq2;
+mod fq6;
+mod fr;
+pub(crate) mod svdw_map;
+
+pub use self::ec::{
+    G1Affine, G1Compressed, G1Prepared, G1Uncompressed, G2Affine, G2Compressed, G2Prepared,
+    G2Uncompressed, G1, G2,
+};
+pub use self::fq::{Fq, FqRepr};
+pub use self::fq12::{Fq12, Fq12Parameters};
+pub use self::fq2::{Fq2, Fq2Parameters};
+pub use self::fq6::{Fq6, Fq6Parameters};
+pub use self::fr::{Fr, FrRepr};
+
+pub mod transmute {
+    pub use super::ec::g1::transmute_affine as g1_affine;
+    pub use super::ec::g1::transmute_projective as g1_projective;
+    pub use super::ec::g2::transmute_affine as g2_affine;
+    pub use super::ec::g2::transmute_projective as g2_projective;
+    pub use super::fq::transmute as fq;
+    pub use super::fr::transmute as fr;
+}
+
+use self::fq::{FROBENIUS_COEFF_FQ6_C1, XI_TO_Q_MINUS_1_OVER_2};
+use super::{CurveAffine, Engine};
+
+use ff::{Field, ScalarEngine};
+
+// The BN parameter u for BN254
+const BN_U: u64 = 4965661367192848881;
+
+// NAF of 6u + 2, least significant digit first
+const SIX_U_PLUS_2_NAF: [i8; 66] = [
+    0, 0, 0, 1, 0, 1, 0, -1, 0, 0, -1, 0, 0, 0, 1, 0, 0, -1, 0, -1, 0, 0, 0, 1, 0, -1, 0, 0, 0, 0,
+    -1, 0, 0, 1, 0, -1, 0, 0, 1, 0, 0, 0, 0, 0, -1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, -1, 0, -1, 0,
+    0, 0, 1, 0, -1, 0, 1,
+];
+
+#[derive(Clone, Debug)]
+pub struct Bn256;
+
+impl ScalarEngine for Bn256 {
+    type Fr = Fr;
+}
+
+impl Engine for Bn256 {
+    type G1 = G1;
+    type G1Affine = G1Affine;
+    type G2 = G2;
+    type G2Affine = G2Affine;
+    type Fq = Fq;
+    type Fqe = Fq2;
+    type Fqk = Fq12;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<
+            Item = &'a (
+                &'a <Self::G1Affine as CurveAffine>::Prepared,
+                &'a <Self::G2Affine as CurveAffine>::Prepared,
+            ),
+        >,
+    {
+        let mut pairs = vec![];
+        for &(p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, q.coeffs.iter()));
+            }
+        }
+
+        // Twisting isomorphism from E to E'
+        fn ell(f: &mut Fq12, coeffs: &(Fq2, Fq2, Fq2), p: &G1Affine) {
+            let mut c0 = coeffs.0;
+            let mut c1 = coeffs.1;
+
+            c0.c0.mul_assign(&p.y);
+            c0.c1.mul_assign(&p.y);
+
+            c1.c0.mul_assign(&p.x);
+            c1.c1.mul_assign(&p.x);
+
+            // Sparse multiplication in Fq12
+            f.mul_by_034(&c0, &c1, &coeffs.2);
+        }
+
+        let mut f = Fq12::one();
+
+        for i in (0..SIX_U_PLUS_2_NAF.len() - 1).rev() {
+            if i != SIX_U_PLUS_2_NAF.len() - 2 {
+                f.square();
+            }
+
+            for &mut (p, ref mut coeffs) in &mut pairs {
+                ell(&mut f, coeffs.next().unwrap(), &p.0);
+            }
+
+            if SIX_U_PLUS_2_NAF[i] != 0 {
+                for &mut (p, ref mut coeffs) in &mut pairs {
+                    ell(&mut f, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        // Lines through pi(Q) and -pi^2(Q)
+        for &mut (p, ref mut coeffs) in &mut pairs {
+            ell(&mut f, coeffs.next().unwrap(), &p.0);
+        }
+
+        for &mut (p, ref mut coeffs) in &mut pairs {
+            ell(&mut f, coeffs.next().unwrap(), &p.0);
+        }
+
+        f
+    }
+
+    fn final_exponentiation(r: &Fq12) -> Option<Fq12> {
+        let mut f1 = *r;
+        f1.conjugate();
+
+        match r.inverse() {
+            Some(mut f2) => {
+                // Easy part: r^((q^6 - 1) * (q^2 + 1))
+                let mut r = f1;
+                r.mul_assign(&f2);
+                f2 = r;
+                r.frobenius_map(2);
+                r.mul_assign(&f2);
+
+                fn exp_by_x(f: &Fq12) -> Fq12 {
+                    f.pow([BN_U])
+                }
+
+                // Hard part: r^((q^4 - q^2 + 1) / r), following Scott et al.,
+                // "On the final exponentiation for calculating pairings on
+                // ordinary elliptic curves", https://eprint.iacr.org/2008/490
+                let mut fp = r;
+                fp.frobenius_map(1);
+                let mut fp2 = r;
+                fp2.frobenius_map(2);
+                let mut fp3 = fp2;
+                fp3.frobenius_map(1);
+
+                let fu = exp_by_x(&r);
+                let fu2 = exp_by_x(&fu);
+                let fu3 = exp_by_x(&fu2);
+
+                let mut y3 = fu;
+                y3.frobenius_map(1);
+                let mut fu2p = fu2;
+                fu2p.frobenius_map(1);
+                let mut fu3p = fu3;
+                fu3p.frobenius_map(1);
+                let mut y2 = fu2;
+                y2.frobenius_map(2);
+
+                let mut y0 = fp;
+                y0.mul_assign(&fp2);
+                y0.mul_assign(&fp3);
+
+                let mut y1 = r;
+                y1.conjugate();
+                let mut y5 = fu2;
+                y5.conjugate();
+                y3.conjugate();
+                let mut y4 = fu;
+                y4.mul_assign(&fu2p);
+                y4.conjugate();
+                let mut y6 = fu3;
+                y6.mul_assign(&fu3p);
+                y6.conjugate();
+
+                let mut t0 = y6;
+                t0.square();
+                t0.mul_assign(&y4);
+                t0.mul_assign(&y5);
+                let mut t1 = y3;
+                t1.mul_assign(&y5);
+                t1.mul_assign(&t0);
+                t0.mul_assign(&y2);
+                t1.square();
+                t1.mul_assign(&t0);
+                t1.square();
+                t0 = t1;
+                t0.mul_assign(&y1);
+                t1.mul_assign(&y0);
+                t0.square();
+                t0.mul_assign(&t1);
+
+                Some(t0)
+            }
+            None => None,
+        }
+    }
+}
+
+impl G2Prepared {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine) -> Self {
+        if q.is_zero() {
+            return G2Prepared {
+                coeffs: vec![],
+                infinity: true,
+            };
+        }
+
+        fn doubling_step(r: &mut G2) -> (Fq2, Fq2, Fq2) {
+            // Adaptation of Algorithm 26, https://eprint.iacr.org/2010/354.pdf
+            let mut tmp0 = r.x;
+            tmp0.square();
+
+            let mut tmp1 = r.y;
+            tmp1.square();
+
+            let mut tmp2 = tmp1;
+            tmp2.square();
+
+            let mut tmp3 = tmp1;
+            tmp3.add_assign(&r.x);
+            tmp3.square();
+            tmp3.sub_assign(&tmp0);
+            tmp3.sub_assign(&tmp2);
+            tmp3.double();
+
+            let mut tmp4 = tmp0;
+            tmp4.double();
+            tmp4.add_assign(&tmp0);
+
+            let mut tmp6 = r.x;
+            tmp6.add_assign(&tmp4);
+
+            let mut tmp5 = tmp4;
+            tmp5.square();
+
+            let mut zsquared = r.z;
+            zsquared.square();
+
+            r.x = tmp5;
+            r.x.sub_assign(&tmp3);
+            r.x.sub_assign(&tmp3);
+
+            r.z.add_assign(&r.y);
+            r.z.square();
+            r.z.sub_assign(&tmp1);
+            r.z.sub_assign(&zsquared);
+
+            r.y = tmp3;
+            r.y.sub_assign(&r.x);
+            r.y.mul_assign(&tmp4);
+
+            tmp2.double();
+            tmp2.double();
+            tmp2.double();
+
+            r.y.sub_assign(&tmp2);
+
+            tmp3 = tmp4;
+            tmp3.mul_assign(&zsquared);
+            tmp3.double();
+            tmp3.negate();
+
+            tmp6.square();
+            tmp6.sub_assign(&tmp0);
+            tmp6.sub_assign(&tmp5);
+
+            tmp1.double();
+            tmp1.double();
+
+            tmp6.sub_assign(&tmp1);
+
+            tmp0 = r.z;
+            tmp0.mul_assign(&zsquared);
+            tmp0.double();
+
+            (tmp0, tmp3, tmp6)
+        }
+
+        fn addition_step(r: &mut G2, q: &G2Affine) -> (Fq2, Fq2, Fq2) {
+            // Adaptation of Algorithm 27, https://eprint.iacr.org/2010/354.pdf
+            let mut zsquared = r.z;
+            zsquared.square();
+
+            let mut ysquared = q.y;
+            ysquared.square();
+
+            let mut t0 = zsquared;
+            t0.mul_assign(&q.x);
+
+            let mut t1 = q.y;
+            t1.add_assign(&r.z);
+            t1.square();
+            t1.sub_assign(&ysquared);
+            t1.sub_assign(&zsquared);
+            t1.mul_assign(&zsquared);
+
+            let mut t2 = t0;
+            t2.sub_assign(&r.x);
+
+            let mut t3 = t2;
+            t3.square();
+
+            let mut t4 = t3;
+            t4.double();
+            t4.double();
+
+            let mut t5 = t4;
+            t5.mul_assign(&t2);
+
+            let mut t6 = t1;
+            t6.sub_assign(&r.y);
+            t6.sub_assign(&r.y);
+
+            let mut t9 = t6;
+            t9.mul_assign(&q.x);
+
+            let mut t7 = t4;
+            t7.mul_assign(&r.x);
+
+            r.x = t6;
+            r.x.square();
+            r.x.sub_assign(&t5);
+            r.x.sub_assign(&t7);
+            r.x.sub_assign(&t7);
+
+            r.z.add_assign(&t2);
+            r.z.square();
+            r.z.sub_assign(&zsquared);
+            r.z.sub_assign(&t3);
+
+            let mut t10 = q.y;
+            t10.add_assign(&r.z);
+
+            let mut t8 = t7;
+            t8.sub_assign(&r.x);
+            t8.mul_assign(&t6);
+
+            t0 = r.y;
+            t0.mul_assign(&t5);
+            t0.double();
+
+            r.y = t8;
+            r.y.sub_assign(&t0);
+
+            t10.square();
+            t10.sub_assign(&ysquared);
+
+            let mut ztsquared = r.z;
+            ztsquared.square();
+
+            t10.sub_assign(&ztsquared);
+
+            t9.double();
+            t9.sub_assign(&t10);
+
+            t10 = r.z;
+            t10.double();
+
+            t6.negate();
+
+            t1 = t6;
+            t1.double();
+
+            (t10, t1, t9)
+        }
+
+        let mut coeffs = vec![];
+        let mut r: G2 = q.into();
+        let mut negq = q;
+        negq.negate();
+
+        for i in (0..SIX_U_PLUS_2_NAF.len() - 1).rev() {
+            coeffs.push(doubling_step(&mut r));
+
+            match SIX_U_PLUS_2_NAF[i] {
+                1 => coeffs.push(addition_step(&mut r, &q)),
+                -1 => coeffs.push(addition_step(&mut r, &negq)),
+                _ => (),
+            }
+        }
+
+        // q1 = pi(q) = (x^p * xi^((p - 1) / 3), y^p * xi^((p - 1) / 2))
+        let mut q1 = q;
+        q1.x.frobenius_map(1);
+        q1.x.mul_assign(&FROBENIUS_COEFF_FQ6_C1[1]);
+        q1.y.frobenius_map(1);
+        q1.y.mul_assign(&XI_TO_Q_MINUS_1_OVER_2);
+        coeffs.push(addition_step(&mut r, &q1));
+
+        // q2 = -pi^2(q) = (x * xi^((p^2 - 1) / 3), y), as xi^((p^2 - 1) / 2) = -1
+        let mut q2 = q;
+        q2.x.mul_assign(&FROBENIUS_COEFF_FQ6_C1[2]);
+        coeffs.push(addition_step(&mut r, &q2));
+
+        G2Prepared {
+            coeffs,
+            infinity: false,
+        }
+    }
+}
+
+#[test]
+fn bn256_engine_tests() {
+    crate::tests::engine::engine_tests::<Bn256>();
+}
+
+#[test]
+fn test_six_u_plus_2_naf() {
+    let mut v = 0i128;
+    for d in SIX_U_PLUS_2_NAF.iter().rev() {
+        v = 2 * v + *d as i128;
+    }
+    assert_eq!(v, 6 * BN_U as i128 + 2);
+}
+
+#[test]
+fn test_final_exponentiation() {
+    use ff::PrimeField;
+    use rand_core::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // (q^4 - q^2 + 1) / r
+    let hard_part = [
+        0xe81bb482ccdf42b1,
+        0x5abf5cc4f49c36d4,
+        0xf1154e7e1da014fd,
+        0xdcc7b44c87cdbacf,
+        0xaaa441e3954bcf8a,
+        0x6b887d56d5095f23,
+        0x79581e16f3fd90c6,
+        0x3b1b1355d189227d,
+        0x4e529a5861876f6b,
+        0x6c0eb522d5b12278,
+        0x331ec15183177faf,
+        0x1baaa710b0759ad,
+    ];
+
+    for _ in 0..5 {
+        let f = Fq12::random(&mut rng);
+
+        // f^((q^6 - 1) * (q^2 + 1))
+        let mut easy = f;
+        easy.conjugate();
+        easy.mul_assign(&f.inverse().unwrap());
+        let mut tmp = easy;
+        tmp.frobenius_map(2);
+        easy.mul_assign(&tmp);
+
+        let expected = easy.pow(hard_part);
+        let result = Bn256::final_exponentiation(&f).unwrap();
+        assert_eq!(result, expected);
+        assert_eq!(result.pow(Fr::char()), Fq12::one());
+    }
+
+    assert!(Bn256::final_exponentiation(&Fq12::zero()).is_none());
+}
+
+/// The ecPairing precompile of EIP-197: the input is a sequence of
+/// (G1, G2) pairs in the Ethereum encoding, and the output is whether the
+/// product of their pairings is one.
+#[cfg(test)]
+fn ec_pairing(input: &[u8]) -> Result<bool, crate::Error> {
+    use crate::EncodedPoint;
+
+    assert_eq!(input.len() % 192, 0);
+    let mut pairs = vec![];
+    for chunk in input.chunks(192) {
+        // Ethereum encodes the point at infinity as all zeroes
+        let p = if chunk[..64].iter().all(|b| *b == 0) {
+            G1Affine::zero()
+        } else {
+            let mut enc = G1Uncompressed::empty();
+            enc.as_mut().copy_from_slice(&chunk[..64]);
+            enc.into_affine()?
+        };
+        let q = if chunk[64..].iter().all(|b| *b == 0) {
+            G2Affine::zero()
+        } else {
+            let mut enc = G2Uncompressed::empty();
+            enc.as_mut().copy_from_slice(&chunk[64..]);
+            enc.into_affine()?
+        };
+        pairs.push((p.prepare(), q.prepare()));
+    }
+    let refs: Vec<_> = pairs.iter().map(|(p, q)| (p, q)).collect();
+    let f = Bn256::final_exponentiation(&Bn256::miller_loop(refs.iter()));
+    Ok(f.unwrap() == Fq12::one())
+}
+
+#[test]
+fn test_eip197_pairing() {
+    // G1 and G2 generators, and -G1, as given in EIP-197
+    const G1_GEN: &str = "0000000000000000000000000000000000000000000000000000000000000001\
+                          0000000000000000000000000000000000000000000000000000000000000002";
+    const G1_NEG: &str = "0000000000000000000000000000000000000000000000000000000000000001\
+                          30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd45";
+    const G2_GEN: &str = "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+                          1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+                          090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+                          12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa";
+    // go-ethereum's "jeff1" ecPairing vector
+    const JEFF1: &str = "1c76476f4def4bb94541d57ebba1193381ffa7aa76ada664dd31c16024c43f59\
+                         3034dd2920f673e204fee2811c678745fc819b55d3e9d294e45c9b03a76aef41\
+                         209dd15ebff5d46c4bd888e51a93cf99a7329636c63514396b4a452003a35bf7\
+                         04bf11ca01483bfa8b34b43561848d28905960114c8ac04049af4b6315a41678\
+                         2bb8324af6cfc93537a2ad1a445cfd0ca2a71acd7ac41fadbf933c2a51be344d\
+                         120a2a4cf30c1bf9845f20c6fe39e07ea2cce61f0c9bb048165fe5e4de877550\
+                         111e129f1cf1097710d41c4ac70fcdfa5ba2023c6ff1cbeac322de49d1b6df7c\
+                         2032c61a830e3c17286de9462bf242fca2883585b93870a73853face6a6bf411";
+
+    let pairing = |parts: &[&str]| ec_pairing(&hex::decode(parts.concat()).unwrap()).unwrap();
+    assert!(pairing(&[]));
+    assert!(pairing(&[JEFF1, G2_GEN]));
+    assert!(pairing(&[G1_GEN, G2_GEN, G1_NEG, G2_GEN]));
+    assert!(!pairing(&[G1_GEN, G2_GEN]));
+    assert!(!pairing(&[G1_GEN, G2_GEN, G1_GEN, G2_GEN]));
+    assert!(!pairing(&[&JEFF1[..128], G2_GEN, G1_GEN, G2_GEN]));
+
+    // the point at infinity on either side pairs to one
+    let zero = "00".repeat(64);
+    assert!(pairing(&[&zero, G2_GEN]));
+    assert!(pairing(&[G1_GEN, &"00".repeat(128)]));
+
+    // a point not on the curve is rejected
+    let mut bad = hex::decode([G1_GEN, G2_GEN].concat()).unwrap();
+    bad[63] = 3;
+    assert!(matches!(ec_pairing(&bad), Err(crate::Error::NotOnCurve)));
+}
+
+#[test]
+fn test_generator_encodings() {
+    use crate::{CurveProjective, EncodedPoint};
+
+    // uncompressed: the Ethereum encoding, with the imaginary parts first
+    let g1 = G1Affine::one();
+    assert_eq!(
+        hex::encode(G1Uncompressed::from_affine(g1).as_ref()),
+        "0000000000000000000000000000000000000000000000000000000000000001\
+         0000000000000000000000000000000000000000000000000000000000000002"
+    );
+    let g2 = G2Affine::one();
+    assert_eq!(
+        hex::encode(G2Uncompressed::from_affine(g2).as_ref()),
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+         1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+         090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+         12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"
+    );
+
+    // compressed: x with the gnark-crypto flags in the top two bits, 0b10
+    // for the smaller y and 0b11 for the larger; both generators have the
+    // smaller y (2, and an imaginary part below (p - 1) / 2)
+    let enc = G1Compressed::from_affine(g1);
+    assert_eq!(
+        hex::encode(enc.as_ref()),
+        "8000000000000000000000000000000000000000000000000000000000000001"
+    );
+    assert_eq!(enc.into_affine().unwrap(), g1);
+    let enc = G2Compressed::from_affine(g2);
+    assert_eq!(
+        hex::encode(enc.as_ref()),
+        "998e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+         1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"
+    );
+    assert_eq!(enc.into_affine().unwrap(), g2);
+
+    // the negated generator has the larger y
+    let mut neg = g1.into_projective();
+    neg.negate();
+    assert_eq!(
+        hex::encode(G1Compressed::from_affine(neg.into_affine()).as_ref()),
+        "c000000000000000000000000000000000000000000000000000000000000001"
+    );
+}
@@ -0,0 +1,56 @@
+/*!
+Constants for the Shallue-van de Woestijne map for G1
+*/
+
+use crate::{
+    bn256::{fq::B_COEFF, Fq, FqRepr, G1},
+    hash_to_curve::svdw::{svdw_help, SvdwConsts, SvdwMap},
+};
+use ff::Field;
+
+// c1 = g(Z) = 4 for Z = 1
+pub(super) const SVDW_C1: Fq = Fq(FqRepr([
+    0x115482203dbf392d,
+    0x926242126eaa626a,
+    0xe16a48076063c052,
+    0x7c5909386eddc93,
+]));
+
+// c2 = -Z / 2
+pub(super) const SVDW_C2: Fq = Fq(FqRepr([
+    0xb461a4448976f7d5,
+    0xc6843fb439555fa7,
+    0x28f0d12384840918,
+    0x112ceb58a394e07d,
+]));
+
+// c3 = sqrt(-g(Z) * 3 Z^2), with sgn0(c3) = 0
+pub(super) const SVDW_C3: Fq = Fq(FqRepr([
+    0x7c8487078735ab72,
+    0x51da7e0048bfb8d4,
+    0x945cfd183cbd7bf4,
+    0xb70b1ec48ae62c6,
+]));
+
+// c4 = -4 g(Z) / (3 Z^2)
+pub(super) const SVDW_C4: Fq = Fq(FqRepr([
+    0xa79a2bdca0800831,
+    0x19fd7617e49815a1,
+    0xbb8d0c885550c7b1,
+    0x5c4aeb6ec7e0f48,
+]));
+
+impl SvdwMap for G1 {
+    fn svdw_map(u: &Fq) -> G1 {
+        let consts = SvdwConsts {
+            b: B_COEFF,
+            z: Fq::one(),
+            c1: SVDW_C1,
+            c2: SVDW_C2,
+            c3: SVDW_C3,
+            c4: SVDW_C4,
+        };
+        let (x, y) = svdw_help(u, &consts);
+        G1 { x, y, z: Fq::one() }
+    }
+}
@@ -0,0 +1,87 @@
+/*!
+Constants for the Shallue-van de Woestijne map for G2
+*/
+
+use crate::{
+    bn256::{fq::B_COEFF_FQ2, Fq, Fq2, FqRepr, G2},
+    hash_to_curve::svdw::{svdw_help, SvdwConsts, SvdwMap},
+};
+use ff::Field;
+
+// c1 = g(Z) = 1 + 3 / (9 + u) for Z = 1
+pub(super) const SVDW_C1: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0xd335f05a64ca12fe,
+        0x75029bbec388940d,
+        0xd4d64ba9406d402e,
+        0x2baef80fc5ae772,
+    ])),
+    c1: Fq(FqRepr([
+        0x38e7ecccd1dcff67,
+        0x65f0b37d93ce0d3e,
+        0xd749d0dd22ac00aa,
+        0x141b9ce4a688d4d,
+    ])),
+};
+
+// c2 = -Z / 2
+pub(super) const SVDW_C2: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0xb461a4448976f7d5,
+        0xc6843fb439555fa7,
+        0x28f0d12384840918,
+        0x112ceb58a394e07d,
+    ])),
+    c1: Fq(FqRepr([0x0, 0x0, 0x0, 0x0])),
+};
+
+// c3 = sqrt(-g(Z) * 3 Z^2), with sgn0(c3) = 0
+pub(super) const SVDW_C3: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0xaaad0cab9a24277f,
+        0xf2209f5b7e5b757a,
+        0xc3a46b7e850013a7,
+        0x1f9e7f3768c5c9af,
+    ])),
+    c1: Fq(FqRepr([
+        0x412278c8de85d863,
+        0xfe3e4c7f559d375a,
+        0x5e44b9da0a96ad23,
+        0x297d818d387725c8,
+    ])),
+};
+
+// c4 = -4 g(Z) / (3 Z^2)
+pub(super) const SVDW_C4: Fq2 = Fq2 {
+    c0: Fq(FqRepr([
+        0x63cdc796b49b3a32,
+        0x73a8220d40eb16f6,
+        0xb46d1eed55c49000,
+        0x1c9ef4f5f0528b82,
+    ])),
+    c1: Fq(FqRepr([
+        0x9aeb505b1600fe13,
+        0x64eb25e9f8b4638f,
+        0x43edd9e4fdf1577a,
+        0x2eb756b528a63917,
+    ])),
+};
+
+impl SvdwMap for G2 {
+    fn svdw_map(u: &Fq2) -> G2 {
+        let consts = SvdwConsts {
+            b: B_COEFF_FQ2,
+            z: Fq2::one(),
+            c1: SVDW_C1,
+            c2: SVDW_C2,
+            c3: SVDW_C3,
+            c4: SVDW_C4,
+        };
+        let (x, y) = svdw_help(u, &consts);
+        G2 {
+            x,
+            y,
+            z: Fq2::one(),
+        }
+    }
+}
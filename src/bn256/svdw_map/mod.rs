@@ -0,0 +1,9 @@
+/*!
+Shallue-van de Woestijne maps for G1 and G2
+see: section 6.6.1 of https://www.rfc-editor.org/rfc/rfc9380
+*/
+
+mod g1;
+mod g2;
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,215 @@
+/*!
+Tests for the Shallue-van de Woestijne map
+*/
+
+use crate::{
+    bn256::{
+        fq::{B_COEFF, B_COEFF_FQ2},
+        Fq, Fq2, G1, G2,
+    },
+    hash_to_curve::{
+        svdw::{eval_g, SvdwMap},
+        HashToCurve,
+    },
+    signum::{Sgn0Result, Signum0},
+    CurveAffine, CurveProjective, EncodedPoint,
+};
+use ff::{Field, PrimeField, SqrtField};
+use rand_core::SeedableRng;
+
+/// check the map constants against their definitions, for Z = 1
+fn check_consts<F: SqrtField + Signum0>(b: &F, c1: &F, c2: &F, c3: &F, c4: &F) {
+    let gz = eval_g(&F::one(), b);
+    assert_eq!(*c1, gz);
+
+    // 2 * c2 = -Z
+    let mut tmp = *c2;
+    tmp.double();
+    tmp.add_assign(&F::one());
+    assert!(tmp.is_zero());
+
+    // c3^2 = -3 g(Z), sgn0(c3) = 0
+    let mut m3gz = gz;
+    m3gz.double();
+    m3gz.add_assign(&gz);
+    m3gz.negate();
+    let mut tmp = *c3;
+    tmp.square();
+    assert_eq!(tmp, m3gz);
+    assert_eq!(c3.sgn0(), Sgn0Result::NonNegative);
+
+    // 3 * c4 = -4 g(Z)
+    let mut tmp = *c4;
+    tmp.double();
+    tmp.add_assign(c4);
+    let mut m4gz = gz;
+    m4gz.double();
+    m4gz.double();
+    m4gz.negate();
+    assert_eq!(tmp, m4gz);
+}
+
+#[test]
+fn test_svdw_consts() {
+    {
+        use super::g1::{SVDW_C1, SVDW_C2, SVDW_C3, SVDW_C4};
+        check_consts(&B_COEFF, &SVDW_C1, &SVDW_C2, &SVDW_C3, &SVDW_C4);
+        assert_eq!(SVDW_C1, Fq::from_str("4").unwrap());
+    }
+    {
+        use super::g2::{SVDW_C1, SVDW_C2, SVDW_C3, SVDW_C4};
+        check_consts(&B_COEFF_FQ2, &SVDW_C1, &SVDW_C2, &SVDW_C3, &SVDW_C4);
+    }
+}
+
+#[test]
+fn test_svdw_g1() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // exceptional case: zero
+    let p = G1::svdw_map(&Fq::zero()).into_affine();
+    assert!(!p.is_zero());
+    assert!(p.into_uncompressed().into_affine().is_ok());
+
+    for _ in 0..1000 {
+        let u = Fq::random(&mut rng);
+        let p = G1::svdw_map(&u).into_affine();
+        let (_, y) = p.as_tuple();
+        assert_eq!(u.sgn0(), y.sgn0());
+        assert!(p.into_uncompressed().into_affine().is_ok());
+    }
+}
+
+#[test]
+fn test_svdw_g2() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // exceptional case: zero
+    let p = G2::svdw_map(&Fq2::zero()).into_affine();
+    assert!(!p.is_zero());
+    assert!(p.into_uncompressed().into_affine_unchecked().is_ok());
+
+    for _ in 0..100 {
+        let u = Fq2::random(&mut rng);
+        let p = G2::svdw_map(&u).into_affine();
+        let (x, y) = p.as_tuple();
+        assert_eq!(u.sgn0(), y.sgn0());
+        let mut y2 = *y;
+        y2.square();
+        assert_eq!(y2, eval_g(x, &B_COEFF_FQ2));
+    }
+}
+
+#[test]
+fn test_hash_to_curve_bn256() {
+    use crate::{hash_to_field::ExpandMsgXmd, SubgroupCheck};
+    use sha2::Sha256;
+
+    const DST: &[u8] = b"pairing-plus-bn256-test";
+    for msg in &[&b""[..], b"abc", b"abcdef0123456789"] {
+        let p = <G1 as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(msg, DST).into_affine();
+        assert!(!p.is_zero() && p.in_subgroup());
+        let q = <G1 as HashToCurve<ExpandMsgXmd<Sha256>>>::encode_to_curve(msg, DST).into_affine();
+        assert!(!q.is_zero() && q.in_subgroup());
+        assert_ne!(p, q);
+
+        let p = <G2 as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(msg, DST).into_affine();
+        assert!(!p.is_zero() && p.in_subgroup());
+        let q = <G2 as HashToCurve<ExpandMsgXmd<Sha256>>>::encode_to_curve(msg, DST).into_affine();
+        assert!(!q.is_zero() && q.in_subgroup());
+        assert_ne!(p, q);
+    }
+}
+
+/// Known-answer tests in the format of appendix J of RFC 9380, which has no
+/// BN254 suites. The vectors were computed with an independent
+/// implementation of sections 5.2, 6.6.1 and 7 of the RFC, with Z = 1 for
+/// both G1 and G2 as chosen by find_z_svdw in appendix H.1, and with
+/// clear_cofactor for G2 the multiplication by h2 = 2p - r. Points are in
+/// the uncompressed encoding of this crate.
+type Xmd = crate::hash_to_field::ExpandMsgXmd<sha2::Sha256>;
+
+fn check_vectors<P: CurveProjective>(map: impl Fn(&[u8]) -> P, tests: &[(&[u8], &str)]) {
+    for (msg, p) in tests {
+        let a = map(msg).into_affine().into_uncompressed();
+        assert_eq!(hex::encode(a.as_ref()), *p);
+    }
+}
+
+#[test]
+fn hash_to_curve_g1_ro() {
+    //suite   = BN254G1_XMD:SHA-256_SVDW_RO_
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_RO_";
+    let tests: [(&[u8], &str); 5] = [
+        (b"", "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e8602925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5"),
+        (b"abc", "23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d104142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d"),
+        (b"abcdef0123456789", "187dbf1c3c89aceceef254d6548d7163fdfa43084145f92c4c91c85c21442d4a0abd99d5b0000910b56058f9cc3b0ab0a22d47cf27615f588924fac1e5c63b4d"),
+        (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", "00fe2b0743575324fc452d590d217390ad48e5a16cf051bee5c40a2eba233f5c0794211e0cc72d3cbbdf8e4e5cd6e7d7e78d101ff94862caae8acbe63e9fdc78"),
+        (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "01b05dc540bd79fd0fea4fbb07de08e94fc2e7bd171fe025c479dc212a2173ce1bf028afc00c0f843d113758968f580640541728cfc6d32ced9779aa613cd9b0"),
+    ];
+
+    check_vectors(
+        |msg| <G1 as HashToCurve<Xmd>>::hash_to_curve(msg, DST),
+        &tests,
+    );
+}
+
+#[test]
+fn encode_to_curve_g1() {
+    //suite   = BN254G1_XMD:SHA-256_SVDW_NU_
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BN254G1_XMD:SHA-256_SVDW_NU_";
+    let tests: [(&[u8], &str); 5] = [
+        (b"", "1bb8810e2ceaf04786d4efd216fc2820ddd9363712efc736ada11049d8af59251efbf8d54c60d865cce08437668ea30f5bf90d287dbd9b5af31da852915e8f11"),
+        (b"abc", "0da4a96147df1f35b0f820bd35c6fac3b80e8e320de7c536b1e054667b22c332189bd3fbffe4c8740d6543754d95c790e44cd2d162858e3b733d2b8387983bb7"),
+        (b"abcdef0123456789", "2ff727cfaaadb3acab713fa22d91f5fddab3ed77948f3ef6233d7ea9b03f4da1304080768fd2f87a852155b727f97db84b191e41970506f0326ed4046d1141aa"),
+        (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", "11a2eaa8e3e89de056d1b3a288a7f733c8a1282efa41d28e71af065ab245df9b060f37c447ac29fd97b9bb83be98ddccf15e34831a9cdf5493b7fede0777ae06"),
+        (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "27409dccc6ee4ce90e24744fda8d72c0bc64e79766f778da0c1c0ef1c186ea841ac201a542feca15e77f30370da183514dc99d8a0b2c136d64ede35cd0b51dc0"),
+    ];
+
+    check_vectors(
+        |msg| <G1 as HashToCurve<Xmd>>::encode_to_curve(msg, DST),
+        &tests,
+    );
+}
+
+#[test]
+fn hash_to_curve_g2_ro() {
+    //suite   = BN254G2_XMD:SHA-256_SVDW_RO_
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_RO_";
+    let tests: [(&[u8], &str); 5] = [
+        (b"", "303dbd430a583c946596158ce500a5ff37babc6dd1ed482aca4daa881581480c22cef87c4dd45a4cc4d32df4295ba3c3e488bd331b07b6b2514b25cf5aeb7cf32eca508abbba76b69a78f7b8c2d22b03403b216a091195a834b64bc512099e1227054759d88e6b9a0b3419858be3b27c3a3d53d21f744e73356a5c41b9a4e815"),
+        (b"abc", "0ab016609756d6c217d6c0e41ba9b9202b82ef8f1bb86ec51bc4c02a3c8acbbf28f105b439abd57dfdd29c4818df5e8ed9b0f67296e5cdd178864ca6e75c36ce0efbfebf454feaa177a5c97b70b665e7b239f5b63599bc225848321ed059e01018bafc8d9cae1eff18aebc4da5803046da89ff3e30c5214618ec396878299d43"),
+        (b"abcdef0123456789", "04093f51639abb1a950efa0a3d8110eb058e2b435f8b38337238c80bfa1d425c12924ebd8d45c1727fb601a61894f478fac2d78e293da1ca1fc25cff1c763636246eee1350d177976a2d875825f5b0e98162b273bf91464bbf74ee8811077d190d1081e649cc986f40f17bb83ce4421a7532ef0540dd0d62fc646ee6654771a8"),
+        (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", "12adc90aaaaaf052d112ecff92d2e3d5c897dbc915abea57795f530bbb6d0f3816d5821c956ea86324a5b8929ea326a77a21a1256e059d300381b2e2900297bb295b0e24678d2d9daaa6584ee396c7c7fc8847a6917dc72c9badb2a202ff48b9055e8e23b05d3fde2ba3d756ffaf7f8db8acdef6c1d6a33a4d076626f6a298cb"),
+        (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "163906f1d12684777536d41910f9215115580892fcfe5ce9593b903aea5cd9ff1049fab5d82d83d39859068328736fd6f9b1c347492daba317f94e860fb5e0611c5d51875da80f7e528ed56778e2166c5362e6bde6506229ab7b0f8cc41c74ac20599687d10e0dd83cfaab701fef29371a9cf8c2c8abfb4b66e8ec4ceadc16c2"),
+    ];
+
+    check_vectors(
+        |msg| <G2 as HashToCurve<Xmd>>::hash_to_curve(msg, DST),
+        &tests,
+    );
+}
+
+#[test]
+fn encode_to_curve_g2() {
+    //suite   = BN254G2_XMD:SHA-256_SVDW_NU_
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BN254G2_XMD:SHA-256_SVDW_NU_";
+    let tests: [(&[u8], &str); 5] = [
+        (b"", "1af7b3481cf420dc30db9dc5bae1e12a8972f20903dc6d432d3e4a70f289b182091555af253ad127f1f474779c6abe2e83c8a24292e495094121a254bc6f669400a446ffa95ab4f2e496d12ef6dab5ddc87b199eac57d86f4bc0cef5408543210a65f3ab04e79d5cc399329279411ad8ecf04c2238aa94769a926179be3324fa"),
+        (b"abc", "304a70be20ee6ebfe9e4f45fe5df3770a76e573dc410a22f2b86b68eb1ef794c0aabad1bd3500488d6b75fe6da07ce115858428e09b28c6db5a8aa1e2e9431e42f79654c3a1252870820bc3fd597f6555aead1429cce74bcbbad1a582adb19ec0c4a7788647bb8ec8f9d718d9940dfeebfde4d17be7548782a5bf30beb6fb423"),
+        (b"abcdef0123456789", "2766f65f530ece1f7384575b62e53f53065b27ecb1f7ec552f160dde80cac6032b8b82c9fe8e1739511a2b0071b3233f3f5a521b448acb30a2008aa01a64b14f0c26cf10356e070ff88f77df3da6aa170bff2e0361390bbe73bee5c3445492cc01f7ac0b42b4f4ae80ab03916be368265dd5353d2ed8f5e42370322b11081c93"),
+        (b"q128_qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq", "0d2ddcf87519a2cf53741be4bf0e715d8ee2cd479f24dfc3ec1ce87409c4e1152baffc29690cef13c688204d64f969c8fe3988dcce457f3cef2897f5a1417ea31775cdd38b040019a2fcb363984d5f310e30db98d63306ad67f87f6c367611840b757201d963cd7d4d1e59269125242b2453104adcd10bf5b916b85d965d69cd"),
+        (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "1915eb90dfeefdc2411eabe413e1d7f9f85eba666167e3f3815f8661322cd3dd055f07309464d2ce581eab7f0b0d64d837b1fd7d4f558c9ab2039c13346452122302dc834e57e095a9393c246051be1c7232e6923f91f990ca08bb7134c6de6e278e8ed521794197564d302b67eb2d55de1e54bb04068b33437061062b175a75"),
+    ];
+
+    check_vectors(
+        |msg| <G2 as HashToCurve<Xmd>>::encode_to_curve(msg, DST),
+        &tests,
+    );
+}
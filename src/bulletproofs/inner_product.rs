@@ -0,0 +1,342 @@
+/*!
+The Bulletproofs inner-product argument.
+
+The prover knows `a` and `b` of length `n`, a power of two, with
+
+```text
+P = <a, G> + <b, H> + <a, b> * Q
+```
+
+and halves the vectors in each of the `log2(n)` rounds. With `a_lo, a_hi`
+the halves of `a`, and so on, a round sends
+
+```text
+L = <a_lo, G_hi> + <b_hi, H_lo> + <a_lo, b_hi> * Q
+R = <a_hi, G_lo> + <b_lo, H_hi> + <a_hi, b_lo> * Q
+```
+
+draws the challenge `u`, and continues with
+
+```text
+a = u * a_lo + 1/u * a_hi        G = 1/u * G_lo + u * G_hi
+b = 1/u * b_lo + u * b_hi        H = u * H_lo + 1/u * H_hi
+```
+
+which satisfy the relation for `P + u^2 * L + 1/u^2 * R`. The proof ends
+with the scalars `a` and `b` of length 1. The verifier unrolls the folding:
+the final `G` is `<s, G>` with `s_i` the product of `u_j` or `1/u_j` over the
+rounds `j`, depending on bit `j` of `i` counted from the top, and the final
+`H` is `<s', H>` with `s'_i = 1 / s_i = s_(n - 1 - i)`.
+
+`prove` and `verify` append `n`, `Q` and `P` to the transcript before the
+first round, so the challenges depend on the statement. A protocol whose
+transcript already determines `Q` and `P`, such as the range proof, can use
+`prove_unbound` and `verification_scalars`, which append only `n`.
+*/
+
+use super::dot;
+use crate::{
+    bls12_381::{Fr, G1Affine, G1},
+    pedersen::sum_of_products_pippinger,
+    transcript::Transcript,
+    CurveAffine, CurveProjective,
+};
+use alloc::vec::Vec;
+use ff::Field;
+
+/// An inner-product argument: the points `L` and `R` of every round, and
+/// the final scalars.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InnerProductProof {
+    pub l: Vec<G1>,
+    pub r: Vec<G1>,
+    pub a: Fr,
+    pub b: Fr,
+}
+
+/// The scalars with which the verifier unrolls a proof: `u_j^2` and
+/// `1/u_j^2` for each round, and `s`.
+pub(crate) struct VerificationScalars {
+    pub(crate) u_sq: Vec<Fr>,
+    pub(crate) u_inv_sq: Vec<Fr>,
+    pub(crate) s: Vec<Fr>,
+}
+
+impl InnerProductProof {
+    /// Proves knowledge of `a` and `b` with
+    /// `P = <a, G> + <b, H> + <a, b> * Q`.
+    ///
+    /// Panics if the lengths of `g`, `h`, `a` and `b` differ or are not a
+    /// power of two.
+    pub fn prove(
+        transcript: &mut Transcript,
+        q: &G1Affine,
+        p: &G1,
+        g: Vec<G1Affine>,
+        h: Vec<G1Affine>,
+        a: Vec<Fr>,
+        b: Vec<Fr>,
+    ) -> Self {
+        append_statement(transcript, q, p);
+        Self::prove_unbound(transcript, q, g, h, a, b)
+    }
+
+    /// `prove` without appending `Q` and `P`, for a transcript that already
+    /// determines them.
+    pub(crate) fn prove_unbound(
+        transcript: &mut Transcript,
+        q: &G1Affine,
+        mut g: Vec<G1Affine>,
+        mut h: Vec<G1Affine>,
+        mut a: Vec<Fr>,
+        mut b: Vec<Fr>,
+    ) -> Self {
+        let mut n = g.len();
+        assert!(n.is_power_of_two(), "length {} is not a power of two", n);
+        assert!(h.len() == n && a.len() == n && b.len() == n);
+        transcript.append_u64(b"n", n as u64);
+
+        let mut l_vec = vec![];
+        let mut r_vec = vec![];
+        while n > 1 {
+            n /= 2;
+            let (a_lo, a_hi) = a.split_at(n);
+            let (b_lo, b_hi) = b.split_at(n);
+            let (g_lo, g_hi) = g.split_at(n);
+            let (h_lo, h_hi) = h.split_at(n);
+
+            let c_l = dot(a_lo, b_hi);
+            let c_r = dot(a_hi, b_lo);
+            let l = sum_of_products_pippinger(
+                &[g_hi, h_lo, &[*q]].concat(),
+                &[a_lo, b_hi, &[c_l]].concat(),
+            );
+            let r = sum_of_products_pippinger(
+                &[g_lo, h_hi, &[*q]].concat(),
+                &[a_hi, b_lo, &[c_r]].concat(),
+            );
+            transcript.append_g1(b"L", &l.into_affine());
+            transcript.append_g1(b"R", &r.into_affine());
+            l_vec.push(l);
+            r_vec.push(r);
+
+            let u = transcript.challenge_scalar(b"u");
+            let u_inv = u.inverse().expect("challenge is zero");
+            let fold = |lo: &[Fr], hi: &[Fr], x: &Fr, y: &Fr| -> Vec<Fr> {
+                lo.iter()
+                    .zip(hi.iter())
+                    .map(|(lo, hi)| {
+                        let mut t = *lo;
+                        t.mul_assign(x);
+                        let mut s = *hi;
+                        s.mul_assign(y);
+                        t.add_assign(&s);
+                        t
+                    })
+                    .collect()
+            };
+            let fold_points = |lo: &[G1Affine], hi: &[G1Affine], x: &Fr, y: &Fr| {
+                let mut points: Vec<G1> = lo
+                    .iter()
+                    .zip(hi.iter())
+                    .map(|(lo, hi)| {
+                        let mut t = lo.mul(*x);
+                        t.add_assign(&hi.mul(*y));
+                        t
+                    })
+                    .collect();
+                G1::batch_normalization(&mut points);
+                points.iter().map(|p| p.into_affine()).collect::<Vec<_>>()
+            };
+            let new_a = fold(a_lo, a_hi, &u, &u_inv);
+            let new_b = fold(b_lo, b_hi, &u_inv, &u);
+            let new_g = fold_points(g_lo, g_hi, &u_inv, &u);
+            let new_h = fold_points(h_lo, h_hi, &u, &u_inv);
+            a = new_a;
+            b = new_b;
+            g = new_g;
+            h = new_h;
+        }
+
+        InnerProductProof {
+            l: l_vec,
+            r: r_vec,
+            a: a[0],
+            b: b[0],
+        }
+    }
+
+    /// Verifies a proof for `P` under the generators `g`, `h` and `q`.
+    pub fn verify(
+        &self,
+        transcript: &mut Transcript,
+        q: &G1Affine,
+        g: &[G1Affine],
+        h: &[G1Affine],
+        p: &G1,
+    ) -> bool {
+        let n = g.len();
+        if h.len() != n {
+            return false;
+        }
+        append_statement(transcript, q, p);
+        let scalars = match self.verification_scalars(n, transcript) {
+            Some(scalars) => scalars,
+            None => return false,
+        };
+
+        // <a * s, G> + <b * s', H> + a * b * Q
+        //     - P - sum_j (u_j^2 * L_j + 1/u_j^2 * R_j) == 0
+        let mut bases = g.to_vec();
+        bases.extend_from_slice(h);
+        bases.push(*q);
+        bases.extend(self.l.iter().chain(self.r.iter()).map(|p| p.into_affine()));
+        bases.push(p.into_affine());
+
+        let mut coeffs: Vec<Fr> = scalars
+            .s
+            .iter()
+            .map(|s| {
+                let mut t = self.a;
+                t.mul_assign(s);
+                t
+            })
+            .collect();
+        coeffs.extend(scalars.s.iter().rev().map(|s| {
+            let mut t = self.b;
+            t.mul_assign(s);
+            t
+        }));
+        let mut ab = self.a;
+        ab.mul_assign(&self.b);
+        coeffs.push(ab);
+        coeffs.extend(scalars.u_sq.iter().chain(scalars.u_inv_sq.iter()).map(|u| {
+            let mut t = *u;
+            t.negate();
+            t
+        }));
+        let mut minus_one = Fr::one();
+        minus_one.negate();
+        coeffs.push(minus_one);
+
+        sum_of_products_pippinger(&bases, &coeffs).is_zero()
+    }
+
+    /// Replays the transcript of a proof for vectors of length `n` made with
+    /// `prove_unbound` and computes the scalars that unroll it. Returns
+    /// `None` if the proof does not have `log2(n)` rounds or a challenge is
+    /// zero.
+    pub(crate) fn verification_scalars(
+        &self,
+        n: usize,
+        transcript: &mut Transcript,
+    ) -> Option<VerificationScalars> {
+        if !n.is_power_of_two() {
+            return None;
+        }
+        let rounds = n.trailing_zeros() as usize;
+        if self.l.len() != rounds || self.r.len() != rounds {
+            return None;
+        }
+        transcript.append_u64(b"n", n as u64);
+
+        let mut u_sq = Vec::with_capacity(rounds);
+        let mut u_inv_sq = Vec::with_capacity(rounds);
+        let mut u_inv_prod = Fr::one();
+        for (l, r) in self.l.iter().zip(self.r.iter()) {
+            transcript.append_g1(b"L", &l.into_affine());
+            transcript.append_g1(b"R", &r.into_affine());
+            let u = transcript.challenge_scalar(b"u");
+            let u_inv = u.inverse()?;
+            u_inv_prod.mul_assign(&u_inv);
+            let mut sq = u;
+            sq.square();
+            u_sq.push(sq);
+            let mut inv_sq = u_inv;
+            inv_sq.square();
+            u_inv_sq.push(inv_sq);
+        }
+
+        // s_0 is the product of the 1/u_j, and setting bit k of i, which is
+        // folded in round rounds - 1 - k, multiplies s_i by u^2 of that round
+        let mut s = Vec::with_capacity(n);
+        s.push(u_inv_prod);
+        for i in 1..n {
+            let k = (usize::BITS - 1 - i.leading_zeros()) as usize;
+            let mut t = s[i - (1 << k)];
+            t.mul_assign(&u_sq[rounds - 1 - k]);
+            s.push(t);
+        }
+
+        Some(VerificationScalars { u_sq, u_inv_sq, s })
+    }
+}
+
+/// Appends `Q` and `P` to the transcript.
+fn append_statement(transcript: &mut Transcript, q: &G1Affine, p: &G1) {
+    transcript.append_g1(b"Q", q);
+    transcript.append_g1(b"P", &p.into_affine());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bulletproofs::Generators;
+    use crate::tests::test_rng;
+
+    #[test]
+    fn test_inner_product_proof() {
+        let mut rng = test_rng();
+        let gens = Generators::new(1);
+        let q = gens.pedersen.g[0];
+        for &n in [1, 2, 8, 64].iter() {
+            let (g, h) = (&gens.g[..n], &gens.h[..n]);
+            let a: Vec<Fr> = (0..n).map(|_| Fr::random(&mut rng)).collect();
+            let b: Vec<Fr> = (0..n).map(|_| Fr::random(&mut rng)).collect();
+            let c = dot(&a, &b);
+            let p =
+                sum_of_products_pippinger(&[g, h, &[q]].concat(), &[&a[..], &b[..], &[c]].concat());
+
+            let proof = InnerProductProof::prove(
+                &mut Transcript::new(b"test"),
+                &q,
+                &p,
+                g.to_vec(),
+                h.to_vec(),
+                a.clone(),
+                b.clone(),
+            );
+            assert_eq!(proof.l.len(), n.trailing_zeros() as usize);
+            assert!(proof.verify(&mut Transcript::new(b"test"), &q, g, h, &p));
+            assert!(!proof.verify(&mut Transcript::new(b"test"), &q, h, g, &p));
+            let mut wrong = p;
+            wrong.add_assign(&G1::one());
+            assert!(!proof.verify(&mut Transcript::new(b"test"), &q, g, h, &wrong));
+            let mut bad = proof.clone();
+            bad.a.add_assign(&Fr::one());
+            assert!(!bad.verify(&mut Transcript::new(b"test"), &q, g, h, &p));
+            if n > 1 {
+                // the challenges depend on the transcript, which binds the
+                // statement; with no rounds there are no challenges
+                assert!(!proof.verify(&mut Transcript::new(b"other"), &q, g, h, &p));
+                let unbound = InnerProductProof::prove_unbound(
+                    &mut Transcript::new(b"test"),
+                    &q,
+                    g.to_vec(),
+                    h.to_vec(),
+                    a.clone(),
+                    b.clone(),
+                );
+                assert_ne!(unbound, proof);
+                assert!(!unbound.verify(&mut Transcript::new(b"test"), &q, g, h, &p));
+                let mut bad = proof.clone();
+                bad.l[0].double();
+                assert!(!bad.verify(&mut Transcript::new(b"test"), &q, g, h, &p));
+                let mut bad = proof.clone();
+                bad.r.pop();
+                assert!(!bad.verify(&mut Transcript::new(b"test"), &q, g, h, &p));
+                assert!(!proof.verify(&mut Transcript::new(b"test"), &q, &g[1..], &h[1..], &p));
+            }
+        }
+    }
+}
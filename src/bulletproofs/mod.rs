@@ -0,0 +1,157 @@
+/*!
+Bulletproofs over BLS12-381 G1: an inner-product argument and aggregated
+64-bit range proofs, with no trusted setup.
+
+* `inner_product`: the logarithmic-size argument of knowledge of vectors `a`
+  and `b` with `P = <a, G> + <b, H> + <a, b> * Q`.
+* `range_proof`: a proof that Pedersen commitments `V_j = v_j * B + g_j *
+  B~` commit to values `v_j` in `[0, 2^64)`, for a power-of-two number of
+  values proven together. The proof has `2 * log2(64 * m) + 4` points and
+  5 scalars.
+
+All generators are hashed to G1 with `pedersen::Generators::with_dst`, so
+nobody knows discrete logarithm relations between them. The commitments
+`V_j` are `pedersen::Commitment`s under `Generators::pedersen`, whose `h` is
+`B~` and whose first value generator is `B`.
+
+The challenges are drawn from a `transcript::Transcript` that the caller
+passes in, so that a proof can be bound to the context it is used in. The
+verifier folds all of its checks into a single multi-scalar multiplication,
+computed with Pippinger's algorithm, with a random weight drawn from the
+transcript after the proof.
+*/
+
+pub mod inner_product;
+pub mod range_proof;
+
+use crate::{
+    bls12_381::{Fr, G1Affine},
+    pedersen,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::Field;
+
+/// The DST from which the generators are derived. The value commitment
+/// generators append "-V", and the vector generators "-G" and "-H".
+pub const BULLETPROOFS_DST: &[u8] = b"PAIRING-PLUS-BULLETPROOFS-V01";
+
+/// The number of bits of the values of a range proof.
+pub const RANGE_BITS: usize = 64;
+
+/// An error from proving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulletproofsError {
+    /// The number of values is not a power of two.
+    InvalidAggregation(usize),
+    /// There are more values than the generators can prove.
+    TooManyValues { capacity: usize, values: usize },
+    /// The numbers of values and of blindings differ.
+    BlindingCount { values: usize, blindings: usize },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BulletproofsError {}
+
+impl fmt::Display for BulletproofsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            BulletproofsError::InvalidAggregation(m) => {
+                write!(f, "cannot aggregate {} values, not a power of two", m)
+            }
+            BulletproofsError::TooManyValues { capacity, values } => write!(
+                f,
+                "cannot prove {} values with generators for {}",
+                values, capacity
+            ),
+            BulletproofsError::BlindingCount { values, blindings } => {
+                write!(f, "{} values but {} blindings", values, blindings)
+            }
+        }
+    }
+}
+
+/// The generators of range proofs of up to `capacity` values.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generators {
+    /// The value commitment generators: `h` is `B~`, and `g[0]` is `B`.
+    pub pedersen: pedersen::Generators,
+    /// The vector generators `G`, `RANGE_BITS * capacity` of them.
+    pub g: Vec<G1Affine>,
+    /// The vector generators `H`, `RANGE_BITS * capacity` of them.
+    pub h: Vec<G1Affine>,
+}
+
+impl Generators {
+    /// Derives the generators for range proofs of up to `capacity` values.
+    /// The first vector generators do not depend on `capacity`.
+    pub fn new(capacity: usize) -> Self {
+        let dst = |suffix: &[u8]| [BULLETPROOFS_DST, suffix].concat();
+        let count = RANGE_BITS * capacity;
+        Generators {
+            pedersen: pedersen::Generators::with_dst(&dst(b"-V"), 1),
+            g: pedersen::Generators::with_dst(&dst(b"-G"), count).g,
+            h: pedersen::Generators::with_dst(&dst(b"-H"), count).g,
+        }
+    }
+
+    /// The maximum number of values in a range proof.
+    pub fn capacity(&self) -> usize {
+        self.g.len() / RANGE_BITS
+    }
+}
+
+/// `[1, x, .., x^(n - 1)]`
+fn powers(x: &Fr, n: usize) -> Vec<Fr> {
+    let mut res = Vec::with_capacity(n);
+    let mut acc = Fr::one();
+    for _ in 0..n {
+        res.push(acc);
+        acc.mul_assign(x);
+    }
+    res
+}
+
+/// `<a, b>`
+fn dot(a: &[Fr], b: &[Fr]) -> Fr {
+    let mut res = Fr::zero();
+    for (x, y) in a.iter().zip(b.iter()) {
+        let mut t = *x;
+        t.mul_assign(y);
+        res.add_assign(&t);
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threshold::fr_from_u64;
+
+    #[test]
+    fn test_generators() {
+        let gens = Generators::new(2);
+        assert_eq!(gens.capacity(), 2);
+        assert_eq!(gens.g.len(), 128);
+        assert_eq!(gens.h.len(), 128);
+        assert_eq!(gens.pedersen.len(), 1);
+        assert_eq!(&Generators::new(4).g[..128], &gens.g[..]);
+        assert_ne!(gens.g[0], gens.h[0]);
+        assert_ne!(gens.g[0], gens.pedersen.g[0]);
+    }
+
+    #[test]
+    fn test_powers_dot() {
+        let two = fr_from_u64(2);
+        let p = powers(&two, 4);
+        assert_eq!(
+            p,
+            [1, 2, 4, 8]
+                .iter()
+                .map(|&x| fr_from_u64(x))
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(dot(&p, &p), fr_from_u64(85));
+        assert_eq!(dot(&[], &[]), Fr::zero());
+    }
+}
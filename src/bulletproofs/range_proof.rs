@@ -0,0 +1,522 @@
+/*!
+Aggregated range proofs of `m` values, each in `[0, 2^64)`, for `m` a power
+of two, with `n = 64` and `N = n * m`.
+
+The prover commits to the bits `a_L` of the values, concatenated, and to
+`a_R = a_L - 1`, then to blinding vectors `s_L` and `s_R`:
+
+```text
+A = alpha * B~ + <a_L, G> + <a_R, H>
+S = rho * B~ + <s_L, G> + <s_R, H>
+```
+
+With the challenges `y` and `z`, and `z_j = z^(2 + j)` for value `j`,
+
+```text
+l(X) = a_L - z * 1 + s_L * X
+r(X) = y^N o (a_R + z * 1 + s_R * X) + (z_0 * 2^n || .. || z_(m-1) * 2^n)
+t(X) = <l(X), r(X)> = t_0 + t_1 * X + t_2 * X^2
+t_0  = sum_j z_j * v_j + delta(y, z)
+delta(y, z) = (z - z^2) * <1, y^N> - sum_j z^(3 + j) * (2^n - 1)
+```
+
+and the prover commits to `t_1` and `t_2` in `T_1` and `T_2`. With the
+challenge `x`, it reveals `t^ = t(x)`, `tau_x`, the blinding of `t^` in
+`t^ * B + tau_x * B~ = sum_j z_j * V_j + delta * B + x * T_1 + x^2 * T_2`,
+and `mu = alpha + rho * x`. Finally, with `Q = w * B` for the challenge `w`
+and `H'_i = y^-i * H_i`, it proves with the inner-product argument that
+`l(x)` and `r(x)` open
+
+```text
+P = A + x * S - z * <1, G> + <z * y^N + (z_0 * 2^n || ..), H'>
+    - mu * B~ + t^ * Q
+```
+
+The verifier weights the `t^` equation with a challenge `c` and adds it to
+the unrolled inner-product equation, and checks both in one multi-scalar
+multiplication over `A, S, V_j, T_1, T_2, L_k, R_k, B~, B, G_i, H_i`.
+`Q` and `P` are determined by the transcript before the inner-product
+argument starts, so it runs unbound, without appending them again; the
+verifier never computes `P` on its own.
+*/
+
+use super::{
+    dot, inner_product::InnerProductProof, powers, BulletproofsError, Generators, RANGE_BITS,
+};
+use crate::{
+    bls12_381::{Fr, G1},
+    pedersen::{sum_of_products_pippinger, Commitment},
+    threshold::fr_from_u64,
+    transcript::Transcript,
+    CurveAffine, CurveProjective,
+};
+use alloc::vec::Vec;
+use ff::Field;
+use rand_core::RngCore;
+
+/// An aggregated range proof.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeProof {
+    pub a: G1,
+    pub s: G1,
+    pub t1: G1,
+    pub t2: G1,
+    pub t_hat: Fr,
+    pub tau_x: Fr,
+    pub mu: Fr,
+    pub ipp: InnerProductProof,
+}
+
+/// The challenges `y` and `z` and their powers.
+struct Challenges {
+    y: Fr,
+    z: Fr,
+    /// `y^i` for `i` in `0..N`
+    y_powers: Vec<Fr>,
+    /// `z^(2 + j)` for `j` in `0..m`
+    z_powers: Vec<Fr>,
+}
+
+impl RangeProof {
+    /// Proves that `values` are in range, and returns the proof with the
+    /// commitments `values[j] * B + blindings[j] * B~`.
+    pub fn prove<R: RngCore + ?Sized>(
+        gens: &Generators,
+        transcript: &mut Transcript,
+        values: &[u64],
+        blindings: &[Fr],
+        rng: &mut R,
+    ) -> Result<(RangeProof, Vec<Commitment>), BulletproofsError> {
+        let m = values.len();
+        if blindings.len() != m {
+            return Err(BulletproofsError::BlindingCount {
+                values: m,
+                blindings: blindings.len(),
+            });
+        }
+        check_aggregation(gens, m)?;
+        let n = m * RANGE_BITS;
+        let (g, h) = (&gens.g[..n], &gens.h[..n]);
+        let (b, b_blind) = (gens.pedersen.g[0], gens.pedersen.h);
+
+        let commitments: Vec<Commitment> = values
+            .iter()
+            .zip(blindings.iter())
+            .map(|(v, gamma)| {
+                Commitment(sum_of_products_pippinger(
+                    &[b, b_blind],
+                    &[fr_from_u64(*v), *gamma],
+                ))
+            })
+            .collect();
+        begin(transcript, &commitments);
+
+        // A and S
+        let mut a_l = Vec::with_capacity(n);
+        let mut a_r = Vec::with_capacity(n);
+        let mut minus_one = Fr::one();
+        minus_one.negate();
+        for v in values {
+            for i in 0..RANGE_BITS {
+                if (v >> i) & 1 == 1 {
+                    a_l.push(Fr::one());
+                    a_r.push(Fr::zero());
+                } else {
+                    a_l.push(Fr::zero());
+                    a_r.push(minus_one);
+                }
+            }
+        }
+        let alpha = Fr::random(rng);
+        let s_l: Vec<Fr> = (0..n).map(|_| Fr::random(rng)).collect();
+        let s_r: Vec<Fr> = (0..n).map(|_| Fr::random(rng)).collect();
+        let rho = Fr::random(rng);
+        let vector_bases = [&[b_blind], g, h].concat();
+        let a = sum_of_products_pippinger(&vector_bases, &[&[alpha], &a_l[..], &a_r[..]].concat());
+        let s = sum_of_products_pippinger(&vector_bases, &[&[rho], &s_l[..], &s_r[..]].concat());
+        transcript.append_g1(b"A", &a.into_affine());
+        transcript.append_g1(b"S", &s.into_affine());
+        let ch = challenges(transcript, m);
+
+        // l(X) = l0 + l1 * X, r(X) = r0 + r1 * X
+        let two_powers = powers(&fr_from_u64(2), RANGE_BITS);
+        let mut l0 = a_l;
+        let mut r0 = a_r;
+        let mut r1 = s_r;
+        for i in 0..n {
+            l0[i].sub_assign(&ch.z);
+            r0[i].add_assign(&ch.z);
+            r0[i].mul_assign(&ch.y_powers[i]);
+            let mut t = two_powers[i % RANGE_BITS];
+            t.mul_assign(&ch.z_powers[i / RANGE_BITS]);
+            r0[i].add_assign(&t);
+            r1[i].mul_assign(&ch.y_powers[i]);
+        }
+        let l1 = s_l;
+
+        // T1 and T2
+        let mut t1 = dot(&l0, &r1);
+        t1.add_assign(&dot(&l1, &r0));
+        let t2 = dot(&l1, &r1);
+        let tau1 = Fr::random(rng);
+        let tau2 = Fr::random(rng);
+        let t1_point = sum_of_products_pippinger(&[b, b_blind], &[t1, tau1]);
+        let t2_point = sum_of_products_pippinger(&[b, b_blind], &[t2, tau2]);
+        transcript.append_g1(b"T1", &t1_point.into_affine());
+        transcript.append_g1(b"T2", &t2_point.into_affine());
+        let x = transcript.challenge_scalar(b"x");
+
+        // l = l(x), r = r(x), t^, tau_x and mu
+        let eval = |c0: &[Fr], c1: &[Fr]| -> Vec<Fr> {
+            c0.iter()
+                .zip(c1.iter())
+                .map(|(c0, c1)| {
+                    let mut t = *c1;
+                    t.mul_assign(&x);
+                    t.add_assign(c0);
+                    t
+                })
+                .collect()
+        };
+        let l = eval(&l0, &l1);
+        let r = eval(&r0, &r1);
+        let t_hat = dot(&l, &r);
+        let mut tau_x = tau2;
+        tau_x.mul_assign(&x);
+        tau_x.add_assign(&tau1);
+        tau_x.mul_assign(&x);
+        tau_x.add_assign(&dot(&ch.z_powers, blindings));
+        let mut mu = rho;
+        mu.mul_assign(&x);
+        mu.add_assign(&alpha);
+        transcript.append_scalar(b"t_hat", &t_hat);
+        transcript.append_scalar(b"tau_x", &tau_x);
+        transcript.append_scalar(b"mu", &mu);
+        let w = transcript.challenge_scalar(b"w");
+
+        // the inner-product argument under Q = w * B and H' = y^-i * H_i
+        let q = b.mul(w).into_affine();
+        let y_inv = ch.y.inverse().expect("challenge is zero");
+        let mut h_prime: Vec<G1> = h
+            .iter()
+            .zip(powers(&y_inv, n))
+            .map(|(h, y)| h.mul(y))
+            .collect();
+        G1::batch_normalization(&mut h_prime);
+        let h_prime = h_prime.iter().map(|p| p.into_affine()).collect();
+        let ipp = InnerProductProof::prove_unbound(transcript, &q, g.to_vec(), h_prime, l, r);
+
+        Ok((
+            RangeProof {
+                a,
+                s,
+                t1: t1_point,
+                t2: t2_point,
+                t_hat,
+                tau_x,
+                mu,
+                ipp,
+            },
+            commitments,
+        ))
+    }
+
+    /// Verifies that `commitments` commit to values in range.
+    pub fn verify(
+        &self,
+        gens: &Generators,
+        transcript: &mut Transcript,
+        commitments: &[Commitment],
+    ) -> bool {
+        let m = commitments.len();
+        if check_aggregation(gens, m).is_err() {
+            return false;
+        }
+        let n = m * RANGE_BITS;
+        let (b, b_blind) = (gens.pedersen.g[0], gens.pedersen.h);
+
+        begin(transcript, commitments);
+        transcript.append_g1(b"A", &self.a.into_affine());
+        transcript.append_g1(b"S", &self.s.into_affine());
+        let ch = challenges(transcript, m);
+        transcript.append_g1(b"T1", &self.t1.into_affine());
+        transcript.append_g1(b"T2", &self.t2.into_affine());
+        let x = transcript.challenge_scalar(b"x");
+        transcript.append_scalar(b"t_hat", &self.t_hat);
+        transcript.append_scalar(b"tau_x", &self.tau_x);
+        transcript.append_scalar(b"mu", &self.mu);
+        let w = transcript.challenge_scalar(b"w");
+        let ipp = match self.ipp.verification_scalars(n, transcript) {
+            Some(ipp) => ipp,
+            None => return false,
+        };
+        let c = transcript.challenge_scalar(b"c");
+        let y_inv = match ch.y.inverse() {
+            Some(y_inv) => y_inv,
+            None => return false,
+        };
+
+        let mul = |a: &Fr, b: &Fr| {
+            let mut t = *a;
+            t.mul_assign(b);
+            t
+        };
+        let neg = |a: &Fr| {
+            let mut t = *a;
+            t.negate();
+            t
+        };
+        let mut x_sq = x;
+        x_sq.square();
+        let cx = mul(&c, &x);
+
+        // delta(y, z) = (z - z^2) * <1, y^N> - sum_j z^(3 + j) * (2^n - 1)
+        let mut delta = ch.z;
+        delta.sub_assign(&ch.z_powers[0]);
+        let mut y_sum = Fr::zero();
+        for y in ch.y_powers.iter() {
+            y_sum.add_assign(y);
+        }
+        delta.mul_assign(&y_sum);
+        let mut z_sum = Fr::zero();
+        for z in ch.z_powers.iter() {
+            z_sum.add_assign(z);
+        }
+        z_sum.mul_assign(&ch.z);
+        z_sum.mul_assign(&fr_from_u64(u64::MAX));
+        delta.sub_assign(&z_sum);
+
+        let mut bases = vec![self.a.into_affine(), self.s.into_affine()];
+        let mut scalars = vec![Fr::one(), x];
+        for (v, z) in commitments.iter().zip(ch.z_powers.iter()) {
+            bases.push(v.0.into_affine());
+            scalars.push(mul(&c, z));
+        }
+        bases.push(self.t1.into_affine());
+        scalars.push(cx);
+        bases.push(self.t2.into_affine());
+        scalars.push(mul(&c, &x_sq));
+        for (l, u) in self.ipp.l.iter().zip(ipp.u_sq.iter()) {
+            bases.push(l.into_affine());
+            scalars.push(*u);
+        }
+        for (r, u) in self.ipp.r.iter().zip(ipp.u_inv_sq.iter()) {
+            bases.push(r.into_affine());
+            scalars.push(*u);
+        }
+
+        // B~: -mu - c * tau_x
+        let mut blind = mul(&c, &self.tau_x);
+        blind.add_assign(&self.mu);
+        bases.push(b_blind);
+        scalars.push(neg(&blind));
+
+        // B: w * (t^ - a * b) + c * (delta - t^)
+        let mut base = self.t_hat;
+        base.sub_assign(&mul(&self.ipp.a, &self.ipp.b));
+        base.mul_assign(&w);
+        delta.sub_assign(&self.t_hat);
+        base.add_assign(&mul(&c, &delta));
+        bases.push(b);
+        scalars.push(base);
+
+        // G_i: -z - a * s_i
+        // H_i: z + y^-i * (z_j * 2^k - b * s_(N - 1 - i)), for i = j * n + k
+        let two_powers = powers(&fr_from_u64(2), RANGE_BITS);
+        let y_inv_powers = powers(&y_inv, n);
+        bases.extend_from_slice(&gens.g[..n]);
+        for s in ipp.s.iter() {
+            let mut t = mul(&self.ipp.a, s);
+            t.add_assign(&ch.z);
+            scalars.push(neg(&t));
+        }
+        bases.extend_from_slice(&gens.h[..n]);
+        for (i, s) in ipp.s.iter().rev().enumerate() {
+            let mut t = mul(&ch.z_powers[i / RANGE_BITS], &two_powers[i % RANGE_BITS]);
+            t.sub_assign(&mul(&self.ipp.b, s));
+            t.mul_assign(&y_inv_powers[i]);
+            t.add_assign(&ch.z);
+            scalars.push(t);
+        }
+
+        sum_of_products_pippinger(&bases, &scalars).is_zero()
+    }
+}
+
+fn check_aggregation(gens: &Generators, m: usize) -> Result<(), BulletproofsError> {
+    if !m.is_power_of_two() {
+        return Err(BulletproofsError::InvalidAggregation(m));
+    }
+    if m > gens.capacity() {
+        return Err(BulletproofsError::TooManyValues {
+            capacity: gens.capacity(),
+            values: m,
+        });
+    }
+    Ok(())
+}
+
+/// Appends the domain separator, the sizes, and the commitments.
+fn begin(transcript: &mut Transcript, commitments: &[Commitment]) {
+    transcript.append_message(b"dom-sep", b"range proof");
+    transcript.append_u64(b"n", RANGE_BITS as u64);
+    transcript.append_u64(b"m", commitments.len() as u64);
+    for v in commitments {
+        transcript.append_g1(b"V", &v.0.into_affine());
+    }
+}
+
+fn challenges(transcript: &mut Transcript, m: usize) -> Challenges {
+    let y = transcript.challenge_scalar(b"y");
+    let z = transcript.challenge_scalar(b"z");
+    let mut z_sq = z;
+    z_sq.square();
+    let mut z_powers = powers(&z, m);
+    for p in z_powers.iter_mut() {
+        p.mul_assign(&z_sq);
+    }
+    Challenges {
+        y,
+        z,
+        y_powers: powers(&y, m * RANGE_BITS),
+        z_powers,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_rng;
+
+    #[test]
+    fn test_range_proof() {
+        let mut rng = test_rng();
+        let gens = Generators::new(4);
+        for values in [&[0][..], &[u64::MAX], &[7, 1 << 40], &[1, 2, 3, u64::MAX]].iter() {
+            let blindings: Vec<Fr> = values.iter().map(|_| Fr::random(&mut rng)).collect();
+            let (proof, commitments) = RangeProof::prove(
+                &gens,
+                &mut Transcript::new(b"test"),
+                values,
+                &blindings,
+                &mut rng,
+            )
+            .unwrap();
+            assert_eq!(
+                proof.ipp.l.len(),
+                6 + values.len().trailing_zeros() as usize
+            );
+            for ((v, gamma), c) in values.iter().zip(blindings.iter()).zip(commitments.iter()) {
+                assert!(gens.pedersen.verify(c, &[fr_from_u64(*v)], gamma));
+            }
+            assert!(proof.verify(&gens, &mut Transcript::new(b"test"), &commitments));
+            assert!(!proof.verify(&gens, &mut Transcript::new(b"other"), &commitments));
+        }
+    }
+
+    #[test]
+    fn test_range_proof_tampered() {
+        let mut rng = test_rng();
+        let gens = Generators::new(2);
+        let blindings = [Fr::random(&mut rng), Fr::random(&mut rng)];
+        let (proof, commitments) = RangeProof::prove(
+            &gens,
+            &mut Transcript::new(b"test"),
+            &[42, 1000],
+            &blindings,
+            &mut rng,
+        )
+        .unwrap();
+        let verify = |proof: &RangeProof, commitments: &[Commitment]| {
+            proof.verify(&gens, &mut Transcript::new(b"test"), commitments)
+        };
+        assert!(verify(&proof, &commitments));
+
+        // the commitments are bound, in order
+        assert!(!verify(&proof, &[commitments[1], commitments[0]]));
+        assert!(!verify(&proof, &commitments[..1]));
+        let mut other = commitments.clone();
+        other[0] = other[0].add(&Commitment(gens.pedersen.g[0].into_projective()));
+        assert!(!verify(&proof, &other));
+
+        let tamper: [&dyn Fn(&mut RangeProof); 8] = [
+            &|p| p.a.double(),
+            &|p| p.s.double(),
+            &|p| p.t1.double(),
+            &|p| p.t2.double(),
+            &|p| p.t_hat.add_assign(&Fr::one()),
+            &|p| p.tau_x.add_assign(&Fr::one()),
+            &|p| p.mu.add_assign(&Fr::one()),
+            &|p| p.ipp.b.add_assign(&Fr::one()),
+        ];
+        for f in tamper.iter() {
+            let mut bad = proof.clone();
+            f(&mut bad);
+            assert!(!verify(&bad, &commitments));
+        }
+        let mut bad = proof.clone();
+        bad.ipp.l.pop();
+        assert!(!verify(&bad, &commitments));
+    }
+
+    #[test]
+    fn test_range_proof_out_of_range() {
+        let mut rng = test_rng();
+        let gens = Generators::new(1);
+        let gamma = Fr::random(&mut rng);
+        let (proof, _) = RangeProof::prove(
+            &gens,
+            &mut Transcript::new(b"test"),
+            &[5],
+            &[gamma],
+            &mut rng,
+        )
+        .unwrap();
+        // 5 - 2^64, which is not in range, under the same blinding
+        let mut v = fr_from_u64(5);
+        let mut two_64 = fr_from_u64(1 << 63);
+        two_64.double();
+        v.sub_assign(&two_64);
+        let c = gens.pedersen.commit(&[v], &gamma).unwrap();
+        assert!(!proof.verify(&gens, &mut Transcript::new(b"test"), &[c]));
+    }
+
+    #[test]
+    fn test_range_proof_errors() {
+        let mut rng = test_rng();
+        let gens = Generators::new(2);
+        let one = Fr::one();
+        let mut prove = |values: &[u64], blindings: &[Fr]| {
+            RangeProof::prove(
+                &gens,
+                &mut Transcript::new(b"test"),
+                values,
+                blindings,
+                &mut rng,
+            )
+            .map(|_| ())
+        };
+        assert_eq!(
+            prove(&[], &[]),
+            Err(BulletproofsError::InvalidAggregation(0))
+        );
+        assert_eq!(
+            prove(&[1, 2, 3], &[one; 3]),
+            Err(BulletproofsError::InvalidAggregation(3))
+        );
+        assert_eq!(
+            prove(&[1, 2, 3, 4], &[one; 4]),
+            Err(BulletproofsError::TooManyValues {
+                capacity: 2,
+                values: 4
+            })
+        );
+        assert_eq!(
+            prove(&[1, 2], &[one]),
+            Err(BulletproofsError::BlindingCount {
+                values: 2,
+                blindings: 1
+            })
+        );
+    }
+}
@@ -0,0 +1,494 @@
+use super::g2::G2Affine;
+use crate::{
+    bw6_761::{Bw6_761, Fq, Fq6, FqRepr, Fr, FrRepr},
+    CurveAffine, CurveProjective, EncodedPoint, Engine, Error, SubgroupCheck,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+curve_impl!(
+    "G1",
+    G1,
+    G1Affine,
+    G1Prepared,
+    Fq,
+    Fr,
+    G1Uncompressed,
+    G1Compressed,
+    G2Affine,
+    Bw6_761,
+    Fq6,
+    bw6_precomp_impl
+);
+
+#[derive(Copy, Clone)]
+pub struct G1Uncompressed([u8; 192]);
+
+impl AsRef<[u8]> for G1Uncompressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G1Uncompressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G1Uncompressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G1Uncompressed {
+    type Affine = G1Affine;
+
+    fn empty() -> Self {
+        G1Uncompressed([0; 192])
+    }
+    fn size() -> usize {
+        192
+    }
+    fn into_affine(&self) -> Result<G1Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        if !affine.is_on_curve() {
+            Err(Error::NotOnCurve)
+        } else if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G1Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+
+        if copy[0] & (1 << 7) != 0 {
+            // Distinguisher bit is set, but this should be uncompressed!
+            return Err(Error::UnexpectedCompressionMode);
+        }
+
+        if copy[0] & (1 << 6) != 0 {
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
+            // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
+            copy[0] &= 0x3f;
+
+            if copy.iter().all(|b| *b == 0) {
+                Ok(G1Affine::zero())
+            } else {
+                Err(Error::NonZeroInfinity)
+            }
+        } else {
+            if copy[0] & (1 << 5) != 0 {
+                // The bit indicating the y-coordinate should be lexicographically
+                // largest is set, but this is an uncompressed element.
+                return Err(Error::BadFlags);
+            }
+
+            // Unset the three most significant bits.
+            copy[0] &= 0x1f;
+
+            let mut x = FqRepr([0; 12]);
+            let mut y = FqRepr([0; 12]);
+
+            {
+                let mut reader = &copy[..];
+
+                x.read_be(&mut reader).unwrap();
+                y.read_be(&mut reader).unwrap();
+            }
+
+            Ok(G1Affine {
+                x: Fq::from_repr(x)
+                    .map_err(|e| Error::NonCanonicalFieldElement("x coordinate", e))?,
+                y: Fq::from_repr(y)
+                    .map_err(|e| Error::NonCanonicalFieldElement("y coordinate", e))?,
+                infinity: false,
+            })
+        }
+    }
+    fn from_affine(affine: G1Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            // Set the second-most significant bit to indicate this point
+            // is at infinity.
+            res.0[0] |= 1 << 6;
+        } else {
+            let mut writer = &mut res.0[..];
+
+            affine.x.into_repr().write_be(&mut writer).unwrap();
+            affine.y.into_repr().write_be(&mut writer).unwrap();
+        }
+
+        res
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct G1Compressed([u8; 96]);
+
+impl AsRef<[u8]> for G1Compressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G1Compressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G1Compressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G1Compressed {
+    type Affine = G1Affine;
+
+    fn empty() -> Self {
+        G1Compressed([0; 96])
+    }
+    fn size() -> usize {
+        96
+    }
+    fn into_affine(&self) -> Result<G1Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        // NB: Decompression guarantees that it is on the curve already.
+
+        if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G1Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+
+        if copy[0] & (1 << 7) == 0 {
+            // Distinguisher bit isn't set.
+            return Err(Error::UnexpectedCompressionMode);
+        }
+
+        if copy[0] & (1 << 6) != 0 {
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
+            // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
+            copy[0] &= 0x3f;
+
+            if copy.iter().all(|b| *b == 0) {
+                Ok(G1Affine::zero())
+            } else {
+                Err(Error::NonZeroInfinity)
+            }
+        } else {
+            // Determine if the intended y coordinate must be greater
+            // lexicographically.
+            let greatest = copy[0] & (1 << 5) != 0;
+
+            // Unset the three most significant bits.
+            copy[0] &= 0x1f;
+
+            let mut x = FqRepr([0; 12]);
+
+            {
+                let mut reader = &copy[..];
+
+                x.read_be(&mut reader).unwrap();
+            }
+
+            // Interpret as Fq element.
+            let x =
+                Fq::from_repr(x).map_err(|e| Error::NonCanonicalFieldElement("x coordinate", e))?;
+
+            G1Affine::get_point_from_x(x, greatest).ok_or(Error::NotOnCurve)
+        }
+    }
+    fn from_affine(affine: G1Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            // Set the second-most significant bit to indicate this point
+            // is at infinity.
+            res.0[0] |= 1 << 6;
+        } else {
+            {
+                let mut writer = &mut res.0[..];
+
+                affine.x.into_repr().write_be(&mut writer).unwrap();
+            }
+
+            let mut negy = affine.y;
+            negy.negate();
+
+            // Set the third most significant bit if the correct y-coordinate
+            // is lexicographically largest.
+            if affine.y > negy {
+                res.0[0] |= 1 << 5;
+            }
+        }
+
+        // Set highest bit to distinguish this as a compressed element.
+        res.0[0] |= 1 << 7;
+
+        res
+    }
+}
+
+impl G1Affine {
+    fn scale_by_cofactor(&self) -> G1 {
+        // G1 cofactor = 0xad1972339049ce762c77d5ac34cb12efc856a0853c9db94cc61c554757551c0c832ba4061000003b3de580000000007c
+        let cofactor = BitIterator::new([
+            0x3de580000000007c,
+            0x832ba4061000003b,
+            0xc61c554757551c0c,
+            0xc856a0853c9db94c,
+            0x2c77d5ac34cb12ef,
+            0xad1972339049ce76,
+        ]);
+        self.mul_bits(cofactor)
+    }
+
+    fn get_generator() -> Self {
+        G1Affine {
+            x: super::super::fq::G1_GENERATOR_X,
+            y: super::super::fq::G1_GENERATOR_Y,
+            infinity: false,
+        }
+    }
+
+    fn get_coeff_b() -> Fq {
+        super::super::fq::B_COEFF
+    }
+
+    fn perform_pairing(&self, other: &G2Affine) -> Fq6 {
+        super::super::Bw6_761::pairing(*self, *other)
+    }
+}
+
+impl G1 {
+    fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
+        let num_bits = scalar.num_bits() as usize;
+
+        if num_bits >= 130 {
+            4
+        } else if num_bits >= 34 {
+            3
+        } else {
+            2
+        }
+    }
+
+    fn empirical_recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        const RECOMMENDATIONS: [usize; 12] =
+            [1, 3, 7, 20, 43, 120, 273, 563, 1630, 3128, 7933, 62569];
+
+        let mut ret = 4;
+        for r in &RECOMMENDATIONS {
+            if num_scalars > *r {
+                ret += 1;
+            } else {
+                break;
+            }
+        }
+
+        ret
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct G1Prepared(pub(crate) G1Affine);
+
+impl G1Prepared {
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn from_affine(p: G1Affine) -> Self {
+        G1Prepared(p)
+    }
+}
+
+mod subgroup_check {
+
+    use super::G1Affine;
+    #[cfg(test)]
+    use super::G1;
+    #[cfg(test)]
+    use crate::CurveProjective;
+    use crate::SubgroupCheck;
+    #[cfg(test)]
+    use rand_core::SeedableRng;
+
+    impl SubgroupCheck for G1Affine {
+        fn in_subgroup(&self) -> bool {
+            self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve()
+        }
+    }
+
+    #[test]
+    fn test_g1_subgroup_check() {
+        let mut rng = rand_xorshift::XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..32 {
+            let p = G1::random(&mut rng).into_affine();
+            assert_eq!(
+                p.in_subgroup(),
+                p.is_in_correct_subgroup_assuming_on_curve()
+            );
+
+            let p = p.scale_by_cofactor().into_affine();
+            assert!(p.in_subgroup() && p.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+}
+
+#[test]
+fn g1_generator() {
+    let g1 = G1Affine::one();
+    assert_eq!(
+        g1.x,
+        Fq::from_str("6238772257594679368032145693622812838779005809760824733138787810501188623461307351759238099287535516224314149266511977132140828635950940021790489507611754366317801811090811367945064510304504157188661901055903167026722666149426237").unwrap()
+    );
+    assert_eq!(
+        g1.y,
+        Fq::from_str("2101735126520897423911504562215834951148127555913367997162789335052900271653517958562461315794228241561913734371411178226936527683203879553093934185950470971848972085321797958124416462268292467002957525517188485984766314758624099").unwrap()
+    );
+    assert!(g1.in_subgroup());
+    assert!(!g1.into_projective().is_zero());
+}
+
+#[test]
+fn g1_test_is_valid() {
+    // Reject point on the curve hosting G2 (y^2 = x^3 + 4)
+    {
+        let p = G1Affine {
+            x: Fq::zero(),
+            y: Fq::from_str("2").unwrap(),
+            infinity: false,
+        };
+        assert!(!p.is_on_curve());
+        assert!(!p.in_subgroup());
+    }
+
+    // Reject point of order 2 on the curve
+    {
+        let p = G1Affine {
+            x: Fq::one(),
+            y: Fq::zero(),
+            infinity: false,
+        };
+        assert!(p.is_on_curve());
+        assert!(!p.in_subgroup());
+        match p.into_compressed().into_affine() {
+            Err(Error::NotInSubgroup) => (),
+            _ => panic!("should have rejected point outside the subgroup"),
+        }
+        assert!(p.into_compressed().into_affine_unchecked().is_ok());
+    }
+}
+
+#[test]
+fn test_g1_addition_correctness() {
+    let mut p = G1::one();
+    p.add_assign(
+        &G1Affine {
+            x: Fq::from_str(
+                "4496858429211802909179620679015619302561330341082554643439456000521263475744464277159032661697405889875630413884245154664527894304228641555969023458676803156847053153395911570022510319558492937646897972300633297222420610866210842",
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "3507899709395964140950558964111558609733110925593635128300959734174041973613715989815392528470499545260977857809594374710639883967518108883387854448869689557723370574852727800194238422465792570797295341786688964085385885543030570",
+            )
+            .unwrap(),
+            infinity: false,
+        }
+        .into_projective(),
+    );
+
+    let p = G1Affine::from(p);
+
+    assert_eq!(
+        p,
+        G1Affine {
+            x: Fq::from_str(
+                "4105347241529950174205663260425839129825499260691900438975059602984630137705804051231210686910923370689812695320747990968555309795875945328900272954293322883594707737396464839548661556111184986417181125745261529660801938041122166"
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "5817909718087039123574100026949749206216802476146800852559936002118426737304533902165221900009381191267079289987769911892833154507563768046763356701380676670839012358917704052139767478205567369282811536030649693060000170689399847"
+            )
+            .unwrap(),
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn test_g1_doubling_correctness() {
+    let mut p = G1::one();
+
+    p.double();
+
+    let p = G1Affine::from(p);
+
+    assert_eq!(
+        p,
+        G1Affine {
+            x: Fq::from_str(
+                "4496858429211802909179620679015619302561330341082554643439456000521263475744464277159032661697405889875630413884245154664527894304228641555969023458676803156847053153395911570022510319558492937646897972300633297222420610866210842"
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "3507899709395964140950558964111558609733110925593635128300959734174041973613715989815392528470499545260977857809594374710639883967518108883387854448869689557723370574852727800194238422465792570797295341786688964085385885543030570"
+            )
+            .unwrap(),
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn test_g1_encoding_flags() {
+    // the generator's y-coordinate is the smaller of the two
+    let c = G1Affine::one().into_compressed();
+    assert_eq!(c.as_ref()[0] >> 5, 0b100);
+    let mut g = G1Affine::one();
+    g.negate();
+    let c = g.into_compressed();
+    assert_eq!(c.as_ref()[0] >> 5, 0b101);
+    assert_eq!(c.into_affine().unwrap(), g);
+
+    let u = G1Affine::one().into_uncompressed();
+    assert_eq!(u.as_ref()[0] >> 5, 0);
+
+    let z = G1Affine::zero().into_compressed();
+    assert_eq!(z.as_ref()[0], 0b110 << 5);
+    assert!(z.as_ref()[1..].iter().all(|b| *b == 0));
+    let z = G1Affine::zero().into_uncompressed();
+    assert_eq!(z.as_ref()[0], 0b010 << 5);
+    assert!(z.as_ref()[1..].iter().all(|b| *b == 0));
+}
+
+#[test]
+fn g1_curve_tests() {
+    crate::tests::curve::curve_tests::<G1>();
+}
@@ -0,0 +1,468 @@
+use super::g1::G1Affine;
+use crate::{
+    bw6_761::{Bw6_761, Fq, Fq6, FqRepr, Fr, FrRepr},
+    CurveAffine, CurveProjective, EncodedPoint, Engine, Error, SubgroupCheck,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+curve_impl!(
+    "G2",
+    G2,
+    G2Affine,
+    G2Prepared,
+    Fq,
+    Fr,
+    G2Uncompressed,
+    G2Compressed,
+    G1Affine,
+    Bw6_761,
+    Fq6,
+    bw6_precomp_impl
+);
+
+#[derive(Copy, Clone)]
+pub struct G2Uncompressed([u8; 192]);
+
+impl AsRef<[u8]> for G2Uncompressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G2Uncompressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G2Uncompressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G2Uncompressed {
+    type Affine = G2Affine;
+
+    fn empty() -> Self {
+        G2Uncompressed([0; 192])
+    }
+    fn size() -> usize {
+        192
+    }
+    fn into_affine(&self) -> Result<G2Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        if !affine.is_on_curve() {
+            Err(Error::NotOnCurve)
+        } else if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G2Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+
+        if copy[0] & (1 << 7) != 0 {
+            // Distinguisher bit is set, but this should be uncompressed!
+            return Err(Error::UnexpectedCompressionMode);
+        }
+
+        if copy[0] & (1 << 6) != 0 {
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
+            // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
+            copy[0] &= 0x3f;
+
+            if copy.iter().all(|b| *b == 0) {
+                Ok(G2Affine::zero())
+            } else {
+                Err(Error::NonZeroInfinity)
+            }
+        } else {
+            if copy[0] & (1 << 5) != 0 {
+                // The bit indicating the y-coordinate should be lexicographically
+                // largest is set, but this is an uncompressed element.
+                return Err(Error::BadFlags);
+            }
+
+            // Unset the three most significant bits.
+            copy[0] &= 0x1f;
+
+            let mut x = FqRepr([0; 12]);
+            let mut y = FqRepr([0; 12]);
+
+            {
+                let mut reader = &copy[..];
+
+                x.read_be(&mut reader).unwrap();
+                y.read_be(&mut reader).unwrap();
+            }
+
+            Ok(G2Affine {
+                x: Fq::from_repr(x)
+                    .map_err(|e| Error::NonCanonicalFieldElement("x coordinate", e))?,
+                y: Fq::from_repr(y)
+                    .map_err(|e| Error::NonCanonicalFieldElement("y coordinate", e))?,
+                infinity: false,
+            })
+        }
+    }
+    fn from_affine(affine: G2Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            // Set the second-most significant bit to indicate this point
+            // is at infinity.
+            res.0[0] |= 1 << 6;
+        } else {
+            let mut writer = &mut res.0[..];
+
+            affine.x.into_repr().write_be(&mut writer).unwrap();
+            affine.y.into_repr().write_be(&mut writer).unwrap();
+        }
+
+        res
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct G2Compressed([u8; 96]);
+
+impl AsRef<[u8]> for G2Compressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for G2Compressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl fmt::Debug for G2Compressed {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        self.0[..].fmt(formatter)
+    }
+}
+
+impl EncodedPoint for G2Compressed {
+    type Affine = G2Affine;
+
+    fn empty() -> Self {
+        G2Compressed([0; 96])
+    }
+    fn size() -> usize {
+        96
+    }
+    fn into_affine(&self) -> Result<G2Affine, Error> {
+        let affine = self.into_affine_unchecked()?;
+
+        // NB: Decompression guarantees that it is on the curve already.
+
+        if !affine.in_subgroup() {
+            Err(Error::NotInSubgroup)
+        } else {
+            Ok(affine)
+        }
+    }
+    fn into_affine_unchecked(&self) -> Result<G2Affine, Error> {
+        // Create a copy of this representation.
+        let mut copy = self.0;
+
+        if copy[0] & (1 << 7) == 0 {
+            // Distinguisher bit isn't set.
+            return Err(Error::UnexpectedCompressionMode);
+        }
+
+        if copy[0] & (1 << 6) != 0 {
+            // This is the point at infinity, which has no sign, and if we mask
+            // away the first two bits, the entire representation should consist
+            // of zeroes.
+            if copy[0] & (1 << 5) != 0 {
+                return Err(Error::BadFlags);
+            }
+            copy[0] &= 0x3f;
+
+            if copy.iter().all(|b| *b == 0) {
+                Ok(G2Affine::zero())
+            } else {
+                Err(Error::NonZeroInfinity)
+            }
+        } else {
+            // Determine if the intended y coordinate must be greater
+            // lexicographically.
+            let greatest = copy[0] & (1 << 5) != 0;
+
+            // Unset the three most significant bits.
+            copy[0] &= 0x1f;
+
+            let mut x = FqRepr([0; 12]);
+
+            {
+                let mut reader = &copy[..];
+
+                x.read_be(&mut reader).unwrap();
+            }
+
+            // Interpret as Fq element.
+            let x =
+                Fq::from_repr(x).map_err(|e| Error::NonCanonicalFieldElement("x coordinate", e))?;
+
+            G2Affine::get_point_from_x(x, greatest).ok_or(Error::NotOnCurve)
+        }
+    }
+    fn from_affine(affine: G2Affine) -> Self {
+        let mut res = Self::empty();
+
+        if affine.is_zero() {
+            // Set the second-most significant bit to indicate this point
+            // is at infinity.
+            res.0[0] |= 1 << 6;
+        } else {
+            {
+                let mut writer = &mut res.0[..];
+
+                affine.x.into_repr().write_be(&mut writer).unwrap();
+            }
+
+            let mut negy = affine.y;
+            negy.negate();
+
+            // Set the third most significant bit if the correct y-coordinate
+            // is lexicographically largest.
+            if affine.y > negy {
+                res.0[0] |= 1 << 5;
+            }
+        }
+
+        // Set highest bit to distinguish this as a compressed element.
+        res.0[0] |= 1 << 7;
+
+        res
+    }
+}
+
+impl G2Affine {
+    fn scale_by_cofactor(&self) -> G2 {
+        // G2 cofactor = 0xad1972339049ce762c77d5ac34cb12efc856a0853c9db94cc61c554757551c0c832ba4061000003b3de5800000000075
+        let cofactor = BitIterator::new([
+            0x3de5800000000075,
+            0x832ba4061000003b,
+            0xc61c554757551c0c,
+            0xc856a0853c9db94c,
+            0x2c77d5ac34cb12ef,
+            0xad1972339049ce76,
+        ]);
+        self.mul_bits(cofactor)
+    }
+
+    fn get_generator() -> Self {
+        G2Affine {
+            x: super::super::fq::G2_GENERATOR_X,
+            y: super::super::fq::G2_GENERATOR_Y,
+            infinity: false,
+        }
+    }
+
+    fn get_coeff_b() -> Fq {
+        super::super::fq::B_COEFF_G2
+    }
+
+    fn perform_pairing(&self, other: &G1Affine) -> Fq6 {
+        super::super::Bw6_761::pairing(*other, *self)
+    }
+}
+
+impl G2 {
+    fn empirical_recommended_wnaf_for_scalar(scalar: FrRepr) -> usize {
+        let num_bits = scalar.num_bits() as usize;
+
+        if num_bits >= 130 {
+            4
+        } else if num_bits >= 34 {
+            3
+        } else {
+            2
+        }
+    }
+
+    fn empirical_recommended_wnaf_for_num_scalars(num_scalars: usize) -> usize {
+        const RECOMMENDATIONS: [usize; 12] =
+            [1, 3, 7, 20, 43, 120, 273, 563, 1630, 3128, 7933, 62569];
+
+        let mut ret = 4;
+        for r in &RECOMMENDATIONS {
+            if num_scalars > *r {
+                ret += 1;
+            } else {
+                break;
+            }
+        }
+
+        ret
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct G2Prepared {
+    // line coefficients for the Miller loop over x + 1
+    pub(crate) coeffs_1: Vec<(Fq, Fq, Fq)>,
+    // line coefficients for the Miller loop over x^3 - x^2 - x
+    pub(crate) coeffs_2: Vec<(Fq, Fq, Fq)>,
+    pub(crate) infinity: bool,
+}
+
+mod subgroup_check {
+
+    use super::G2Affine;
+    #[cfg(test)]
+    use super::G2;
+    #[cfg(test)]
+    use crate::CurveProjective;
+    use crate::SubgroupCheck;
+    #[cfg(test)]
+    use rand_core::SeedableRng;
+
+    impl SubgroupCheck for G2Affine {
+        fn in_subgroup(&self) -> bool {
+            self.is_on_curve() && self.is_in_correct_subgroup_assuming_on_curve()
+        }
+    }
+
+    #[test]
+    fn test_g2_subgroup_check() {
+        let mut rng = rand_xorshift::XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        for _ in 0..32 {
+            let p = G2::random(&mut rng).into_affine();
+            assert_eq!(
+                p.in_subgroup(),
+                p.is_in_correct_subgroup_assuming_on_curve()
+            );
+
+            let p = p.scale_by_cofactor().into_affine();
+            assert!(p.in_subgroup() && p.is_in_correct_subgroup_assuming_on_curve());
+        }
+    }
+}
+
+#[test]
+fn g2_generator() {
+    let g2 = G2Affine::one();
+    assert_eq!(
+        g2.x,
+        Fq::from_str("6445332910596979336035888152774071626898886139774101364933948236926875073754470830732273879639675437155036544153105017729592600560631678554299562762294743927912429096636156401171909259073181112518725201388196280039960074422214428").unwrap()
+    );
+    assert_eq!(
+        g2.y,
+        Fq::from_str("562923658089539719386922163444547387757586534741080263946953401595155211934630598999300396317104182598044793758153214972605680357108252243146746187917218885078195819486220416605630144001533548163105316661692978285266378674355041").unwrap()
+    );
+    assert!(g2.in_subgroup());
+    assert!(!g2.into_projective().is_zero());
+}
+
+#[test]
+fn g2_test_is_valid() {
+    // Reject point not on the curve
+    {
+        let p = G2Affine {
+            x: Fq::one(),
+            y: Fq::one(),
+            infinity: false,
+        };
+        assert!(!p.is_on_curve());
+        assert!(!p.in_subgroup());
+    }
+
+    // Reject point of order 3 on the curve
+    {
+        let p = G2Affine {
+            x: Fq::zero(),
+            y: Fq::from_str("2").unwrap(),
+            infinity: false,
+        };
+        assert!(p.is_on_curve());
+        assert!(!p.in_subgroup());
+        match p.into_uncompressed().into_affine() {
+            Err(Error::NotInSubgroup) => (),
+            _ => panic!("should have rejected point outside the subgroup"),
+        }
+        assert!(p.into_compressed().into_affine_unchecked().is_ok());
+    }
+}
+
+#[test]
+fn test_g2_addition_correctness() {
+    let mut p = G2::one();
+    p.add_assign(
+        &G2Affine {
+            x: Fq::from_str(
+                "918875045005619483179045292195745587358966927792208278319475889037256697864558782500929304597755535588727708682423087202760620533964377356883163914630646985313190157974911706802276151218733018359565946457670512084385097348617879",
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "6038604481184079799801472454507043243099924948569301722341773810185797894424386676071250252509091468071042587659067706332046024660730550512070630008635753256912036038063102685354699821250217637240298404267671857393741147018323638",
+            )
+            .unwrap(),
+            infinity: false,
+        }
+        .into_projective(),
+    );
+
+    let p = G2Affine::from(p);
+
+    assert_eq!(
+        p,
+        G2Affine {
+            x: Fq::from_str(
+                "3437605321205037789162719688045273911538500448758501470891346067532005012451494679612965601878992658183747191977726181244831793343445027494101306872951336686689850543282479001620447129168262265979989194345550788886906537664521968"
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "2328038040826088868126581948019363035431876951133850477041914476023715228314835782357609964075618422789160128010688082173336023735207459380966072642779613942739917131209557664058201440063313924819368187962560301169153197013531743"
+            )
+            .unwrap(),
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn test_g2_doubling_correctness() {
+    let mut p = G2::one();
+
+    p.double();
+
+    let p = G2Affine::from(p);
+
+    assert_eq!(
+        p,
+        G2Affine {
+            x: Fq::from_str(
+                "918875045005619483179045292195745587358966927792208278319475889037256697864558782500929304597755535588727708682423087202760620533964377356883163914630646985313190157974911706802276151218733018359565946457670512084385097348617879"
+            )
+            .unwrap(),
+            y: Fq::from_str(
+                "6038604481184079799801472454507043243099924948569301722341773810185797894424386676071250252509091468071042587659067706332046024660730550512070630008635753256912036038063102685354699821250217637240298404267671857393741147018323638"
+            )
+            .unwrap(),
+            infinity: false,
+        }
+    );
+}
+
+#[test]
+fn g2_curve_tests() {
+    crate::tests::curve::curve_tests::<G2>();
+}
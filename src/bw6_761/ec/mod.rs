@@ -0,0 +1,77 @@
+// Points are encoded as for BLS12-381: big-endian coordinates, with the
+// compression, infinity and sort flags in the three most significant bits of
+// the first byte, which are free because the modulus is below 2^761.
+// The scalars are [u64; 6], which the interleaved windows of precomp_impl
+// do not cover: precomputation is a no-op, and the multiplications use wNAF
+// and ignore the tables.
+macro_rules! bw6_precomp_impl {
+    ($projective:ident) => {
+        fn precomp_3(&self, _pre: &mut [Self]) {}
+
+        fn precomp_256(&self, _pre: &mut [Self]) {}
+
+        fn mul_precomp_3<S: Into<<Self::Scalar as PrimeField>::Repr>>(
+            &self,
+            other: S,
+            _pre: &[Self],
+        ) -> $projective {
+            let repr = other.into();
+            let _: &[u64; 6] = &repr.0;
+            self.mul(repr)
+        }
+
+        fn mul_precomp_256<S: Into<<Self::Scalar as PrimeField>::Repr>>(
+            &self,
+            other: S,
+            _pre: &[Self],
+        ) -> $projective {
+            let repr = other.into();
+            let _: &[u64; 6] = &repr.0;
+            self.mul(repr)
+        }
+    };
+}
+
+pub mod g1;
+pub mod g2;
+
+pub use self::g1::*;
+pub use self::g2::*;
+
+#[test]
+fn test_group_defaults() {
+    use crate::{CurveAffine, CurveProjective};
+
+    assert_eq!(G1::default(), G1::zero());
+    assert_eq!(G2::default(), G2::zero());
+    assert_eq!(G1Affine::default(), G1Affine::zero());
+    assert_eq!(G2Affine::default(), G2Affine::zero());
+}
+
+#[test]
+fn test_mul_precomp() {
+    use crate::{bw6_761::Fr, CurveAffine, CurveProjective};
+    use ff::Field;
+    use rand_core::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // scalars wider than 256 bits are multiplied in full, and the tables are
+    // left untouched
+    let p = G1::random(&mut rng).into_affine();
+    let mut pre_3 = vec![G1Affine::zero(); 3];
+    p.precomp_3(&mut pre_3);
+    let mut pre_256 = vec![G1Affine::zero(); 256];
+    p.precomp_256(&mut pre_256);
+    assert!(pre_3.iter().chain(pre_256.iter()).all(|p| p.is_zero()));
+    let q = G2::random(&mut rng).into_affine();
+    for _ in 0..10 {
+        let s = Fr::random(&mut rng);
+        assert_eq!(p.mul_precomp_3(s, &pre_3), p.mul(s));
+        assert_eq!(p.mul_precomp_256(s, &pre_256), p.mul(s));
+        assert_eq!(q.mul_precomp_3(s, &[]), q.mul(s));
+    }
+}
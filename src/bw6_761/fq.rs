@@ -0,0 +1,404 @@
+use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
+
+// B coefficient of BW6-761 curve, -1.
+pub const B_COEFF: Fq = Fq(FqRepr([
+    0xf29a000000007ab6,
+    0x8c391832e000739b,
+    0x77738a6b6870f959,
+    0xbe36179047832b03,
+    0x84f3089e56574722,
+    0xc5a3614ac0b1d984,
+    0x5c81153f4906e9fe,
+    0x4d28be3a9f55c815,
+    0xd72c1d6f77d5f5c5,
+    0x73a18e069ac04458,
+    0xf9dfaa846595555f,
+    0xd0f0a60a5be58c,
+]));
+
+// B coefficient of the M-type twist hosting G2, 4 = -1 * -4.
+pub const B_COEFF_G2: Fq = Fq(FqRepr([
+    0x136efffffffe16c9,
+    0x82cf5a6dcffe3319,
+    0x6458c05f1f0e0741,
+    0xd10ae605e52a4eda,
+    0x41ca591c0266e100,
+    0x7d0fd59c3626929f,
+    0x9967dc004d00c112,
+    0x1ccff9c033379af5,
+    0x9ad6ec10a23f63af,
+    0x5cec11251a72c235,
+    0x8d18b1ae789ba83e,
+    0x24f5d6c91bd3ec,
+]));
+
+// Generator of G1
+// x = 6238772257594679368032145693622812838779005809760824733138787810501188623461307351759238099287535516224314149266511977132140828635950940021790489507611754366317801811090811367945064510304504157188661901055903167026722666149426237
+// y = 2101735126520897423911504562215834951148127555913367997162789335052900271653517958562461315794228241561913734371411178226936527683203879553093934185950470971848972085321797958124416462268292467002957525517188485984766314758624099
+pub const G1_GENERATOR_X: Fq = Fq(FqRepr([
+    0xd6e42d7614c2d770,
+    0x4bb886eddbc3fc21,
+    0x64648b044098b4d2,
+    0x1a585c895a422985,
+    0xf1a9ac17cf8685c9,
+    0x352785830727aea5,
+    0xddf8cb12306266fe,
+    0x6913b4bfbc9e949a,
+    0x3a4b78d67ba5f6ab,
+    0xf481c06a8d02a04,
+    0x91d4e7365c43edac,
+    0xf4d17cd48beca5,
+]));
+
+pub const G1_GENERATOR_Y: Fq = Fq(FqRepr([
+    0x97e805c4bd16411f,
+    0x870d844e1ee6dd08,
+    0x1eba7a37cb9eab4d,
+    0xd544c4df10b9889a,
+    0x8fe37f21a33897be,
+    0xe9bf99a43a0885d2,
+    0xd7ee0c9e273de139,
+    0xaa6a9ec7a38dd791,
+    0x8f95d3fcf765da8e,
+    0x42326e7db7357c99,
+    0xe217e407e218695f,
+    0x9d1eb23b7cf684,
+]));
+
+// Generator of G2
+// x = 6445332910596979336035888152774071626898886139774101364933948236926875073754470830732273879639675437155036544153105017729592600560631678554299562762294743927912429096636156401171909259073181112518725201388196280039960074422214428
+// y = 562923658089539719386922163444547387757586534741080263946953401595155211934630598999300396317104182598044793758153214972605680357108252243146746187917218885078195819486220416605630144001533548163105316661692978285266378674355041
+pub const G2_GENERATOR_X: Fq = Fq(FqRepr([
+    0x3d902a84cd9f4f78,
+    0x864e451b8a9c05dd,
+    0xc2b3c0d6646c5673,
+    0x17a7682def1ecb9d,
+    0xbe31a1e0fb768fe3,
+    0x4df125e09b92d1a6,
+    0x943fce635b02ee9,
+    0xffc8e7ad0605e780,
+    0x8165c00a39341e95,
+    0x8ccc2ae90a0f094f,
+    0x73a8b8cc0ad09e0c,
+    0x11027e203edd9f4,
+]));
+
+pub const G2_GENERATOR_Y: Fq = Fq(FqRepr([
+    0x9a159be4e773f67c,
+    0x6b957244aa8f4e6b,
+    0xa27b70c9c945a38c,
+    0xacb6a09fda11d0ab,
+    0x3abbdaa9bb6b1291,
+    0xdbdf642af5694c36,
+    0xb6360bb9560b369f,
+    0xac0bd1e822b8d6da,
+    0xfa355d17afe6945f,
+    0x8d6a0fc1fbcad35e,
+    0x72a63c7874409840,
+    0x114976e5b0db280,
+]));
+
+// (-4)^((q^i - 1) / 3) for i in 0..3
+pub const FROBENIUS_COEFF_FQ3_C1: [Fq; 3] = [
+    Fq(FqRepr([
+        0x202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x51f77ef127e87d,
+    ])),
+    Fq(FqRepr([
+        0x7f96b51bd840c549,
+        0xd59782096496171f,
+        0x49b046fd9ce14bbc,
+        0x4b6163bba7527a56,
+        0xef6c92fb771d59f1,
+        0x425bedbac1dfdc7,
+        0xd3ac39de759c0ffd,
+        0x9f43ed0e063a81d0,
+        0x5bd7d20b4f9a3ce2,
+        0x411f03c36cf5c3c,
+        0x2d658fd49661c472,
+        0x1100249ae760b93,
+    ])),
+    Fq(FqRepr([
+        0x67a04ae427bfb5f8,
+        0x9d32d491eb6a5cff,
+        0x43d03c1cb68051d4,
+        0xb75ca96f69859a5,
+        0x763497f5325ec60,
+        0x48076b5c278dd94d,
+        0x8ca3965ff91efd06,
+        0x1e6077657ea02f5d,
+        0xcdd6c153a8c37724,
+        0x28b5b634e5c22ea4,
+        0x9e01e3efd42e902c,
+        0xe3d6815769a804,
+    ])),
+];
+
+// (-4)^((2 q^i - 2) / 3) for i in 0..3
+pub const FROBENIUS_COEFF_FQ3_C2: [Fq; 3] = [
+    Fq(FqRepr([
+        0x202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x51f77ef127e87d,
+    ])),
+    Fq(FqRepr([
+        0x67a04ae427bfb5f8,
+        0x9d32d491eb6a5cff,
+        0x43d03c1cb68051d4,
+        0xb75ca96f69859a5,
+        0x763497f5325ec60,
+        0x48076b5c278dd94d,
+        0x8ca3965ff91efd06,
+        0x1e6077657ea02f5d,
+        0xcdd6c153a8c37724,
+        0x28b5b634e5c22ea4,
+        0x9e01e3efd42e902c,
+        0xe3d6815769a804,
+    ])),
+    Fq(FqRepr([
+        0x7f96b51bd840c549,
+        0xd59782096496171f,
+        0x49b046fd9ce14bbc,
+        0x4b6163bba7527a56,
+        0xef6c92fb771d59f1,
+        0x425bedbac1dfdc7,
+        0xd3ac39de759c0ffd,
+        0x9f43ed0e063a81d0,
+        0x5bd7d20b4f9a3ce2,
+        0x411f03c36cf5c3c,
+        0x2d658fd49661c472,
+        0x1100249ae760b93,
+    ])),
+];
+
+// (-4)^((q^i - 1) / 6) for i in 0..6
+pub const FROBENIUS_COEFF_FQ6_C1: [Fq; 6] = [
+    Fq(FqRepr([
+        0x202ffffffff85d5,
+        0x5a5826358fff8ce7,
+        0x9e996e43827faade,
+        0xda6aff320ee47df4,
+        0xece9cb3e1d94b80b,
+        0xc0e667a25248240b,
+        0xa74da5bfdcad3905,
+        0x2352e7fe462f2103,
+        0x7b56588008b1c87c,
+        0x45848a63e711022f,
+        0xd7a81ebb9f65a9df,
+        0x51f77ef127e87d,
+    ])),
+    Fq(FqRepr([
+        0x8cfcb51bd8404a93,
+        0x495e69d68495a383,
+        0xd23cbc9234705263,
+        0x8d2b4c2b5fcf4f52,
+        0x6a798a5d20c612ce,
+        0x3e825d90eb6c2443,
+        0x772b249f2c9525fe,
+        0x521b2ed366e4b9bb,
+        0x84abb49bd7c4471d,
+        0x907062359c0f17e3,
+        0x3385e55030cc6f12,
+        0x3f11a3a41a2606,
+    ])),
+    Fq(FqRepr([
+        0x7f96b51bd840c549,
+        0xd59782096496171f,
+        0x49b046fd9ce14bbc,
+        0x4b6163bba7527a56,
+        0xef6c92fb771d59f1,
+        0x425bedbac1dfdc7,
+        0xd3ac39de759c0ffd,
+        0x9f43ed0e063a81d0,
+        0x5bd7d20b4f9a3ce2,
+        0x411f03c36cf5c3c,
+        0x2d658fd49661c472,
+        0x1100249ae760b93,
+    ])),
+    Fq(FqRepr([
+        0xf29a000000007ab6,
+        0x8c391832e000739b,
+        0x77738a6b6870f959,
+        0xbe36179047832b03,
+        0x84f3089e56574722,
+        0xc5a3614ac0b1d984,
+        0x5c81153f4906e9fe,
+        0x4d28be3a9f55c815,
+        0xd72c1d6f77d5f5c5,
+        0x73a18e069ac04458,
+        0xf9dfaa846595555f,
+        0xd0f0a60a5be58c,
+    ])),
+    Fq(FqRepr([
+        0x67a04ae427bfb5f8,
+        0x9d32d491eb6a5cff,
+        0x43d03c1cb68051d4,
+        0xb75ca96f69859a5,
+        0x763497f5325ec60,
+        0x48076b5c278dd94d,
+        0x8ca3965ff91efd06,
+        0x1e6077657ea02f5d,
+        0xcdd6c153a8c37724,
+        0x28b5b634e5c22ea4,
+        0x9e01e3efd42e902c,
+        0xe3d6815769a804,
+    ])),
+    Fq(FqRepr([
+        0x75064ae427bf3b42,
+        0x10f9bc5f0b69e963,
+        0xcc5cb1b14e0f587b,
+        0x4d3fb306af152ea1,
+        0x827040e0fccea53d,
+        0x82640a1166dbffc8,
+        0x30228120b0181307,
+        0xd137b92adf4a6748,
+        0xf6aaa3e430ed815e,
+        0xb514282e4b01ea4b,
+        0xa422396b6e993acc,
+        0x12e5db4d0dc277,
+    ])),
+];
+
+#[derive(PrimeField, Zeroize)]
+#[PrimeFieldModulus = "6891450384315732539396789682275657542479668912536150109513790160209623422243491736087683183289411687640864567753786613451161759120554247759349511699125301598951605099378508850372543631423596795951899700429969112842764913119068299"]
+#[PrimeFieldGenerator = "2"]
+pub struct Fq(pub(super) FqRepr);
+
+/// # Safety
+pub const unsafe fn transmute(r: FqRepr) -> Fq {
+    Fq(r)
+}
+
+#[test]
+fn test_b_coeff() {
+    let mut b = Fq::one();
+    b.negate();
+    assert_eq!(b, B_COEFF);
+    assert_eq!(Fq::from_repr(FqRepr::from(4)).unwrap(), B_COEFF_G2);
+}
+
+#[test]
+fn test_frob_coeffs() {
+    // u^3 = -4
+    let mut nr = Fq::from_repr(FqRepr::from(4)).unwrap();
+    nr.negate();
+
+    // (q - 1) / 3
+    let q_m1_over3 = [
+        0xa6df00000000002e,
+        0xa2306a22d000002b,
+        0x5caefd8fa3a58c12,
+        0x8835b240c777e2fd,
+        0xd09ef1497bf9550f,
+        0x822deda45ba8a9da,
+        0x569a3e550c916101,
+        0xd02937684c81a308,
+        0xc62b7ca52ad7ea15,
+        0xe8620823809b1782,
+        0x9b2d431556fe5514,
+        0x60f80c53d69a03,
+    ];
+
+    // -4 is a cubic non-residue, so u^3 = -4 defines Fq3
+    assert_ne!(nr.pow(q_m1_over3), Fq::one());
+
+    // (-4)^((q^i - 1) / 6) determines the other coefficients
+    let mut xi_pow = Fq::one();
+    for i in 0..6 {
+        let coeff = FROBENIUS_COEFF_FQ6_C1[i];
+        assert_eq!(coeff, xi_pow);
+        if i < 3 {
+            let mut c = coeff;
+            c.square();
+            assert_eq!(FROBENIUS_COEFF_FQ3_C1[i], c);
+            c.square();
+            assert_eq!(FROBENIUS_COEFF_FQ3_C2[i], c);
+        }
+
+        // Frobenius fixes Fq, so the exponents add up
+        xi_pow.mul_assign(&FROBENIUS_COEFF_FQ6_C1[1]);
+    }
+
+    // ((-4)^((q - 1) / 6))^2 = (-4)^((q - 1) / 3)
+    let mut c = FROBENIUS_COEFF_FQ6_C1[1];
+    c.square();
+    assert_eq!(c, nr.pow(q_m1_over3));
+
+    // v^q = -v when i = 3
+    let mut neg_one = Fq::one();
+    neg_one.negate();
+    assert_eq!(FROBENIUS_COEFF_FQ6_C1[3], neg_one);
+}
+
+#[test]
+fn test_fq_root_of_unity() {
+    use ff::SqrtField;
+
+    assert_eq!(Fq::S, 1);
+    assert_eq!(
+        Fq::multiplicative_generator(),
+        Fq::from_repr(FqRepr::from(2)).unwrap()
+    );
+    assert_eq!(
+        Fq::multiplicative_generator().pow([
+            0x7a4e800000000045,
+            0xf3489f3438000041,
+            0xb067c577578521b,
+            0x4c508b612b33d47c,
+            0x38ee69ee39f5ff97,
+            0x4344e476897cfec8,
+            0x81e75d7f92da1182,
+            0xb83dd31c72c2748c,
+            0x29413af7c043df20,
+            0x5c930c3540e8a344,
+            0x68c3e4a0027d7f9f,
+            0x9174127dc1e705,
+        ]),
+        Fq::root_of_unity()
+    );
+    assert_eq!(Fq::root_of_unity().pow([1 << Fq::S]), Fq::one());
+    assert!(Fq::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn fq_field_tests() {
+    crate::tests::field::random_field_tests::<Fq>();
+    crate::tests::field::random_sqrt_tests::<Fq>();
+    crate::tests::field::random_frobenius_tests::<Fq, _>(Fq::char(), 13);
+    crate::tests::field::from_str_tests::<Fq>();
+}
+
+#[test]
+fn test_fq_ordering() {
+    // FqRepr's ordering is well-tested, but we still need to make sure the Fq
+    // elements aren't being compared in Montgomery form.
+    for i in 0..100 {
+        assert!(
+            Fq::from_repr(FqRepr::from(i + 1)).unwrap() > Fq::from_repr(FqRepr::from(i)).unwrap()
+        );
+    }
+}
+
+#[test]
+fn fq_repr_tests() {
+    crate::tests::repr::random_repr_tests::<Fq, FqRepr>();
+}
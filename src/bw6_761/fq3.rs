@@ -0,0 +1,109 @@
+use super::fq::{Fq, FROBENIUS_COEFF_FQ3_C1, FROBENIUS_COEFF_FQ3_C2};
+use crate::tower::{CubicExtParameters, CubicExtension};
+use ff::Field;
+
+/// Parameters of Fq3 = Fq[u] / (u^3 + 4).
+#[derive(Copy, Clone, Debug)]
+pub struct Fq3Parameters;
+
+impl CubicExtParameters for Fq3Parameters {
+    type BaseField = Fq;
+
+    const NAME: &'static str = "Fq3";
+    const VAR: &'static str = "u";
+
+    /// Multiply an element of Fq by the cubic nonresidue u^3 = -4.
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &mut Fq) {
+        fe.double();
+        fe.double();
+        fe.negate();
+    }
+
+    #[inline(always)]
+    fn mul_by_frobenius_coeffs(c1: &mut Fq, c2: &mut Fq, power: usize) {
+        c1.mul_assign(&FROBENIUS_COEFF_FQ3_C1[power % 3]);
+        c2.mul_assign(&FROBENIUS_COEFF_FQ3_C2[power % 3]);
+    }
+}
+
+/// An element of Fq3, represented by c0 + c1 * u + c2 * u^(2).
+pub type Fq3 = CubicExtension<Fq3Parameters>;
+
+#[cfg(test)]
+use rand_core::SeedableRng;
+
+#[test]
+fn test_fq3_mul_nonresidue() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    let nqr = Fq3 {
+        c0: Fq::zero(),
+        c1: Fq::one(),
+        c2: Fq::zero(),
+    };
+
+    for _ in 0..1000 {
+        let mut a = Fq3::random(&mut rng);
+        let mut b = a;
+        a.mul_by_nonresidue();
+        b.mul_assign(&nqr);
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq3_mul_by_1() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..1000 {
+        let c1 = Fq::random(&mut rng);
+        let mut a = Fq3::random(&mut rng);
+        let mut b = a;
+
+        a.mul_by_1(&c1);
+        b.mul_assign(&Fq3 {
+            c0: Fq::zero(),
+            c1,
+            c2: Fq::zero(),
+        });
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn test_fq3_mul_by_01() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..1000 {
+        let c0 = Fq::random(&mut rng);
+        let c1 = Fq::random(&mut rng);
+        let mut a = Fq3::random(&mut rng);
+        let mut b = a;
+
+        a.mul_by_01(&c0, &c1);
+        b.mul_assign(&Fq3 {
+            c0,
+            c1,
+            c2: Fq::zero(),
+        });
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn fq3_field_tests() {
+    use ff::PrimeField;
+
+    crate::tests::field::random_field_tests::<Fq3>();
+    crate::tests::field::random_frobenius_tests::<Fq3, _>(Fq::char(), 13);
+}
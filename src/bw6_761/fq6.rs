@@ -0,0 +1,74 @@
+#[cfg(test)]
+use super::fq::Fq;
+use super::fq::FROBENIUS_COEFF_FQ6_C1;
+use super::fq3::Fq3;
+use crate::tower::{QuadraticExtParameters, QuadraticExtension};
+use ff::Field;
+
+/// Parameters of Fq6 = Fq3[v] / (v^2 - u).
+#[derive(Copy, Clone, Debug)]
+pub struct Fq6Parameters;
+
+impl QuadraticExtParameters for Fq6Parameters {
+    type BaseField = Fq3;
+
+    const NAME: &'static str = "Fq6";
+    const VAR: &'static str = "v";
+
+    #[inline(always)]
+    fn mul_base_field_by_nonresidue(fe: &mut Fq3) {
+        fe.mul_by_nonresidue();
+    }
+
+    #[inline(always)]
+    fn mul_by_frobenius_coeff(c1: &mut Fq3, power: usize) {
+        c1.c0.mul_assign(&FROBENIUS_COEFF_FQ6_C1[power % 6]);
+        c1.c1.mul_assign(&FROBENIUS_COEFF_FQ6_C1[power % 6]);
+        c1.c2.mul_assign(&FROBENIUS_COEFF_FQ6_C1[power % 6]);
+    }
+}
+
+/// An element of Fq6, represented by c0 + c1 * v.
+pub type Fq6 = QuadraticExtension<Fq6Parameters>;
+
+#[cfg(test)]
+use rand_core::SeedableRng;
+
+#[test]
+fn test_fq6_mul_by_014() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+    for _ in 0..1000 {
+        let c0 = Fq::random(&mut rng);
+        let c1 = Fq::random(&mut rng);
+        let c5 = Fq::random(&mut rng);
+        let mut a = Fq6::random(&mut rng);
+        let mut b = a;
+
+        a.mul_by_014(&c0, &c1, &c5);
+        b.mul_assign(&Fq6 {
+            c0: Fq3 {
+                c0,
+                c1,
+                c2: Fq::zero(),
+            },
+            c1: Fq3 {
+                c0: Fq::zero(),
+                c1: c5,
+                c2: Fq::zero(),
+            },
+        });
+
+        assert_eq!(a, b);
+    }
+}
+
+#[test]
+fn fq6_field_tests() {
+    use ff::PrimeField;
+
+    crate::tests::field::random_field_tests::<Fq6>();
+    crate::tests::field::random_frobenius_tests::<Fq6, _>(Fq::char(), 13);
+}
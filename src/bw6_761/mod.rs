@@ -0,0 +1,467 @@
+/*!
+The BW6-761 pairing-friendly curve, whose scalar field is the base field of
+BLS12-377, so that BLS12-377 proofs can be verified inside BW6-761 circuits.
+
+The curve is y^2 = x^3 - 1 over the 761-bit field Fq, with the M-type sextic
+twist y^2 = x^3 + 4 over Fq itself, so G1 and G2 both have coordinates in Fq.
+The target group lives in Fq6 = Fq3[v] / (v^2 - u), Fq3 = Fq[u] / (u^3 + 4).
+The pairing is the optimal ate pairing f_{x+1,Q}(P) * f_{x^3-x^2-x,Q}(P)^q,
+where x = 0x8508c00000000001 is the BLS12-377 parameter.
+*/
+
+mod ec;
+mod fq;
+mod fq3;
+mod fq6;
+
+pub use self::ec::{
+    G1Affine, G1Compressed, G1Prepared, G1Uncompressed, G2Affine, G2Compressed, G2Prepared,
+    G2Uncompressed, G1, G2,
+};
+pub use self::fq::{Fq, FqRepr};
+pub use self::fq3::{Fq3, Fq3Parameters};
+pub use self::fq6::{Fq6, Fq6Parameters};
+pub use crate::bls12_377::{Fq as Fr, FqRepr as FrRepr};
+
+pub mod transmute {
+    pub use super::ec::g1::transmute_affine as g1_affine;
+    pub use super::ec::g1::transmute_projective as g1_projective;
+    pub use super::ec::g2::transmute_affine as g2_affine;
+    pub use super::ec::g2::transmute_projective as g2_projective;
+    pub use super::fq::transmute as fq;
+    pub use crate::bls12_377::transmute::fq as fr;
+}
+
+use super::{CurveAffine, Engine};
+
+use ff::{BitIterator, Field, ScalarEngine};
+
+// The first Miller loop runs over x + 1, where x = 0x8508c00000000001 is the
+// BLS12-377 parameter
+const ATE_LOOP_COUNT_1: u64 = 0x8508c00000000002;
+
+// The second Miller loop runs over the NAF of
+// x^3 - x^2 - x = 0x23ed1347970dec008a442f991fffffffffffffffffffffff,
+// least significant digit first
+const ATE_LOOP_COUNT_2_NAF: [i8; 190] = [
+    -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0,
+    0, 1, 0, 0, -1, 0, 1, 0, -1, 0, 0, 0, 0, -1, 0, 1, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 1, 0, 1, 0, 0,
+    0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -1, 0, -1, 0, 0, 0, 0, -1, 0, 0, 1, 0, 0, 0, -1, 0, 0, -1,
+    0, 1, 0, -1, 0, 0, 0, 1, 0, 0, 1, 0, -1, 0, 1, 0, 1, 0, 0, 0, 1, 0, -1, 0, -1, 0, 0, 0, 0, 0,
+    1, 0, 0, 1,
+];
+
+// (q^2 - q + 1) / r, the hard part of the final exponentiation
+const FINAL_EXP_HARD: [u64; 18] = [
+    0xf335c00000004aef,
+    0xc14a69eb100066c1,
+    0x84d74e193dc6ff54,
+    0xb1ea2024801a659,
+    0x351f136eb9423dac,
+    0x75c1c8b32b6af1e2,
+    0x9660e55a2333ce25,
+    0xcbcdaa4d1bb2a327,
+    0xc3be81e9dcafdcc8,
+    0xe33fb491f2c5cc9,
+    0x77c4729e3955b7f0,
+    0xedeea0ca453b1ce2,
+    0x26b1eb1ee9c72b9,
+    0x3825a11a07c3de42,
+    0x9fac795e2fd52698,
+    0xb9bfdd86ce189a02,
+    0xd8c73eb8090bd134,
+    0xc4b3cb6f8d4fee,
+];
+
+#[derive(Clone, Debug)]
+pub struct Bw6_761;
+
+impl ScalarEngine for Bw6_761 {
+    type Fr = Fr;
+}
+
+impl Engine for Bw6_761 {
+    type G1 = G1;
+    type G1Affine = G1Affine;
+    type G2 = G2;
+    type G2Affine = G2Affine;
+    type Fq = Fq;
+    type Fqe = Fq;
+    type Fqk = Fq6;
+
+    fn miller_loop<'a, I>(i: I) -> Self::Fqk
+    where
+        I: IntoIterator<
+            Item = &'a (
+                &'a <Self::G1Affine as CurveAffine>::Prepared,
+                &'a <Self::G2Affine as CurveAffine>::Prepared,
+            ),
+        >,
+    {
+        let mut pairs = vec![];
+        for &(p, q) in i {
+            if !p.is_zero() && !q.is_zero() {
+                pairs.push((p, q.coeffs_1.iter(), q.coeffs_2.iter()));
+            }
+        }
+
+        // Twisting isomorphism from E to E'
+        fn ell(f: &mut Fq6, coeffs: &(Fq, Fq, Fq), p: &G1Affine) {
+            let mut c0 = coeffs.0;
+            let mut c1 = coeffs.1;
+
+            c0.mul_assign(&p.y);
+            c1.mul_assign(&p.x);
+
+            // Sparse multiplication in Fq6
+            f.mul_by_014(&coeffs.2, &c1, &c0);
+        }
+
+        // f_{x+1,Q}(P)
+        let mut f1 = Fq6::one();
+
+        let mut found_one = false;
+        for i in BitIterator::new(&[ATE_LOOP_COUNT_1]) {
+            if !found_one {
+                found_one = i;
+                continue;
+            }
+
+            f1.square();
+
+            for &mut (p, ref mut coeffs, _) in &mut pairs {
+                ell(&mut f1, coeffs.next().unwrap(), &p.0);
+            }
+
+            if i {
+                for &mut (p, ref mut coeffs, _) in &mut pairs {
+                    ell(&mut f1, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        // f_{x^3-x^2-x,Q}(P)
+        let mut f2 = Fq6::one();
+
+        for i in (0..ATE_LOOP_COUNT_2_NAF.len() - 1).rev() {
+            if i != ATE_LOOP_COUNT_2_NAF.len() - 2 {
+                f2.square();
+            }
+
+            for &mut (p, _, ref mut coeffs) in &mut pairs {
+                ell(&mut f2, coeffs.next().unwrap(), &p.0);
+            }
+
+            if ATE_LOOP_COUNT_2_NAF[i] != 0 {
+                for &mut (p, _, ref mut coeffs) in &mut pairs {
+                    ell(&mut f2, coeffs.next().unwrap(), &p.0);
+                }
+            }
+        }
+
+        f2.frobenius_map(1);
+        f1.mul_assign(&f2);
+
+        f1
+    }
+
+    fn final_exponentiation(r: &Fq6) -> Option<Fq6> {
+        let mut f1 = *r;
+        f1.conjugate();
+
+        match r.inverse() {
+            Some(mut f2) => {
+                // Easy part: r^((q^3 - 1) * (q + 1))
+                let mut r = f1;
+                r.mul_assign(&f2);
+                f2 = r;
+                r.frobenius_map(1);
+                r.mul_assign(&f2);
+
+                // Hard part: r^((q^2 - q + 1) / r)
+                Some(r.pow(FINAL_EXP_HARD))
+            }
+            None => None,
+        }
+    }
+}
+
+impl G2Prepared {
+    pub fn is_zero(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn from_affine(q: G2Affine) -> Self {
+        if q.is_zero() {
+            return G2Prepared {
+                coeffs_1: vec![],
+                coeffs_2: vec![],
+                infinity: true,
+            };
+        }
+
+        fn doubling_step(r: &mut G2) -> (Fq, Fq, Fq) {
+            // Adaptation of Algorithm 26, https://eprint.iacr.org/2010/354.pdf
+            let mut tmp0 = r.x;
+            tmp0.square();
+
+            let mut tmp1 = r.y;
+            tmp1.square();
+
+            let mut tmp2 = tmp1;
+            tmp2.square();
+
+            let mut tmp3 = tmp1;
+            tmp3.add_assign(&r.x);
+            tmp3.square();
+            tmp3.sub_assign(&tmp0);
+            tmp3.sub_assign(&tmp2);
+            tmp3.double();
+
+            let mut tmp4 = tmp0;
+            tmp4.double();
+            tmp4.add_assign(&tmp0);
+
+            let mut tmp6 = r.x;
+            tmp6.add_assign(&tmp4);
+
+            let mut tmp5 = tmp4;
+            tmp5.square();
+
+            let mut zsquared = r.z;
+            zsquared.square();
+
+            r.x = tmp5;
+            r.x.sub_assign(&tmp3);
+            r.x.sub_assign(&tmp3);
+
+            r.z.add_assign(&r.y);
+            r.z.square();
+            r.z.sub_assign(&tmp1);
+            r.z.sub_assign(&zsquared);
+
+            r.y = tmp3;
+            r.y.sub_assign(&r.x);
+            r.y.mul_assign(&tmp4);
+
+            tmp2.double();
+            tmp2.double();
+            tmp2.double();
+
+            r.y.sub_assign(&tmp2);
+
+            tmp3 = tmp4;
+            tmp3.mul_assign(&zsquared);
+            tmp3.double();
+            tmp3.negate();
+
+            tmp6.square();
+            tmp6.sub_assign(&tmp0);
+            tmp6.sub_assign(&tmp5);
+
+            tmp1.double();
+            tmp1.double();
+
+            tmp6.sub_assign(&tmp1);
+
+            tmp0 = r.z;
+            tmp0.mul_assign(&zsquared);
+            tmp0.double();
+
+            (tmp0, tmp3, tmp6)
+        }
+
+        fn addition_step(r: &mut G2, q: &G2Affine) -> (Fq, Fq, Fq) {
+            // Adaptation of Algorithm 27, https://eprint.iacr.org/2010/354.pdf
+            let mut zsquared = r.z;
+            zsquared.square();
+
+            let mut ysquared = q.y;
+            ysquared.square();
+
+            let mut t0 = zsquared;
+            t0.mul_assign(&q.x);
+
+            let mut t1 = q.y;
+            t1.add_assign(&r.z);
+            t1.square();
+            t1.sub_assign(&ysquared);
+            t1.sub_assign(&zsquared);
+            t1.mul_assign(&zsquared);
+
+            let mut t2 = t0;
+            t2.sub_assign(&r.x);
+
+            let mut t3 = t2;
+            t3.square();
+
+            let mut t4 = t3;
+            t4.double();
+            t4.double();
+
+            let mut t5 = t4;
+            t5.mul_assign(&t2);
+
+            let mut t6 = t1;
+            t6.sub_assign(&r.y);
+            t6.sub_assign(&r.y);
+
+            let mut t9 = t6;
+            t9.mul_assign(&q.x);
+
+            let mut t7 = t4;
+            t7.mul_assign(&r.x);
+
+            r.x = t6;
+            r.x.square();
+            r.x.sub_assign(&t5);
+            r.x.sub_assign(&t7);
+            r.x.sub_assign(&t7);
+
+            r.z.add_assign(&t2);
+            r.z.square();
+            r.z.sub_assign(&zsquared);
+            r.z.sub_assign(&t3);
+
+            let mut t10 = q.y;
+            t10.add_assign(&r.z);
+
+            let mut t8 = t7;
+            t8.sub_assign(&r.x);
+            t8.mul_assign(&t6);
+
+            t0 = r.y;
+            t0.mul_assign(&t5);
+            t0.double();
+
+            r.y = t8;
+            r.y.sub_assign(&t0);
+
+            t10.square();
+            t10.sub_assign(&ysquared);
+
+            let mut ztsquared = r.z;
+            ztsquared.square();
+
+            t10.sub_assign(&ztsquared);
+
+            t9.double();
+            t9.sub_assign(&t10);
+
+            t10 = r.z;
+            t10.double();
+
+            t6.negate();
+
+            t1 = t6;
+            t1.double();
+
+            (t10, t1, t9)
+        }
+
+        let mut coeffs_1 = vec![];
+        let mut r: G2 = q.into();
+
+        let mut found_one = false;
+        for i in BitIterator::new([ATE_LOOP_COUNT_1]) {
+            if !found_one {
+                found_one = i;
+                continue;
+            }
+
+            coeffs_1.push(doubling_step(&mut r));
+
+            if i {
+                coeffs_1.push(addition_step(&mut r, &q));
+            }
+        }
+
+        let mut coeffs_2 = vec![];
+        let mut r: G2 = q.into();
+        let mut negq = q;
+        negq.negate();
+
+        for i in (0..ATE_LOOP_COUNT_2_NAF.len() - 1).rev() {
+            coeffs_2.push(doubling_step(&mut r));
+
+            match ATE_LOOP_COUNT_2_NAF[i] {
+                1 => coeffs_2.push(addition_step(&mut r, &q)),
+                -1 => coeffs_2.push(addition_step(&mut r, &negq)),
+                _ => (),
+            }
+        }
+
+        G2Prepared {
+            coeffs_1,
+            coeffs_2,
+            infinity: false,
+        }
+    }
+}
+
+#[test]
+fn bw6_761_engine_tests() {
+    crate::tests::engine::engine_tests::<Bw6_761>();
+}
+
+#[test]
+fn test_ate_loop_counts() {
+    use ff::PrimeField;
+
+    let x = Fr::from_repr(FrRepr::from(0x8508c00000000001)).unwrap();
+    let mut expect = x;
+    expect.add_assign(&Fr::one());
+    assert_eq!(
+        Fr::from_repr(FrRepr::from(ATE_LOOP_COUNT_1)).unwrap(),
+        expect
+    );
+
+    // x^3 - x^2 - x
+    let mut x2 = x;
+    x2.square();
+    let mut expect = x2;
+    expect.mul_assign(&x);
+    expect.sub_assign(&x2);
+    expect.sub_assign(&x);
+
+    let two = Fr::from_repr(FrRepr::from(2)).unwrap();
+    let mut v = Fr::zero();
+    for d in ATE_LOOP_COUNT_2_NAF.iter().rev() {
+        v.mul_assign(&two);
+        match *d {
+            1 => v.add_assign(&Fr::one()),
+            -1 => v.sub_assign(&Fr::one()),
+            _ => (),
+        }
+    }
+    assert_eq!(v, expect);
+}
+
+#[test]
+fn test_final_exponentiation() {
+    use ff::PrimeField;
+    use rand_core::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    for _ in 0..4 {
+        let f = Fq6::random(&mut rng);
+        let e = Bw6_761::final_exponentiation(&f).unwrap();
+
+        // the result lies in the order-r subgroup of Fq6*
+        assert_eq!(e.pow(Fr::char()), Fq6::one());
+
+        // and agrees with raising to (q^3 - 1) (q + 1) directly
+        let mut easy = f.pow(Fq::char());
+        easy.mul_assign(&f);
+        let mut cube = easy;
+        cube.frobenius_map(3);
+        cube.mul_assign(&easy.inverse().unwrap());
+        assert_eq!(cube.pow(FINAL_EXP_HARD), e);
+    }
+}
@@ -0,0 +1,205 @@
+/*!
+ Named hash-to-curve ciphersuites for BLS12-381, following the suite
+ naming convention of RFC 9380, section 8.
+
+ Each suite binds a curve, an expand_message variant, a mapping (the
+ simplified SWU map plus isogeny for BLS12-381), and an encoding type.
+ The DST for a suite is built from an application tag as
+
+ ```text
+ DST = app_tag || "-with-" || suite ID
+ ```
+
+ e.g., app_tag "QUUX-V01-CS02" yields the DSTs used by the RFC test vectors.
+*/
+
+use crate::{
+    bls12_381::{G1, G2},
+    hash_to_curve::{HashToCurve, HashToCurveHasher},
+    hash_to_field::{ExpandMsg, ExpandMsgError, ExpandMsgXmd, ExpandMsgXof},
+};
+use alloc::vec::Vec;
+use sha2::Sha256;
+use sha3::Shake128;
+
+/// Encoding type of a hash-to-curve suite
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodingType {
+    /// hash_to_curve: random oracle encoding, suite ID ends in "RO_"
+    Uniform,
+    /// encode_to_curve: nonuniform encoding, suite ID ends in "NU_"
+    NonUniform,
+}
+
+/// A hash-to-curve ciphersuite
+pub trait HashToCurveSuite {
+    /// The curve (group) this suite hashes to
+    type Point: HashToCurve<Self::Expander>;
+
+    /// The expand_message variant used by hash_to_field
+    type Expander: ExpandMsg;
+
+    /// The suite ID, e.g., "BLS12381G1_XMD:SHA-256_SSWU_RO_"
+    const SUITE_ID: &'static str;
+
+    /// Whether this suite is a random oracle or a nonuniform encoding
+    const ENCODING: EncodingType;
+
+    /// Build the DST for this suite from an application tag
+    fn dst<Tt: AsRef<[u8]>>(app_tag: Tt) -> Vec<u8> {
+        let mut dst = app_tag.as_ref().to_vec();
+        dst.extend_from_slice(b"-with-");
+        dst.extend_from_slice(Self::SUITE_ID.as_bytes());
+        dst
+    }
+
+    /// Hash a message to the curve under the DST derived from app_tag
+    fn hash<Mt: AsRef<[u8]>, Tt: AsRef<[u8]>>(msg: Mt, app_tag: Tt) -> Self::Point {
+        Self::hash_with_dst(msg, Self::dst(app_tag))
+    }
+
+    /// Hash a message to the curve under the DST derived from app_tag;
+    /// returns an error if message expansion fails
+    fn try_hash<Mt: AsRef<[u8]>, Tt: AsRef<[u8]>>(
+        msg: Mt,
+        app_tag: Tt,
+    ) -> Result<Self::Point, ExpandMsgError> {
+        Self::try_hash_with_dst(msg, Self::dst(app_tag))
+    }
+
+    /// Hash a message to the curve under a caller-supplied DST
+    fn hash_with_dst<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(msg: Mt, dst: Dt) -> Self::Point {
+        match Self::ENCODING {
+            EncodingType::Uniform => Self::Point::hash_to_curve(msg, dst),
+            EncodingType::NonUniform => Self::Point::encode_to_curve(msg, dst),
+        }
+    }
+
+    /// Hash a message to the curve under a caller-supplied DST;
+    /// returns an error if message expansion fails
+    fn try_hash_with_dst<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(
+        msg: Mt,
+        dst: Dt,
+    ) -> Result<Self::Point, ExpandMsgError> {
+        match Self::ENCODING {
+            EncodingType::Uniform => Self::Point::try_hash_to_curve(msg, dst),
+            EncodingType::NonUniform => Self::Point::try_encode_to_curve(msg, dst),
+        }
+    }
+
+    /// Returns a hasher for streaming message input; finalize it with
+    /// finalize or try_finalize
+    fn hasher() -> HashToCurveHasher<Self::Point, Self::Expander> {
+        HashToCurveHasher::new()
+    }
+
+    /// Map the message fed into hasher to the curve under the DST derived from app_tag
+    fn finalize<Tt: AsRef<[u8]>>(
+        hasher: HashToCurveHasher<Self::Point, Self::Expander>,
+        app_tag: Tt,
+    ) -> Self::Point {
+        match Self::ENCODING {
+            EncodingType::Uniform => hasher.hash_to_curve(Self::dst(app_tag)),
+            EncodingType::NonUniform => hasher.encode_to_curve(Self::dst(app_tag)),
+        }
+    }
+
+    /// Map the message fed into hasher to the curve under the DST derived from app_tag;
+    /// returns an error if message expansion fails
+    fn try_finalize<Tt: AsRef<[u8]>>(
+        hasher: HashToCurveHasher<Self::Point, Self::Expander>,
+        app_tag: Tt,
+    ) -> Result<Self::Point, ExpandMsgError> {
+        match Self::ENCODING {
+            EncodingType::Uniform => hasher.try_hash_to_curve(Self::dst(app_tag)),
+            EncodingType::NonUniform => hasher.try_encode_to_curve(Self::dst(app_tag)),
+        }
+    }
+}
+
+macro_rules! hash_to_curve_suite {
+    ($(#[$attr:meta])* $name:ident, $point:ty, $expander:ty, $suite_id:expr, $encoding:expr) => {
+        $(#[$attr])*
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name;
+
+        impl HashToCurveSuite for $name {
+            type Point = $point;
+            type Expander = $expander;
+            const SUITE_ID: &'static str = $suite_id;
+            const ENCODING: EncodingType = $encoding;
+        }
+    };
+}
+
+hash_to_curve_suite!(
+    /// BLS12381G1_XMD:SHA-256_SSWU_RO_
+    Bls12381G1XmdSha256SswuRo,
+    G1,
+    ExpandMsgXmd<Sha256>,
+    "BLS12381G1_XMD:SHA-256_SSWU_RO_",
+    EncodingType::Uniform
+);
+
+hash_to_curve_suite!(
+    /// BLS12381G1_XMD:SHA-256_SSWU_NU_
+    Bls12381G1XmdSha256SswuNu,
+    G1,
+    ExpandMsgXmd<Sha256>,
+    "BLS12381G1_XMD:SHA-256_SSWU_NU_",
+    EncodingType::NonUniform
+);
+
+hash_to_curve_suite!(
+    /// BLS12381G2_XMD:SHA-256_SSWU_RO_
+    Bls12381G2XmdSha256SswuRo,
+    G2,
+    ExpandMsgXmd<Sha256>,
+    "BLS12381G2_XMD:SHA-256_SSWU_RO_",
+    EncodingType::Uniform
+);
+
+hash_to_curve_suite!(
+    /// BLS12381G2_XMD:SHA-256_SSWU_NU_
+    Bls12381G2XmdSha256SswuNu,
+    G2,
+    ExpandMsgXmd<Sha256>,
+    "BLS12381G2_XMD:SHA-256_SSWU_NU_",
+    EncodingType::NonUniform
+);
+
+hash_to_curve_suite!(
+    /// BLS12381G1_XOF:SHAKE-128_SSWU_RO_
+    Bls12381G1XofShake128SswuRo,
+    G1,
+    ExpandMsgXof<Shake128>,
+    "BLS12381G1_XOF:SHAKE-128_SSWU_RO_",
+    EncodingType::Uniform
+);
+
+hash_to_curve_suite!(
+    /// BLS12381G1_XOF:SHAKE-128_SSWU_NU_
+    Bls12381G1XofShake128SswuNu,
+    G1,
+    ExpandMsgXof<Shake128>,
+    "BLS12381G1_XOF:SHAKE-128_SSWU_NU_",
+    EncodingType::NonUniform
+);
+
+hash_to_curve_suite!(
+    /// BLS12381G2_XOF:SHAKE-128_SSWU_RO_
+    Bls12381G2XofShake128SswuRo,
+    G2,
+    ExpandMsgXof<Shake128>,
+    "BLS12381G2_XOF:SHAKE-128_SSWU_RO_",
+    EncodingType::Uniform
+);
+
+hash_to_curve_suite!(
+    /// BLS12381G2_XOF:SHAKE-128_SSWU_NU_
+    Bls12381G2XofShake128SswuNu,
+    G2,
+    ExpandMsgXof<Shake128>,
+    "BLS12381G2_XOF:SHAKE-128_SSWU_NU_",
+    EncodingType::NonUniform
+);
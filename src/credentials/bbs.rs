@@ -0,0 +1,768 @@
+/*!
+BBS signatures, after the IRTF CFRG draft "The BBS Signature Scheme"
+(draft-irtf-cfrg-bbs-signatures), in its BLS12381G1_XMD:SHA-256 ciphersuite
+with messages mapped to scalars by hashing.
+
+A secret key `SK` in `Fr` has the public key `W = SK * P2` in G2. A
+signature on messages `m_1..m_L` under a header is `(A, e)` with
+
+```text
+domain = H(W || L || Q_1 || H_1 .. H_L || api_id || len(header) || header)
+e      = H(SK || m_1 .. m_L || domain)
+B      = P1 + Q_1 * domain + H_1 * m_1 + .. + H_L * m_L
+A      = B * 1 / (SK + e)
+```
+
+and verifies with `e(A, W + e * P2) == e(B, P2)`. `H` is the draft's
+`hash_to_scalar`: 48 bytes of expand_message_xmd with SHA-256 under
+`BBS_API_ID || "H2S_"`, reduced mod r. The generators `Q_1` and `H_i` come
+from the draft's `create_generators`, and `P1` is the suite's base point,
+derived the same way from its own seed. Lengths are 8-byte big-endian
+integers, scalars are 32 bytes big-endian, and points are compressed.
+
+Messages are scalars; `messages_to_scalars` maps octet strings to them as
+the draft does.
+
+A proof of knowledge of a signature randomizes it into `(Abar, Bbar, D)`
+with `e(Abar, W) == e(Bbar, P2)` and proves, with a Schnorr proof, knowledge
+of `e`, the randomness, and the hidden messages. The challenge is
+`H(R || i_1 || m_i1 .. i_R || m_iR || Abar || Bbar || D || T1 || T2 ||
+domain || len(ph) || ph)` over the `R` disclosed messages, with 0-based
+indices, and the presentation header `ph`.
+*/
+
+use super::{undisclosed, CredentialError};
+use crate::{
+    bls12_381::{Bls12, Fr, G1Affine, G2Affine, G1, G2},
+    hash_to_curve::HashToCurve,
+    hash_to_field::{ExpandMsg, ExpandMsgXmd},
+    hash_to_scalar::HashToScalar,
+    threshold::{mul_generator, sum_of_products},
+    CurveAffine, CurveProjective, Engine,
+};
+use alloc::vec::Vec;
+use ff::Field;
+use rand_core::RngCore;
+use sha2::Sha256;
+
+/// The draft's `api_id` of the ciphersuite, which prefixes every DST of the
+/// scheme.
+pub const BBS_API_ID: &[u8] = b"BBS_BLS12381G1_XMD:SHA-256_SSWU_RO_H2G_HM2S_";
+
+/// The generators for signing `h.len()` messages.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generators {
+    pub p1: G1Affine,
+    pub q1: G1Affine,
+    pub h: Vec<G1Affine>,
+}
+
+/// A signature `(A, e)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub a: G1,
+    pub e: Fr,
+}
+
+/// A proof of knowledge of a signature, disclosing some of the messages.
+/// `m_hat` holds the responses for the hidden messages, in index order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub abar: G1,
+    pub bbar: G1,
+    pub d: G1,
+    pub e_hat: Fr,
+    pub r1_hat: Fr,
+    pub r3_hat: Fr,
+    pub m_hat: Vec<Fr>,
+    pub c: Fr,
+}
+
+impl Generators {
+    /// Derives the generators for `count` messages. The first ones do not
+    /// depend on `count`.
+    pub fn new(count: usize) -> Self {
+        let p1 = create_generators(b"BP_MESSAGE_GENERATOR_SEED", 1)[0];
+        let mut gens = create_generators(b"MESSAGE_GENERATOR_SEED", count + 1);
+        let q1 = gens.remove(0);
+        Generators { p1, q1, h: gens }
+    }
+
+    /// The number of messages.
+    pub fn count(&self) -> usize {
+        self.h.len()
+    }
+
+    /// `P1 + Q_1 * domain + sum_i H_i * m_i` over the given messages.
+    fn b(&self, domain: &Fr, messages: &[(usize, Fr)]) -> G1 {
+        let mut bases = vec![self.p1, self.q1];
+        let mut scalars = vec![Fr::one(), *domain];
+        for &(i, m) in messages {
+            bases.push(self.h[i]);
+            scalars.push(m);
+        }
+        sum_of_products(&bases, &scalars)
+    }
+}
+
+impl Signature {
+    /// The draft's encoding: `A` compressed, then `e`.
+    pub fn to_bytes(&self) -> [u8; 80] {
+        let mut bytes = [0u8; 80];
+        bytes[..48].copy_from_slice(&self.a.into_affine().to_compressed_bytes());
+        bytes[48..].copy_from_slice(&self.e.to_bytes_be());
+        bytes
+    }
+}
+
+impl Proof {
+    /// The draft's encoding: `Abar`, `Bbar` and `D` compressed, then
+    /// `e^`, `r1^`, `r3^`, the `m^_j` and `c`.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(3 * 48 + (self.m_hat.len() + 4) * 32);
+        for p in [self.abar, self.bbar, self.d].iter() {
+            bytes.extend_from_slice(&p.into_affine().to_compressed_bytes());
+        }
+        for s in [self.e_hat, self.r1_hat, self.r3_hat]
+            .iter()
+            .chain(self.m_hat.iter())
+            .chain(Some(&self.c))
+        {
+            bytes.extend_from_slice(&s.to_bytes_be());
+        }
+        bytes
+    }
+}
+
+/// The draft's `create_generators`: each generator is the hash to G1 of a
+/// seed, and each seed is expanded from the previous one.
+fn create_generators(seed: &[u8], count: usize) -> Vec<G1Affine> {
+    let seed_dst = dst(b"SIG_GENERATOR_SEED_");
+    let generator_dst = dst(b"SIG_GENERATOR_DST_");
+    let mut v = ExpandMsgXmd::<Sha256>::expand_message(&dst(seed), &seed_dst, 48);
+    (1..=count as u64)
+        .map(|i| {
+            v.extend_from_slice(&i.to_be_bytes());
+            v = ExpandMsgXmd::<Sha256>::expand_message(&v, &seed_dst, 48);
+            <G1 as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(&v, &generator_dst)
+                .into_affine()
+        })
+        .collect()
+}
+
+/// The draft's `KeyGen` with its default DST. `key_material` must have at
+/// least 32 bytes, and `key_info` at most 65535.
+pub fn key_gen(key_material: &[u8], key_info: &[u8]) -> Result<Fr, CredentialError> {
+    if key_material.len() < 32 || key_info.len() > u16::MAX as usize {
+        return Err(CredentialError::InvalidKeyMaterial);
+    }
+    let mut input = key_material.to_vec();
+    input.extend_from_slice(&(key_info.len() as u16).to_be_bytes());
+    input.extend_from_slice(key_info);
+    Ok(hash_to_scalar(&input, b"KEYGEN_DST_"))
+}
+
+/// The public key `sk * P2`.
+pub fn public_key(sk: &Fr) -> G2 {
+    mul_generator(sk)
+}
+
+/// Maps octet-string messages to scalars, as the draft's
+/// `messages_to_scalars`.
+pub fn messages_to_scalars<M: AsRef<[u8]>>(messages: &[M]) -> Vec<Fr> {
+    messages
+        .iter()
+        .map(|m| hash_to_scalar(m.as_ref(), b"MAP_MSG_TO_SCALAR_AS_HASH_"))
+        .collect()
+}
+
+/// Signs `messages` under `header`.
+pub fn sign(
+    sk: &Fr,
+    pk: &G2,
+    gens: &Generators,
+    header: &[u8],
+    messages: &[Fr],
+) -> Result<Signature, CredentialError> {
+    check_count(gens, messages.len())?;
+
+    let domain = domain(pk, gens, header);
+    let mut input = sk.to_bytes_be().to_vec();
+    for m in messages {
+        input.extend_from_slice(&m.to_bytes_be());
+    }
+    input.extend_from_slice(&domain.to_bytes_be());
+    let e = hash_to_scalar(&input, b"H2S_");
+
+    let mut a = gens.b(&domain, &indexed(messages));
+    let mut exp = *sk;
+    exp.add_assign(&e);
+    // sk + e is zero with negligible probability
+    a.mul_assign(exp.inverse().expect("sk + e is zero"));
+    Ok(Signature { a, e })
+}
+
+/// Verifies a signature on `messages` under `header`.
+pub fn verify(pk: &G2, gens: &Generators, header: &[u8], messages: &[Fr], sig: &Signature) -> bool {
+    if messages.len() != gens.count() || sig.a.is_zero() {
+        return false;
+    }
+
+    let b = gens.b(&domain(pk, gens, header), &indexed(messages));
+    let mut w = mul_generator::<G2>(&sig.e);
+    w.add_assign(pk);
+    let mut p2 = G2Affine::one();
+    p2.negate();
+    Bls12::pairing_product(sig.a, w, b, p2.into_projective()) == <Bls12 as Engine>::Fqk::one()
+}
+
+/// Proves knowledge of a signature on `messages`, disclosing those at the
+/// indices `disclosed`, which must be increasing.
+#[allow(clippy::too_many_arguments)]
+pub fn prove<R: RngCore + ?Sized>(
+    pk: &G2,
+    sig: &Signature,
+    gens: &Generators,
+    header: &[u8],
+    ph: &[u8],
+    messages: &[Fr],
+    disclosed: &[usize],
+    rng: &mut R,
+) -> Result<Proof, CredentialError> {
+    let count = 5 + messages.len().saturating_sub(disclosed.len());
+    let random: Vec<Fr> = (0..count).map(|_| Fr::random(rng)).collect();
+    prove_with_scalars(pk, sig, gens, header, ph, messages, disclosed, &random)
+}
+
+/// `prove` with the random scalars `(r1, r2, e~, r1~, r3~, m~_j..)` given,
+/// one `m~_j` for each hidden message.
+#[allow(clippy::too_many_arguments)]
+fn prove_with_scalars(
+    pk: &G2,
+    sig: &Signature,
+    gens: &Generators,
+    header: &[u8],
+    ph: &[u8],
+    messages: &[Fr],
+    disclosed: &[usize],
+    random: &[Fr],
+) -> Result<Proof, CredentialError> {
+    check_count(gens, messages.len())?;
+    let hidden = undisclosed(messages.len(), disclosed)?;
+    let (r1, r2, e_tilde, r1_tilde, r3_tilde) =
+        (random[0], random[1], random[2], random[3], random[4]);
+    let m_tilde = &random[5..];
+
+    let domain = domain(pk, gens, header);
+    // D = B * r2, Abar = A * r1 * r2, Bbar = D * r1 - Abar * e
+    let mut d = gens.b(&domain, &indexed(messages));
+    d.mul_assign(r2);
+    let mut r1r2 = r1;
+    r1r2.mul_assign(&r2);
+    let mut abar = sig.a;
+    abar.mul_assign(r1r2);
+    let mut neg_e = sig.e;
+    neg_e.negate();
+    let bbar = sum_of_products(&[d.into_affine(), abar.into_affine()], &[r1, neg_e]);
+
+    // T1 = Abar * e~ + D * r1~, T2 = D * r3~ + sum_j H_j * m~_j
+    let t1 = sum_of_products(&[abar.into_affine(), d.into_affine()], &[e_tilde, r1_tilde]);
+    let mut bases = vec![d.into_affine()];
+    let mut scalars = vec![r3_tilde];
+    for (&j, m) in hidden.iter().zip(m_tilde.iter()) {
+        bases.push(gens.h[j]);
+        scalars.push(*m);
+    }
+    let t2 = sum_of_products(&bases, &scalars);
+
+    let revealed: Vec<(usize, Fr)> = disclosed.iter().map(|&i| (i, messages[i])).collect();
+    let c = challenge(&abar, &bbar, &d, &t1, &t2, &revealed, &domain, ph);
+
+    // r3 = 1 / r2, and the responses
+    let r3 = r2.inverse().expect("r2 is zero");
+    let response = |tilde: &Fr, secret: &Fr, negate: bool| {
+        let mut t = *secret;
+        t.mul_assign(&c);
+        if negate {
+            t.negate();
+        }
+        t.add_assign(tilde);
+        t
+    };
+    Ok(Proof {
+        abar,
+        bbar,
+        d,
+        e_hat: response(&e_tilde, &sig.e, false),
+        r1_hat: response(&r1_tilde, &r1, true),
+        r3_hat: response(&r3_tilde, &r3, true),
+        m_hat: hidden
+            .iter()
+            .zip(m_tilde.iter())
+            .map(|(&j, m)| response(m, &messages[j], false))
+            .collect(),
+        c,
+    })
+}
+
+/// Verifies a proof of knowledge of a signature under `header`, with the
+/// presentation header `ph`, on the `disclosed` messages `(index, message)`.
+pub fn verify_proof(
+    pk: &G2,
+    proof: &Proof,
+    gens: &Generators,
+    header: &[u8],
+    ph: &[u8],
+    disclosed: &[(usize, Fr)],
+) -> bool {
+    let indices: Vec<usize> = disclosed.iter().map(|&(i, _)| i).collect();
+    let hidden = match undisclosed(gens.count(), &indices) {
+        Ok(hidden) => hidden,
+        Err(_) => return false,
+    };
+    if proof.m_hat.len() != hidden.len() || proof.abar.is_zero() {
+        return false;
+    }
+
+    let domain = domain(pk, gens, header);
+    // T1 = Bbar * c + Abar * e^ + D * r1^
+    let t1 = sum_of_products(
+        &[
+            proof.bbar.into_affine(),
+            proof.abar.into_affine(),
+            proof.d.into_affine(),
+        ],
+        &[proof.c, proof.e_hat, proof.r1_hat],
+    );
+    // T2 = (P1 + Q_1 * domain + sum_i H_i * m_i) * c + D * r3^
+    //      + sum_j H_j * m^_j
+    let mut bases = vec![gens.p1, gens.q1, proof.d.into_affine()];
+    let mut dc = domain;
+    dc.mul_assign(&proof.c);
+    let mut scalars = vec![proof.c, dc, proof.r3_hat];
+    for &(i, m) in disclosed {
+        let mut mc = m;
+        mc.mul_assign(&proof.c);
+        bases.push(gens.h[i]);
+        scalars.push(mc);
+    }
+    for (&j, m) in hidden.iter().zip(proof.m_hat.iter()) {
+        bases.push(gens.h[j]);
+        scalars.push(*m);
+    }
+    let t2 = sum_of_products(&bases, &scalars);
+
+    let c = challenge(
+        &proof.abar,
+        &proof.bbar,
+        &proof.d,
+        &t1,
+        &t2,
+        disclosed,
+        &domain,
+        ph,
+    );
+    if c != proof.c {
+        return false;
+    }
+
+    let mut p2 = G2Affine::one();
+    p2.negate();
+    Bls12::pairing_product(proof.abar, *pk, proof.bbar, p2.into_projective())
+        == <Bls12 as Engine>::Fqk::one()
+}
+
+fn check_count(gens: &Generators, count: usize) -> Result<(), CredentialError> {
+    if count != gens.count() {
+        return Err(CredentialError::MessageCount {
+            expected: gens.count(),
+            actual: count,
+        });
+    }
+    Ok(())
+}
+
+fn indexed(messages: &[Fr]) -> Vec<(usize, Fr)> {
+    messages.iter().cloned().enumerate().collect()
+}
+
+/// `H(W || L || Q_1 || H_1 .. H_L || api_id || len(header) || header)`
+fn domain(pk: &G2, gens: &Generators, header: &[u8]) -> Fr {
+    let mut input = pk.into_affine().to_compressed_bytes().to_vec();
+    input.extend_from_slice(&(gens.count() as u64).to_be_bytes());
+    input.extend_from_slice(&gens.q1.to_compressed_bytes());
+    for h in gens.h.iter() {
+        input.extend_from_slice(&h.to_compressed_bytes());
+    }
+    input.extend_from_slice(BBS_API_ID);
+    input.extend_from_slice(&(header.len() as u64).to_be_bytes());
+    input.extend_from_slice(header);
+    hash_to_scalar(&input, b"H2S_")
+}
+
+/// `H(R || i_1 || m_i1 .. i_R || m_iR || Abar || Bbar || D || T1 || T2 ||
+/// domain || len(ph) || ph)`
+#[allow(clippy::too_many_arguments)]
+fn challenge(
+    abar: &G1,
+    bbar: &G1,
+    d: &G1,
+    t1: &G1,
+    t2: &G1,
+    disclosed: &[(usize, Fr)],
+    domain: &Fr,
+    ph: &[u8],
+) -> Fr {
+    let mut input = (disclosed.len() as u64).to_be_bytes().to_vec();
+    for &(i, m) in disclosed {
+        input.extend_from_slice(&(i as u64).to_be_bytes());
+        input.extend_from_slice(&m.to_bytes_be());
+    }
+    for p in [abar, bbar, d, t1, t2].iter() {
+        input.extend_from_slice(&p.into_affine().to_compressed_bytes());
+    }
+    input.extend_from_slice(&domain.to_bytes_be());
+    input.extend_from_slice(&(ph.len() as u64).to_be_bytes());
+    input.extend_from_slice(ph);
+    hash_to_scalar(&input, b"H2S_")
+}
+
+/// The draft's `hash_to_scalar` under `BBS_API_ID || suffix`.
+fn hash_to_scalar(input: &[u8], suffix: &[u8]) -> Fr {
+    <Fr as HashToScalar<ExpandMsgXmd<Sha256>>>::hash_to_scalar(input, dst(suffix))
+}
+
+fn dst(suffix: &[u8]) -> Vec<u8> {
+    [BBS_API_ID, suffix].concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_rng;
+
+    // The fixtures of the draft's BLS12-381-SHA-256 ciphersuite.
+    const KEY_MATERIAL: &[u8] = b"this-IS-just-an-Test-IKM-to-generate-$e(r@t#-key";
+    const KEY_INFO: &[u8] = b"this-IS-some-key-metadata-to-be-used-in-test-key-gen";
+    const SK: &str = "60e55110f76883a13d030b2f6bd11883422d5abde717569fc0731f51237169fc";
+    const PK: &str = "a820f230f6ae38503b86c70dc50b61c58a77e45c39ab25c0652bbaa8fa136f2851bd4781c9dcde39fc9d1d52c9e60268061e7d7632171d91aa8d460acee0e96f1e7c4cfb12d3ff9ab5d5dc91c277db75c845d649ef3c4f63aebc364cd55ded0c";
+    const HEADER: &str = "11223344556677889900aabbccddeeff";
+    const MESSAGES: [&str; 10] = [
+        "9872ad089e452c7b6e283dfac2a80d58e8d0ff71cc4d5e310a1debdda4a45f02",
+        "c344136d9ab02da4dd5908bbba913ae6f58c2cc844b802a6f811f5fb075f9b80",
+        "7372e9daa5ed31e6cd5c825eac1b855e84476a1d94932aa348e07b73",
+        "77fe97eb97a1ebe2e81e4e3597a3ee740a66e9ef2412472c",
+        "496694774c5604ab1b2544eababcf0f53278ff50",
+        "515ae153e22aae04ad16f759e07237b4",
+        "d183ddc6e2665aa4e2f088af",
+        "ac55fb33a75909ed",
+        "96012096",
+        "",
+    ];
+
+    fn hex(s: &str) -> Vec<u8> {
+        hex::decode(s).unwrap()
+    }
+
+    fn fixture_messages() -> Vec<Vec<u8>> {
+        MESSAGES.iter().map(|m| hex(m)).collect()
+    }
+
+    fn fixture_key() -> (Fr, G2) {
+        let sk = key_gen(KEY_MATERIAL, KEY_INFO).unwrap();
+        (sk, public_key(&sk))
+    }
+
+    /// The draft's mocked random scalars, which its proof fixtures use.
+    fn mocked_scalars(count: usize) -> Vec<Fr> {
+        <Fr as HashToScalar<ExpandMsgXmd<Sha256>>>::hash_to_scalars(
+            b"3.141592653589793238462643383279",
+            dst(b"MOCK_RANDOM_SCALARS_DST_"),
+            count,
+        )
+    }
+
+    #[test]
+    fn test_fixture_generators() {
+        let gens = Generators::new(2);
+        let expected = [
+            (gens.p1, "a8ce256102840821a3e94ea9025e4662b205762f9776b3a766c872b948f1fd225e7c59698588e70d11406d161b4e28c9"),
+            (gens.q1, "a9ec65b70a7fbe40c874c9eb041c2cb0a7af36ccec1bea48fa2ba4c2eb67ef7f9ecb17ed27d38d27cdeddff44c8137be"),
+            (gens.h[0], "98cd5313283aaf5db1b3ba8611fe6070d19e605de4078c38df36019fbaad0bd28dd090fd24ed27f7f4d22d5ff5dea7d4"),
+            (gens.h[1], "a31fbe20c5c135bcaa8d9fc4e4ac665cc6db0226f35e737507e803044093f37697a9d452490a970eea6f9ad6c3dcaa3a"),
+        ];
+        for (p, bytes) in expected.iter() {
+            assert_eq!(p.to_compressed_bytes().to_vec(), hex(bytes));
+        }
+    }
+
+    #[test]
+    fn test_fixture_key_gen() {
+        let (sk, pk) = fixture_key();
+        assert_eq!(sk.to_bytes_be().to_vec(), hex(SK));
+        assert_eq!(pk.into_affine().to_compressed_bytes().to_vec(), hex(PK));
+
+        assert_eq!(
+            key_gen(&KEY_MATERIAL[..31], KEY_INFO),
+            Err(CredentialError::InvalidKeyMaterial)
+        );
+        assert_eq!(
+            key_gen(KEY_MATERIAL, &[0u8; 65536]),
+            Err(CredentialError::InvalidKeyMaterial)
+        );
+    }
+
+    #[test]
+    fn test_fixture_messages_to_scalars() {
+        let expected = [
+            "1cb5bb86114b34dc438a911617655a1db595abafac92f47c5001799cf624b430",
+            "154249d503c093ac2df516d4bb88b510d54fd97e8d7121aede420a25d9521952",
+            "0c7c4c85cdab32e6fdb0de267b16fa3212733d4e3a3f0d0f751657578b26fe22",
+            "4a196deafee5c23f630156ae13be3e46e53b7e39094d22877b8cba7f14640888",
+            "34c5ea4f2ba49117015a02c711bb173c11b06b3f1571b88a2952b93d0ed4cf7e",
+            "4045b39b83055cd57a4d0203e1660800fabe434004dbdc8730c21ce3f0048b08",
+            "064621da4377b6b1d05ecc37cf3b9dfc94b9498d7013dc5c4a82bf3bb1750743",
+            "34ac9196ace0a37e147e32319ea9b3d8cc7d21870d3c3ba071246859cca49b02",
+            "57eb93f417c43200e9784fa5ea5a59168d3dbc38df707a13bb597c871b2a5f74",
+            "08e3afeb2b4f2b5f907924ef42856616e6f2d5f1fb373736db1cca32707a7d16",
+        ];
+        let scalars = messages_to_scalars(&fixture_messages());
+        for (m, e) in scalars.iter().zip(expected.iter()) {
+            assert_eq!(m.to_bytes_be().to_vec(), hex(e));
+        }
+    }
+
+    #[test]
+    fn test_fixture_signatures() {
+        let (sk, pk) = fixture_key();
+        let messages = messages_to_scalars(&fixture_messages());
+        let header = hex(HEADER);
+        let expected = [
+            (1, "84773160b824e194073a57493dac1a20b667af70cd2352d8af241c77658da5253aa8458317cca0eae615690d55b1f27164657dcafee1d5c1973947aa70e2cfbb4c892340be5969920d0916067b4565a0"),
+            (10, "8339b285a4acd89dec7777c09543a43e3cc60684b0a6f8ab335da4825c96e1463e28f8c5f4fd0641d19cec5920d3a8ff4bedb6c9691454597bbd298288abed3632078557b2ace7d44caed846e1a0a1e8"),
+        ];
+        for &(count, bytes) in expected.iter() {
+            let gens = Generators::new(count);
+            let messages = &messages[..count];
+            let sig = sign(&sk, &pk, &gens, &header, messages).unwrap();
+            assert_eq!(sig.to_bytes().to_vec(), hex(bytes));
+            assert!(verify(&pk, &gens, &header, messages, &sig));
+        }
+    }
+
+    #[test]
+    fn test_fixture_proof() {
+        // With the mocked random scalars, proving is deterministic.
+        let (sk, pk) = fixture_key();
+        let messages = messages_to_scalars(&fixture_messages());
+        let header = hex(HEADER);
+        let ph = hex("bed231d880675ed101ead304512e043ade9958dd0241ea70b4b3957fba941501");
+
+        let gens = Generators::new(1);
+        let sig = sign(&sk, &pk, &gens, &header, &messages[..1]).unwrap();
+        let proof = prove_with_scalars(
+            &pk,
+            &sig,
+            &gens,
+            &header,
+            &ph,
+            &messages[..1],
+            &[0],
+            &mocked_scalars(5),
+        )
+        .unwrap();
+        assert_eq!(proof.to_bytes(), hex("94916292a7a6bade28456c601d3af33fcf39278d6594b467e128a3f83686a104ef2b2fcf72df0215eeaf69262ffe8194a19fab31a82ddbe06908985abc4c9825788b8a1610942d12b7f5debbea8985296361206dbace7af0cc834c80f33e0aadaeea5597befbb651827b5eed5a66f1a959bb46cfd5ca1a817a14475960f69b32c54db7587b5ee3ab665fbd37b506830a49f21d592f5e634f47cee05a025a2f8f94e73a6c15f02301d1178a92873b6e8634bafe4983c3e15a663d64080678dbf29417519b78af042be2b3e1c4d08b8d520ffab008cbaaca5671a15b22c239b38e940cfeaa5e72104576a9ec4a6fad78c532381aeaa6fb56409cef56ee5c140d455feeb04426193c57086c9b6d397d9418"));
+        assert!(verify_proof(
+            &pk,
+            &proof,
+            &gens,
+            &header,
+            &ph,
+            &[(0, messages[0])]
+        ));
+
+        let gens = Generators::new(10);
+        let sig = sign(&sk, &pk, &gens, &header, &messages).unwrap();
+        let disclosed = [0, 2, 4, 6];
+        let revealed: Vec<(usize, Fr)> = disclosed.iter().map(|&i| (i, messages[i])).collect();
+        let proof = prove_with_scalars(
+            &pk,
+            &sig,
+            &gens,
+            &header,
+            &ph,
+            &messages,
+            &disclosed,
+            &mocked_scalars(5 + 6),
+        )
+        .unwrap();
+        assert_eq!(proof.to_bytes().len(), 3 * 48 + (4 + 6) * 32);
+        assert!(verify_proof(&pk, &proof, &gens, &header, &ph, &revealed));
+        assert!(!verify_proof(&pk, &proof, &gens, &header, b"", &revealed));
+    }
+
+    #[test]
+    fn test_generators() {
+        let gens = Generators::new(3);
+        assert_eq!(gens.count(), 3);
+        let more = Generators::new(5);
+        assert_eq!(more.p1, gens.p1);
+        assert_eq!(more.q1, gens.q1);
+        assert_eq!(&more.h[..3], &gens.h[..]);
+        assert_ne!(gens.h[0], gens.h[1]);
+        assert_ne!(gens.p1, G1Affine::one());
+    }
+
+    #[test]
+    fn test_bbs_sign_verify() {
+        let mut rng = test_rng();
+        let gens = Generators::new(4);
+        let sk = Fr::random(&mut rng);
+        let pk = public_key(&sk);
+        let messages: Vec<Fr> = (0..4).map(|_| Fr::random(&mut rng)).collect();
+
+        let sig = sign(&sk, &pk, &gens, b"header", &messages).unwrap();
+        assert_eq!(sig, sign(&sk, &pk, &gens, b"header", &messages).unwrap());
+        assert!(verify(&pk, &gens, b"header", &messages, &sig));
+        assert!(!verify(&pk, &gens, b"other", &messages, &sig));
+        assert!(!verify(&G2::one(), &gens, b"header", &messages, &sig));
+        let mut bad = messages.clone();
+        bad[2].add_assign(&Fr::one());
+        assert!(!verify(&pk, &gens, b"header", &bad, &sig));
+        assert!(!verify(&pk, &gens, b"header", &messages[..3], &sig));
+
+        assert_eq!(
+            sign(&sk, &pk, &gens, b"header", &messages[..3]),
+            Err(CredentialError::MessageCount {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn test_bbs_proof() {
+        let mut rng = test_rng();
+        let gens = Generators::new(5);
+        let sk = Fr::random(&mut rng);
+        let pk = public_key(&sk);
+        let messages: Vec<Fr> = (0..5).map(|_| Fr::random(&mut rng)).collect();
+        let sig = sign(&sk, &pk, &gens, b"header", &messages).unwrap();
+
+        for disclosed in [&[][..], &[1, 3], &[0, 1, 2, 3, 4]].iter() {
+            let revealed: Vec<(usize, Fr)> = disclosed.iter().map(|&i| (i, messages[i])).collect();
+            let proof = prove(
+                &pk, &sig, &gens, b"header", b"ph", &messages, disclosed, &mut rng,
+            )
+            .unwrap();
+            assert_eq!(proof.m_hat.len(), 5 - disclosed.len());
+            assert!(verify_proof(
+                &pk, &proof, &gens, b"header", b"ph", &revealed
+            ));
+            assert!(!verify_proof(
+                &pk, &proof, &gens, b"other", b"ph", &revealed
+            ));
+            assert!(!verify_proof(
+                &pk, &proof, &gens, b"header", b"other", &revealed
+            ));
+            assert!(!verify_proof(
+                &G2::one(),
+                &proof,
+                &gens,
+                b"header",
+                b"ph",
+                &revealed
+            ));
+        }
+
+        let revealed = [(1, messages[1]), (3, messages[3])];
+        let proof = prove(
+            &pk,
+            &sig,
+            &gens,
+            b"header",
+            b"ph",
+            &messages,
+            &[1, 3],
+            &mut rng,
+        )
+        .unwrap();
+        // proofs are randomized
+        let again = prove(
+            &pk,
+            &sig,
+            &gens,
+            b"header",
+            b"ph",
+            &messages,
+            &[1, 3],
+            &mut rng,
+        )
+        .unwrap();
+        assert_ne!(proof.abar, again.abar);
+
+        let mut wrong = revealed;
+        wrong[0].1.add_assign(&Fr::one());
+        assert!(!verify_proof(&pk, &proof, &gens, b"header", b"ph", &wrong));
+        assert!(!verify_proof(
+            &pk,
+            &proof,
+            &gens,
+            b"header",
+            b"ph",
+            &revealed[..1]
+        ));
+        assert!(!verify_proof(
+            &pk,
+            &proof,
+            &gens,
+            b"header",
+            b"ph",
+            &[(3, messages[3]), (1, messages[1])]
+        ));
+        let mut bad = proof.clone();
+        bad.m_hat[0].add_assign(&Fr::one());
+        assert!(!verify_proof(&pk, &bad, &gens, b"header", b"ph", &revealed));
+        let mut bad = proof.clone();
+        bad.bbar.double();
+        assert!(!verify_proof(&pk, &bad, &gens, b"header", b"ph", &revealed));
+        let mut bad = proof;
+        bad.abar = G1::zero();
+        bad.bbar = G1::zero();
+        assert!(!verify_proof(&pk, &bad, &gens, b"header", b"ph", &revealed));
+
+        // a proof of a forged signature does not verify
+        let forged = Signature {
+            a: G1::random(&mut rng),
+            e: sig.e,
+        };
+        let proof = prove(
+            &pk,
+            &forged,
+            &gens,
+            b"header",
+            b"ph",
+            &messages,
+            &[1, 3],
+            &mut rng,
+        )
+        .unwrap();
+        assert!(!verify_proof(
+            &pk, &proof, &gens, b"header", b"ph", &revealed
+        ));
+
+        assert_eq!(
+            prove(
+                &pk,
+                &sig,
+                &gens,
+                b"header",
+                b"ph",
+                &messages,
+                &[3, 1],
+                &mut rng
+            ),
+            Err(CredentialError::InvalidIndex(1))
+        );
+    }
+}
@@ -0,0 +1,99 @@
+/*!
+Multi-message signatures over BLS12-381 for anonymous credentials.
+
+Both schemes sign a vector of messages, each an `Fr` (hash other data into
+`Fr` first, e.g. with `hash_to_scalar`), and let the holder of a signature
+prove knowledge of it in zero knowledge while disclosing any subset of the
+messages. A proof reveals nothing about the hidden messages, and two proofs
+from the same signature are unlinkable.
+
+* `bbs`: BBS signatures as in the IRTF draft, with signatures in G1 and
+  public keys in G2. Proof challenges use the draft's `hash_to_scalar`.
+* `ps`: Pointcheval-Sanders signatures, with signatures in G1 and public
+  keys in G2. The signature is randomizable, so proofs are short Schnorr
+  proofs over a randomized signature.
+
+Disclosed messages are given by their indices in increasing order. The PS
+proof challenges are derived with `transcript::Transcript`.
+*/
+
+pub mod bbs;
+pub mod ps;
+
+use crate::{bls12_381::Fr, transcript::Transcript};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// An error from signing or proving.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CredentialError {
+    /// The number of messages does not match the key or the generators.
+    MessageCount { expected: usize, actual: usize },
+    /// The disclosed indices are out of range or not strictly increasing.
+    InvalidIndex(usize),
+    /// The key material is shorter than 32 bytes, or the key info longer
+    /// than 65535.
+    InvalidKeyMaterial,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CredentialError {}
+
+impl fmt::Display for CredentialError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CredentialError::MessageCount { expected, actual } => {
+                write!(f, "expected {} messages, got {}", expected, actual)
+            }
+            CredentialError::InvalidIndex(index) => {
+                write!(
+                    f,
+                    "disclosed index {} is out of range or out of order",
+                    index
+                )
+            }
+            CredentialError::InvalidKeyMaterial => write!(f, "invalid key material or key info"),
+        }
+    }
+}
+
+/// The indices in `0..count` that are not in `disclosed`, which must be
+/// strictly increasing and less than `count`.
+fn undisclosed(count: usize, disclosed: &[usize]) -> Result<Vec<usize>, CredentialError> {
+    for (i, &index) in disclosed.iter().enumerate() {
+        if index >= count || (i > 0 && disclosed[i - 1] >= index) {
+            return Err(CredentialError::InvalidIndex(index));
+        }
+    }
+    Ok((0..count).filter(|i| !disclosed.contains(i)).collect())
+}
+
+/// Appends the disclosed messages and their indices to a transcript.
+fn append_disclosed(transcript: &mut Transcript, disclosed: &[(usize, Fr)]) {
+    transcript.append_u64(b"disclosed", disclosed.len() as u64);
+    for (index, msg) in disclosed {
+        transcript.append_u64(b"index", *index as u64);
+        transcript.append_scalar(b"message", msg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_undisclosed() {
+        assert_eq!(undisclosed(4, &[]).unwrap(), [0, 1, 2, 3]);
+        assert_eq!(undisclosed(4, &[0, 2]).unwrap(), [1, 3]);
+        assert_eq!(undisclosed(2, &[0, 1]).unwrap(), Vec::<usize>::new());
+        assert_eq!(undisclosed(4, &[4]), Err(CredentialError::InvalidIndex(4)));
+        assert_eq!(
+            undisclosed(4, &[2, 1]),
+            Err(CredentialError::InvalidIndex(1))
+        );
+        assert_eq!(
+            undisclosed(4, &[1, 1]),
+            Err(CredentialError::InvalidIndex(1))
+        );
+    }
+}
@@ -0,0 +1,392 @@
+/*!
+Pointcheval-Sanders signatures on multiple messages.
+
+A secret key is `(x, y_1..y_L)` in `Fr`, and the public key is
+`(X = x * g2, Y_i = y_i * g2)` in G2. A signature on `m_1..m_L` is
+`(sigma_1, sigma_2) = (h, h * (x + y_1 * m_1 + .. + y_L * m_L))` for a random
+`h` in G1, and verifies with `sigma_1 != 0` and
+`e(sigma_1, X + Y_1 * m_1 + .. + Y_L * m_L) == e(sigma_2, g2)`.
+
+A proof of knowledge of a signature randomizes it into
+`(r * sigma_1, r * (sigma_2 + t * sigma_1))`, which verifies against
+`X + sum_i Y_i * m_i + t * g2`. The proof carries the commitment
+`K = t * g2 + sum_j Y_j * m_j` over the hidden messages, which is perfectly
+hiding, and a Schnorr proof of knowledge of its opening. The verifier adds
+the disclosed messages and `K` to `X` and checks the pairing equation. The
+challenge binds the public key, so a proof only verifies under the key it
+was made for.
+*/
+
+use super::{append_disclosed, undisclosed, CredentialError};
+use crate::{
+    bls12_381::{Bls12, Fr, G2Affine, G1, G2},
+    threshold::{mul_generator, sum_of_products},
+    transcript::Transcript,
+    CurveAffine, CurveProjective, Engine,
+};
+use alloc::vec::Vec;
+use ff::Field;
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+/// The protocol label of the proof transcripts.
+const PROOF_LABEL: &[u8] = b"PAIRING-PLUS-PS-PROOF-V01";
+
+/// A secret key for signing `y.len()` messages.
+#[derive(Clone, Debug, PartialEq, Eq, Zeroize)]
+#[zeroize(drop)]
+pub struct SecretKey {
+    pub x: Fr,
+    pub y: Vec<Fr>,
+}
+
+/// A public key `(X, Y_1..Y_L)`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicKey {
+    pub x: G2,
+    pub y: Vec<G2>,
+}
+
+/// A signature `(sigma_1, sigma_2)`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature {
+    pub sigma1: G1,
+    pub sigma2: G1,
+}
+
+/// A proof of knowledge of a signature, disclosing some of the messages.
+/// `m_hat` holds the responses for the hidden messages, in index order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub sigma1: G1,
+    pub sigma2: G1,
+    pub k: G2,
+    pub c: Fr,
+    pub t_hat: Fr,
+    pub m_hat: Vec<Fr>,
+}
+
+impl SecretKey {
+    /// A random secret key for `count` messages.
+    pub fn random<R: RngCore + ?Sized>(count: usize, rng: &mut R) -> Self {
+        SecretKey {
+            x: Fr::random(rng),
+            y: (0..count).map(|_| Fr::random(rng)).collect(),
+        }
+    }
+
+    /// The public key.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey {
+            x: mul_generator(&self.x),
+            y: self.y.iter().map(mul_generator).collect(),
+        }
+    }
+
+    /// Signs `messages`.
+    pub fn sign<R: RngCore + ?Sized>(
+        &self,
+        messages: &[Fr],
+        rng: &mut R,
+    ) -> Result<Signature, CredentialError> {
+        check_count(self.y.len(), messages.len())?;
+
+        let mut sigma1: G1 = mul_generator(&Fr::random(rng));
+        while sigma1.is_zero() {
+            sigma1 = mul_generator(&Fr::random(rng));
+        }
+        let mut exp = self.x;
+        for (y, m) in self.y.iter().zip(messages.iter()) {
+            let mut t = *y;
+            t.mul_assign(m);
+            exp.add_assign(&t);
+        }
+        let mut sigma2 = sigma1;
+        sigma2.mul_assign(exp);
+        Ok(Signature { sigma1, sigma2 })
+    }
+}
+
+impl PublicKey {
+    /// The number of messages.
+    pub fn count(&self) -> usize {
+        self.y.len()
+    }
+
+    /// Verifies a signature on `messages`.
+    pub fn verify(&self, messages: &[Fr], sig: &Signature) -> bool {
+        if messages.len() != self.count() {
+            return false;
+        }
+        let indexed: Vec<(usize, Fr)> = messages.iter().cloned().enumerate().collect();
+        pairing_check(&sig.sigma1, &sig.sigma2, self.combine(&indexed))
+    }
+
+    /// `X + sum_i Y_i * m_i` over the given messages.
+    fn combine(&self, messages: &[(usize, Fr)]) -> G2 {
+        let mut bases = vec![self.x.into_affine()];
+        let mut scalars = vec![Fr::one()];
+        for &(i, m) in messages {
+            bases.push(self.y[i].into_affine());
+            scalars.push(m);
+        }
+        sum_of_products(&bases, &scalars)
+    }
+
+    /// `t * g2 + sum_j Y_j * m_j` over the hidden messages.
+    fn commit(&self, t: &Fr, hidden: &[usize], messages: &[Fr]) -> G2 {
+        let mut bases = vec![G2Affine::one()];
+        let mut scalars = vec![*t];
+        for (&j, m) in hidden.iter().zip(messages.iter()) {
+            bases.push(self.y[j].into_affine());
+            scalars.push(*m);
+        }
+        sum_of_products(&bases, &scalars)
+    }
+
+    /// Proves knowledge of a signature on `messages`, disclosing those at
+    /// the indices `disclosed`, which must be increasing. `ph` is bound into
+    /// the proof.
+    pub fn prove<R: RngCore + ?Sized>(
+        &self,
+        sig: &Signature,
+        ph: &[u8],
+        messages: &[Fr],
+        disclosed: &[usize],
+        rng: &mut R,
+    ) -> Result<Proof, CredentialError> {
+        check_count(self.count(), messages.len())?;
+        let hidden = undisclosed(messages.len(), disclosed)?;
+        let hidden_messages: Vec<Fr> = hidden.iter().map(|&j| messages[j]).collect();
+
+        // (r * sigma_1, r * (sigma_2 + t * sigma_1))
+        let r = Fr::random(rng);
+        let t = Fr::random(rng);
+        let mut sigma1 = sig.sigma1;
+        sigma1.mul_assign(r);
+        let mut rt = r;
+        rt.mul_assign(&t);
+        let sigma2 = sum_of_products(
+            &[sig.sigma2.into_affine(), sig.sigma1.into_affine()],
+            &[r, rt],
+        );
+
+        let k = self.commit(&t, &hidden, &hidden_messages);
+        let t_tilde = Fr::random(rng);
+        let m_tilde: Vec<Fr> = hidden.iter().map(|_| Fr::random(rng)).collect();
+        let commitment = self.commit(&t_tilde, &hidden, &m_tilde);
+
+        let revealed: Vec<(usize, Fr)> = disclosed.iter().map(|&i| (i, messages[i])).collect();
+        let c = challenge(self, &sigma1, &sigma2, &k, &commitment, &revealed, ph);
+        let response = |tilde: &Fr, secret: &Fr| {
+            let mut s = *secret;
+            s.mul_assign(&c);
+            s.add_assign(tilde);
+            s
+        };
+        Ok(Proof {
+            sigma1,
+            sigma2,
+            k,
+            c,
+            t_hat: response(&t_tilde, &t),
+            m_hat: m_tilde
+                .iter()
+                .zip(hidden_messages.iter())
+                .map(|(tilde, m)| response(tilde, m))
+                .collect(),
+        })
+    }
+
+    /// Verifies a proof of knowledge of a signature with the presentation
+    /// header `ph` on the `disclosed` messages `(index, message)`.
+    pub fn verify_proof(&self, proof: &Proof, ph: &[u8], disclosed: &[(usize, Fr)]) -> bool {
+        let indices: Vec<usize> = disclosed.iter().map(|&(i, _)| i).collect();
+        let hidden = match undisclosed(self.count(), &indices) {
+            Ok(hidden) => hidden,
+            Err(_) => return false,
+        };
+        if proof.m_hat.len() != hidden.len() {
+            return false;
+        }
+
+        // commitment = t^ * g2 + sum_j Y_j * m^_j - c * K
+        let mut commitment = self.commit(&proof.t_hat, &hidden, &proof.m_hat);
+        let mut ck = proof.k;
+        ck.mul_assign(proof.c);
+        commitment.sub_assign(&ck);
+        let c = challenge(
+            self,
+            &proof.sigma1,
+            &proof.sigma2,
+            &proof.k,
+            &commitment,
+            disclosed,
+            ph,
+        );
+        if c != proof.c {
+            return false;
+        }
+
+        let mut key = self.combine(disclosed);
+        key.add_assign(&proof.k);
+        pairing_check(&proof.sigma1, &proof.sigma2, key)
+    }
+}
+
+/// `sigma_1 != 0` and `e(sigma_1, key) == e(sigma_2, g2)`.
+fn pairing_check(sigma1: &G1, sigma2: &G1, key: G2) -> bool {
+    if sigma1.is_zero() {
+        return false;
+    }
+    let mut g2 = G2Affine::one();
+    g2.negate();
+    Bls12::pairing_product(*sigma1, key, *sigma2, g2.into_projective())
+        == <Bls12 as Engine>::Fqk::one()
+}
+
+fn check_count(expected: usize, actual: usize) -> Result<(), CredentialError> {
+    if expected != actual {
+        return Err(CredentialError::MessageCount { expected, actual });
+    }
+    Ok(())
+}
+
+fn challenge(
+    pk: &PublicKey,
+    sigma1: &G1,
+    sigma2: &G1,
+    k: &G2,
+    commitment: &G2,
+    disclosed: &[(usize, Fr)],
+    ph: &[u8],
+) -> Fr {
+    let mut t = Transcript::new(PROOF_LABEL);
+    t.append_g2(b"X", &pk.x.into_affine());
+    t.append_u64(b"count", pk.y.len() as u64);
+    for y in pk.y.iter() {
+        t.append_g2(b"Y", &y.into_affine());
+    }
+    t.append_g1(b"sigma1", &sigma1.into_affine());
+    t.append_g1(b"sigma2", &sigma2.into_affine());
+    t.append_g2(b"K", &k.into_affine());
+    t.append_g2(b"commitment", &commitment.into_affine());
+    append_disclosed(&mut t, disclosed);
+    t.append_message(b"ph", ph);
+    t.challenge_scalar(b"c")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_rng;
+
+    #[test]
+    fn test_ps_sign_verify() {
+        let mut rng = test_rng();
+        let sk = SecretKey::random(3, &mut rng);
+        let pk = sk.public_key();
+        let messages: Vec<Fr> = (0..3).map(|_| Fr::random(&mut rng)).collect();
+
+        let sig = sk.sign(&messages, &mut rng).unwrap();
+        assert!(pk.verify(&messages, &sig));
+        assert_ne!(sig, sk.sign(&messages, &mut rng).unwrap());
+
+        let mut bad = messages.clone();
+        bad[1].add_assign(&Fr::one());
+        assert!(!pk.verify(&bad, &sig));
+        assert!(!pk.verify(&messages[..2], &sig));
+        let other = SecretKey::random(3, &mut rng).public_key();
+        assert!(!other.verify(&messages, &sig));
+        let zero = Signature {
+            sigma1: G1::zero(),
+            sigma2: G1::zero(),
+        };
+        assert!(!pk.verify(&messages, &zero));
+
+        assert_eq!(
+            sk.sign(&messages[..2], &mut rng),
+            Err(CredentialError::MessageCount {
+                expected: 3,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn test_ps_proof() {
+        let mut rng = test_rng();
+        let sk = SecretKey::random(4, &mut rng);
+        let pk = sk.public_key();
+        let messages: Vec<Fr> = (0..4).map(|_| Fr::random(&mut rng)).collect();
+        let sig = sk.sign(&messages, &mut rng).unwrap();
+
+        for disclosed in [&[][..], &[0, 2], &[0, 1, 2, 3]].iter() {
+            let revealed: Vec<(usize, Fr)> = disclosed.iter().map(|&i| (i, messages[i])).collect();
+            let proof = pk
+                .prove(&sig, b"ph", &messages, disclosed, &mut rng)
+                .unwrap();
+            assert_eq!(proof.m_hat.len(), 4 - disclosed.len());
+            assert!(pk.verify_proof(&proof, b"ph", &revealed));
+            assert!(!pk.verify_proof(&proof, b"other", &revealed));
+        }
+
+        let revealed = [(0, messages[0]), (2, messages[2])];
+        let proof = pk.prove(&sig, b"ph", &messages, &[0, 2], &mut rng).unwrap();
+        // proofs are randomized
+        let again = pk.prove(&sig, b"ph", &messages, &[0, 2], &mut rng).unwrap();
+        assert_ne!(proof.sigma1, again.sigma1);
+        assert_ne!(proof.k, again.k);
+
+        let mut wrong = revealed;
+        wrong[1].1.add_assign(&Fr::one());
+        assert!(!pk.verify_proof(&proof, b"ph", &wrong));
+        assert!(!pk.verify_proof(&proof, b"ph", &revealed[..1]));
+        let other = SecretKey::random(4, &mut rng).public_key();
+        assert!(!other.verify_proof(&proof, b"ph", &revealed));
+
+        // the challenge binds X and every Y_i
+        let challenge_for = |key: &PublicKey| {
+            challenge(
+                key,
+                &proof.sigma1,
+                &proof.sigma2,
+                &proof.k,
+                &proof.k,
+                &revealed,
+                b"ph",
+            )
+        };
+        let mut changed = pk.clone();
+        changed.x.double();
+        assert_ne!(challenge_for(&changed), challenge_for(&pk));
+        let mut changed = pk.clone();
+        changed.y[3].double();
+        assert_ne!(challenge_for(&changed), challenge_for(&pk));
+        let mut bad = proof.clone();
+        bad.m_hat[0].add_assign(&Fr::one());
+        assert!(!pk.verify_proof(&bad, b"ph", &revealed));
+        let mut bad = proof.clone();
+        bad.sigma2.double();
+        assert!(!pk.verify_proof(&bad, b"ph", &revealed));
+        let mut bad = proof;
+        bad.sigma1 = G1::zero();
+        bad.sigma2 = G1::zero();
+        assert!(!pk.verify_proof(&bad, b"ph", &revealed));
+
+        // a proof of a forged signature does not verify
+        let forged = Signature {
+            sigma1: sig.sigma1,
+            sigma2: G1::random(&mut rng),
+        };
+        let proof = pk
+            .prove(&forged, b"ph", &messages, &[0, 2], &mut rng)
+            .unwrap();
+        assert!(!pk.verify_proof(&proof, b"ph", &revealed));
+
+        assert_eq!(
+            pk.prove(&sig, b"ph", &messages, &[2, 0], &mut rng),
+            Err(CredentialError::InvalidIndex(0))
+        );
+    }
+}
@@ -0,0 +1,896 @@
+/*!
+Distributed key generation for threshold BLS keys, after Gennaro, Jarecki,
+Krawczyk and Rabin, "Secure Distributed Key Generation for Discrete-Log
+Based Cryptosystems".
+
+`n` parties with indices `1..=n` jointly generate a secret key `x` that none
+of them knows, with public key `x * g` in G1, and a `t`-of-`n` Shamir sharing
+of `x`, as `threshold::split_secret` would produce. Every party deals a random
+secret `z_i` with Pedersen VSS; `x` is the sum of the secrets of the dealers
+that survive the complaint rounds. Dealers then publish Feldman commitments
+to the same polynomials to extract the public key. The Pedersen commitments
+keep the key uniformly random even if some dealers see the others'
+commitments first and choose theirs accordingly.
+
+This module contains no networking. `Dkg` is a state machine for one party;
+each round consumes the messages of the previous round and returns the
+messages to send, which the caller delivers. Every message is broadcast
+except `DealtShare`, which goes privately from its dealer to the recipient
+`share.index`. The protocol assumes a reliable broadcast channel, so that
+all honest parties get the same broadcast messages, and private channels.
+A party that sends nothing in a round is treated as if it had sent nothing
+valid. `justify` checks that the broadcast complaints include our own, and
+fails with `DkgError::InconsistentBroadcast` if they do not.
+
+The rounds, in order:
+
+1. `deal`: broadcast a `DealCommitment` and send each party its `DealtShare`.
+2. `complain`: verify the shares received, and broadcast a `Complaint`
+   against each dealer whose share is missing or invalid. Dealers without a
+   valid commitment are disqualified.
+3. `justify`: answer each complaint against us with a `Justification` that
+   reveals the disputed share.
+4. `qualify`: disqualify each dealer with `t` or more complaints, or with
+   a complaint that is not answered by a valid justification. The remaining
+   dealers form the qualified set; each of them broadcasts the Feldman
+   commitment to its polynomial as `PublicCoefficients`.
+5. `extract`: verify our shares against the Feldman commitments, and
+   broadcast an `ExtractionComplaint` with the share as evidence for each
+   one that fails.
+6. `reveal`: the dealers with a missing Feldman commitment or a proven
+   extraction complaint are reconstructed in the open: broadcast a `Reveal`
+   of our share from each of them.
+7. `finish`: reconstruct those dealers' polynomials from `t` valid revealed
+   shares, and output the group public key, our share of the key, and the
+   public keys of all shares.
+*/
+
+use crate::{
+    bls12_381::{Fr, G1},
+    threshold::{
+        evaluate_polynomial, fr_from_u64, lagrange_coefficients, mul_generator, random_polynomial,
+        PublicShare, SecretShare, ThresholdError,
+    },
+    vss::{commit_feldman, commit_pedersen, FeldmanCommitment, PedersenCommitment, PedersenShare},
+    CurveProjective,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::Field;
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+/// An error from the key generation protocol.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DkgError {
+    /// The threshold is zero or larger than the number of parties.
+    InvalidParameters(ThresholdError),
+    /// The party's index is not in `1..=n`.
+    InvalidIndex(u64),
+    /// A round was run out of order.
+    UnexpectedRound,
+    /// Every dealer was disqualified.
+    NoQualifiedDealers,
+    /// Fewer than `t` valid shares were revealed for a dealer that must be
+    /// reconstructed.
+    Reconstruction(u64),
+    /// This party holds no valid share from a qualified dealer, which can
+    /// only happen if the broadcast channel failed.
+    MissingShare(u64),
+    /// Our complaint against this dealer is missing from the broadcast
+    /// complaints, so the other parties may not have seen it.
+    InconsistentBroadcast(u64),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DkgError {}
+
+impl fmt::Display for DkgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DkgError::InvalidParameters(ref err) => write!(f, "invalid parameters: {}", err),
+            DkgError::InvalidIndex(index) => write!(f, "party index {} is out of range", index),
+            DkgError::UnexpectedRound => write!(f, "round run out of order"),
+            DkgError::NoQualifiedDealers => write!(f, "every dealer was disqualified"),
+            DkgError::Reconstruction(dealer) => {
+                write!(
+                    f,
+                    "not enough valid shares to reconstruct dealer {}",
+                    dealer
+                )
+            }
+            DkgError::MissingShare(dealer) => {
+                write!(f, "no valid share from qualified dealer {}", dealer)
+            }
+            DkgError::InconsistentBroadcast(dealer) => {
+                write!(
+                    f,
+                    "our complaint against dealer {} was not broadcast",
+                    dealer
+                )
+            }
+        }
+    }
+}
+
+/// Broadcast in round 1: the dealer's Pedersen commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DealCommitment {
+    pub dealer: u64,
+    pub commitment: PedersenCommitment,
+}
+
+/// Sent privately in round 1 from the dealer to party `share.index`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DealtShare {
+    pub dealer: u64,
+    pub share: PedersenShare,
+}
+
+/// Broadcast in round 2 against a dealer whose share was missing or did not
+/// verify.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Complaint {
+    pub dealer: u64,
+    pub complainer: u64,
+}
+
+/// Broadcast in round 3 by a dealer: the share disputed by the complaint of
+/// party `share.index`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Justification {
+    pub dealer: u64,
+    pub share: PedersenShare,
+}
+
+/// Broadcast in round 4 by a qualified dealer: the Feldman commitment to its
+/// polynomial.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PublicCoefficients {
+    pub dealer: u64,
+    pub commitment: FeldmanCommitment,
+}
+
+/// Broadcast in round 5 by party `share.index`: its share from the dealer,
+/// which is valid for the dealer's Pedersen commitment but not for its
+/// Feldman commitment.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExtractionComplaint {
+    pub dealer: u64,
+    pub share: PedersenShare,
+}
+
+/// Broadcast in round 6 by party `share.index`: its share from a dealer that
+/// is being reconstructed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Reveal {
+    pub dealer: u64,
+    pub share: PedersenShare,
+}
+
+/// The result of key generation for one party.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DkgOutput {
+    /// The indices of the dealers whose secrets make up the key.
+    pub qualified: Vec<u64>,
+    /// The group public key.
+    pub public_key: G1,
+    /// This party's share of the secret key.
+    pub share: SecretShare,
+    /// The public keys of the shares of all parties, in index order.
+    pub public_shares: Vec<PublicShare<G1>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Round {
+    Deal,
+    Complain,
+    Justify,
+    Qualify,
+    Extract,
+    Reveal,
+    Finish,
+    Done,
+}
+
+/// The state of one party in the key generation protocol.
+#[derive(Debug)]
+pub struct Dkg {
+    index: u64,
+    threshold: usize,
+    parties: usize,
+    round: Round,
+    poly: Vec<Fr>,
+    blinding: Vec<Fr>,
+    commitments: BTreeMap<u64, PedersenCommitment>,
+    shares: BTreeMap<u64, PedersenShare>,
+    complaints: BTreeSet<(u64, u64)>,
+    qualified: BTreeSet<u64>,
+    public_coeffs: BTreeMap<u64, FeldmanCommitment>,
+    reconstruct: BTreeSet<u64>,
+}
+
+impl Dkg {
+    /// Starts key generation as party `index` of `parties`, with a random
+    /// secret to deal.
+    pub fn new<R: RngCore + ?Sized>(
+        index: u64,
+        threshold: usize,
+        parties: usize,
+        rng: &mut R,
+    ) -> Result<Self, DkgError> {
+        if threshold == 0 || threshold > parties {
+            return Err(DkgError::InvalidParameters(
+                ThresholdError::InvalidThreshold {
+                    threshold,
+                    shares: parties,
+                },
+            ));
+        }
+        if index == 0 || index > parties as u64 {
+            return Err(DkgError::InvalidIndex(index));
+        }
+
+        let poly = random_polynomial(&Fr::random(rng), threshold, rng);
+        let blinding = random_polynomial(&Fr::random(rng), threshold, rng);
+        Ok(Dkg {
+            index,
+            threshold,
+            parties,
+            round: Round::Deal,
+            poly,
+            blinding,
+            commitments: BTreeMap::new(),
+            shares: BTreeMap::new(),
+            complaints: BTreeSet::new(),
+            qualified: BTreeSet::new(),
+            public_coeffs: BTreeMap::new(),
+            reconstruct: BTreeSet::new(),
+        })
+    }
+
+    /// This party's index.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Round 1: the commitment to broadcast, and the shares to send to the
+    /// other parties.
+    pub fn deal(&mut self) -> Result<(DealCommitment, Vec<DealtShare>), DkgError> {
+        self.advance(Round::Deal)?;
+
+        let commitment = commit_pedersen(&self.poly, &self.blinding);
+        self.commitments.insert(self.index, commitment.clone());
+        self.shares.insert(self.index, self.share_for(self.index));
+        let shares = (1..=self.parties as u64)
+            .filter(|&j| j != self.index)
+            .map(|j| DealtShare {
+                dealer: self.index,
+                share: self.share_for(j),
+            })
+            .collect();
+        Ok((
+            DealCommitment {
+                dealer: self.index,
+                commitment,
+            },
+            shares,
+        ))
+    }
+
+    /// Round 2: takes the broadcast commitments and the shares sent to this
+    /// party, and returns the complaints to broadcast.
+    pub fn complain(
+        &mut self,
+        commitments: &[DealCommitment],
+        shares: &[DealtShare],
+    ) -> Result<Vec<Complaint>, DkgError> {
+        self.advance(Round::Complain)?;
+
+        for c in commitments {
+            if self.is_party(c.dealer) && c.commitment.threshold() == self.threshold {
+                self.commitments
+                    .entry(c.dealer)
+                    .or_insert_with(|| c.commitment.clone());
+            }
+        }
+        for s in shares {
+            if s.share.index != self.index || self.shares.contains_key(&s.dealer) {
+                continue;
+            }
+            if let Some(c) = self.commitments.get(&s.dealer) {
+                if c.verify(&s.share) {
+                    self.shares.insert(s.dealer, s.share.clone());
+                }
+            }
+        }
+
+        // remember our own complaints, to check that the broadcast delivers
+        // them
+        let complaints: Vec<Complaint> = self
+            .commitments
+            .keys()
+            .filter(|dealer| !self.shares.contains_key(dealer))
+            .map(|&dealer| Complaint {
+                dealer,
+                complainer: self.index,
+            })
+            .collect();
+        for c in complaints.iter() {
+            self.complaints.insert((c.dealer, c.complainer));
+        }
+        Ok(complaints)
+    }
+
+    /// Round 3: takes the broadcast complaints, and returns the
+    /// justifications to broadcast for those against this party.
+    ///
+    /// Fails with `DkgError::InconsistentBroadcast`, and stays in this round,
+    /// if one of our complaints is missing: the other parties could then
+    /// qualify a dealer that never answered us.
+    pub fn justify(&mut self, complaints: &[Complaint]) -> Result<Vec<Justification>, DkgError> {
+        if self.round == Round::Justify {
+            // before this round, the only complaints we hold are our own
+            let missing = self.complaints.iter().find(|&&(dealer, complainer)| {
+                !complaints.contains(&Complaint { dealer, complainer })
+            });
+            if let Some(&(dealer, _)) = missing {
+                return Err(DkgError::InconsistentBroadcast(dealer));
+            }
+        }
+        self.advance(Round::Justify)?;
+
+        for c in complaints {
+            if self.commitments.contains_key(&c.dealer) && self.is_party(c.complainer) {
+                self.complaints.insert((c.dealer, c.complainer));
+            }
+        }
+        Ok(self
+            .complaints
+            .iter()
+            .filter(|&&(dealer, _)| dealer == self.index)
+            .map(|&(_, complainer)| Justification {
+                dealer: self.index,
+                share: self.share_for(complainer),
+            })
+            .collect())
+    }
+
+    /// Round 4: takes the broadcast justifications and computes the qualified
+    /// set. Returns the Feldman commitment to broadcast if this party is
+    /// qualified.
+    pub fn qualify(
+        &mut self,
+        justifications: &[Justification],
+    ) -> Result<Option<PublicCoefficients>, DkgError> {
+        self.advance(Round::Qualify)?;
+
+        for (&dealer, commitment) in self.commitments.iter() {
+            let against: Vec<u64> = self
+                .complaints
+                .iter()
+                .filter(|&&(d, _)| d == dealer)
+                .map(|&(_, complainer)| complainer)
+                .collect();
+            if against.len() >= self.threshold {
+                continue;
+            }
+            let answered = against.iter().all(|&complainer| {
+                justifications.iter().any(|j| {
+                    j.dealer == dealer && j.share.index == complainer && commitment.verify(&j.share)
+                })
+            });
+            if answered {
+                self.qualified.insert(dealer);
+            }
+        }
+        if self.qualified.is_empty() {
+            return Err(DkgError::NoQualifiedDealers);
+        }
+
+        // a justified complaint of ours replaces the missing share
+        for j in justifications {
+            if j.share.index == self.index
+                && self.qualified.contains(&j.dealer)
+                && !self.shares.contains_key(&j.dealer)
+                && self.commitments[&j.dealer].verify(&j.share)
+            {
+                self.shares.insert(j.dealer, j.share.clone());
+            }
+        }
+
+        if !self.qualified.contains(&self.index) {
+            return Ok(None);
+        }
+        let commitment = commit_feldman(&self.poly);
+        self.public_coeffs.insert(self.index, commitment.clone());
+        Ok(Some(PublicCoefficients {
+            dealer: self.index,
+            commitment,
+        }))
+    }
+
+    /// Round 5: takes the broadcast Feldman commitments, and returns the
+    /// complaints to broadcast for those that do not match this party's
+    /// shares.
+    pub fn extract(
+        &mut self,
+        public_coeffs: &[PublicCoefficients],
+    ) -> Result<Vec<ExtractionComplaint>, DkgError> {
+        self.advance(Round::Extract)?;
+
+        for p in public_coeffs {
+            if self.qualified.contains(&p.dealer) && p.commitment.threshold() == self.threshold {
+                self.public_coeffs
+                    .entry(p.dealer)
+                    .or_insert_with(|| p.commitment.clone());
+            }
+        }
+
+        let mut res = vec![];
+        for &dealer in self.qualified.iter() {
+            match self.public_coeffs.get(&dealer) {
+                None => {
+                    self.reconstruct.insert(dealer);
+                }
+                Some(c) => {
+                    let share = self.share_from(dealer)?;
+                    if !c.verify(&to_secret_share(share)) {
+                        res.push(ExtractionComplaint {
+                            dealer,
+                            share: share.clone(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(res)
+    }
+
+    /// Round 6: takes the broadcast extraction complaints, and returns the
+    /// shares to reveal for the dealers that must be reconstructed.
+    pub fn reveal(&mut self, complaints: &[ExtractionComplaint]) -> Result<Vec<Reveal>, DkgError> {
+        self.advance(Round::Reveal)?;
+
+        for c in complaints {
+            let proven = match (
+                self.commitments.get(&c.dealer),
+                self.public_coeffs.get(&c.dealer),
+            ) {
+                (Some(pedersen), Some(feldman)) => {
+                    self.qualified.contains(&c.dealer)
+                        && pedersen.verify(&c.share)
+                        && !feldman.verify(&to_secret_share(&c.share))
+                }
+                _ => false,
+            };
+            if proven {
+                self.reconstruct.insert(c.dealer);
+            }
+        }
+
+        self.reconstruct
+            .iter()
+            .map(|&dealer| {
+                Ok(Reveal {
+                    dealer,
+                    share: self.share_from(dealer)?.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Round 7: takes the broadcast revealed shares, reconstructs the dealers
+    /// that need it, and outputs the key.
+    pub fn finish(&mut self, reveals: &[Reveal]) -> Result<DkgOutput, DkgError> {
+        self.advance(Round::Finish)?;
+
+        // sums of the committed polynomials of the dealers that are not
+        // reconstructed, and of the evaluations at 0..=n of those that are
+        let mut coeffs = vec![G1::zero(); self.threshold];
+        let mut values = vec![Fr::zero(); self.parties + 1];
+        for &dealer in self.qualified.iter() {
+            if !self.reconstruct.contains(&dealer) {
+                for (sum, c) in coeffs
+                    .iter_mut()
+                    .zip(self.public_coeffs[&dealer].coeffs.iter())
+                {
+                    sum.add_assign_mixed(c);
+                }
+                continue;
+            }
+
+            let commitment = &self.commitments[&dealer];
+            let mut indices = vec![];
+            let mut shares = vec![];
+            for s in self.shares.get(&dealer).into_iter().chain(
+                reveals
+                    .iter()
+                    .filter(|r| r.dealer == dealer)
+                    .map(|r| &r.share),
+            ) {
+                if shares.len() < self.threshold
+                    && self.is_party(s.index)
+                    && !indices.contains(&s.index)
+                    && commitment.verify(s)
+                {
+                    indices.push(s.index);
+                    shares.push(s.value);
+                }
+            }
+            if shares.len() < self.threshold {
+                return Err(DkgError::Reconstruction(dealer));
+            }
+            for (x, value) in values.iter_mut().enumerate() {
+                let lambdas = lagrange_coefficients(&indices, x as u64)
+                    .map_err(DkgError::InvalidParameters)?;
+                for (l, s) in lambdas.iter().zip(shares.iter()) {
+                    let mut t = *s;
+                    t.mul_assign(l);
+                    value.add_assign(&t);
+                }
+            }
+        }
+
+        G1::batch_normalization(&mut coeffs);
+        let sum = FeldmanCommitment {
+            coeffs: coeffs.iter().map(|c| c.into_affine()).collect(),
+        };
+        // f(0) * g, the constant term of the summed commitment
+        let mut public_key = sum.evaluate(0);
+        public_key.add_assign(&mul_generator(&values[0]));
+        let public_shares = (1..=self.parties as u64)
+            .map(|j| {
+                let mut key = sum.evaluate(j);
+                key.add_assign(&mul_generator(&values[j as usize]));
+                PublicShare { index: j, key }
+            })
+            .collect();
+
+        let mut value = Fr::zero();
+        for &dealer in self.qualified.iter() {
+            value.add_assign(&self.share_from(dealer)?.value);
+        }
+        self.poly.zeroize();
+        self.blinding.zeroize();
+        Ok(DkgOutput {
+            qualified: self.qualified.iter().cloned().collect(),
+            public_key,
+            share: SecretShare {
+                index: self.index,
+                value,
+            },
+            public_shares,
+        })
+    }
+
+    /// Moves past `round`, or fails if the protocol is elsewhere.
+    fn advance(&mut self, round: Round) -> Result<(), DkgError> {
+        if self.round != round {
+            return Err(DkgError::UnexpectedRound);
+        }
+        self.round = match round {
+            Round::Deal => Round::Complain,
+            Round::Complain => Round::Justify,
+            Round::Justify => Round::Qualify,
+            Round::Qualify => Round::Extract,
+            Round::Extract => Round::Reveal,
+            Round::Reveal => Round::Finish,
+            Round::Finish | Round::Done => Round::Done,
+        };
+        Ok(())
+    }
+
+    fn is_party(&self, index: u64) -> bool {
+        index != 0 && index <= self.parties as u64
+    }
+
+    /// Our share from a qualified dealer. `qualify` only keeps dealers that
+    /// sent us a valid share or answered our complaint with one.
+    fn share_from(&self, dealer: u64) -> Result<&PedersenShare, DkgError> {
+        self.shares
+            .get(&dealer)
+            .ok_or(DkgError::MissingShare(dealer))
+    }
+
+    fn share_for(&self, index: u64) -> PedersenShare {
+        let x = fr_from_u64(index);
+        PedersenShare {
+            index,
+            value: evaluate_polynomial(&self.poly, &x),
+            blinding: evaluate_polynomial(&self.blinding, &x),
+        }
+    }
+}
+
+fn to_secret_share(share: &PedersenShare) -> SecretShare {
+    SecretShare {
+        index: share.index,
+        value: share.value,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::G2;
+    use crate::tests::test_rng;
+    use crate::threshold::{combine_signatures, recover_secret, verify, verify_partial};
+
+    /// Misbehaviour of the simulated parties, applied to the messages they
+    /// send.
+    #[derive(Default)]
+    struct Faults {
+        /// parties that send nothing in round 1
+        silent: Vec<u64>,
+        /// (dealer, recipient) pairs with a corrupted private share
+        bad_shares: Vec<(u64, u64)>,
+        /// dealers that do not answer complaints
+        unjustified: Vec<u64>,
+        /// dealers that broadcast a wrong Feldman commitment
+        bad_coeffs: Vec<u64>,
+        /// parties that send nothing in round 6
+        unrevealed: Vec<u64>,
+        /// (dealer, complainer) complaints made up by the complainer
+        false_complaints: Vec<(u64, u64)>,
+        /// (dealer, complainer) complaints lost by the broadcast channel
+        dropped_complaints: Vec<(u64, u64)>,
+    }
+
+    /// Runs the protocol among `parties` simulated parties, delivering every
+    /// message in-process. Returns the parties, their dealt secrets, and
+    /// their outputs.
+    fn run(
+        threshold: usize,
+        parties: usize,
+        faults: &Faults,
+    ) -> (Vec<Dkg>, Vec<Fr>, Vec<Result<DkgOutput, DkgError>>) {
+        let mut rng = test_rng();
+        let mut dkgs: Vec<Dkg> = (1..=parties as u64)
+            .map(|i| Dkg::new(i, threshold, parties, &mut rng).unwrap())
+            .collect();
+        let secrets = dkgs.iter().map(|d| d.poly[0]).collect();
+
+        let mut commitments = vec![];
+        let mut dealt = vec![];
+        for d in dkgs.iter_mut() {
+            let (c, mut shares) = d.deal().unwrap();
+            if faults.silent.contains(&d.index()) {
+                continue;
+            }
+            for s in shares.iter_mut() {
+                if faults.bad_shares.contains(&(s.dealer, s.share.index)) {
+                    s.share.value.add_assign(&Fr::one());
+                }
+            }
+            commitments.push(c);
+            dealt.extend(shares);
+        }
+
+        let mut complaints = vec![];
+        for d in dkgs.iter_mut() {
+            let mine: Vec<DealtShare> = dealt
+                .iter()
+                .filter(|s| s.share.index == d.index())
+                .cloned()
+                .collect();
+            complaints.extend(d.complain(&commitments, &mine).unwrap());
+        }
+        complaints.retain(|c| {
+            !faults
+                .dropped_complaints
+                .contains(&(c.dealer, c.complainer))
+        });
+        for &(dealer, complainer) in faults.false_complaints.iter() {
+            complaints.push(Complaint { dealer, complainer });
+        }
+
+        let mut justifications = vec![];
+        let mut results: Vec<Option<DkgError>> = vec![None; parties];
+        for (d, res) in dkgs.iter_mut().zip(results.iter_mut()) {
+            match d.justify(&complaints) {
+                Ok(js) => {
+                    if !faults.unjustified.contains(&d.index()) {
+                        justifications.extend(js);
+                    }
+                }
+                Err(e) => *res = Some(e),
+            }
+        }
+
+        let mut public_coeffs = vec![];
+        for (d, res) in dkgs.iter_mut().zip(results.iter_mut()) {
+            if res.is_some() {
+                continue;
+            }
+            match d.qualify(&justifications) {
+                Ok(Some(mut p)) => {
+                    if faults.bad_coeffs.contains(&p.dealer) {
+                        p.commitment.coeffs[1] = p.commitment.coeffs[0];
+                    }
+                    public_coeffs.push(p);
+                }
+                Ok(None) => (),
+                Err(e) => *res = Some(e),
+            }
+        }
+
+        let mut extraction = vec![];
+        for d in dkgs.iter_mut() {
+            if let Ok(c) = d.extract(&public_coeffs) {
+                extraction.extend(c);
+            }
+        }
+
+        let mut reveals = vec![];
+        for d in dkgs.iter_mut() {
+            if let Ok(r) = d.reveal(&extraction) {
+                if !faults.unrevealed.contains(&d.index()) {
+                    reveals.extend(r);
+                }
+            }
+        }
+
+        let outputs = dkgs
+            .iter_mut()
+            .zip(results)
+            .map(|(d, err)| match err {
+                Some(e) => Err(e),
+                None => d.finish(&reveals),
+            })
+            .collect();
+        (dkgs, secrets, outputs)
+    }
+
+    /// Checks that the honest parties agree on a consistent key.
+    fn check(
+        secrets: &[Fr],
+        outputs: &[Result<DkgOutput, DkgError>],
+        honest: &[u64],
+        qualified: &[u64],
+    ) {
+        let first = outputs[honest[0] as usize - 1].as_ref().unwrap();
+        assert_eq!(first.qualified, qualified);
+        for &i in honest {
+            let out = outputs[i as usize - 1].as_ref().unwrap();
+            assert_eq!(out.qualified, first.qualified);
+            assert_eq!(out.public_key, first.public_key);
+            assert_eq!(out.public_shares, first.public_shares);
+            assert_eq!(
+                out.share.public_key::<G1>(),
+                out.public_shares[i as usize - 1]
+            );
+        }
+
+        // the key is the sum of the secrets of the qualified dealers
+        let mut secret = Fr::zero();
+        for &i in qualified {
+            secret.add_assign(&secrets[i as usize - 1]);
+        }
+        assert_eq!(first.public_key, mul_generator(&secret));
+        let shares: Vec<SecretShare> = honest
+            .iter()
+            .map(|&i| outputs[i as usize - 1].as_ref().unwrap().share.clone())
+            .collect();
+        assert_eq!(recover_secret(&shares).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_dkg_honest() {
+        let (_, secrets, outputs) = run(3, 5, &Faults::default());
+        check(&secrets, &outputs, &[1, 2, 3, 4, 5], &[1, 2, 3, 4, 5]);
+
+        // the shares sign under the group key
+        let out: Vec<&DkgOutput> = outputs.iter().map(|o| o.as_ref().unwrap()).collect();
+        let dst = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+        let msg = b"dkg";
+        let partials: Vec<_> = out[1..4]
+            .iter()
+            .map(|o| o.share.sign::<G2>(msg, dst))
+            .collect();
+        for (o, p) in out[1..4].iter().zip(partials.iter()) {
+            assert!(verify_partial(
+                &o.public_shares[p.index as usize - 1],
+                msg,
+                dst,
+                p
+            ));
+        }
+        let sig = combine_signatures(&partials).unwrap();
+        assert!(verify(&out[0].public_key, msg, dst, &sig));
+    }
+
+    #[test]
+    fn test_dkg_complaints() {
+        // dealer 2 corrupts a share but answers the complaint; dealer 4
+        // corrupts one and does not; dealer 5 is silent
+        let faults = Faults {
+            silent: vec![5],
+            bad_shares: vec![(2, 1), (4, 3)],
+            unjustified: vec![4],
+            ..Faults::default()
+        };
+        let (_, secrets, outputs) = run(3, 5, &faults);
+        check(&secrets, &outputs, &[1, 2, 3], &[1, 2, 3]);
+
+        // false complaints are answered, unless there are too many
+        let faults = Faults {
+            false_complaints: vec![(1, 4), (2, 4), (2, 5), (2, 3)],
+            ..Faults::default()
+        };
+        let (_, secrets, outputs) = run(3, 5, &faults);
+        check(&secrets, &outputs, &[1, 2, 3, 4, 5], &[1, 3, 4, 5]);
+
+        // party 1's complaint against dealer 4 never reaches the others, and
+        // party 1 stops rather than disqualify the dealer on its own
+        let faults = Faults {
+            bad_shares: vec![(4, 1)],
+            dropped_complaints: vec![(4, 1)],
+            ..Faults::default()
+        };
+        let (_, _, outputs) = run(3, 5, &faults);
+        assert_eq!(outputs[0], Err(DkgError::InconsistentBroadcast(4)));
+
+        // once the complaint is broadcast, dealer 4 answers it, and every
+        // party agrees on the key
+        let faults = Faults {
+            bad_shares: vec![(4, 1)],
+            ..Faults::default()
+        };
+        let (_, secrets, outputs) = run(3, 5, &faults);
+        check(&secrets, &outputs, &[1, 2, 3, 4, 5], &[1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_dkg_reconstruction() {
+        // dealer 3 publishes a wrong Feldman commitment, so the parties
+        // reconstruct its secret; party 5 does not reveal its shares
+        let faults = Faults {
+            bad_coeffs: vec![3],
+            unrevealed: vec![5],
+            ..Faults::default()
+        };
+        let (dkgs, secrets, outputs) = run(3, 5, &faults);
+        check(&secrets, &outputs, &[1, 2, 3, 4, 5], &[1, 2, 3, 4, 5]);
+        assert_eq!(dkgs[0].reconstruct.iter().cloned().collect::<Vec<_>>(), [3]);
+
+        // with too few revealed shares the reconstruction fails
+        let faults = Faults {
+            bad_coeffs: vec![3],
+            unrevealed: vec![1, 2, 4, 5],
+            ..Faults::default()
+        };
+        let (_, _, outputs) = run(3, 5, &faults);
+        assert_eq!(outputs[0], Err(DkgError::Reconstruction(3)));
+    }
+
+    #[test]
+    fn test_dkg_errors() {
+        let mut rng = test_rng();
+        assert_eq!(
+            Dkg::new(1, 0, 3, &mut rng).unwrap_err(),
+            DkgError::InvalidParameters(ThresholdError::InvalidThreshold {
+                threshold: 0,
+                shares: 3
+            })
+        );
+        assert_eq!(
+            Dkg::new(4, 2, 3, &mut rng).unwrap_err(),
+            DkgError::InvalidIndex(4)
+        );
+        let mut dkg = Dkg::new(1, 2, 3, &mut rng).unwrap();
+        assert_eq!(dkg.justify(&[]).unwrap_err(), DkgError::UnexpectedRound);
+        dkg.deal().unwrap();
+        assert_eq!(dkg.deal().unwrap_err(), DkgError::UnexpectedRound);
+
+        // a dropped complaint of ours leaves us in round 3
+        let (c, _) = Dkg::new(2, 2, 3, &mut rng).unwrap().deal().unwrap();
+        let complaints = dkg.complain(&[c], &[]).unwrap();
+        assert_eq!(
+            dkg.justify(&[]).unwrap_err(),
+            DkgError::InconsistentBroadcast(2)
+        );
+        assert!(dkg.justify(&complaints).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,54 @@
+use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
+
+// The scalar field of Bandersnatch, i.e., the order of its prime order subgroup
+#[derive(PrimeField, Zeroize)]
+#[PrimeFieldModulus = "13108968793781547619861935127046491459309155893440570251786403306729687672801"]
+#[PrimeFieldGenerator = "7"]
+pub struct Fs(FsRepr);
+
+/// set the default value for Fs to 0
+impl ::core::default::Default for Fs {
+    fn default() -> Self {
+        Fs::zero()
+    }
+}
+
+/// # Safety
+pub const unsafe fn transmute(r: FsRepr) -> Fs {
+    Fs(r)
+}
+
+#[test]
+fn test_fs_root_of_unity() {
+    use ff::SqrtField;
+
+    assert_eq!(Fs::S, 5);
+    assert_eq!(
+        Fs::multiplicative_generator(),
+        Fs::from_repr(FsRepr::from(7)).unwrap()
+    );
+    assert_eq!(
+        Fs::multiplicative_generator().pow([
+            0x8ba7e835a943b73f,
+            0x7fc7c3803a0c823,
+            0x906673b0101343b0,
+            0xe7db4ea6533afa
+        ]),
+        Fs::root_of_unity()
+    );
+    assert_eq!(Fs::root_of_unity().pow([1 << Fs::S]), Fs::one());
+    assert!(Fs::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn fs_field_tests() {
+    crate::tests::field::random_field_tests::<Fs>();
+    crate::tests::field::random_sqrt_tests::<Fs>();
+    crate::tests::field::random_frobenius_tests::<Fs, _>(Fs::char(), 13);
+    crate::tests::field::from_str_tests::<Fs>();
+}
+
+#[test]
+fn fs_repr_tests() {
+    crate::tests::repr::random_repr_tests::<Fs, FsRepr>();
+}
@@ -0,0 +1,216 @@
+/*!
+Bandersnatch, the twisted Edwards curve -5 x^2 + y^2 = 1 + d x^2 y^2 over the
+BLS12-381 scalar field, with cofactor 4.
+
+Since a = -5 is not a square the addition law is not complete: it fails
+exactly when the difference of the two points is one of the two points of
+order 2 at infinity. This cannot happen for points in the prime order
+subgroup, nor when doubling, so decode untrusted points with `from_bytes` or
+clear their cofactor with `mul_by_cofactor` before doing arithmetic on them.
+*/
+
+mod fs;
+
+pub use self::fs::{Fs, FsRepr};
+
+pub mod transmute {
+    pub use super::fs::transmute as fs;
+}
+
+use super::{EdwardsAffine, EdwardsProjective};
+use crate::{
+    bls12_381::{transmute::fr, Fr, FrRepr},
+    Error,
+};
+use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+
+edwards_impl!("Bandersnatch", Bandersnatch, BandersnatchAffine, Fs, 2);
+
+// a = -5
+const COEFF_A: Fr = unsafe {
+    fr(FrRepr([
+        0xfffffff40000000c,
+        0xece3b023ffec4ff3,
+        0x66b620607396203f,
+        0x6f23d7e5f361df62,
+    ]))
+};
+
+// d = 45022363124591815672509500913686876175488063829319466900776701791074614335719
+const COEFF_D: Fr = unsafe {
+    fr(FrRepr([
+        0xa8dced1b47a2c730,
+        0x381c065aad3cccc7,
+        0x53ff52e1188351f8,
+        0x362e8d63990fe940,
+    ]))
+};
+
+// Generator of the prime order subgroup
+// x = 18886178867200960497001835917649091219057080094937609519140440539760939937304
+// y = 19188667384257783945677642223292697773471335439753913231509108946878080696678
+const GENERATOR_X: Fr = unsafe {
+    fr(FrRepr([
+        0xec2627e1e7ab47f5,
+        0x3e63de484f01aa9c,
+        0xfe0f5c3b53946dc4,
+        0x2d71920baeb2cfcd,
+    ]))
+};
+
+const GENERATOR_Y: Fr = unsafe {
+    fr(FrRepr([
+        0x4e30593e1895bd34,
+        0x156d738f32afbe4b,
+        0x45ef0b1ccdeb75f4,
+        0x6a7cca0037d2e71f,
+    ]))
+};
+
+impl BandersnatchAffine {
+    fn get_coeff_a() -> Fr {
+        COEFF_A
+    }
+
+    fn get_coeff_d() -> Fr {
+        COEFF_D
+    }
+
+    fn get_generator() -> Self {
+        BandersnatchAffine {
+            x: GENERATOR_X,
+            y: GENERATOR_Y,
+        }
+    }
+}
+
+#[test]
+fn test_bandersnatch_coeffs() {
+    let mut a = Fr::from_str("5").unwrap();
+    a.negate();
+    assert_eq!(BandersnatchAffine::get_coeff_a(), a);
+    assert!(a.sqrt().is_none());
+
+    assert_eq!(
+        BandersnatchAffine::get_coeff_d(),
+        Fr::from_str(
+            "45022363124591815672509500913686876175488063829319466900776701791074614335719"
+        )
+        .unwrap()
+    );
+    assert!(BandersnatchAffine::get_coeff_d().sqrt().is_none());
+}
+
+#[test]
+fn test_bandersnatch_generator() {
+    let g = BandersnatchAffine::one();
+    assert_eq!(
+        g.x,
+        Fr::from_str(
+            "18886178867200960497001835917649091219057080094937609519140440539760939937304"
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        g.y,
+        Fr::from_str(
+            "19188667384257783945677642223292697773471335439753913231509108946878080696678"
+        )
+        .unwrap()
+    );
+    assert!(g.is_on_curve());
+    assert!(g.into_projective().is_in_subgroup());
+    assert!(!g.into_projective().is_small_order());
+}
+
+#[test]
+fn test_bandersnatch_addition_and_doubling() {
+    let mut p = Bandersnatch::one();
+    p.double();
+    assert_eq!(
+        p.into_affine(),
+        BandersnatchAffine {
+            x: Fr::from_str(
+                "21829743261194590194992413705867576097158323059182896808782966767024601242412"
+            )
+            .unwrap(),
+            y: Fr::from_str(
+                "19075870567762384361343718229920461045746972450262741916171739040424605531019"
+            )
+            .unwrap(),
+        }
+    );
+
+    p.add_assign(&Bandersnatch::one());
+    assert_eq!(
+        p.into_affine(),
+        BandersnatchAffine {
+            x: Fr::from_str(
+                "19213755708763254619264831853746015614457568707574289360541474768076689519718"
+            )
+            .unwrap(),
+            y: Fr::from_str(
+                "17364390373284516257285034247139577682165868767001357086426373468799918686336"
+            )
+            .unwrap(),
+        }
+    );
+}
+
+#[test]
+fn test_bandersnatch_small_order() {
+    // (0, -1) has order 2
+    let mut bytes = [0u8; 32];
+    let mut y = Fr::one();
+    y.negate();
+    y.into_repr().write_le(&mut bytes[..]).unwrap();
+    let t2 = BandersnatchAffine::from_bytes_unchecked(&bytes).unwrap();
+    assert!(t2.x.is_zero());
+    match BandersnatchAffine::from_bytes(&bytes) {
+        Err(Error::NotInSubgroup) => (),
+        _ => panic!("should have rejected point outside the subgroup"),
+    }
+
+    let t2 = t2.into_projective();
+    assert!(t2.is_small_order());
+    assert!(!t2.is_in_subgroup());
+
+    // there are no affine points with y = 0, since 1 / a is not a square
+    match BandersnatchAffine::from_bytes_unchecked(&[0u8; 32]) {
+        Err(Error::NotOnCurve) => (),
+        _ => panic!("should have rejected point not on the curve"),
+    }
+
+    // a generator plus a small-order point is cleared by the cofactor
+    let mut p = Bandersnatch::one();
+    p.add_assign(&t2);
+    assert!(!p.is_in_subgroup());
+    p.mul_by_cofactor();
+    let mut expect = Bandersnatch::one();
+    expect.mul_assign(Fs::from_str("4").unwrap());
+    assert_eq!(p, expect);
+}
+
+#[test]
+fn test_bandersnatch_encoding() {
+    let mut one = [0u8; 32];
+    one[0] = 1;
+    assert_eq!(BandersnatchAffine::zero().into_bytes(), one);
+    assert!(BandersnatchAffine::from_bytes(&one).unwrap().is_zero());
+
+    // the generator has an even x-coordinate
+    let g = BandersnatchAffine::one();
+    assert_eq!(g.into_bytes()[31] >> 7, 0);
+    let mut neg_g = g;
+    neg_g.negate();
+    assert_eq!(neg_g.into_bytes()[31] >> 7, 1);
+    assert_eq!(
+        BandersnatchAffine::from_bytes(&neg_g.into_bytes()).unwrap(),
+        neg_g
+    );
+}
+
+#[test]
+fn bandersnatch_curve_tests() {
+    crate::tests::edwards::edwards_tests::<Bandersnatch>();
+}
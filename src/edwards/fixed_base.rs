@@ -0,0 +1,107 @@
+/*!
+Fixed-base scalar multiplication with precomputed window tables.
+
+For a base B and window width w, row i of the table holds j 2^(w i) B for
+j in 0..2^w, so that a scalar multiplication costs one mixed addition per
+w-bit window of the scalar and no doublings.
+*/
+
+use super::EdwardsProjective;
+use alloc::vec::Vec;
+use ff::PrimeField;
+
+/// Precomputed multiples of a fixed base point.
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<G: EdwardsProjective> {
+    window: usize,
+    table: Vec<Vec<G::Affine>>,
+}
+
+impl<G: EdwardsProjective> FixedBaseTable<G> {
+    /// Precomputes the table for base with windows of window bits, for
+    /// window in 1..=16.
+    pub fn new(base: &G, window: usize) -> Self {
+        assert!(
+            window > 0 && window <= 16,
+            "window must be between 1 and 16 bits"
+        );
+
+        let num_bits = <G::Scalar as PrimeField>::NUM_BITS as usize;
+        let num_rows = num_bits.div_ceil(window);
+
+        let mut table = Vec::with_capacity(num_rows);
+        let mut row_base = *base;
+        for _ in 0..num_rows {
+            let mut row = Vec::with_capacity(1 << window);
+            let mut acc = G::zero();
+            for _ in 0..(1 << window) {
+                row.push(acc.into_affine());
+                acc.add_assign(&row_base);
+            }
+            table.push(row);
+
+            // acc is now 2^window * row_base
+            row_base = acc;
+        }
+
+        FixedBaseTable { window, table }
+    }
+
+    /// Returns the window width of this table.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Multiplies the base by scalar.
+    pub fn mul<S: Into<<G::Scalar as PrimeField>::Repr>>(&self, scalar: S) -> G {
+        let repr = scalar.into();
+        let limbs = repr.as_ref();
+
+        let mut res = G::zero();
+        for (idx, row) in self.table.iter().enumerate() {
+            let digit = get_bits(limbs, idx * self.window, self.window);
+            res.add_assign_mixed(&row[digit]);
+        }
+        res
+    }
+}
+
+/// Returns len bits of the little-endian limbs starting at bit start.
+fn get_bits(limbs: &[u64], start: usize, len: usize) -> usize {
+    let mut ret = 0usize;
+    for jdx in 0..len {
+        let bit = start + jdx;
+        if bit / 64 < limbs.len() && (limbs[bit / 64] >> (bit % 64)) & 1 == 1 {
+            ret |= 1 << jdx;
+        }
+    }
+    ret
+}
+
+#[test]
+fn test_fixed_base_table() {
+    use super::jubjub::{Fs, Jubjub};
+    use ff::Field;
+    use rand_core::SeedableRng;
+
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let base = Jubjub::random(&mut rng);
+    for window in [1, 4, 5, 8].iter() {
+        let table = FixedBaseTable::new(&base, *window);
+        assert_eq!(table.window(), *window);
+
+        assert!(table.mul(Fs::zero()).is_zero());
+        assert_eq!(table.mul(Fs::one()), base);
+
+        for _ in 0..10 {
+            let s = Fs::random(&mut rng);
+            let mut expect = base;
+            expect.mul_assign(s);
+            assert_eq!(table.mul(s), expect);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr};
+
+// The scalar field of Jubjub, i.e., the order of its prime order subgroup
+#[derive(PrimeField, Zeroize)]
+#[PrimeFieldModulus = "6554484396890773809930967563523245729705921265872317281365359162392183254199"]
+#[PrimeFieldGenerator = "6"]
+pub struct Fs(FsRepr);
+
+/// set the default value for Fs to 0
+impl ::core::default::Default for Fs {
+    fn default() -> Self {
+        Fs::zero()
+    }
+}
+
+/// # Safety
+pub const unsafe fn transmute(r: FsRepr) -> Fs {
+    Fs(r)
+}
+
+#[test]
+fn test_fs_root_of_unity() {
+    use ff::SqrtField;
+
+    assert_eq!(Fs::S, 1);
+    assert_eq!(
+        Fs::multiplicative_generator(),
+        Fs::from_repr(FsRepr::from(6)).unwrap()
+    );
+    assert_eq!(
+        Fs::multiplicative_generator().pow([
+            0x684b872f6b7b965b,
+            0x53341049e6640841,
+            0x83339d80809a1d80,
+            0x73eda753299d7d4
+        ]),
+        Fs::root_of_unity()
+    );
+    assert_eq!(Fs::root_of_unity().pow([1 << Fs::S]), Fs::one());
+    assert!(Fs::multiplicative_generator().sqrt().is_none());
+}
+
+#[test]
+fn fs_field_tests() {
+    crate::tests::field::random_field_tests::<Fs>();
+    crate::tests::field::random_sqrt_tests::<Fs>();
+    crate::tests::field::random_frobenius_tests::<Fs, _>(Fs::char(), 13);
+    crate::tests::field::from_str_tests::<Fs>();
+}
+
+#[test]
+fn fs_repr_tests() {
+    crate::tests::repr::random_repr_tests::<Fs, FsRepr>();
+}
@@ -0,0 +1,227 @@
+/*!
+Jubjub, the twisted Edwards curve -x^2 + y^2 = 1 - (10240/10241) x^2 y^2 over
+the BLS12-381 scalar field, with cofactor 8. Since a = -1 is a square and d is
+not, the addition law is complete.
+*/
+
+mod fs;
+
+pub use self::fs::{Fs, FsRepr};
+
+pub mod transmute {
+    pub use super::fs::transmute as fs;
+}
+
+use super::{EdwardsAffine, EdwardsProjective};
+use crate::{
+    bls12_381::{transmute::fr, Fr, FrRepr},
+    Error,
+};
+use ff::{BitIterator, Field, PrimeField, PrimeFieldRepr, SqrtField};
+
+edwards_impl!("Jubjub", Jubjub, JubjubAffine, Fs, 3);
+
+// a = -1
+const COEFF_A: Fr = unsafe {
+    fr(FrRepr([
+        0xfffffffd00000003,
+        0xfb38ec08fffb13fc,
+        0x99ad88181ce5880f,
+        0x5bc8f5f97cd877d8,
+    ]))
+};
+
+// d = -(10240/10241)
+const COEFF_D: Fr = unsafe {
+    fr(FrRepr([
+        0x2a522455b974f6b0,
+        0xfc6cc9ef0d9acab3,
+        0x7a08fb94c27628d1,
+        0x57f8f6a8fe0e262e,
+    ]))
+};
+
+// Generator of the prime order subgroup
+// x = 8076246640662884909881801758704306714034609987455869804520522091855516602923
+// y = 13262374693698910701929044844600465831413122818447359594527400194675274060458
+const GENERATOR_X: Fr = unsafe {
+    fr(FrRepr([
+        0x36d32c5896c2f1c5,
+        0x56f8ca079b9bc5f6,
+        0x98e8d1d8a8683c8d,
+        0x15ad7c5e2e165cc,
+    ]))
+};
+
+const GENERATOR_Y: Fr = unsafe {
+    fr(FrRepr([
+        0xfe0637f4ed09b09f,
+        0x5ad237c95e4b2d2c,
+        0xd943ede0fed9a448,
+        0x5e2655b261c9d780,
+    ]))
+};
+
+impl JubjubAffine {
+    fn get_coeff_a() -> Fr {
+        COEFF_A
+    }
+
+    fn get_coeff_d() -> Fr {
+        COEFF_D
+    }
+
+    fn get_generator() -> Self {
+        JubjubAffine {
+            x: GENERATOR_X,
+            y: GENERATOR_Y,
+        }
+    }
+}
+
+#[test]
+fn test_jubjub_coeffs() {
+    let mut a = Fr::one();
+    a.negate();
+    assert_eq!(JubjubAffine::get_coeff_a(), a);
+
+    let mut d = Fr::from_str("10240").unwrap();
+    d.mul_assign(&Fr::from_str("10241").unwrap().inverse().unwrap());
+    d.negate();
+    assert_eq!(JubjubAffine::get_coeff_d(), d);
+
+    // d is not a square, so the addition law is complete
+    assert!(d.sqrt().is_none());
+}
+
+#[test]
+fn test_jubjub_generator() {
+    let g = JubjubAffine::one();
+    assert_eq!(
+        g.x,
+        Fr::from_str(
+            "8076246640662884909881801758704306714034609987455869804520522091855516602923"
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        g.y,
+        Fr::from_str(
+            "13262374693698910701929044844600465831413122818447359594527400194675274060458"
+        )
+        .unwrap()
+    );
+    assert!(g.is_on_curve());
+    assert!(g.into_projective().is_in_subgroup());
+    assert!(!g.into_projective().is_small_order());
+}
+
+#[test]
+fn test_jubjub_addition_and_doubling() {
+    let mut p = Jubjub::one();
+    p.double();
+    assert_eq!(
+        p.into_affine(),
+        JubjubAffine {
+            x: Fr::from_str(
+                "29927994414980659866747158113976867771786823169860303107907009997724489194957"
+            )
+            .unwrap(),
+            y: Fr::from_str(
+                "462950763047385854792912911337076492277172577361226262929952084963852328241"
+            )
+            .unwrap(),
+        }
+    );
+
+    p.add_assign(&Jubjub::one());
+    assert_eq!(
+        p.into_affine(),
+        JubjubAffine {
+            x: Fr::from_str(
+                "45763976842262823160295807685326507554022491488280968540559802656136203717715"
+            )
+            .unwrap(),
+            y: Fr::from_str(
+                "28613822079681605882499475341323216283573790414551935851064205296797669937565"
+            )
+            .unwrap(),
+        }
+    );
+}
+
+#[test]
+fn test_jubjub_small_order() {
+    // (0, -1) has order 2
+    let mut bytes = [0u8; 32];
+    let mut y = Fr::one();
+    y.negate();
+    y.into_repr().write_le(&mut bytes[..]).unwrap();
+    let t2 = JubjubAffine::from_bytes_unchecked(&bytes).unwrap();
+    assert!(t2.x.is_zero());
+    match JubjubAffine::from_bytes(&bytes) {
+        Err(Error::NotInSubgroup) => (),
+        _ => panic!("should have rejected point outside the subgroup"),
+    }
+
+    // (+-sqrt(-1), 0) have order 4
+    let t4 = JubjubAffine::from_bytes_unchecked(&[0u8; 32]).unwrap();
+    let mut t4_double = t4.into_projective();
+    t4_double.double();
+    assert_eq!(t4_double.into_affine(), t2);
+
+    for t in [t2, t4].iter() {
+        let t = t.into_projective();
+        assert!(t.is_small_order());
+        assert!(!t.is_in_subgroup());
+
+        // a generator plus a small-order point is cleared by the cofactor
+        let mut p = Jubjub::one();
+        p.add_assign(&t);
+        assert!(!p.is_in_subgroup());
+        p.mul_by_cofactor();
+        let mut expect = Jubjub::one();
+        expect.mul_assign(Fs::from_str("8").unwrap());
+        assert_eq!(p, expect);
+    }
+}
+
+#[test]
+fn test_jubjub_encoding() {
+    // the identity encodes y = 1 with a positive x
+    let mut one = [0u8; 32];
+    one[0] = 1;
+    assert_eq!(JubjubAffine::zero().into_bytes(), one);
+    assert!(JubjubAffine::from_bytes(&one).unwrap().is_zero());
+
+    // there is no negative zero
+    one[31] |= 1 << 7;
+    match JubjubAffine::from_bytes_unchecked(&one) {
+        Err(Error::NotOnCurve) => (),
+        _ => panic!("should have rejected negative zero x-coordinate"),
+    }
+
+    // y must be canonical
+    let mut bytes = [0xffu8; 32];
+    bytes[31] = 0x7f;
+    match JubjubAffine::from_bytes_unchecked(&bytes) {
+        Err(Error::NonCanonicalFieldElement(..)) => (),
+        _ => panic!("should have rejected non-canonical y-coordinate"),
+    }
+
+    // the generator has an odd x-coordinate
+    let g = JubjubAffine::one();
+    assert_eq!(g.into_bytes()[31] >> 7, 1);
+    let mut neg_g = g;
+    neg_g.negate();
+    assert_eq!(neg_g.into_bytes()[31] >> 7, 0);
+    assert_eq!(
+        JubjubAffine::from_bytes(&neg_g.into_bytes()).unwrap(),
+        neg_g
+    );
+}
+
+#[test]
+fn jubjub_curve_tests() {
+    crate::tests::edwards::edwards_tests::<Jubjub>();
+}
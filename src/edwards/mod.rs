@@ -0,0 +1,520 @@
+/*!
+Twisted Edwards curves embedded in BLS12-381, i.e., defined over the BLS12-381
+scalar field Fr, so that their group operations are cheap inside circuits
+over BLS12-381.
+
+Two curves are provided: Jubjub, -x^2 + y^2 = 1 + d x^2 y^2 with
+d = -(10240/10241) and cofactor 8, and Bandersnatch, -5 x^2 + y^2 = 1 + d x^2 y^2
+with cofactor 4. Points are kept in extended twisted Edwards coordinates
+(X : Y : Z : T) with x = X/Z, y = Y/Z and x y = T/Z, and are encoded in
+32 bytes as the little-endian y-coordinate with the sign of x in the most
+significant bit, as in Zcash.
+
+Unlike the pairing groups, a point decoded without checks or built from
+arbitrary coordinates may have a small-order component; use
+`mul_by_cofactor` or `is_in_subgroup` to deal with it.
+*/
+
+use crate::Error;
+use ff::{PrimeField, SqrtField};
+use core::fmt;
+
+/// Extended twisted Edwards representation of a point on an embedded curve.
+pub trait EdwardsProjective:
+    PartialEq + Eq + Sized + Copy + Clone + Send + Sync + fmt::Debug + fmt::Display + 'static
+{
+    type Scalar: PrimeField + SqrtField;
+    type Base: SqrtField;
+    type Affine: EdwardsAffine<Projective = Self, Scalar = Self::Scalar>;
+
+    /// Generate a random point in the prime order subgroup.
+    fn random<R: rand_core::RngCore>(rng: &mut R) -> Self;
+
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns a fixed generator of the prime order subgroup.
+    fn one() -> Self;
+
+    /// Determines if this point is the identity.
+    fn is_zero(&self) -> bool;
+
+    /// Doubles this element.
+    fn double(&mut self);
+
+    /// Adds another element to this element.
+    fn add_assign(&mut self, other: &Self);
+
+    /// Subtracts another element from this element.
+    fn sub_assign(&mut self, other: &Self) {
+        let mut tmp = *other;
+        tmp.negate();
+        self.add_assign(&tmp);
+    }
+
+    /// Adds an affine element to this element.
+    fn add_assign_mixed(&mut self, other: &Self::Affine);
+
+    /// Subtracts an affine element from this element
+    fn sub_assign_mixed(&mut self, other: &Self::Affine) {
+        let mut tmp = *other;
+        tmp.negate();
+        self.add_assign_mixed(&tmp);
+    }
+
+    /// Negates this element.
+    fn negate(&mut self);
+
+    /// Performs scalar multiplication of this element.
+    fn mul_assign<S: Into<<Self::Scalar as PrimeField>::Repr>>(&mut self, other: S);
+
+    /// Converts this element into its affine representation.
+    fn into_affine(&self) -> Self::Affine;
+
+    /// Multiplies this element by the cofactor of the curve, mapping any
+    /// point into the prime order subgroup.
+    fn mul_by_cofactor(&mut self);
+
+    /// Determines if this point has order dividing the cofactor.
+    fn is_small_order(&self) -> bool;
+
+    /// Determines if this point lies in the prime order subgroup.
+    fn is_in_subgroup(&self) -> bool;
+
+    /// Borrow references to the X, Y, Z and T coordinates of this point.
+    fn as_tuple(&self) -> (&Self::Base, &Self::Base, &Self::Base, &Self::Base);
+}
+
+/// Affine representation of a point on an embedded twisted Edwards curve.
+pub trait EdwardsAffine:
+    Copy + Clone + Sized + Send + Sync + fmt::Debug + fmt::Display + PartialEq + Eq + 'static
+{
+    type Scalar: PrimeField + SqrtField;
+    type Base: SqrtField;
+    type Projective: EdwardsProjective<Affine = Self, Scalar = Self::Scalar>;
+
+    /// Returns the additive identity (0, 1).
+    fn zero() -> Self;
+
+    /// Returns a fixed generator of the prime order subgroup.
+    fn one() -> Self;
+
+    /// Determines if this point is the identity.
+    fn is_zero(&self) -> bool;
+
+    /// Negates this element.
+    fn negate(&mut self);
+
+    /// Performs scalar multiplication of this element with mixed addition.
+    fn mul<S: Into<<Self::Scalar as PrimeField>::Repr>>(&self, other: S) -> Self::Projective;
+
+    /// Converts this element into its extended representation.
+    fn into_projective(&self) -> Self::Projective;
+
+    /// Encodes this element as the little-endian y-coordinate, with the
+    /// sign of x in the most significant bit.
+    fn into_bytes(&self) -> [u8; 32];
+
+    /// Decodes a point, checking that it lies in the prime order subgroup.
+    fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Error>;
+
+    /// Decodes a point on the curve, which may have a small-order component.
+    fn from_bytes_unchecked(bytes: &[u8; 32]) -> Result<Self, Error>;
+
+    /// Borrow references to the x and y coordinates of this point.
+    fn as_tuple(&self) -> (&Self::Base, &Self::Base);
+}
+
+macro_rules! edwards_impl {
+    (
+        $name:expr,
+        $projective:ident,
+        $affine:ident,
+        $scalarfield:ident,
+        $cofactor_log2:expr
+    ) => {
+        #[derive(Copy, Clone, PartialEq, Eq, Debug, Zeroize)]
+        pub struct $affine {
+            pub(crate) x: Fr,
+            pub(crate) y: Fr,
+        }
+
+        impl ::core::default::Default for $affine {
+            fn default() -> Self {
+                $affine::zero()
+            }
+        }
+
+        impl ::core::fmt::Display for $affine {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                write!(f, "{}(x={}, y={})", $name, self.x, self.y)
+            }
+        }
+
+        #[derive(Copy, Clone, Debug, Eq, Zeroize)]
+        pub struct $projective {
+            pub(crate) x: Fr,
+            pub(crate) y: Fr,
+            pub(crate) z: Fr,
+            pub(crate) t: Fr,
+        }
+
+        impl ::core::default::Default for $projective {
+            fn default() -> Self {
+                $projective::zero()
+            }
+        }
+
+        impl ::core::fmt::Display for $projective {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                write!(f, "{}", self.into_affine())
+            }
+        }
+
+        impl PartialEq for $projective {
+            fn eq(&self, other: &$projective) -> bool {
+                // (X : Y : Z : T) and (X' : Y' : Z' : T') are equal when
+                // X Z' = X' Z and Y Z' = Y' Z; T is determined by X, Y and Z.
+                let mut x1 = self.x;
+                x1.mul_assign(&other.z);
+                let mut x2 = other.x;
+                x2.mul_assign(&self.z);
+                if x1 != x2 {
+                    return false;
+                }
+
+                let mut y1 = self.y;
+                y1.mul_assign(&other.z);
+                let mut y2 = other.y;
+                y2.mul_assign(&self.z);
+                y1 == y2
+            }
+        }
+
+        impl $affine {
+            /// Attempts to construct a point given its y-coordinate and the sign
+            /// (least significant bit) of its x-coordinate. The point is not
+            /// guaranteed to be in the prime order subgroup.
+            fn get_point_from_y(y: Fr, sign: bool) -> Option<$affine> {
+                // x^2 = (y^2 - 1) / (d y^2 - a)
+                let mut y2 = y;
+                y2.square();
+
+                let mut num = y2;
+                num.sub_assign(&Fr::one());
+
+                let mut den = y2;
+                den.mul_assign(&Self::get_coeff_d());
+                den.sub_assign(&Self::get_coeff_a());
+
+                let mut x2 = den.inverse()?;
+                x2.mul_assign(&num);
+
+                x2.sqrt().and_then(|mut x| {
+                    if x.is_zero() && sign {
+                        // there is no negative zero
+                        return None;
+                    }
+                    if (x.into_repr().as_ref()[0] & 1 == 1) != sign {
+                        x.negate();
+                    }
+                    Some($affine { x, y })
+                })
+            }
+
+            #[cfg(test)]
+            fn is_on_curve(&self) -> bool {
+                // a x^2 + y^2 = 1 + d x^2 y^2
+                let mut x2 = self.x;
+                x2.square();
+                let mut y2 = self.y;
+                y2.square();
+
+                let mut lhs = x2;
+                lhs.mul_assign(&Self::get_coeff_a());
+                lhs.add_assign(&y2);
+
+                let mut rhs = x2;
+                rhs.mul_assign(&y2);
+                rhs.mul_assign(&Self::get_coeff_d());
+                rhs.add_assign(&Fr::one());
+
+                lhs == rhs
+            }
+        }
+
+        impl EdwardsAffine for $affine {
+            type Scalar = $scalarfield;
+            type Base = Fr;
+            type Projective = $projective;
+
+            fn zero() -> Self {
+                $affine {
+                    x: Fr::zero(),
+                    y: Fr::one(),
+                }
+            }
+
+            fn one() -> Self {
+                Self::get_generator()
+            }
+
+            fn is_zero(&self) -> bool {
+                self.x.is_zero() && self.y == Fr::one()
+            }
+
+            fn negate(&mut self) {
+                self.x.negate();
+            }
+
+            fn mul<S: Into<<Self::Scalar as PrimeField>::Repr>>(&self, by: S) -> $projective {
+                let mut res = $projective::zero();
+                for i in BitIterator::new(by.into()) {
+                    res.double();
+                    if i {
+                        res.add_assign_mixed(self);
+                    }
+                }
+                res
+            }
+
+            fn into_projective(&self) -> $projective {
+                (*self).into()
+            }
+
+            fn into_bytes(&self) -> [u8; 32] {
+                let mut res = [0u8; 32];
+                self.y.into_repr().write_le(&mut res[..]).unwrap();
+                if self.x.into_repr().as_ref()[0] & 1 == 1 {
+                    res[31] |= 1 << 7;
+                }
+                res
+            }
+
+            fn from_bytes(bytes: &[u8; 32]) -> Result<Self, Error> {
+                let affine = Self::from_bytes_unchecked(bytes)?;
+
+                if !affine.into_projective().is_in_subgroup() {
+                    Err(Error::NotInSubgroup)
+                } else {
+                    Ok(affine)
+                }
+            }
+
+            fn from_bytes_unchecked(bytes: &[u8; 32]) -> Result<Self, Error> {
+                let mut copy = *bytes;
+                let sign = copy[31] >> 7 == 1;
+                copy[31] &= 0x7f;
+
+                let mut y = FrRepr::default();
+                y.read_le(&copy[..]).unwrap();
+                let y = Fr::from_repr(y)
+                    .map_err(|e| Error::NonCanonicalFieldElement("y coordinate", e))?;
+
+                $affine::get_point_from_y(y, sign).ok_or(Error::NotOnCurve)
+            }
+
+            fn as_tuple(&self) -> (&Fr, &Fr) {
+                (&self.x, &self.y)
+            }
+        }
+
+        impl EdwardsProjective for $projective {
+            type Scalar = $scalarfield;
+            type Base = Fr;
+            type Affine = $affine;
+
+            fn random<R: rand_core::RngCore>(rng: &mut R) -> Self {
+                loop {
+                    let y = Fr::random(rng);
+                    let sign = rng.next_u32() % 2 != 0;
+                    if let Some(p) = $affine::get_point_from_y(y, sign) {
+                        let mut p = p.into_projective();
+                        p.mul_by_cofactor();
+
+                        if !p.is_zero() {
+                            return p;
+                        }
+                    }
+                }
+            }
+
+            fn zero() -> Self {
+                $projective {
+                    x: Fr::zero(),
+                    y: Fr::one(),
+                    z: Fr::one(),
+                    t: Fr::zero(),
+                }
+            }
+
+            fn one() -> Self {
+                $affine::one().into()
+            }
+
+            fn is_zero(&self) -> bool {
+                self.x.is_zero() && self.y == self.z
+            }
+
+            fn double(&mut self) {
+                // http://hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#doubling-dbl-2008-hwcd
+                let mut a = self.x;
+                a.square();
+                let mut b = self.y;
+                b.square();
+                let mut c = self.z;
+                c.square();
+                c.double();
+                let mut d = a;
+                d.mul_assign(&$affine::get_coeff_a());
+                let mut e = self.x;
+                e.add_assign(&self.y);
+                e.square();
+                e.sub_assign(&a);
+                e.sub_assign(&b);
+                let mut g = d;
+                g.add_assign(&b);
+                let mut f = g;
+                f.sub_assign(&c);
+                let mut h = d;
+                h.sub_assign(&b);
+
+                self.x = e;
+                self.x.mul_assign(&f);
+                self.y = g;
+                self.y.mul_assign(&h);
+                self.t = e;
+                self.t.mul_assign(&h);
+                self.z = f;
+                self.z.mul_assign(&g);
+            }
+
+            fn add_assign(&mut self, other: &Self) {
+                // http://hyperelliptic.org/EFD/g1p/auto-twisted-extended.html#addition-add-2008-hwcd
+                let mut d = self.z;
+                d.mul_assign(&other.z);
+                self.add_assign_inner(&other.x, &other.y, &other.t, d);
+            }
+
+            fn add_assign_mixed(&mut self, other: &$affine) {
+                let mut t = other.x;
+                t.mul_assign(&other.y);
+                self.add_assign_inner(&other.x, &other.y, &t, self.z);
+            }
+
+            fn negate(&mut self) {
+                self.x.negate();
+                self.t.negate();
+            }
+
+            fn mul_assign<S: Into<<Self::Scalar as PrimeField>::Repr>>(&mut self, other: S) {
+                let mut res = Self::zero();
+                for i in BitIterator::new(other.into()) {
+                    res.double();
+                    if i {
+                        res.add_assign(self);
+                    }
+                }
+                *self = res;
+            }
+
+            fn into_affine(&self) -> $affine {
+                (*self).into()
+            }
+
+            fn mul_by_cofactor(&mut self) {
+                for _ in 0..$cofactor_log2 {
+                    self.double();
+                }
+            }
+
+            fn is_small_order(&self) -> bool {
+                let mut tmp = *self;
+                tmp.mul_by_cofactor();
+                tmp.is_zero()
+            }
+
+            fn is_in_subgroup(&self) -> bool {
+                let mut tmp = *self;
+                tmp.mul_assign($scalarfield::char());
+                tmp.is_zero()
+            }
+
+            fn as_tuple(&self) -> (&Fr, &Fr, &Fr, &Fr) {
+                (&self.x, &self.y, &self.z, &self.t)
+            }
+        }
+
+        impl $projective {
+            // Unified addition; d is Z1 Z2, which the caller computes so that
+            // mixed addition can skip a multiplication.
+            fn add_assign_inner(&mut self, x2: &Fr, y2: &Fr, t2: &Fr, d: Fr) {
+                let mut a = self.x;
+                a.mul_assign(x2);
+                let mut b = self.y;
+                b.mul_assign(y2);
+                let mut c = self.t;
+                c.mul_assign(t2);
+                c.mul_assign(&$affine::get_coeff_d());
+                let mut e = self.x;
+                e.add_assign(&self.y);
+                let mut tmp = *x2;
+                tmp.add_assign(y2);
+                e.mul_assign(&tmp);
+                e.sub_assign(&a);
+                e.sub_assign(&b);
+                let mut f = d;
+                f.sub_assign(&c);
+                let mut g = d;
+                g.add_assign(&c);
+                a.mul_assign(&$affine::get_coeff_a());
+                let mut h = b;
+                h.sub_assign(&a);
+
+                self.x = e;
+                self.x.mul_assign(&f);
+                self.y = g;
+                self.y.mul_assign(&h);
+                self.t = e;
+                self.t.mul_assign(&h);
+                self.z = f;
+                self.z.mul_assign(&g);
+            }
+        }
+
+        impl From<$affine> for $projective {
+            fn from(p: $affine) -> $projective {
+                let mut t = p.x;
+                t.mul_assign(&p.y);
+                $projective {
+                    x: p.x,
+                    y: p.y,
+                    z: Fr::one(),
+                    t,
+                }
+            }
+        }
+
+        impl From<$projective> for $affine {
+            fn from(p: $projective) -> $affine {
+                // Z is nonzero: the addition law is complete on Jubjub, and on
+                // Bandersnatch its exceptional cases involve points outside
+                // the prime order subgroup
+                let zinv = p.z.inverse().unwrap();
+                let mut x = p.x;
+                x.mul_assign(&zinv);
+                let mut y = p.y;
+                y.mul_assign(&zinv);
+                $affine { x, y }
+            }
+        }
+    };
+}
+
+pub mod bandersnatch;
+pub mod fixed_base;
+pub mod jubjub;
+pub mod pedersen;
+
+pub use self::fixed_base::FixedBaseTable;
+pub use self::pedersen::PedersenHash;
@@ -0,0 +1,195 @@
+/*!
+Pedersen hashing of bit strings to an embedded curve.
+
+This is the Zcash Sapling construction: the message is split into segments
+of 63 three-bit chunks, chunk j of a segment is encoded as the integer
+(1 - 2 s2) (1 + s0 + 2 s1) times 16^j, and the hash is the sum over segments
+of the segment's encoding times that segment's generator. With 63 chunks the
+encodings are distinct modulo any subgroup order above 2^252, so the hash is
+collision resistant as long as discrete logarithms between the generators
+are unknown.
+
+The generators are derived from a domain separation tag with
+expand_message_xmd (SHA-256) and try-and-increment, so this is not
+compatible with the Zcash generators.
+*/
+
+use super::{EdwardsAffine, EdwardsProjective, FixedBaseTable};
+use crate::hash_to_field::{ExpandMsg, ExpandMsgXmd};
+use alloc::vec::Vec;
+use ff::{Field, PrimeField};
+
+/// Number of three-bit chunks hashed with each generator
+pub const CHUNKS_PER_SEGMENT: usize = 63;
+
+/// Pedersen hash with a fixed set of generators.
+#[derive(Clone, Debug)]
+pub struct PedersenHash<G: EdwardsProjective> {
+    generators: Vec<FixedBaseTable<G>>,
+}
+
+impl<G: EdwardsProjective> PedersenHash<G> {
+    /// Derives generators for num_segments segments from dst, so that
+    /// messages of up to 189 num_segments bits can be hashed.
+    pub fn new(dst: &[u8], num_segments: usize) -> Self {
+        let generators = (0..num_segments as u32)
+            .map(|idx| FixedBaseTable::new(&hash_to_subgroup::<G>(dst, &idx.to_be_bytes()), 4))
+            .collect();
+        PedersenHash { generators }
+    }
+
+    /// Returns the longest message, in bits, that this instance can hash.
+    pub fn max_bits(&self) -> usize {
+        self.generators.len() * CHUNKS_PER_SEGMENT * 3
+    }
+
+    /// Hashes a bit string to a point in the prime order subgroup, or
+    /// returns None if the message is longer than max_bits.
+    pub fn hash<I: IntoIterator<Item = bool>>(&self, bits: I) -> Option<G> {
+        let mut bits = bits.into_iter().peekable();
+        let sixteen = G::Scalar::from_repr(<G::Scalar as PrimeField>::Repr::from(16)).unwrap();
+
+        let mut res = G::zero();
+        for table in &self.generators {
+            if bits.peek().is_none() {
+                return Some(res);
+            }
+
+            let mut acc = G::Scalar::zero();
+            let mut pow = G::Scalar::one();
+            for _ in 0..CHUNKS_PER_SEGMENT {
+                if bits.peek().is_none() {
+                    break;
+                }
+
+                // the last chunk is padded with zeros
+                let s0 = bits.next().unwrap_or(false);
+                let s1 = bits.next().unwrap_or(false);
+                let s2 = bits.next().unwrap_or(false);
+
+                let mut enc = pow;
+                if s0 {
+                    enc.add_assign(&pow);
+                }
+                if s1 {
+                    enc.add_assign(&pow);
+                    enc.add_assign(&pow);
+                }
+                if s2 {
+                    enc.negate();
+                }
+                acc.add_assign(&enc);
+                pow.mul_assign(&sixteen);
+            }
+
+            res.add_assign(&table.mul(acc));
+        }
+
+        if bits.peek().is_none() {
+            Some(res)
+        } else {
+            None
+        }
+    }
+}
+
+/// Deterministically derives a point of the prime order subgroup, with
+/// unknown discrete logarithm, from a domain separation tag and a message.
+pub fn hash_to_subgroup<G: EdwardsProjective>(dst: &[u8], msg: &[u8]) -> G {
+    for ctr in 0u8..=255 {
+        let mut input = msg.to_vec();
+        input.push(ctr);
+
+        let okm = ExpandMsgXmd::<sha2::Sha256>::expand_message(&input, dst, 32);
+        let mut bytes = [0u8; 32];
+        bytes.copy_from_slice(&okm);
+
+        if let Ok(p) = G::Affine::from_bytes_unchecked(&bytes) {
+            let mut p = p.into_projective();
+            p.mul_by_cofactor();
+            if !p.is_zero() {
+                return p;
+            }
+        }
+    }
+    panic!("hash_to_subgroup failed after 256 attempts");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::edwards::{bandersnatch::Bandersnatch, jubjub::Jubjub};
+
+    fn pedersen_tests<G: EdwardsProjective>() {
+        let h = PedersenHash::<G>::new(b"pairing-plus-pedersen-test", 2);
+        assert_eq!(h.max_bits(), 378);
+
+        // generators are deterministic, distinct and in the subgroup
+        let g0 = hash_to_subgroup::<G>(b"pairing-plus-pedersen-test", &0u32.to_be_bytes());
+        let g1 = hash_to_subgroup::<G>(b"pairing-plus-pedersen-test", &1u32.to_be_bytes());
+        assert_ne!(g0, g1);
+        assert!(g0.is_in_subgroup() && g1.is_in_subgroup());
+        let other = hash_to_subgroup::<G>(b"pairing-plus-pedersen-other", &0u32.to_be_bytes());
+        assert_ne!(g0, other);
+
+        // a single chunk (s0, s1, s2) hashes to (1 - 2 s2) (1 + s0 + 2 s1) g0
+        for (bits, k) in [
+            ([false, false, false], 1i64),
+            ([true, false, false], 2),
+            ([false, true, false], 3),
+            ([true, true, false], 4),
+            ([false, false, true], -1),
+            ([true, true, true], -4),
+        ]
+        .iter()
+        {
+            let mut expect = g0;
+            expect.mul_assign(G::Scalar::from_repr(k.unsigned_abs().into()).unwrap());
+            if *k < 0 {
+                expect.negate();
+            }
+            assert_eq!(h.hash(bits.iter().cloned()).unwrap(), expect);
+        }
+
+        // short chunks are padded with zeros
+        assert_eq!(
+            h.hash(vec![true]).unwrap(),
+            h.hash(vec![true, false, false]).unwrap()
+        );
+
+        // the second chunk is weighted by 16
+        let mut expect = g0;
+        expect.mul_assign(G::Scalar::from_repr(17.into()).unwrap());
+        assert_eq!(
+            h.hash(vec![false, false, false, false, false, false])
+                .unwrap(),
+            expect
+        );
+
+        // the second segment uses the second generator
+        let mut bits = vec![false; CHUNKS_PER_SEGMENT * 3];
+        bits.extend_from_slice(&[true, false, false]);
+        let mut expect = g1;
+        expect.double();
+        let mut first = PedersenHash::<G>::new(b"pairing-plus-pedersen-test", 1)
+            .hash(vec![false; CHUNKS_PER_SEGMENT * 3])
+            .unwrap();
+        first.add_assign(&expect);
+        assert_eq!(h.hash(bits).unwrap(), first);
+
+        // messages that are too long are rejected
+        assert!(h.hash(vec![false; 378]).is_some());
+        assert!(h.hash(vec![false; 379]).is_none());
+        assert!(h.hash(vec![]).unwrap().is_zero());
+    }
+
+    #[test]
+    fn test_pedersen_jubjub() {
+        pedersen_tests::<Jubjub>();
+    }
+
+    #[test]
+    fn test_pedersen_bandersnatch() {
+        pedersen_tests::<Bandersnatch>();
+    }
+}
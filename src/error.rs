@@ -0,0 +1,99 @@
+use core::fmt;
+use ff::PrimeFieldDecodingError;
+
+/// An error that may occur when decoding a field element or a point.
+#[derive(Debug)]
+pub enum Error {
+    /// The coordinate(s) do not lie on the curve.
+    NotOnCurve,
+    /// The point is not part of the prime-order subgroup.
+    NotInSubgroup,
+    /// A field element, or a coordinate of a point, is not less than the
+    /// modulus. The string names the element.
+    NonCanonicalFieldElement(&'static str, PrimeFieldDecodingError),
+    /// The flag bits of an encoded point are set in a combination that is
+    /// not valid, e.g. a sign bit on an uncompressed point.
+    BadFlags,
+    /// The encoded point was compressed but an uncompressed point was
+    /// expected, or vice versa.
+    UnexpectedCompressionMode,
+    /// The encoding of the point at infinity has nonzero coordinates.
+    NonZeroInfinity,
+    /// The encoding does not have the length of the expected format.
+    UnexpectedLength { expected: usize, actual: usize },
+}
+
+/// The previous name of `Error`, from when it only covered points.
+pub type GroupDecodingError = Error;
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match *self {
+            Error::NonCanonicalFieldElement(_, ref err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::NotOnCurve => write!(f, "coordinate(s) do not lie on the curve"),
+            Error::NotInSubgroup => write!(f, "the point is not part of the prime-order subgroup"),
+            Error::NonCanonicalFieldElement(name, ref err) => {
+                write!(f, "{} is not canonical: {}", name, err)
+            }
+            Error::BadFlags => write!(f, "encoding has invalid flag bits"),
+            Error::UnexpectedCompressionMode => {
+                write!(f, "encoding has unexpected compression mode")
+            }
+            Error::NonZeroInfinity => write!(f, "point at infinity has nonzero coordinates"),
+            Error::UnexpectedLength { expected, actual } => {
+                write!(f, "encoding has {} bytes, expected {}", actual, expected)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl From<Error> for std::io::Error {
+    /// Decoding errors are `InvalidData`; the `Error` can be recovered with
+    /// `get_ref` and `downcast_ref`.
+    fn from(err: Error) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, err)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::bls12_381::{Fq, G1Affine};
+    use crate::CurveAffine;
+
+    #[test]
+    fn test_error_source_and_io() {
+        let err = Fq::from_bytes_be(&[0xff; 48]).unwrap_err();
+        match err {
+            Error::NonCanonicalFieldElement("Fq", _) => (),
+            _ => panic!("expected a non-canonical field element"),
+        }
+        assert!(std::error::Error::source(&err).is_some());
+        assert!(err.to_string().starts_with("Fq is not canonical"));
+
+        let io: std::io::Error = Error::NotInSubgroup.into();
+        assert_eq!(io.kind(), std::io::ErrorKind::InvalidData);
+        match io.get_ref().and_then(|e| e.downcast_ref::<Error>()) {
+            Some(Error::NotInSubgroup) => (),
+            _ => panic!("expected the decoding error inside the io::Error"),
+        }
+
+        // a sign bit on an uncompressed point
+        let mut bytes = G1Affine::one().to_uncompressed_bytes();
+        bytes[0] |= 1 << 5;
+        match G1Affine::from_uncompressed_bytes(&bytes) {
+            Err(Error::BadFlags) => (),
+            _ => panic!("expected bad flags"),
+        }
+    }
+}
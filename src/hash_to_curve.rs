@@ -2,24 +2,73 @@
  This module defines a hash_to_curve trait.
 */
 
+mod cofactor;
+pub(crate) mod svdw;
+
+pub(crate) use self::cofactor::{mul_by_limbs, ClearH};
+pub(crate) use self::svdw::SvdwMap;
+
 use crate::{
-    bls12_381::{ClearH, IsogenyMap, OSSWUMap},
-    hash_to_field::{hash_to_field, ExpandMsg, FromRO},
+    bls12_377,
+    bls12_381::{IsogenyMap, OSSWUMap},
+    bn256,
+    hash_to_field::{try_hash_to_field_with, ExpandMsg, ExpandMsgError, FromRO},
     CurveProjective,
 };
+use digest::Update;
+use core::fmt;
+use core::marker::PhantomData;
 
 type CoordT<PtT> = <PtT as CurveProjective>::Base;
 
 /// Random oracle and injective maps to curve
-pub trait HashToCurve<X>
+pub trait HashToCurve<X>: Sized
 where
     X: ExpandMsg,
 {
     /// Random oracle
-    fn hash_to_curve<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(msg: Mt, dst: Dt) -> Self;
+    fn hash_to_curve<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(msg: Mt, dst: Dt) -> Self {
+        match Self::try_hash_to_curve(msg, dst) {
+            Ok(p) => p,
+            Err(e) => panic!("hash_to_curve failed: {}", e),
+        }
+    }
 
     /// Injective encoding
-    fn encode_to_curve<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(msg: Mt, dst: Dt) -> Self;
+    fn encode_to_curve<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(msg: Mt, dst: Dt) -> Self {
+        match Self::try_encode_to_curve(msg, dst) {
+            Ok(p) => p,
+            Err(e) => panic!("encode_to_curve failed: {}", e),
+        }
+    }
+
+    /// Random oracle; returns an error if message expansion fails
+    fn try_hash_to_curve<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(
+        msg: Mt,
+        dst: Dt,
+    ) -> Result<Self, ExpandMsgError> {
+        Self::try_hash_to_curve_with(X::hasher().chain(msg), dst)
+    }
+
+    /// Injective encoding; returns an error if message expansion fails
+    fn try_encode_to_curve<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(
+        msg: Mt,
+        dst: Dt,
+    ) -> Result<Self, ExpandMsgError> {
+        Self::try_encode_to_curve_with(X::hasher().chain(msg), dst)
+    }
+
+    /// Random oracle applied to the message fed into hasher
+    fn try_hash_to_curve_with<Dt: AsRef<[u8]>>(
+        hasher: X::Hasher,
+        dst: Dt,
+    ) -> Result<Self, ExpandMsgError>;
+
+    /// Injective encoding applied to the message fed into hasher
+    fn try_encode_to_curve_with<Dt: AsRef<[u8]>>(
+        hasher: X::Hasher,
+        dst: Dt,
+    ) -> Result<Self, ExpandMsgError>;
 }
 
 impl<PtT, X> HashToCurve<X> for PtT
@@ -28,9 +77,12 @@ where
     CoordT<PtT>: FromRO,
     X: ExpandMsg,
 {
-    fn hash_to_curve<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(msg: Mt, dst: Dt) -> PtT {
+    fn try_hash_to_curve_with<Dt: AsRef<[u8]>>(
+        hasher: X::Hasher,
+        dst: Dt,
+    ) -> Result<PtT, ExpandMsgError> {
         let mut p = {
-            let u = hash_to_field::<CoordT<PtT>, X>(msg.as_ref(), dst.as_ref(), 2);
+            let u = try_hash_to_field_with::<CoordT<PtT>, X>(hasher, dst.as_ref(), 2)?;
             let mut q0 = PtT::osswu_map(&u[0]);
             q0.isogeny_map();
             let mut q1 = PtT::osswu_map(&u[1]);
@@ -39,16 +91,156 @@ where
             q0
         };
         p.clear_h();
-        p
+        Ok(p)
     }
 
-    fn encode_to_curve<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(msg: Mt, dst: Dt) -> PtT {
+    fn try_encode_to_curve_with<Dt: AsRef<[u8]>>(
+        hasher: X::Hasher,
+        dst: Dt,
+    ) -> Result<PtT, ExpandMsgError> {
         let mut p = {
-            let u = hash_to_field::<CoordT<PtT>, X>(msg.as_ref(), dst.as_ref(), 1);
+            let u = try_hash_to_field_with::<CoordT<PtT>, X>(hasher, dst.as_ref(), 1)?;
             PtT::osswu_map(&u[0])
         };
         p.isogeny_map();
         p.clear_h();
-        p
+        Ok(p)
+    }
+}
+
+/// Implement hash_to_curve and encode_to_curve with the Shallue-van de Woestijne map
+macro_rules! svdw_hash_to_curve_impl {
+    ($pt:ty) => {
+        impl<X> HashToCurve<X> for $pt
+        where
+            X: ExpandMsg,
+        {
+            fn try_hash_to_curve_with<Dt: AsRef<[u8]>>(
+                hasher: X::Hasher,
+                dst: Dt,
+            ) -> Result<$pt, ExpandMsgError> {
+                let mut p = {
+                    let u = try_hash_to_field_with::<CoordT<$pt>, X>(hasher, dst.as_ref(), 2)?;
+                    let mut q0 = <$pt>::svdw_map(&u[0]);
+                    let q1 = <$pt>::svdw_map(&u[1]);
+                    q0.add_assign(&q1);
+                    q0
+                };
+                p.clear_h();
+                Ok(p)
+            }
+
+            fn try_encode_to_curve_with<Dt: AsRef<[u8]>>(
+                hasher: X::Hasher,
+                dst: Dt,
+            ) -> Result<$pt, ExpandMsgError> {
+                let mut p = {
+                    let u = try_hash_to_field_with::<CoordT<$pt>, X>(hasher, dst.as_ref(), 1)?;
+                    <$pt>::svdw_map(&u[0])
+                };
+                p.clear_h();
+                Ok(p)
+            }
+        }
+    };
+}
+
+svdw_hash_to_curve_impl!(bn256::G1);
+svdw_hash_to_curve_impl!(bn256::G2);
+svdw_hash_to_curve_impl!(bls12_377::G1);
+svdw_hash_to_curve_impl!(bls12_377::G2);
+
+/// Streaming input for hash_to_curve and encode_to_curve: feed the message
+/// in chunks with digest::Update, then finalize to a curve point.
+///
+/// The result is identical to calling HashToCurve on the concatenated chunks.
+pub struct HashToCurveHasher<PtT, X>
+where
+    X: ExpandMsg,
+{
+    hasher: X::Hasher,
+    phantom: PhantomData<PtT>,
+}
+
+impl<PtT, X> HashToCurveHasher<PtT, X>
+where
+    PtT: HashToCurve<X>,
+    X: ExpandMsg,
+{
+    /// Start hashing a new message
+    pub fn new() -> Self {
+        HashToCurveHasher {
+            hasher: X::hasher(),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Random oracle applied to the message fed so far
+    pub fn hash_to_curve<Dt: AsRef<[u8]>>(self, dst: Dt) -> PtT {
+        match self.try_hash_to_curve(dst) {
+            Ok(p) => p,
+            Err(e) => panic!("hash_to_curve failed: {}", e),
+        }
+    }
+
+    /// Injective encoding applied to the message fed so far
+    pub fn encode_to_curve<Dt: AsRef<[u8]>>(self, dst: Dt) -> PtT {
+        match self.try_encode_to_curve(dst) {
+            Ok(p) => p,
+            Err(e) => panic!("encode_to_curve failed: {}", e),
+        }
+    }
+
+    /// Random oracle applied to the message fed so far;
+    /// returns an error if message expansion fails
+    pub fn try_hash_to_curve<Dt: AsRef<[u8]>>(self, dst: Dt) -> Result<PtT, ExpandMsgError> {
+        PtT::try_hash_to_curve_with(self.hasher, dst)
+    }
+
+    /// Injective encoding applied to the message fed so far;
+    /// returns an error if message expansion fails
+    pub fn try_encode_to_curve<Dt: AsRef<[u8]>>(self, dst: Dt) -> Result<PtT, ExpandMsgError> {
+        PtT::try_encode_to_curve_with(self.hasher, dst)
+    }
+}
+
+impl<PtT, X> Default for HashToCurveHasher<PtT, X>
+where
+    PtT: HashToCurve<X>,
+    X: ExpandMsg,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<PtT, X> Clone for HashToCurveHasher<PtT, X>
+where
+    X: ExpandMsg,
+    X::Hasher: Clone,
+{
+    fn clone(&self) -> Self {
+        HashToCurveHasher {
+            hasher: self.hasher.clone(),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<PtT, X> Update for HashToCurveHasher<PtT, X>
+where
+    X: ExpandMsg,
+{
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.hasher.update(data);
+    }
+}
+
+impl<PtT, X> fmt::Debug for HashToCurveHasher<PtT, X>
+where
+    X: ExpandMsg,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HashToCurveHasher").finish()
     }
 }
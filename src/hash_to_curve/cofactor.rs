@@ -0,0 +1,24 @@
+/*!
+Cofactor clearing, the last step of hash_to_curve and encode_to_curve.
+*/
+
+use crate::CurveProjective;
+use ff::BitIterator;
+
+/// Trait implementing cofactor clearing for projective coords
+pub trait ClearH: CurveProjective {
+    /// Clear the cofactor in-place
+    fn clear_h(&mut self);
+}
+
+/// Multiply by a little-endian multiprecision integer, which may be wider than Fr
+pub(crate) fn mul_by_limbs<G: CurveProjective>(p: &mut G, limbs: &[u64]) {
+    let mut res = G::zero();
+    for bit in BitIterator::new(limbs) {
+        res.double();
+        if bit {
+            res.add_assign(p);
+        }
+    }
+    *p = res;
+}
@@ -0,0 +1,98 @@
+/*!
+The Shallue-van de Woestijne map for curves y^2 = x^3 + B, used by BN254
+and BLS12-377
+see: section 6.6.1 of https://www.rfc-editor.org/rfc/rfc9380
+*/
+
+use crate::{signum::Signum0, CurveProjective};
+use ff::SqrtField;
+
+/// Trait for mapping from base field element to curve point
+pub trait SvdwMap: CurveProjective {
+    /// Evaluate the Shallue-van de Woestijne map on supplied base field element
+    fn svdw_map(u: &<Self as CurveProjective>::Base) -> Self;
+}
+
+/// Constants of the Shallue-van de Woestijne map for y^2 = x^3 + B and Z:
+/// c1 = g(Z), c2 = -Z / 2, c3 = sqrt(-g(Z) * 3 Z^2) with sgn0(c3) = 0,
+/// and c4 = -4 g(Z) / (3 Z^2)
+pub(crate) struct SvdwConsts<F> {
+    pub(crate) b: F,
+    pub(crate) z: F,
+    pub(crate) c1: F,
+    pub(crate) c2: F,
+    pub(crate) c3: F,
+    pub(crate) c4: F,
+}
+
+/// Evaluate g(x) = x^3 + B
+#[inline(always)]
+pub(crate) fn eval_g<F: SqrtField>(x: &F, b: &F) -> F {
+    let mut tmp = *x;
+    tmp.square();
+    tmp.mul_assign(x);
+    tmp.add_assign(b);
+    tmp
+}
+
+/// Evaluate the map on u, returning the affine point (x, y)
+pub(crate) fn svdw_help<F: SqrtField + Signum0>(u: &F, consts: &SvdwConsts<F>) -> (F, F) {
+    let SvdwConsts {
+        b,
+        z,
+        c1,
+        c2,
+        c3,
+        c4,
+    } = consts;
+
+    let mut tv1 = *u;
+    tv1.square();
+    tv1.mul_assign(c1); // u^2 * c1
+    let mut tv2 = F::one();
+    tv2.add_assign(&tv1); // 1 + u^2 * c1
+    tv1.negate();
+    tv1.add_assign(&F::one()); // 1 - u^2 * c1
+
+    // inv0(tv1 * tv2)
+    let tv3 = {
+        let mut tmp = tv1;
+        tmp.mul_assign(&tv2);
+        tmp.inverse().unwrap_or_else(F::zero)
+    };
+
+    let mut tv4 = *u;
+    tv4.mul_assign(&tv1);
+    tv4.mul_assign(&tv3);
+    tv4.mul_assign(c3); // u * tv1 * tv3 * c3
+
+    let mut x1 = *c2;
+    x1.sub_assign(&tv4); // c2 - tv4
+    let mut x2 = *c2;
+    x2.add_assign(&tv4); // c2 + tv4
+
+    // select the first of x1, x2, x3 for which g(x) is square
+    let (x, mut y) = if let Some(y) = eval_g(&x1, b).sqrt() {
+        (x1, y)
+    } else if let Some(y) = eval_g(&x2, b).sqrt() {
+        (x2, y)
+    } else {
+        let mut x3 = tv2;
+        x3.square();
+        x3.mul_assign(&tv3);
+        x3.square();
+        x3.mul_assign(c4);
+        x3.add_assign(z); // Z + c4 * (tv2^2 * tv3)^2
+        let y = eval_g(&x3, b)
+            .sqrt()
+            .expect("g(x3) is square whenever g(x1) and g(x2) are not");
+        (x3, y)
+    };
+
+    // fix the sign of y
+    if u.sgn0() != y.sgn0() {
+        y.negate();
+    }
+
+    (x, y)
+}
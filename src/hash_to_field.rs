@@ -3,19 +3,59 @@
  for use with BLS signatures.
 */
 
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
 use digest::generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
-use digest::{BlockInput, Digest, ExtendableOutput, Update};
-use std::marker::PhantomData;
+use digest::{BlockInput, ExtendableOutput, FixedOutput, Update};
 
 /// hash_to_field for type T using ExpandMsg variant X
+///
+/// Panics if the requested output length is invalid for X; see try_hash_to_field.
 pub fn hash_to_field<T, X>(msg: &[u8], dst: &[u8], count: usize) -> Vec<T>
+where
+    T: FromRO,
+    X: ExpandMsg,
+{
+    match try_hash_to_field::<T, X>(msg, dst, count) {
+        Ok(ret) => ret,
+        Err(e) => panic!("hash_to_field failed: {}", e),
+    }
+}
+
+/// hash_to_field for type T using ExpandMsg variant X
+///
+/// Returns an error if count elements of T require more output than X can produce.
+pub fn try_hash_to_field<T, X>(
+    msg: &[u8],
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<T>, ExpandMsgError>
+where
+    T: FromRO,
+    X: ExpandMsg,
+{
+    try_hash_to_field_with::<T, X>(X::hasher().chain(msg), dst, count)
+}
+
+/// hash_to_field for type T using ExpandMsg variant X, applied to the
+/// message that has been fed incrementally into hasher (see ExpandMsg::hasher).
+///
+/// Returns an error if count elements of T require more output than X can produce.
+pub fn try_hash_to_field_with<T, X>(
+    hasher: X::Hasher,
+    dst: &[u8],
+    count: usize,
+) -> Result<Vec<T>, ExpandMsgError>
 where
     T: FromRO,
     X: ExpandMsg,
 {
     let len_per_elm = <T as FromRO>::Length::to_usize();
-    let len_in_bytes = count * len_per_elm;
-    let pseudo_random_bytes = X::expand_message(msg, dst, len_in_bytes);
+    let len_in_bytes = count
+        .checked_mul(len_per_elm)
+        .ok_or(ExpandMsgError::LengthTooLarge(usize::MAX))?;
+    let pseudo_random_bytes = X::try_expand_hasher(hasher, dst, len_in_bytes)?;
 
     let mut ret = Vec::<T>::with_capacity(count);
     for idx in 0..count {
@@ -24,7 +64,7 @@ where
         ret.push(T::from_ro(bytes_arr));
     }
 
-    ret
+    Ok(ret)
 }
 
 /// Generate a field element from a random string of bytes
@@ -51,89 +91,375 @@ pub trait BaseFromRO {
     fn from_okm(okm: &GenericArray<u8, <Self as BaseFromRO>::BaseLength>) -> Self;
 }
 
+/// Prefix used to hash a DST longer than 255 bytes
+const OVERSIZE_DST_SALT: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Largest len_in_bytes accepted by expand_message (it is encoded in two bytes)
+const MAX_LEN_IN_BYTES: usize = 65535;
+
+/// An error that may occur when expanding a message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpandMsgError {
+    /// The requested output length exceeds 65535 bytes
+    LengthTooLarge(usize),
+    /// expand_message_xmd would need more than 255 hash invocations
+    EllTooLarge(usize),
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExpandMsgError {}
+
+impl fmt::Display for ExpandMsgError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match *self {
+            ExpandMsgError::LengthTooLarge(len) => write!(
+                f,
+                "requested {} bytes, but expand_message outputs at most {}",
+                len, MAX_LEN_IN_BYTES
+            ),
+            ExpandMsgError::EllTooLarge(ell) => write!(
+                f,
+                "expand_message_xmd needs ell = {} blocks, but allows at most 255",
+                ell
+            ),
+        }
+    }
+}
+
+/// The state of an expand_message variant into which a message is fed
+/// incrementally. It can only be obtained from ExpandMsg::hasher, which
+/// absorbs the variant's prefix (e.g., Z_pad for expand_message_xmd), so a
+/// bare hash function state cannot be passed where one is expected:
+///
+/// ```compile_fail
+/// use pairing_plus::hash_to_field::{ExpandMsg, ExpandMsgXmd};
+/// use sha2::Sha256;
+///
+/// let _ = ExpandMsgXmd::<Sha256>::try_expand_hasher(Sha256::default(), b"DST", 32);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ExpandMsgHasher<HashT>(HashT);
+
+impl<HashT: Update> Update for ExpandMsgHasher<HashT> {
+    fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.0.update(data);
+    }
+}
+
 /// Trait for types implementing expand_message interface for hash_to_field
 pub trait ExpandMsg {
-    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8>;
+    /// Hasher state into which the message is fed incrementally
+    type Hasher: Update;
+
+    /// Returns a fresh hasher state; feed it the message with
+    /// digest::Update and pass it to try_expand_hasher.
+    fn hasher() -> Self::Hasher;
+
+    /// Expand the message fed into hasher to len_in_bytes pseudorandom bytes,
+    /// or return an error if len_in_bytes is not supported.
+    fn try_expand_hasher(
+        hasher: Self::Hasher,
+        dst: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, ExpandMsgError>;
+
+    /// Expand msg to len_in_bytes pseudorandom bytes, or return an error
+    /// if len_in_bytes is not supported.
+    fn try_expand_message(
+        msg: &[u8],
+        dst: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, ExpandMsgError> {
+        Self::try_expand_hasher(Self::hasher().chain(msg), dst, len_in_bytes)
+    }
+
+    /// Expand msg to len_in_bytes pseudorandom bytes.
+    /// Panics if len_in_bytes is not supported.
+    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
+        match Self::try_expand_message(msg, dst, len_in_bytes) {
+            Ok(ret) => ret,
+            Err(e) => panic!("expand_message failed: {}", e),
+        }
+    }
 }
 
 /// Placeholder type for implementing expand_message_xof based on a hash function
+///
+/// DSTs longer than 255 bytes are hashed to 32 bytes, i.e., k = 128.
 #[derive(Debug)]
 pub struct ExpandMsgXof<HashT> {
     phantom: PhantomData<HashT>,
 }
 
+impl<HashT> ExpandMsgXof<HashT>
+where
+    HashT: Default + ExtendableOutput + Update,
+{
+    /// Returns DST', hashing the DST first if it is longer than 255 bytes
+    fn dst_prime(dst: &[u8]) -> Vec<u8> {
+        let mut ret = if dst.len() > 255 {
+            HashT::default()
+                .chain(OVERSIZE_DST_SALT)
+                .chain(dst)
+                .finalize_boxed(32)
+                .to_vec()
+        } else {
+            dst.to_vec()
+        };
+        ret.push(ret.len() as u8);
+        ret
+    }
+}
+
 /// ExpandMsgXof implements expand_message_xof for the ExpandMsg trait
 impl<HashT> ExpandMsg for ExpandMsgXof<HashT>
 where
     HashT: Default + ExtendableOutput + Update,
 {
-    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
-        HashT::default()
-            .chain(msg)
+    type Hasher = ExpandMsgHasher<HashT>;
+
+    fn hasher() -> ExpandMsgHasher<HashT> {
+        ExpandMsgHasher(HashT::default())
+    }
+
+    fn try_expand_hasher(
+        hasher: ExpandMsgHasher<HashT>,
+        dst: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, ExpandMsgError> {
+        if len_in_bytes > MAX_LEN_IN_BYTES {
+            return Err(ExpandMsgError::LengthTooLarge(len_in_bytes));
+        }
+
+        Ok(hasher
+            .0
             .chain([(len_in_bytes >> 8) as u8, len_in_bytes as u8])
-            .chain(dst)
-            .chain([dst.len() as u8])
+            .chain(Self::dst_prime(dst))
             .finalize_boxed(len_in_bytes)
-            .to_vec()
+            .to_vec())
     }
 }
 
 /// Placeholder type for implementing expand_message_xmd based on a hash function
+///
+/// DSTs longer than 255 bytes are hashed with HashT.
 #[derive(Debug)]
 pub struct ExpandMsgXmd<HashT> {
     phantom: PhantomData<HashT>,
 }
 
+impl<HashT> ExpandMsgXmd<HashT>
+where
+    HashT: BlockInput + Default + FixedOutput + Update,
+{
+    /// Returns DST', hashing the DST first if it is longer than 255 bytes
+    fn dst_prime(dst: &[u8]) -> Vec<u8> {
+        let mut ret = if dst.len() > 255 {
+            HashT::default()
+                .chain(OVERSIZE_DST_SALT)
+                .chain(dst)
+                .finalize_fixed()
+                .to_vec()
+        } else {
+            dst.to_vec()
+        };
+        ret.push(ret.len() as u8);
+        ret
+    }
+}
+
 /// ExpandMsgXmd implements expand_message_xmd for the ExpandMsg trait
 impl<HashT> ExpandMsg for ExpandMsgXmd<HashT>
 where
-    HashT: Digest + BlockInput,
+    HashT: BlockInput + Default + FixedOutput + Update,
 {
-    fn expand_message(msg: &[u8], dst: &[u8], len_in_bytes: usize) -> Vec<u8> {
-        let b_in_bytes = <HashT as Digest>::OutputSize::to_usize();
+    type Hasher = ExpandMsgHasher<HashT>;
+
+    /// Returns a hasher that has already absorbed Z_pad
+    fn hasher() -> ExpandMsgHasher<HashT> {
+        ExpandMsgHasher(
+            HashT::default().chain(GenericArray::<u8, <HashT as BlockInput>::BlockSize>::default()),
+        )
+    }
+
+    fn try_expand_hasher(
+        hasher: ExpandMsgHasher<HashT>,
+        dst: &[u8],
+        len_in_bytes: usize,
+    ) -> Result<Vec<u8>, ExpandMsgError> {
+        // check the length first, so that computing ell cannot overflow
+        if len_in_bytes > MAX_LEN_IN_BYTES {
+            return Err(ExpandMsgError::LengthTooLarge(len_in_bytes));
+        }
+        let b_in_bytes = <HashT as FixedOutput>::OutputSize::to_usize();
         let ell = (len_in_bytes + b_in_bytes - 1) / b_in_bytes;
         if ell > 255 {
-            panic!("ell was too big in expand_message_xmd");
+            return Err(ExpandMsgError::EllTooLarge(ell));
         }
-        let b_0 = HashT::new()
-            .chain(GenericArray::<u8, <HashT as BlockInput>::BlockSize>::default())
-            .chain(msg)
+
+        let dst_prime = Self::dst_prime(dst);
+        let b_0 = hasher
+            .0
             .chain([(len_in_bytes >> 8) as u8, len_in_bytes as u8, 0u8])
-            .chain(dst)
-            .chain([dst.len() as u8])
-            .finalize();
+            .chain(&dst_prime)
+            .finalize_fixed();
 
         let mut b_vals = Vec::<u8>::with_capacity(ell * b_in_bytes);
         // b_1
         b_vals.extend_from_slice(
-            HashT::new()
+            HashT::default()
                 .chain(&b_0[..])
                 .chain([1u8])
-                .chain(dst)
-                .chain([dst.len() as u8])
-                .finalize()
+                .chain(&dst_prime)
+                .finalize_fixed()
                 .as_ref(),
         );
 
         for idx in 1..ell {
             // b_0 XOR b_(idx - 1)
-            let mut tmp = GenericArray::<u8, <HashT as Digest>::OutputSize>::default();
+            let mut tmp = GenericArray::<u8, <HashT as FixedOutput>::OutputSize>::default();
             b_0.iter()
                 .zip(&b_vals[(idx - 1) * b_in_bytes..idx * b_in_bytes])
                 .enumerate()
                 .for_each(|(jdx, (b0val, bi1val))| tmp[jdx] = b0val ^ bi1val);
             b_vals.extend_from_slice(
-                HashT::new()
+                HashT::default()
                     .chain(tmp)
                     .chain([(idx + 1) as u8])
-                    .chain(dst)
-                    .chain([dst.len() as u8])
-                    .finalize()
+                    .chain(&dst_prime)
+                    .finalize_fixed()
                     .as_ref(),
             );
         }
 
         b_vals.truncate(len_in_bytes);
-        b_vals
+        Ok(b_vals)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::{Fq, Fq2};
+    use digest::Digest;
+    use sha2::Sha256;
+    use sha3::Shake128;
+
+    fn long_dst(prefix: &[u8]) -> Vec<u8> {
+        let mut dst = prefix.to_vec();
+        dst.resize(256, b'1');
+        dst
+    }
+
+    #[test]
+    fn test_expand_message_xmd_oversize_dst() {
+        // expand_message_xmd(SHA-256), long DST
+        let dst = long_dst(b"QUUX-V01-CS02-with-expander-SHA256-128-long-DST-");
+        let tests: [(&[u8], usize, &str); 3] = [
+            (b"", 0x20, "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3"),
+            (b"abc", 0x20, "52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12"),
+            (b"abcdef0123456789", 0x80, "d2ecef3635d2397f34a9f86438d772db19ffe9924e28a1caf6f1c8f15603d4028f40891044e5c7e39ebb9b31339979ff33a4249206f67d4a1e7c765410bcd249ad78d407e303675918f20f26ce6d7027ed3774512ef5b00d816e51bfcc96c3539601fa48ef1c07e494bdc37054ba96ecb9dbd666417e3de289d4f424f502a982"),
+        ];
+        for (msg, len, expect) in tests.iter() {
+            let out = ExpandMsgXmd::<Sha256>::expand_message(msg, &dst, *len);
+            assert_eq!(hex::encode(out), *expect);
+        }
+
+        // an oversize DST is equivalent to its hash
+        let hashed =
+            Digest::chain(Digest::chain(Sha256::new(), OVERSIZE_DST_SALT), &dst).finalize();
+        assert_eq!(
+            ExpandMsgXmd::<Sha256>::expand_message(b"abc", &dst, 0x20),
+            ExpandMsgXmd::<Sha256>::expand_message(b"abc", &hashed, 0x20)
+        );
+    }
+
+    #[test]
+    fn test_expand_message_xof_oversize_dst() {
+        // expand_message_xof(SHAKE128), long DST
+        let dst = long_dst(b"QUUX-V01-CS02-with-expander-SHAKE128-long-DST-");
+        let tests: [(&[u8], usize, &str); 3] = [
+            (b"", 0x20, "827c6216330a122352312bccc0c8d6e7a146c5257a776dbd9ad9d75cd880fc53"),
+            (b"abc", 0x20, "690c8d82c7213b4282c6cb41c00e31ea1d3e2005f93ad19bbf6da40f15790c5c"),
+            (b"abcdef0123456789", 0x80, "55317e4a21318472cd2290c3082957e1242241d9e0d04f47026f03401643131401071f01aa03038b2783e795bdfa8a3541c194ad5de7cb9c225133e24af6c86e748deb52e560569bd54ef4dac03465111a3a44b0ea490fb36777ff8ea9f1a8a3e8e0de3cf0880b4b2f8dd37d3a85a8b82375aee4fa0e909f9763319b55778e71"),
+        ];
+        for (msg, len, expect) in tests.iter() {
+            let out = ExpandMsgXof::<Shake128>::expand_message(msg, &dst, *len);
+            assert_eq!(hex::encode(out), *expect);
+        }
+    }
+
+    #[test]
+    fn test_expand_message_streaming() {
+        let msg: Vec<u8> = (0..300u32).map(|i| i as u8).collect();
+        let mut xmd = ExpandMsgXmd::<Sha256>::hasher();
+        let mut xof = ExpandMsgXof::<Shake128>::hasher();
+        for chunk in msg.chunks(17) {
+            Update::update(&mut xmd, chunk);
+            Update::update(&mut xof, chunk);
+        }
+        assert_eq!(
+            ExpandMsgXmd::<Sha256>::try_expand_hasher(xmd, b"DST", 96).unwrap(),
+            ExpandMsgXmd::<Sha256>::expand_message(&msg, b"DST", 96)
+        );
+        assert_eq!(
+            ExpandMsgXof::<Shake128>::try_expand_hasher(xof, b"DST", 96).unwrap(),
+            ExpandMsgXof::<Shake128>::expand_message(&msg, b"DST", 96)
+        );
+
+        let hasher = Update::chain(ExpandMsgXmd::<Sha256>::hasher(), &msg);
+        assert_eq!(
+            try_hash_to_field_with::<Fq2, ExpandMsgXmd<Sha256>>(hasher, b"DST", 2).unwrap(),
+            hash_to_field::<Fq2, ExpandMsgXmd<Sha256>>(&msg, b"DST", 2)
+        );
+    }
+
+    #[test]
+    fn test_expand_message_invalid_length() {
+        assert_eq!(
+            ExpandMsgXmd::<Sha256>::try_expand_message(b"abc", b"DST", 255 * 32 + 1),
+            Err(ExpandMsgError::EllTooLarge(256))
+        );
+        assert_eq!(
+            ExpandMsgXmd::<Sha256>::try_expand_message(b"abc", b"DST", 255 * 32)
+                .unwrap()
+                .len(),
+            255 * 32
+        );
+        assert_eq!(
+            ExpandMsgXmd::<Sha256>::try_expand_message(b"abc", b"DST", usize::MAX),
+            Err(ExpandMsgError::LengthTooLarge(usize::MAX))
+        );
+        assert_eq!(
+            ExpandMsgXof::<Shake128>::try_expand_message(b"abc", b"DST", 65536),
+            Err(ExpandMsgError::LengthTooLarge(65536))
+        );
+        assert_eq!(
+            ExpandMsgXof::<Shake128>::try_expand_message(b"abc", b"DST", 65535)
+                .unwrap()
+                .len(),
+            65535
+        );
+
+        // 128 Fq elements need 8192 bytes, which is too many for XMD with SHA-256
+        assert!(try_hash_to_field::<Fq, ExpandMsgXmd<Sha256>>(b"abc", b"DST", 127).is_ok());
+        assert_eq!(
+            try_hash_to_field::<Fq, ExpandMsgXmd<Sha256>>(b"abc", b"DST", 128).err(),
+            Some(ExpandMsgError::EllTooLarge(256))
+        );
+        assert_eq!(
+            try_hash_to_field::<Fq2, ExpandMsgXof<Shake128>>(b"abc", b"DST", 512).err(),
+            Some(ExpandMsgError::LengthTooLarge(65536))
+        );
+        assert_eq!(
+            try_hash_to_field::<Fq2, ExpandMsgXof<Shake128>>(b"abc", b"DST", usize::MAX).err(),
+            Some(ExpandMsgError::LengthTooLarge(usize::MAX))
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hash_to_field_panics_on_invalid_length() {
+        hash_to_field::<Fq, ExpandMsgXmd<Sha256>>(b"abc", b"DST", 128);
     }
 }
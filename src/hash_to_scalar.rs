@@ -0,0 +1,145 @@
+/*!
+ This module defines a hash_to_scalar trait for deriving scalars (e.g.,
+ Fiat-Shamir challenges) from messages and transcripts.
+*/
+
+use crate::hash_to_field::{hash_to_field, ExpandMsg, FromRO};
+use alloc::vec::Vec;
+use digest::generic_array::typenum::U64;
+use digest::Digest;
+use ff::PrimeField;
+
+/// Hash a message (or a running transcript) to one or more scalars
+/// using expand_message variant X.
+pub trait HashToScalar<X>: Sized
+where
+    X: ExpandMsg,
+{
+    /// Hash a message to a single scalar with domain separation tag dst
+    fn hash_to_scalar<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(msg: Mt, dst: Dt) -> Self;
+
+    /// Hash a message to count independent scalars with a single call to
+    /// expand_message; useful for deriving several challenges at once
+    fn hash_to_scalars<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(
+        msg: Mt,
+        dst: Dt,
+        count: usize,
+    ) -> Vec<Self>;
+
+    /// Finalize a transcript held in a Digest state and hash the result
+    /// to a single scalar
+    fn hash_digest_to_scalar<D: Digest, Dt: AsRef<[u8]>>(transcript: D, dst: Dt) -> Self {
+        Self::hash_to_scalar(transcript.finalize(), dst)
+    }
+
+    /// Finalize a transcript held in a Digest state and hash the result
+    /// to count independent scalars
+    fn hash_digest_to_scalars<D: Digest, Dt: AsRef<[u8]>>(
+        transcript: D,
+        dst: Dt,
+        count: usize,
+    ) -> Vec<Self> {
+        Self::hash_to_scalars(transcript.finalize(), dst, count)
+    }
+}
+
+impl<T, X> HashToScalar<X> for T
+where
+    T: PrimeField + FromRO,
+    X: ExpandMsg,
+{
+    fn hash_to_scalar<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(msg: Mt, dst: Dt) -> T {
+        hash_to_field::<T, X>(msg.as_ref(), dst.as_ref(), 1)[0]
+    }
+
+    fn hash_to_scalars<Mt: AsRef<[u8]>, Dt: AsRef<[u8]>>(msg: Mt, dst: Dt, count: usize) -> Vec<T> {
+        hash_to_field::<T, X>(msg.as_ref(), dst.as_ref(), count)
+    }
+}
+
+/// Reduce a 64-byte uniformly random string to a field element.
+/// The bias of the result is at most 2^-(512 - log2(p)), i.e., negligible
+/// for any field of at most 384 bits.
+pub trait FromBytesWide: Sized {
+    /// Interpret bytes as a big-endian integer and reduce it modulo p
+    fn from_bytes_wide(bytes: &[u8; 64]) -> Self;
+
+    /// Finalize a Digest with 64-byte output (e.g., Sha512) and reduce
+    /// the output to a field element
+    fn from_digest_wide<D: Digest<OutputSize = U64>>(transcript: D) -> Self {
+        let mut bytes = [0u8; 64];
+        bytes.copy_from_slice(&transcript.finalize()[..]);
+        Self::from_bytes_wide(&bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::Fr;
+    use crate::hash_to_field::{ExpandMsgXmd, ExpandMsgXof};
+    use sha2::{Sha256, Sha512};
+    use sha3::Shake128;
+
+    type Xmd = ExpandMsgXmd<Sha256>;
+    type Xof = ExpandMsgXof<Shake128>;
+
+    #[test]
+    fn test_hash_to_scalar_matches_hash_to_field() {
+        const DST: &[u8] = b"HASH_TO_SCALAR_TEST";
+        let msg = b"hello world";
+
+        let s = <Fr as HashToScalar<Xmd>>::hash_to_scalar(msg, DST);
+        assert_eq!(s, hash_to_field::<Fr, Xmd>(msg, DST, 1)[0]);
+
+        let ss = <Fr as HashToScalar<Xof>>::hash_to_scalars(msg, DST, 3);
+        assert_eq!(ss, hash_to_field::<Fr, Xof>(msg, DST, 3));
+        assert_ne!(ss[0], ss[1]);
+        assert_ne!(ss[1], ss[2]);
+    }
+
+    #[test]
+    fn test_hash_digest_to_scalar() {
+        const DST: &[u8] = b"HASH_TO_SCALAR_TEST";
+        let transcript = Sha256::new().chain(b"hello ").chain(b"world");
+        let expect = <Fr as HashToScalar<Xmd>>::hash_to_scalar(Sha256::digest(b"hello world"), DST);
+        assert_eq!(
+            <Fr as HashToScalar<Xmd>>::hash_digest_to_scalar(transcript.clone(), DST),
+            expect
+        );
+        assert_eq!(
+            <Fr as HashToScalar<Xmd>>::hash_digest_to_scalars(transcript, DST, 2)[0],
+            <Fr as HashToScalar<Xmd>>::hash_to_scalars(Sha256::digest(b"hello world"), DST, 2)[0]
+        );
+    }
+
+    #[test]
+    fn test_fr_from_bytes_wide() {
+        let mut bytes = [0u8; 64];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        assert_eq!(
+            Fr::from_bytes_wide(&bytes),
+            Fr::from_str(
+                "49390169590659034352182350487395535045561095549834662993610314140065425466348"
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Fr::from_bytes_wide(&[0xff; 64]),
+            Fr::from_str(
+                "3294906474794265442129797520630710739278575682199800681788903916070560242796"
+            )
+            .unwrap()
+        );
+        assert_eq!(Fr::from_bytes_wide(&[0u8; 64]), Fr::from_str("0").unwrap());
+
+        let mut wide = [0u8; 64];
+        wide.copy_from_slice(&Sha512::digest(b"hello world"));
+        assert_eq!(
+            Fr::from_digest_wide(Sha512::new().chain(b"hello world")),
+            Fr::from_bytes_wide(&wide)
+        );
+    }
+}
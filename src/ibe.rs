@@ -0,0 +1,248 @@
+/*!
+Boneh-Franklin identity-based encryption over BLS12-381, in the fully
+secure variant `FullIdent` obtained with the Fujisaki-Okamoto transform.
+
+Identities are hashed to one source group with `HashToCurve`, and the master
+public key lives in the other one; the choice is made with
+`threshold::SignatureGroup`, e.g. `S = G2` for identities in G2 and a master
+public key in G1. The master secret key `s` is an `Fr`, the master public
+key is `s * g`, and the secret key of an identity is `s * H1(id)`, which is
+also the BLS signature on the identity under `IBE_DST`.
+
+To encrypt `m` to `id`, pick a random 32-byte `sigma` and compute
+
+```text
+r = H3(sigma || m)
+U = r * g
+V = sigma xor H2(e(r * mpk, H1(id)))
+W = m xor H4(sigma)
+```
+
+Decryption recovers `sigma` with `e(U, sk)` and `m` from it, and accepts
+only if `U` is `H3(sigma || m) * g` again. `H3` is `hash_to_field` into
+`Fr`; the KDFs `H2`, over the `SerDes` encoding of the pairing output, and
+`H4` are SHAKE256. Every hash is separated by `IBE_DST`.
+*/
+
+use crate::{
+    bls12_381::Fr,
+    hash_to_curve::HashToCurve,
+    hash_to_field::ExpandMsgXmd,
+    hash_to_scalar::HashToScalar,
+    threshold::{mul_generator, sign, SignatureGroup},
+    CurveProjective,
+};
+use alloc::vec::Vec;
+use digest::{ExtendableOutput, Update, XofReader};
+use rand_core::RngCore;
+use sha2::Sha256;
+use sha3::Shake256;
+
+/// The DST of the identity hash `H1`. The other hashes append "-H2", "-H3"
+/// and "-H4".
+pub const IBE_DST: &[u8] = b"PAIRING-PLUS-BF-IBE-V01";
+
+/// A ciphertext `(U, V, W)`, with `U` in the master public key group `P`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Ciphertext<P> {
+    pub u: P,
+    pub v: [u8; 32],
+    pub w: Vec<u8>,
+}
+
+#[cfg(feature = "std")]
+impl<P: crate::serdes::SerDes> crate::serdes::SerDes for Ciphertext<P> {
+    /// Serialize `U` in the given mode, followed by `V`, the length of `W`
+    /// as 8 big-endian bytes, and `W`.
+    fn serialize<W: std::io::Write>(
+        &self,
+        writer: &mut W,
+        compressed: bool,
+    ) -> std::io::Result<()> {
+        self.u.serialize(writer, compressed)?;
+        writer.write_all(&self.v)?;
+        writer.write_all(&(self.w.len() as u64).to_be_bytes())?;
+        writer.write_all(&self.w)
+    }
+
+    /// Deserialize a ciphertext; `U` must be in the given mode.
+    fn deserialize<R: std::io::Read>(reader: &mut R, compressed: bool) -> std::io::Result<Self> {
+        use std::io::Read;
+
+        let u = P::deserialize(reader, compressed)?;
+        let mut v = [0u8; 32];
+        reader.read_exact(&mut v)?;
+        let mut len = [0u8; 8];
+        reader.read_exact(&mut len)?;
+        let len = u64::from_be_bytes(len);
+
+        // read through `take`, so that a bogus length cannot allocate
+        let mut w = vec![];
+        reader.take(len).read_to_end(&mut w)?;
+        if w.len() as u64 != len {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+        Ok(Ciphertext { u, v, w })
+    }
+}
+
+/// The master public key `s * g` of the master secret key `s`.
+pub fn master_public_key<S: SignatureGroup>(msk: &Fr) -> S::PublicKey {
+    mul_generator(msk)
+}
+
+/// The secret key `s * H1(id)` of an identity.
+pub fn extract<S: SignatureGroup>(msk: &Fr, id: &[u8]) -> S {
+    sign(msk, id, IBE_DST)
+}
+
+/// Encrypts `msg` to `id` under the master public key `mpk`.
+pub fn encrypt<S: SignatureGroup, R: RngCore + ?Sized>(
+    mpk: &S::PublicKey,
+    id: &[u8],
+    msg: &[u8],
+    rng: &mut R,
+) -> Ciphertext<S::PublicKey> {
+    let mut sigma = [0u8; 32];
+    rng.fill_bytes(&mut sigma);
+    let r = h3(&sigma, msg);
+
+    let q = <S as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(id, IBE_DST);
+    let mut rp = *mpk;
+    rp.mul_assign(r);
+    let mut v = h2(&S::pairing(&rp, &q).to_bytes_be());
+    xor(&mut v, &sigma);
+    let mut w = h4(&sigma, msg.len());
+    xor(&mut w, msg);
+    Ciphertext {
+        u: mul_generator(&r),
+        v,
+        w,
+    }
+}
+
+/// Decrypts a ciphertext with the secret key of its identity. Returns
+/// `None` if the ciphertext is not valid for that identity.
+pub fn decrypt<S: SignatureGroup>(sk: &S, ct: &Ciphertext<S::PublicKey>) -> Option<Vec<u8>> {
+    let mut sigma = h2(&S::pairing(&ct.u, sk).to_bytes_be());
+    xor(&mut sigma, &ct.v);
+    let mut msg = h4(&sigma, ct.w.len());
+    xor(&mut msg, &ct.w);
+    if ct.u == mul_generator(&h3(&sigma, &msg)) {
+        Some(msg)
+    } else {
+        None
+    }
+}
+
+/// `H2`: the mask of `sigma`, from the encoded pairing output.
+fn h2(gt: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    shake(b"-H2", gt, &mut out);
+    out
+}
+
+/// `H3`: the encryption randomness.
+fn h3(sigma: &[u8; 32], msg: &[u8]) -> Fr {
+    let mut dst = IBE_DST.to_vec();
+    dst.extend_from_slice(b"-H3");
+    let mut input = sigma.to_vec();
+    input.extend_from_slice(msg);
+    <Fr as HashToScalar<ExpandMsgXmd<Sha256>>>::hash_to_scalar(input, dst)
+}
+
+/// `H4`: the mask of the message.
+fn h4(sigma: &[u8; 32], len: usize) -> Vec<u8> {
+    let mut out = vec![0u8; len];
+    shake(b"-H4", sigma, &mut out);
+    out
+}
+
+/// SHAKE256 of `IBE_DST || suffix || input`.
+fn shake(suffix: &[u8], input: &[u8], out: &mut [u8]) {
+    Shake256::default()
+        .chain(IBE_DST)
+        .chain(suffix)
+        .chain(input)
+        .finalize_xof()
+        .read(out);
+}
+
+fn xor(a: &mut [u8], b: &[u8]) {
+    for (x, y) in a.iter_mut().zip(b.iter()) {
+        *x ^= y;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::{G1, G2};
+    use crate::tests::test_rng;
+    use crate::threshold::verify;
+    use ff::Field;
+
+    fn ibe<S: SignatureGroup>() {
+        let mut rng = test_rng();
+        let msk = Fr::random(&mut rng);
+        let mpk = master_public_key::<S>(&msk);
+        let alice = extract::<S>(&msk, b"alice@example.com");
+        let bob = extract::<S>(&msk, b"bob@example.com");
+        assert!(verify(&mpk, b"alice@example.com", IBE_DST, &alice));
+
+        for msg in [&b""[..], b"escrowed key", &[0xab; 1000][..]].iter() {
+            let ct = encrypt::<S, _>(&mpk, b"alice@example.com", msg, &mut rng);
+            assert_eq!(ct.w.len(), msg.len());
+            assert_eq!(decrypt(&alice, &ct).as_deref(), Some(*msg));
+            assert_eq!(decrypt(&bob, &ct), None);
+        }
+
+        let ct = encrypt::<S, _>(&mpk, b"alice@example.com", b"escrowed key", &mut rng);
+        assert_ne!(
+            ct,
+            encrypt::<S, _>(&mpk, b"alice@example.com", b"escrowed key", &mut rng)
+        );
+        let mut bad = ct.clone();
+        bad.u.double();
+        assert_eq!(decrypt(&alice, &bad), None);
+        let mut bad = ct.clone();
+        bad.v[0] ^= 1;
+        assert_eq!(decrypt(&alice, &bad), None);
+        let mut bad = ct.clone();
+        bad.w[5] ^= 1;
+        assert_eq!(decrypt(&alice, &bad), None);
+        let mut bad = ct;
+        bad.w.pop();
+        assert_eq!(decrypt(&alice, &bad), None);
+    }
+
+    #[test]
+    fn test_ibe_g2_identities() {
+        ibe::<G2>();
+    }
+
+    #[test]
+    fn test_ibe_g1_identities() {
+        ibe::<G1>();
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_ciphertext_serdes() {
+        use crate::serdes::SerDes;
+
+        let mut rng = test_rng();
+        let msk = Fr::random(&mut rng);
+        let mpk = master_public_key::<G2>(&msk);
+        let ct = encrypt::<G2, _>(&mpk, b"alice", b"escrowed key", &mut rng);
+        for &compressed in [true, false].iter() {
+            let mut buf = vec![];
+            ct.serialize(&mut buf, compressed).unwrap();
+            assert_eq!(buf.len(), if compressed { 48 } else { 96 } + 32 + 8 + 12);
+            let de = Ciphertext::<G1>::deserialize(&mut &buf[..], compressed).unwrap();
+            assert_eq!(de, ct);
+            assert!(Ciphertext::<G1>::deserialize(&mut &buf[..], !compressed).is_err());
+            assert!(Ciphertext::<G1>::deserialize(&mut &buf[..buf.len() - 1], compressed).is_err());
+        }
+    }
+}
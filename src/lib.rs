@@ -12,6 +12,18 @@
 #![cfg_attr(feature = "cargo-clippy", allow(clippy::cognitive_complexity))]
 // Force public structures to implement Debug
 #![deny(missing_debug_implementations)]
+// Without the `std` feature the crate needs only `core` and `alloc`.
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(feature = "alloc"))]
+compile_error!("pairing-plus needs the `alloc` feature, which `std` enables");
+
+#[macro_use]
+extern crate alloc;
+
+// The PrimeField derive emits `::std` paths, which `core` provides.
+#[cfg(not(any(feature = "std", test)))]
+extern crate core as std;
 
 #[macro_use]
 extern crate zeroize;
@@ -19,18 +31,39 @@ extern crate zeroize;
 #[cfg(test)]
 pub mod tests;
 
+#[macro_use]
 pub mod bls12_381;
+pub mod bls12_377;
+pub mod bulletproofs;
+pub mod bn256;
+pub mod bw6_761;
+pub mod ciphersuite;
+pub mod credentials;
+pub mod dkg;
+pub mod edwards;
+mod error;
 pub mod hash_to_curve;
 pub mod hash_to_field;
+pub mod hash_to_scalar;
+pub mod ibe;
+pub mod nizk;
+pub mod pedersen;
+#[cfg(feature = "std")]
 pub mod serdes;
 pub mod signum;
+pub mod threshold;
+pub mod tower;
+pub mod transcript;
+pub mod vrf;
+pub mod vss;
 
 mod wnaf;
+pub use self::error::{Error, GroupDecodingError};
 pub use self::wnaf::Wnaf;
 
-use ff::{Field, PrimeField, PrimeFieldDecodingError, PrimeFieldRepr, ScalarEngine, SqrtField};
-use std::error::Error;
-use std::fmt;
+use alloc::vec::Vec;
+use core::fmt;
+use ff::{Field, PrimeField, PrimeFieldRepr, ScalarEngine, SqrtField};
 
 /// An "engine" is a collection of types (fields, elliptic curve groups, etc.)
 /// with well-defined relationships. In particular, the G1/G2 curve groups are
@@ -305,7 +338,9 @@ pub trait CurveAffine:
     /// Find the optimal window for running Pippinger's algorithm; preprogrammed values
     fn find_pippinger_window(num_components: usize) -> usize;
 
-    /// Find the optimal window for running Pippinger's algorithm; computed values via an estimate of running time
+    /// Find the optimal window for running Pippinger's algorithm; computed values via an estimate of running time.
+    /// Needs `std`, for floating point powers.
+    #[cfg(feature = "std")]
     fn find_pippinger_window_via_estimate(num_components: usize) -> usize;
 
     /// multiplication of many points with Pippinger's algorithm of window size w
@@ -326,6 +361,10 @@ pub trait CurveAffine:
     ) -> Self::Projective;
 
     /// pre[0] becomes (2^64) * self, pre[1]  becomes (2^128) * self, and pre[2] (becomes 2^196) * self
+    ///
+    /// The tables of `precomp_3` and `precomp_256` only serve scalars of four
+    /// limbs. BW6-761, whose scalars have six, does not benefit: both leave
+    /// `pre` untouched, and `mul_precomp_3` and `mul_precomp_256` ignore it.
     fn precomp_3(&self, pre: &mut [Self]);
 
     /// Performs scalar multiplication of this element,
@@ -362,7 +401,7 @@ pub trait EncodedPoint:
 
     /// Converts an `EncodedPoint` into a `CurveAffine` element,
     /// if the encoding represents a valid element.
-    fn into_affine(&self) -> Result<Self::Affine, GroupDecodingError>;
+    fn into_affine(&self) -> Result<Self::Affine, Error>;
 
     /// Converts an `EncodedPoint` into a `CurveAffine` element,
     /// without guaranteeing that the encoding represents a valid
@@ -371,7 +410,7 @@ pub trait EncodedPoint:
     ///
     /// If the encoding is invalid, this can break API invariants,
     /// so caution is strongly encouraged.
-    fn into_affine_unchecked(&self) -> Result<Self::Affine, GroupDecodingError>;
+    fn into_affine_unchecked(&self) -> Result<Self::Affine, Error>;
 
     /// Creates an `EncodedPoint` from an affine point, as long as the
     /// point is not the point at infinity.
@@ -383,43 +422,3 @@ pub trait SubgroupCheck {
     /// i.e., raise to the power of group order
     fn in_subgroup(&self) -> bool;
 }
-
-/// An error that may occur when trying to decode an `EncodedPoint`.
-#[derive(Debug)]
-pub enum GroupDecodingError {
-    /// The coordinate(s) do not lie on the curve.
-    NotOnCurve,
-    /// The element is not part of the r-order subgroup.
-    NotInSubgroup,
-    /// One of the coordinates could not be decoded
-    CoordinateDecodingError(&'static str, PrimeFieldDecodingError),
-    /// The compression mode of the encoded element was not as expected
-    UnexpectedCompressionMode,
-    /// The encoding contained bits that should not have been set
-    UnexpectedInformation,
-}
-
-impl Error for GroupDecodingError {
-    fn description(&self) -> &str {
-        match *self {
-            GroupDecodingError::NotOnCurve => "coordinate(s) do not lie on the curve",
-            GroupDecodingError::NotInSubgroup => "the element is not part of an r-order subgroup",
-            GroupDecodingError::CoordinateDecodingError(..) => "coordinate(s) could not be decoded",
-            GroupDecodingError::UnexpectedCompressionMode => {
-                "encoding has unexpected compression mode"
-            }
-            GroupDecodingError::UnexpectedInformation => "encoding has unexpected information",
-        }
-    }
-}
-
-impl fmt::Display for GroupDecodingError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> Result<(), fmt::Error> {
-        match *self {
-            GroupDecodingError::CoordinateDecodingError(description, ref err) => {
-                write!(f, "{} decoding error: {}", description, err)
-            }
-            _ => write!(f, "{:?}", self),
-        }
-    }
-}
@@ -0,0 +1,336 @@
+/*!
+Non-interactive zero-knowledge proofs about discrete logarithms, made
+non-interactive with the Fiat-Shamir transform.
+
+* `SchnorrProof` proves knowledge of `x` with `X = x * B`.
+* `DleqProof` (Chaum-Pedersen) proves knowledge of `x` with `X = x * B` and
+  `Y = x * C`. The bases may lie in different groups, e.g. in G1 and G2, so
+  the same proof shows that a key has the same discrete logarithm in both.
+
+The proofs are generic over BLS12-381 groups, i.e. `CurveProjective` with
+scalar field `Fr`. The challenge is `hash_to_field` into `Fr`, with
+expand_message_xmd over SHA-256, of the compressed encodings of the bases,
+the statement and the commitments, under a DST chosen by the caller.
+
+A proof holds its commitments rather than its challenge, so that many
+proofs can be batch verified: each verification equation is multiplied by
+a random weight, and the sum is checked with one multi-scalar
+multiplication per group.
+*/
+
+use crate::{
+    bls12_381::Fr,
+    hash_to_field::{hash_to_field, ExpandMsgXmd},
+    threshold::sum_of_products,
+    CurveAffine, CurveProjective,
+};
+use alloc::vec::Vec;
+use ff::Field;
+use rand_core::RngCore;
+use sha2::Sha256;
+
+/// A proof of knowledge of `x` with `X = x * B`: the commitment `R = k * B`
+/// and the response `s = k + c * x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SchnorrProof<G> {
+    pub commitment: G,
+    pub response: Fr,
+}
+
+/// A proof of knowledge of `x` with `X = x * B` and `Y = x * C`: the
+/// commitments `k * B` and `k * C`, and the response `s = k + c * x`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DleqProof<G, H> {
+    pub commitments: (G, H),
+    pub response: Fr,
+}
+
+/// A DLEQ proof with its statement:
+/// `(base_g, public_g, base_h, public_h, proof)`.
+pub type DleqItem<G, H> = (G, G, H, H, DleqProof<G, H>);
+
+impl<G: CurveProjective<Scalar = Fr>> SchnorrProof<G> {
+    /// Proves knowledge of `x` for the statement `x * base`.
+    pub fn prove<R: RngCore + ?Sized>(base: &G, x: &Fr, dst: &[u8], rng: &mut R) -> Self {
+        let k = Fr::random(rng);
+        let mut public = *base;
+        public.mul_assign(*x);
+        let mut commitment = *base;
+        commitment.mul_assign(k);
+
+        let mut response = challenge(&[base, &public, &commitment], dst);
+        response.mul_assign(x);
+        response.add_assign(&k);
+        SchnorrProof {
+            commitment,
+            response,
+        }
+    }
+
+    /// Checks the proof for `public = x * base` with `s * B == R + c * X`.
+    pub fn verify(&self, base: &G, public: &G, dst: &[u8]) -> bool {
+        let c = challenge(&[base, public, &self.commitment], dst);
+        equation_holds(base, &self.response, public, &c, &self.commitment)
+    }
+
+    /// Checks many proofs `(base, public, proof)` under `dst` at once.
+    pub fn batch_verify<R: RngCore + ?Sized>(
+        items: &[(G, G, SchnorrProof<G>)],
+        dst: &[u8],
+        rng: &mut R,
+    ) -> bool {
+        let mut terms = Terms::with_capacity(3 * items.len());
+        for (base, public, proof) in items {
+            let c = challenge(&[base, public, &proof.commitment], dst);
+            let w = Fr::random(rng);
+            terms.push_equation(&w, base, &proof.response, public, &c, &proof.commitment);
+        }
+        terms.is_zero()
+    }
+}
+
+impl<G, H> DleqProof<G, H>
+where
+    G: CurveProjective<Scalar = Fr>,
+    H: CurveProjective<Scalar = Fr>,
+{
+    /// Proves that `x * base_g` and `x * base_h` have the same discrete
+    /// logarithm `x`.
+    pub fn prove<R: RngCore + ?Sized>(
+        base_g: &G,
+        base_h: &H,
+        x: &Fr,
+        dst: &[u8],
+        rng: &mut R,
+    ) -> Self {
+        let k = Fr::random(rng);
+        let mut public_g = *base_g;
+        public_g.mul_assign(*x);
+        let mut public_h = *base_h;
+        public_h.mul_assign(*x);
+        let mut commitment_g = *base_g;
+        commitment_g.mul_assign(k);
+        let mut commitment_h = *base_h;
+        commitment_h.mul_assign(k);
+
+        let mut response = dleq_challenge(
+            (base_g, &public_g, &commitment_g),
+            (base_h, &public_h, &commitment_h),
+            dst,
+        );
+        response.mul_assign(x);
+        response.add_assign(&k);
+        DleqProof {
+            commitments: (commitment_g, commitment_h),
+            response,
+        }
+    }
+
+    /// Checks the proof for `public_g = x * base_g` and
+    /// `public_h = x * base_h`.
+    pub fn verify(&self, base_g: &G, public_g: &G, base_h: &H, public_h: &H, dst: &[u8]) -> bool {
+        let c = self.challenge(base_g, public_g, base_h, public_h, dst);
+        equation_holds(base_g, &self.response, public_g, &c, &self.commitments.0)
+            && equation_holds(base_h, &self.response, public_h, &c, &self.commitments.1)
+    }
+
+    /// Checks many proofs with their statements under `dst` at once.
+    pub fn batch_verify<R: RngCore + ?Sized>(
+        items: &[DleqItem<G, H>],
+        dst: &[u8],
+        rng: &mut R,
+    ) -> bool {
+        let mut terms_g = Terms::with_capacity(3 * items.len());
+        let mut terms_h = Terms::with_capacity(3 * items.len());
+        for (base_g, public_g, base_h, public_h, proof) in items {
+            let c = proof.challenge(base_g, public_g, base_h, public_h, dst);
+            let w = Fr::random(rng);
+            let (commitment_g, commitment_h) = &proof.commitments;
+            terms_g.push_equation(&w, base_g, &proof.response, public_g, &c, commitment_g);
+            terms_h.push_equation(&w, base_h, &proof.response, public_h, &c, commitment_h);
+        }
+        terms_g.is_zero() && terms_h.is_zero()
+    }
+
+    fn challenge(&self, base_g: &G, public_g: &G, base_h: &H, public_h: &H, dst: &[u8]) -> Fr {
+        dleq_challenge(
+            (base_g, public_g, &self.commitments.0),
+            (base_h, public_h, &self.commitments.1),
+            dst,
+        )
+    }
+}
+
+/// Checks the verification equation `s * B == R + c * X`.
+fn equation_holds<G: CurveProjective<Scalar = Fr>>(
+    base: &G,
+    s: &Fr,
+    public: &G,
+    c: &Fr,
+    commitment: &G,
+) -> bool {
+    let mut lhs = *base;
+    lhs.mul_assign(*s);
+    let mut rhs = *public;
+    rhs.mul_assign(*c);
+    rhs.add_assign(commitment);
+    lhs == rhs
+}
+
+/// The terms of a sum of weighted verification equations
+/// `w * (s * B - R - c * X)`, which is zero if they all hold.
+struct Terms<G: CurveProjective> {
+    bases: Vec<G::Affine>,
+    scalars: Vec<Fr>,
+}
+
+impl<G: CurveProjective<Scalar = Fr>> Terms<G> {
+    fn with_capacity(n: usize) -> Self {
+        Terms {
+            bases: Vec::with_capacity(n),
+            scalars: Vec::with_capacity(n),
+        }
+    }
+
+    fn push_equation(&mut self, w: &Fr, base: &G, s: &Fr, public: &G, c: &Fr, commitment: &G) {
+        let mut ws = *s;
+        ws.mul_assign(w);
+        let mut wc = *c;
+        wc.mul_assign(w);
+        wc.negate();
+        let mut neg_w = *w;
+        neg_w.negate();
+
+        self.bases.push(base.into_affine());
+        self.scalars.push(ws);
+        self.bases.push(public.into_affine());
+        self.scalars.push(wc);
+        self.bases.push(commitment.into_affine());
+        self.scalars.push(neg_w);
+    }
+
+    fn is_zero(&self) -> bool {
+        sum_of_products(&self.bases, &self.scalars).is_zero()
+    }
+}
+
+/// `hash_to_field` of the concatenated compressed encodings of `points`.
+fn challenge<G: CurveProjective<Scalar = Fr>>(points: &[&G], dst: &[u8]) -> Fr {
+    let mut input = vec![];
+    append_points(&mut input, points);
+    hash_to_field::<Fr, ExpandMsgXmd<Sha256>>(&input, dst, 1)[0]
+}
+
+fn dleq_challenge<G, H>(g: (&G, &G, &G), h: (&H, &H, &H), dst: &[u8]) -> Fr
+where
+    G: CurveProjective<Scalar = Fr>,
+    H: CurveProjective<Scalar = Fr>,
+{
+    let mut input = vec![];
+    append_points(&mut input, &[g.0, g.1, g.2]);
+    append_points(&mut input, &[h.0, h.1, h.2]);
+    hash_to_field::<Fr, ExpandMsgXmd<Sha256>>(&input, dst, 1)[0]
+}
+
+fn append_points<G: CurveProjective>(input: &mut Vec<u8>, points: &[&G]) {
+    for p in points {
+        input.extend_from_slice(p.into_affine().into_compressed().as_ref());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::{G1, G2};
+    use crate::tests::test_rng;
+    use crate::threshold::mul_generator;
+
+    const DST: &[u8] = b"PAIRING-PLUS-NIZK-TEST";
+
+    fn schnorr<G: CurveProjective<Scalar = Fr>>() {
+        let mut rng = test_rng();
+        let mut items = vec![];
+        for _ in 0..5 {
+            let base = G::random(&mut rng);
+            let x = Fr::random(&mut rng);
+            let mut public = base;
+            public.mul_assign(x);
+            let proof = SchnorrProof::prove(&base, &x, DST, &mut rng);
+            assert!(proof.verify(&base, &public, DST));
+            assert!(!proof.verify(&base, &public, b"other DST"));
+            assert!(!proof.verify(&public, &base, DST));
+            items.push((base, public, proof));
+        }
+        assert!(SchnorrProof::batch_verify(&items, DST, &mut rng));
+        assert!(SchnorrProof::<G>::batch_verify(&[], DST, &mut rng));
+
+        let mut bad = items.clone();
+        bad[3].2.response.add_assign(&Fr::one());
+        assert!(!bad[3].2.verify(&bad[3].0, &bad[3].1, DST));
+        assert!(!SchnorrProof::batch_verify(&bad, DST, &mut rng));
+        let mut bad = items;
+        bad[0].1.double();
+        assert!(!SchnorrProof::batch_verify(&bad, DST, &mut rng));
+    }
+
+    #[test]
+    fn test_schnorr() {
+        schnorr::<G1>();
+        schnorr::<G2>();
+    }
+
+    fn dleq<G, H>()
+    where
+        G: CurveProjective<Scalar = Fr>,
+        H: CurveProjective<Scalar = Fr>,
+    {
+        let mut rng = test_rng();
+        let mut items = vec![];
+        for _ in 0..5 {
+            let base_g = G::random(&mut rng);
+            let base_h = H::random(&mut rng);
+            let x = Fr::random(&mut rng);
+            let mut public_g = base_g;
+            public_g.mul_assign(x);
+            let mut public_h = base_h;
+            public_h.mul_assign(x);
+            let proof = DleqProof::prove(&base_g, &base_h, &x, DST, &mut rng);
+            assert!(proof.verify(&base_g, &public_g, &base_h, &public_h, DST));
+            assert!(!proof.verify(&base_g, &public_g, &base_h, &public_h, b"other DST"));
+            items.push((base_g, public_g, base_h, public_h, proof));
+        }
+        assert!(DleqProof::batch_verify(&items, DST, &mut rng));
+
+        // different discrete logarithms
+        let mut bad = items.clone();
+        let (base_g, public_g, base_h, _, _) = bad[2];
+        let mut public_h = base_h;
+        public_h.mul_assign(Fr::random(&mut rng));
+        bad[2].3 = public_h;
+        assert!(!bad[2].4.verify(&base_g, &public_g, &base_h, &public_h, DST));
+        assert!(!DleqProof::batch_verify(&bad, DST, &mut rng));
+
+        let mut bad = items;
+        bad[4].4.commitments.1.double();
+        assert!(!DleqProof::batch_verify(&bad, DST, &mut rng));
+    }
+
+    #[test]
+    fn test_dleq() {
+        dleq::<G1, G1>();
+        dleq::<G2, G2>();
+        dleq::<G1, G2>();
+    }
+
+    #[test]
+    fn test_dleq_across_groups() {
+        // a key has the same discrete logarithm in G1 and G2
+        let mut rng = test_rng();
+        let sk = Fr::random(&mut rng);
+        let pk1 = mul_generator::<G1>(&sk);
+        let pk2 = mul_generator::<G2>(&sk);
+        let proof = DleqProof::prove(&G1::one(), &G2::one(), &sk, DST, &mut rng);
+        assert!(proof.verify(&G1::one(), &pk1, &G2::one(), &pk2, DST));
+        assert!(!proof.verify(&G1::one(), &pk1, &G2::one(), &G2::one(), DST));
+    }
+}
@@ -0,0 +1,346 @@
+/*!
+Pedersen vector commitments over BLS12-381 G1.
+
+A commitment to `v_1..v_k` with blinding `r` is
+
+```text
+C = r * h + v_1 * g_1 + .. + v_k * g_k
+```
+
+for generators `h, g_1..g_n`, `k <= n`. It hides the values perfectly and
+binds them as long as nobody knows a discrete logarithm relation between the
+generators. So the generators are hashed to G1 with `hash_to_curve`, each
+under its own DST: the DST of `h` is `dst || "-H"`, and the DST of `g_i` is
+`dst || "-G" || I2OSP(i, 8)` with `i` counted from 1. The message is empty.
+`PEDERSEN_DST` is the default `dst`; protocols that need generators of their
+own pick another one.
+
+Commitments are additively homomorphic: the sum of two commitments commits
+to the sum of the values with the sum of the blindings, and a multiple of a
+commitment commits to the multiple of the values and of the blinding.
+
+`Generators::commit` computes the multi-scalar multiplication with
+Pippinger's algorithm. `PrecomputedGenerators` caches a `precomp_256` table
+of every generator, which makes each commitment several times faster at the
+cost of 256 points of memory per generator.
+*/
+
+use crate::{
+    bls12_381::{Fr, FrRepr, G1Affine, G1},
+    hash_to_curve::HashToCurve,
+    hash_to_field::ExpandMsgXmd,
+    CurveAffine, CurveProjective,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::PrimeField;
+use sha2::Sha256;
+
+/// The default DST from which the generators are derived.
+pub const PEDERSEN_DST: &[u8] = b"PAIRING-PLUS-PEDERSEN-VC-V01";
+
+/// An error from committing to a vector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommitmentError {
+    /// There are more values than generators.
+    TooManyValues { generators: usize, values: usize },
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CommitmentError {}
+
+impl fmt::Display for CommitmentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            CommitmentError::TooManyValues { generators, values } => write!(
+                f,
+                "cannot commit to {} values with {} generators",
+                values, generators
+            ),
+        }
+    }
+}
+
+/// The blinding generator `h` and the value generators `g_1..g_n`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Generators {
+    pub h: G1Affine,
+    pub g: Vec<G1Affine>,
+}
+
+/// Generators together with their `precomp_256` tables.
+#[derive(Clone, Debug)]
+pub struct PrecomputedGenerators {
+    gens: Generators,
+    /// The tables of `h, g_1..g_n`, 256 points each.
+    table: Vec<G1Affine>,
+}
+
+/// A commitment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Commitment(pub G1);
+
+impl Generators {
+    /// Derives `count` value generators and `h` from `PEDERSEN_DST`.
+    pub fn new(count: usize) -> Self {
+        Self::with_dst(PEDERSEN_DST, count)
+    }
+
+    /// Derives `count` value generators and `h` from `dst`. The first
+    /// generators do not depend on `count`.
+    pub fn with_dst(dst: &[u8], count: usize) -> Self {
+        let mut h_dst = dst.to_vec();
+        h_dst.extend_from_slice(b"-H");
+        Generators {
+            h: hash_to_g1(&h_dst),
+            g: (1..=count as u64)
+                .map(|i| {
+                    let mut g_dst = dst.to_vec();
+                    g_dst.extend_from_slice(b"-G");
+                    g_dst.extend_from_slice(&i.to_be_bytes());
+                    hash_to_g1(&g_dst)
+                })
+                .collect(),
+        }
+    }
+
+    /// The number of value generators.
+    pub fn len(&self) -> usize {
+        self.g.len()
+    }
+
+    /// Whether there are no value generators.
+    pub fn is_empty(&self) -> bool {
+        self.g.is_empty()
+    }
+
+    /// Commits to `values` with the blinding `blinding`, using the first
+    /// `values.len()` value generators.
+    pub fn commit(&self, values: &[Fr], blinding: &Fr) -> Result<Commitment, CommitmentError> {
+        let (bases, scalars) = self.inputs(values, blinding)?;
+        Ok(Commitment(sum_of_products_pippinger(&bases, &scalars)))
+    }
+
+    /// Whether `commitment` opens to `values` with `blinding`.
+    pub fn verify(&self, commitment: &Commitment, values: &[Fr], blinding: &Fr) -> bool {
+        self.commit(values, blinding) == Ok(*commitment)
+    }
+
+    /// Computes the `precomp_256` tables of the generators.
+    pub fn precompute(self) -> PrecomputedGenerators {
+        let mut table = vec![G1Affine::zero(); 256 * (self.len() + 1)];
+        for (base, pre) in Some(&self.h)
+            .into_iter()
+            .chain(self.g.iter())
+            .zip(table.chunks_mut(256))
+        {
+            base.precomp_256(pre);
+        }
+        PrecomputedGenerators { gens: self, table }
+    }
+
+    /// The bases `h, g_1..g_k` and the scalars `blinding, v_1..v_k`.
+    fn inputs(
+        &self,
+        values: &[Fr],
+        blinding: &Fr,
+    ) -> Result<(Vec<G1Affine>, Vec<Fr>), CommitmentError> {
+        if values.len() > self.len() {
+            return Err(CommitmentError::TooManyValues {
+                generators: self.len(),
+                values: values.len(),
+            });
+        }
+        let mut bases = vec![self.h];
+        bases.extend_from_slice(&self.g[..values.len()]);
+        let mut scalars = vec![*blinding];
+        scalars.extend_from_slice(values);
+        Ok((bases, scalars))
+    }
+}
+
+impl PrecomputedGenerators {
+    /// The generators.
+    pub fn generators(&self) -> &Generators {
+        &self.gens
+    }
+
+    /// Commits to `values` with the blinding `blinding`, as
+    /// `Generators::commit` does, with the precomputed tables.
+    pub fn commit(&self, values: &[Fr], blinding: &Fr) -> Result<Commitment, CommitmentError> {
+        let (bases, scalars) = self.gens.inputs(values, blinding)?;
+        let reprs: Vec<FrRepr> = scalars.iter().map(|s| s.into_repr()).collect();
+        let limbs: Vec<&[u64; 4]> = reprs.iter().map(|r| &r.0).collect();
+        Ok(Commitment(G1Affine::sum_of_products_precomp_256(
+            &bases,
+            &limbs,
+            &self.table,
+        )))
+    }
+
+    /// Whether `commitment` opens to `values` with `blinding`.
+    pub fn verify(&self, commitment: &Commitment, values: &[Fr], blinding: &Fr) -> bool {
+        self.commit(values, blinding) == Ok(*commitment)
+    }
+}
+
+impl Commitment {
+    /// The commitment to the sums of the values and of the blindings.
+    pub fn add(&self, other: &Commitment) -> Commitment {
+        let mut c = self.0;
+        c.add_assign(&other.0);
+        Commitment(c)
+    }
+
+    /// The commitment to the differences of the values and of the
+    /// blindings.
+    pub fn sub(&self, other: &Commitment) -> Commitment {
+        let mut c = self.0;
+        c.sub_assign(&other.0);
+        Commitment(c)
+    }
+
+    /// The commitment to the values and the blinding multiplied by `x`.
+    pub fn scale(&self, x: &Fr) -> Commitment {
+        let mut c = self.0;
+        c.mul_assign(*x);
+        Commitment(c)
+    }
+}
+
+/// Computes `sum_i scalars[i] * bases[i]` with Pippinger's algorithm, in
+/// the window that `find_pippinger_window` picks for the number of bases.
+pub(crate) fn sum_of_products_pippinger(bases: &[G1Affine], scalars: &[Fr]) -> G1 {
+    let reprs: Vec<FrRepr> = scalars.iter().map(|s| s.into_repr()).collect();
+    let limbs: Vec<&[u64; 4]> = reprs.iter().map(|r| &r.0).collect();
+    let window = G1Affine::find_pippinger_window(bases.len());
+    G1Affine::sum_of_products_pippinger(bases, &limbs, window)
+}
+
+fn hash_to_g1(dst: &[u8]) -> G1Affine {
+    <G1 as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(b"", dst).into_affine()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_rng;
+    use ff::Field;
+
+    #[test]
+    fn test_generators() {
+        let gens = Generators::new(4);
+        assert_eq!(gens.len(), 4);
+        assert_eq!(gens, Generators::new(4));
+        assert_eq!(&Generators::new(6).g[..4], &gens.g[..]);
+        assert_eq!(Generators::new(0).h, gens.h);
+        assert_ne!(gens.g[0], gens.g[1]);
+        assert_ne!(gens.g[0], gens.h);
+        assert_ne!(Generators::with_dst(b"other", 4), gens);
+        assert_eq!(
+            gens.g[2],
+            <G1 as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(
+                b"",
+                b"PAIRING-PLUS-PEDERSEN-VC-V01-G\x00\x00\x00\x00\x00\x00\x00\x03"
+            )
+            .into_affine()
+        );
+    }
+
+    #[test]
+    fn test_commit() {
+        let mut rng = test_rng();
+        let gens = Generators::new(5);
+        let values: Vec<Fr> = (0..5).map(|_| Fr::random(&mut rng)).collect();
+        let r = Fr::random(&mut rng);
+
+        let c = gens.commit(&values, &r).unwrap();
+        let mut expected = gens.h.mul(r);
+        for (g, v) in gens.g.iter().zip(values.iter()) {
+            expected.add_assign(&g.mul(*v));
+        }
+        assert_eq!(c.0, expected);
+        assert!(gens.verify(&c, &values, &r));
+        assert!(!gens.verify(&c, &values, &Fr::one()));
+        assert!(!gens.verify(&c, &values[..4], &r));
+
+        // fewer values use a prefix of the generators
+        let short = gens.commit(&values[..2], &r).unwrap();
+        assert_eq!(short, Generators::new(2).commit(&values[..2], &r).unwrap());
+        assert_eq!(gens.commit(&[], &r).unwrap().0, gens.h.mul(r));
+
+        assert_eq!(
+            Generators::new(2).commit(&values, &r),
+            Err(CommitmentError::TooManyValues {
+                generators: 2,
+                values: 5
+            })
+        );
+        assert!(!Generators::new(2).verify(&c, &values, &r));
+    }
+
+    #[test]
+    fn test_precomputed() {
+        let mut rng = test_rng();
+        let gens = Generators::new(4);
+        let pre = gens.clone().precompute();
+        assert_eq!(pre.generators(), &gens);
+        for k in 0..=4 {
+            let values: Vec<Fr> = (0..k).map(|_| Fr::random(&mut rng)).collect();
+            let r = Fr::random(&mut rng);
+            let c = pre.commit(&values, &r).unwrap();
+            assert_eq!(c, gens.commit(&values, &r).unwrap());
+            assert!(pre.verify(&c, &values, &r));
+        }
+        assert_eq!(
+            pre.commit(&[Fr::one(); 5], &Fr::one()),
+            Err(CommitmentError::TooManyValues {
+                generators: 4,
+                values: 5
+            })
+        );
+    }
+
+    #[test]
+    fn test_homomorphism() {
+        let mut rng = test_rng();
+        let gens = Generators::new(3);
+        let a: Vec<Fr> = (0..3).map(|_| Fr::random(&mut rng)).collect();
+        let b: Vec<Fr> = (0..3).map(|_| Fr::random(&mut rng)).collect();
+        let (ra, rb) = (Fr::random(&mut rng), Fr::random(&mut rng));
+        let ca = gens.commit(&a, &ra).unwrap();
+        let cb = gens.commit(&b, &rb).unwrap();
+
+        let combine = |f: &dyn Fn(&mut Fr, &Fr)| {
+            let values: Vec<Fr> = a
+                .iter()
+                .zip(b.iter())
+                .map(|(x, y)| {
+                    let mut x = *x;
+                    f(&mut x, y);
+                    x
+                })
+                .collect();
+            let mut r = ra;
+            f(&mut r, &rb);
+            (values, r)
+        };
+        let (sum, r) = combine(&|x, y| x.add_assign(y));
+        assert!(gens.verify(&ca.add(&cb), &sum, &r));
+        let (diff, r) = combine(&|x, y| x.sub_assign(y));
+        assert!(gens.verify(&ca.sub(&cb), &diff, &r));
+
+        let x = Fr::random(&mut rng);
+        let scaled: Vec<Fr> = a
+            .iter()
+            .map(|v| {
+                let mut v = *v;
+                v.mul_assign(&x);
+                v
+            })
+            .collect();
+        let mut r = ra;
+        r.mul_assign(&x);
+        assert!(gens.verify(&ca.scale(&x), &scaled, &r));
+    }
+}
@@ -1,6 +1,5 @@
 use crate::{bls12_381::*, CurveAffine, CurveProjective, EncodedPoint};
-use ff::{PrimeField, PrimeFieldRepr};
-use std::io::{Error, ErrorKind, Read, Result, Write};
+use std::io::{Read, Result, Write};
 type Compressed = bool;
 
 /// Serialization support for group elements.
@@ -16,157 +15,28 @@ pub trait SerDes: Sized {
 impl SerDes for Fr {
     /// The compressed parameter has no effect since Fr element will always be compressed.
     fn serialize<W: Write>(&self, writer: &mut W, _compressed: Compressed) -> Result<()> {
-        self.into_repr().write_be(writer)
+        writer.write_all(&self.to_bytes_be())
     }
 
     /// The compressed parameter has no effect since Fr element will always be compressed.
     fn deserialize<R: Read>(reader: &mut R, _compressed: Compressed) -> Result<Self> {
-        let mut r = FrRepr::default();
-        r.read_be(reader)?;
-        match Fr::from_repr(r) {
-            Err(e) => Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => Ok(p),
-        }
+        let mut buf = [0u8; 32];
+        reader.read_exact(&mut buf)?;
+        Ok(Fr::from_bytes_be(&buf)?)
     }
 }
 
 impl SerDes for Fq12 {
-    /// The compressed parameter has no effect since Fr element will always be compressed.
+    /// The compressed parameter has no effect since Fq12 element will always be compressed.
     fn serialize<W: Write>(&self, writer: &mut W, _compressed: Compressed) -> Result<()> {
-        let mut buf: Vec<u8> = vec![];
-
-        match self.c0.c0.c0.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        match self.c0.c0.c1.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        match self.c0.c1.c0.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        match self.c0.c1.c1.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        match self.c0.c2.c0.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        match self.c0.c2.c1.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        match self.c1.c0.c0.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-
-        match self.c1.c0.c1.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        match self.c1.c1.c0.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        match self.c1.c1.c1.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        match self.c1.c2.c0.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        match self.c1.c2.c1.into_repr().write_be(&mut buf) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(p) => p,
-        };
-        writer.write_all(&buf)?;
-        Ok(())
+        writer.write_all(&self.to_bytes_be())
     }
 
-    /// The compressed parameter has no effect since Fr element will always be compressed.
-    fn deserialize<R: Read>(mut reader: &mut R, _compressed: Compressed) -> Result<Self> {
-        let mut q = FqRepr::default();
-        q.read_be(&mut reader)?;
-        let c000 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c001 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c010 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c011 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c020 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c021 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c100 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c101 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c110 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c111 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c120 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        q.read_be(&mut reader)?;
-        let c121 = match Fq::from_repr(q) {
-            Err(e) => return Err(Error::new(ErrorKind::Other, e)),
-            Ok(q) => q,
-        };
-        Ok(Fq12 {
-            c0: Fq6 {
-                c0: Fq2 { c0: c000, c1: c001 },
-
-                c1: Fq2 { c0: c010, c1: c011 },
-
-                c2: Fq2 { c0: c020, c1: c021 },
-            },
-            c1: Fq6 {
-                c0: Fq2 { c0: c100, c1: c101 },
-
-                c1: Fq2 { c0: c110, c1: c111 },
-
-                c2: Fq2 { c0: c120, c1: c121 },
-            },
-        })
+    /// The compressed parameter has no effect since Fq12 element will always be compressed.
+    fn deserialize<R: Read>(reader: &mut R, _compressed: Compressed) -> Result<Self> {
+        let mut buf = [0u8; 576];
+        reader.read_exact(&mut buf)?;
+        Ok(Fq12::from_bytes_be(&buf)?)
     }
 }
 
@@ -203,18 +73,14 @@ impl SerDes for G1 {
         // first bit is 1 => compressed mode
         // first bit is 0 => uncompressed mode
         if ((buf[0] & 0x80) == 0x80) != compressed {
-            return Err(Error::new(ErrorKind::InvalidData, "Invalid compressness"));
+            return Err(crate::Error::UnexpectedCompressionMode.into());
         }
 
         if compressed {
             // convert the blob into a group element
             let mut g_buf = G1Compressed::empty();
             g_buf.as_mut().copy_from_slice(&buf);
-            let g = match g_buf.into_affine() {
-                Ok(p) => p.into_projective(),
-                Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
-            };
-            Ok(g)
+            Ok(g_buf.into_affine()?.into_projective())
         } else {
             // read the next uncompressed - compressed size
             let mut buf2 = vec![0u8; G1Uncompressed::size() - G1Compressed::size()];
@@ -224,11 +90,7 @@ impl SerDes for G1 {
             // convert the buf into a group element
             let mut g_buf = G1Uncompressed::empty();
             g_buf.as_mut().copy_from_slice(&buf);
-            let g = match g_buf.into_affine() {
-                Ok(p) => p.into_projective(),
-                Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
-            };
-            Ok(g)
+            Ok(g_buf.into_affine()?.into_projective())
         }
     }
 }
@@ -265,18 +127,14 @@ impl SerDes for G2 {
         // first bit is 1 => compressed mode
         // first bit is 0 => uncompressed mode
         if ((buf[0] & 0x80) == 0x80) != compressed {
-            return Err(Error::new(ErrorKind::InvalidData, "Invalid compressness"));
+            return Err(crate::Error::UnexpectedCompressionMode.into());
         }
 
         if compressed {
             // convert the buf into a group element
             let mut g_buf = G2Compressed::empty();
             g_buf.as_mut().copy_from_slice(&buf);
-            let g = match g_buf.into_affine() {
-                Ok(p) => p.into_projective(),
-                Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
-            };
-            Ok(g)
+            Ok(g_buf.into_affine()?.into_projective())
         } else {
             // read the next uncompressed - compressed size
             let mut buf2 = vec![0u8; G2Uncompressed::size() - G2Compressed::size()];
@@ -286,11 +144,7 @@ impl SerDes for G2 {
             // convert the buf into a group element
             let mut g_buf = G2Uncompressed::empty();
             g_buf.as_mut().copy_from_slice(&buf);
-            let g = match g_buf.into_affine() {
-                Ok(p) => p.into_projective(),
-                Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
-            };
-            Ok(g)
+            Ok(g_buf.into_affine()?.into_projective())
         }
     }
 }
@@ -326,18 +180,14 @@ impl SerDes for G1Affine {
         // first bit is 1 => compressed mode
         // first bit is 0 => uncompressed mode
         if ((buf[0] & 0x80) == 0x80) != compressed {
-            return Err(Error::new(ErrorKind::InvalidData, "Invalid compressness"));
+            return Err(crate::Error::UnexpectedCompressionMode.into());
         }
 
         if compressed {
             // convert the blob into a group element
             let mut g_buf = G1Compressed::empty();
             g_buf.as_mut().copy_from_slice(&buf);
-            let g = match g_buf.into_affine() {
-                Ok(p) => p,
-                Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
-            };
-            Ok(g)
+            Ok(g_buf.into_affine()?)
         } else {
             // read the next uncompressed - compressed size
             let mut buf2 = vec![0u8; G1Uncompressed::size() - G1Compressed::size()];
@@ -347,11 +197,7 @@ impl SerDes for G1Affine {
             // convert the buf into a group element
             let mut g_buf = G1Uncompressed::empty();
             g_buf.as_mut().copy_from_slice(&buf);
-            let g = match g_buf.into_affine() {
-                Ok(p) => p,
-                Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
-            };
-            Ok(g)
+            Ok(g_buf.into_affine()?)
         }
     }
 }
@@ -387,18 +233,14 @@ impl SerDes for G2Affine {
         // first bit is 1 => compressed mode
         // first bit is 0 => uncompressed mode
         if ((buf[0] & 0x80) == 0x80) != compressed {
-            return Err(Error::new(ErrorKind::InvalidData, "Invalid compressness"));
+            return Err(crate::Error::UnexpectedCompressionMode.into());
         }
 
         if compressed {
             // convert the buf into a group element
             let mut g_buf = G2Compressed::empty();
             g_buf.as_mut().copy_from_slice(&buf);
-            let g = match g_buf.into_affine() {
-                Ok(p) => p,
-                Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
-            };
-            Ok(g)
+            Ok(g_buf.into_affine()?)
         } else {
             // read the next uncompressed - compressed size
             let mut buf2 = vec![0u8; G2Uncompressed::size() - G2Compressed::size()];
@@ -408,11 +250,7 @@ impl SerDes for G2Affine {
             // convert the buf into a group element
             let mut g_buf = G2Uncompressed::empty();
             g_buf.as_mut().copy_from_slice(&buf);
-            let g = match g_buf.into_affine() {
-                Ok(p) => p,
-                Err(e) => return Err(Error::new(ErrorKind::InvalidData, e)),
-            };
-            Ok(g)
+            Ok(g_buf.into_affine()?)
         }
     }
 }
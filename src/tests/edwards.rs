@@ -0,0 +1,128 @@
+use crate::edwards::{EdwardsAffine, EdwardsProjective};
+use ff::{Field, PrimeField};
+use rand_core::{RngCore, SeedableRng};
+
+pub fn edwards_tests<G: EdwardsProjective>() {
+    let mut rng = rand_xorshift::XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    // Identity edge cases
+    {
+        let mut z = G::zero();
+        z.negate();
+        assert!(z.is_zero());
+        z.double();
+        assert!(z.is_zero());
+        assert!(z.is_small_order() && z.is_in_subgroup());
+        assert!(G::Affine::zero().into_projective().is_zero());
+        assert!(z.into_affine().is_zero());
+
+        let r = G::random(&mut rng);
+        let mut r2 = r;
+        r2.add_assign(&G::zero());
+        assert_eq!(r, r2);
+        r2.add_assign_mixed(&G::Affine::zero());
+        assert_eq!(r, r2);
+
+        let mut z = G::zero();
+        z.add_assign_mixed(&r.into_affine());
+        assert_eq!(z, r);
+    }
+
+    for _ in 0..50 {
+        let a = G::random(&mut rng);
+        let b = G::random(&mut rng);
+        let c = G::random(&mut rng);
+        assert!(a.is_in_subgroup());
+        assert!(!a.is_small_order());
+
+        // Transformations
+        assert_eq!(a, a.into_affine().into_projective());
+
+        // Commutativity and associativity
+        let mut ab = a;
+        ab.add_assign(&b);
+        let mut ba = b;
+        ba.add_assign(&a);
+        assert_eq!(ab, ba);
+
+        let mut ab_c = ab;
+        ab_c.add_assign(&c);
+        let mut bc = b;
+        bc.add_assign(&c);
+        let mut a_bc = a;
+        a_bc.add_assign(&bc);
+        assert_eq!(ab_c, a_bc);
+
+        // Mixed addition
+        let mut ab_mixed = a;
+        ab_mixed.add_assign_mixed(&b.into_affine());
+        assert_eq!(ab, ab_mixed);
+
+        // Doubling
+        let mut aa = a;
+        aa.add_assign(&a);
+        let mut a2 = a;
+        a2.double();
+        assert_eq!(aa, a2);
+
+        // Negation
+        let mut neg_a = a;
+        neg_a.negate();
+        let mut tmp = a;
+        tmp.add_assign(&neg_a);
+        assert!(tmp.is_zero());
+        let mut tmp = ab;
+        tmp.sub_assign(&b);
+        assert_eq!(tmp, a);
+        let mut tmp = ab;
+        tmp.sub_assign_mixed(&b.into_affine());
+        assert_eq!(tmp, a);
+
+        // Scalar multiplication is a homomorphism
+        let s = G::Scalar::random(&mut rng);
+        let t = G::Scalar::random(&mut rng);
+        let mut st = s;
+        st.add_assign(&t);
+        let mut lhs = a;
+        lhs.mul_assign(st);
+        let mut rhs = a;
+        rhs.mul_assign(s);
+        let mut tmp = a;
+        tmp.mul_assign(t);
+        rhs.add_assign(&tmp);
+        assert_eq!(lhs, rhs);
+        assert_eq!(a.into_affine().mul(s), {
+            let mut tmp = a;
+            tmp.mul_assign(s);
+            tmp
+        });
+
+        let mut tmp = a;
+        tmp.mul_assign(G::Scalar::char());
+        assert!(tmp.is_zero());
+
+        // Encoding
+        let bytes = a.into_affine().into_bytes();
+        assert_eq!(G::Affine::from_bytes(&bytes).unwrap(), a.into_affine());
+        let bytes = neg_a.into_affine().into_bytes();
+        assert_eq!(G::Affine::from_bytes(&bytes).unwrap(), neg_a.into_affine());
+    }
+
+    // Random encodings decode to points whose cofactor can be cleared
+    let mut found = 0;
+    while found < 10 {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes);
+        if let Ok(p) = G::Affine::from_bytes_unchecked(&bytes) {
+            found += 1;
+            assert_eq!(p.into_bytes(), bytes);
+
+            let mut p = p.into_projective();
+            p.mul_by_cofactor();
+            assert!(p.is_in_subgroup());
+        }
+    }
+}
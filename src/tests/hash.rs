@@ -1,12 +1,14 @@
 /// Taken from section J.9 and J.10 in
 /// <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve/?include_text=1>
-/// draft 10
+/// draft 10, which are unchanged in appendices J.9, J.10, K.1 and K.3 of RFC 9380
 use crate::{
     bls12_381::{G1, G2},
-    hash_to_curve::HashToCurve,
-    hash_to_field::ExpandMsgXmd,
+    ciphersuite::*,
+    hash_to_curve::{HashToCurve, HashToCurveHasher},
+    hash_to_field::{ExpandMsg, ExpandMsgXmd, ExpandMsgXof},
     serdes::SerDes,
 };
+use digest::Update;
 use std::io::Cursor;
 
 macro_rules! run_to_curve_tests {
@@ -23,6 +25,11 @@ macro_rules! run_to_curve_tests {
                 let e = P::deserialize(&mut cur, false).unwrap();
                 let a = P::$func(msg, dst);
                 assert_eq!(a, e);
+
+                // streaming input gives the same result
+                let mut h = HashToCurveHasher::<P, ExpandMsgXmd<sha2::Sha256>>::new();
+                msg.chunks(7).for_each(|c| h.update(c));
+                assert_eq!(h.$func(dst), e);
             }
         }
     };
@@ -31,6 +38,28 @@ macro_rules! run_to_curve_tests {
 run_to_curve_tests!(run_hash_to_curve_tests, hash_to_curve);
 run_to_curve_tests!(run_encode_to_curve_tests, encode_to_curve);
 
+/// application tag used by the RFC 9380 test vectors
+const APP_TAG: &[u8] = b"QUUX-V01-CS02";
+
+fn run_suite_tests<S>(dst: &[u8], tests: &[(&[u8], &str)])
+where
+    S: HashToCurveSuite,
+    S::Point: SerDes + PartialEq + std::fmt::Debug,
+{
+    assert_eq!(S::dst(APP_TAG), dst);
+    for (msg, p) in tests {
+        let p_bytes = hex::decode(p).unwrap();
+        let mut cur = Cursor::new(p_bytes.as_slice());
+        let e = S::Point::deserialize(&mut cur, false).unwrap();
+        assert_eq!(S::hash(msg, APP_TAG), e);
+        assert_eq!(S::try_hash(msg, APP_TAG).unwrap(), e);
+
+        let mut h = S::hasher();
+        msg.chunks(5).for_each(|c| h.update(c));
+        assert_eq!(S::finalize(h, APP_TAG), e);
+    }
+}
+
 #[test]
 fn hash_to_curve_g1_ro() {
     //suite   = BLS12381G1_XMD:SHA-256_SSWU_RO_
@@ -44,7 +73,8 @@ fn hash_to_curve_g1_ro() {
         (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "082aabae8b7dedb0e78aeb619ad3bfd9277a2f77ba7fad20ef6aabdc6c31d19ba5a6d12283553294c1825c4b3ca2dcfe05b84ae5a942248eea39e1d91030458c40153f3b654ab7872d779ad1e942856a20c438e8d99bc8abfbf74729ce1f7ac8")
     ];
 
-    run_hash_to_curve_tests::<G1>(DST, &tests)
+    run_hash_to_curve_tests::<G1>(DST, &tests);
+    run_suite_tests::<Bls12381G1XmdSha256SswuRo>(DST, &tests);
 }
 
 #[test]
@@ -60,6 +90,7 @@ fn encode_to_curve_g1() {
     ];
 
     run_encode_to_curve_tests::<G1>(DST, &tests);
+    run_suite_tests::<Bls12381G1XmdSha256SswuNu>(DST, &tests);
 }
 
 #[test]
@@ -75,7 +106,8 @@ fn hash_to_curve_g2_ro() {
         (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "11fca2ff525572795a801eed17eb12785887c7b63fb77a42be46ce4a34131d71f7a73e95fee3f812aea3de78b4d0156901a6ba2f9a11fa5598b2d8ace0fbe0a0eacb65deceb476fbbcb64fd24557c2f4b18ecfc5663e54ae16a84f5ab7f6253403a47f8e6d1763ba0cad63d6114c0accbef65707825a511b251a660a9b3994249ae4e63fac38b23da0c398689ee2ab520b6798718c8aed24bc19cb27f866f1c9effcdbf92397ad6448b5c9db90d2b9da6cbabf48adc1adf59a1a28344e79d57e")
     ];
 
-    run_hash_to_curve_tests::<G2>(DST, &tests)
+    run_hash_to_curve_tests::<G2>(DST, &tests);
+    run_suite_tests::<Bls12381G2XmdSha256SswuRo>(DST, &tests);
 }
 
 #[test]
@@ -90,5 +122,135 @@ fn encode_to_curve_g2() {
         (b"a512_aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa", "1565c2f625032d232f13121d3cfb476f45275c303a037faa255f9da62000c2c864ea881e2bcddd111edc4a3c0da3e88d0ea4e7c33d43e17cc516a72f76437c4bf81d8f4eac69ac355d3bf9b71b8138d55dc10fd458be115afa798b55dac34be10f8991d2a1ad662e7b6f58ab787947f1fa607fce12dde171bc17903b012091b657e15333e11701edcf5b63ba2a561247043b6f5fe4e52c839148dc66f2b3751e69a0f6ebb3d056d6465d50d4108543ecd956e10fa1640dfd9bc0030cc2558d28")
     ];
 
-    run_encode_to_curve_tests::<G2>(DST, &tests)
+    run_encode_to_curve_tests::<G2>(DST, &tests);
+    run_suite_tests::<Bls12381G2XmdSha256SswuNu>(DST, &tests);
+}
+
+#[test]
+fn hash_to_curve_oversize_dst() {
+    use digest::FixedOutput;
+
+    // a DST longer than 255 bytes is replaced by H("H2C-OVERSIZE-DST-" || DST)
+    let dst = [b'1'; 256];
+    let hashed = sha2::Sha256::default()
+        .chain(b"H2C-OVERSIZE-DST-")
+        .chain(&dst[..])
+        .finalize_fixed();
+
+    let p = <G1 as HashToCurve<ExpandMsgXmd<sha2::Sha256>>>::try_hash_to_curve(b"abc", &dst[..]);
+    let e = <G1 as HashToCurve<ExpandMsgXmd<sha2::Sha256>>>::hash_to_curve(b"abc", hashed);
+    assert_eq!(p.unwrap(), e);
+
+    let p = <G2 as HashToCurve<ExpandMsgXmd<sha2::Sha256>>>::try_encode_to_curve(b"abc", &dst[..]);
+    let e = <G2 as HashToCurve<ExpandMsgXmd<sha2::Sha256>>>::encode_to_curve(b"abc", hashed);
+    assert_eq!(p.unwrap(), e);
+}
+
+fn run_streaming_tests<P, X>(dst: &[u8])
+where
+    P: HashToCurve<X> + PartialEq + std::fmt::Debug,
+    X: ExpandMsg,
+{
+    let msg: Vec<u8> = (0..1000u32).map(|i| (i * 7 + 3) as u8).collect();
+    for chunk_size in [1, 63, 64, 65, 1000].iter() {
+        let mut h = HashToCurveHasher::<P, X>::new();
+        msg.chunks(*chunk_size).for_each(|c| h.update(c));
+        assert_eq!(h.hash_to_curve(dst), P::hash_to_curve(&msg, dst));
+
+        let mut h = HashToCurveHasher::<P, X>::new();
+        msg.chunks(*chunk_size).for_each(|c| h.update(c));
+        assert_eq!(h.encode_to_curve(dst), P::encode_to_curve(&msg, dst));
+    }
+
+    // no input at all
+    let h = HashToCurveHasher::<P, X>::default();
+    assert_eq!(h.hash_to_curve(dst), P::hash_to_curve(b"", dst));
+}
+
+#[test]
+fn hash_to_curve_streaming() {
+    const DST: &[u8] = b"HASH_TO_CURVE_STREAMING_TEST";
+    run_streaming_tests::<G1, ExpandMsgXmd<sha2::Sha256>>(DST);
+    run_streaming_tests::<G2, ExpandMsgXmd<sha2::Sha256>>(DST);
+    run_streaming_tests::<G1, ExpandMsgXof<sha3::Shake128>>(DST);
+    run_streaming_tests::<G2, ExpandMsgXof<sha3::Shake128>>(DST);
+}
+
+#[test]
+fn hash_to_curve_xof_suites() {
+    // RFC 9380 has no BLS12-381 vectors for the XOF suites, so check that they
+    // agree with HashToCurve under ExpandMsgXof<Shake128> and their suite DSTs
+    fn check<S: HashToCurveSuite>(dst: &[u8])
+    where
+        S::Point: HashToCurve<ExpandMsgXof<sha3::Shake128>> + PartialEq + std::fmt::Debug,
+    {
+        assert_eq!(S::dst(APP_TAG), dst);
+        for msg in [&b""[..], b"abc", b"abcdef0123456789"].iter() {
+            let e = match S::ENCODING {
+                EncodingType::Uniform => S::Point::hash_to_curve(msg, dst),
+                EncodingType::NonUniform => S::Point::encode_to_curve(msg, dst),
+            };
+            assert_eq!(S::hash(msg, APP_TAG), e);
+        }
+    }
+
+    check::<Bls12381G1XofShake128SswuRo>(b"QUUX-V01-CS02-with-BLS12381G1_XOF:SHAKE-128_SSWU_RO_");
+    check::<Bls12381G1XofShake128SswuNu>(b"QUUX-V01-CS02-with-BLS12381G1_XOF:SHAKE-128_SSWU_NU_");
+    check::<Bls12381G2XofShake128SswuRo>(b"QUUX-V01-CS02-with-BLS12381G2_XOF:SHAKE-128_SSWU_RO_");
+    check::<Bls12381G2XofShake128SswuNu>(b"QUUX-V01-CS02-with-BLS12381G2_XOF:SHAKE-128_SSWU_NU_");
+}
+
+fn run_expand_message_tests<X: ExpandMsg>(dst: &[u8], tests: &[(&[u8], usize, &str)]) {
+    for (msg, len_in_bytes, expect) in tests {
+        let uniform_bytes = X::expand_message(msg, dst, *len_in_bytes);
+        assert_eq!(hex::encode(uniform_bytes), *expect);
+    }
+}
+
+fn q128_a512() -> (Vec<u8>, Vec<u8>) {
+    let mut q128 = b"q128_".to_vec();
+    q128.resize(5 + 128, b'q');
+    let mut a512 = b"a512_".to_vec();
+    a512.resize(5 + 512, b'a');
+    (q128, a512)
+}
+
+#[test]
+fn expand_message_xmd_sha256() {
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHA256-128";
+    let (q128, a512) = q128_a512();
+    let tests: [(&[u8], usize, &str); 10] = [
+        (b"", 0x20, "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235"),
+        (b"abc", 0x20, "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615"),
+        (b"abcdef0123456789", 0x20, "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1"),
+        (&q128, 0x20, "b23a1d2b4d97b2ef7785562a7e8bac7eed54ed6e97e29aa51bfe3f12ddad1ff9"),
+        (&a512, 0x20, "4623227bcc01293b8c130bf771da8c298dede7383243dc0993d2d94823958c4c"),
+        (b"", 0x80, "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbee0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dcc541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced"),
+        (b"abc", 0x80, "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40"),
+        (b"abcdef0123456789", 0x80, "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4bc95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be14cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df"),
+        (&q128, 0x80, "80be107d0884f0d881bb460322f0443d38bd222db8bd0b0a5312a6fedb49c1bbd88fd75d8b9a09486c60123dfa1d73c1cc3169761b17476d3c6b7cbbd727acd0e2c942f4dd96ae3da5de368d26b32286e32de7e5a8cb2949f866a0b80c58116b29fa7fabb3ea7d520ee603e0c25bcaf0b9a5e92ec6a1fe4e0391d1cdbce8c68a"),
+        (&a512, 0x80, "546aff5444b5b79aa6148bd81728704c32decb73a3ba76e9e75885cad9def1d06d6792f8a7d12794e90efed817d96920d728896a4510864370c207f99bd4a608ea121700ef01ed879745ee3e4ceef777eda6d9e5e38b90c86ea6fb0b36504ba4a45d22e86f6db5dd43d98a294bebb9125d5b794e9d2a81181066eb954966a487"),
+    ];
+
+    run_expand_message_tests::<ExpandMsgXmd<sha2::Sha256>>(DST, &tests);
+}
+
+#[test]
+fn expand_message_xof_shake128() {
+    const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHAKE128";
+    let (q128, a512) = q128_a512();
+    let tests: [(&[u8], usize, &str); 10] = [
+        (b"", 0x20, "86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2"),
+        (b"abc", 0x20, "8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468"),
+        (b"abcdef0123456789", 0x20, "912c58deac4821c3509dbefa094df54b34b8f5d01a191d1d3108a2c89077acca"),
+        (&q128, 0x20, "1adbcc448aef2a0cebc71dac9f756b22e51839d348e031e63b33ebb50faeaf3f"),
+        (&a512, 0x20, "df3447cc5f3e9a77da10f819218ddf31342c310778e0e4ef72bbaecee786a4fe"),
+        (b"", 0x80, "7314ff1a155a2fb99a0171dc71b89ab6e3b2b7d59e38e64419b8b6294d03ffee42491f11370261f436220ef787f8f76f5b26bdcd850071920ce023f3ac46847744f4612b8714db8f5db83205b2e625d95afd7d7b4d3094d3bdde815f52850bb41ead9822e08f22cf41d615a303b0d9dde73263c049a7b9898208003a739a2e57"),
+        (b"abc", 0x80, "c952f0c8e529ca8824acc6a4cab0e782fc3648c563ddb00da7399f2ae35654f4860ec671db2356ba7baa55a34a9d7f79197b60ddae6e64768a37d699a78323496db3878c8d64d909d0f8a7de4927dcab0d3dbbc26cb20a49eceb0530b431cdf47bc8c0fa3e0d88f53b318b6739fbed7d7634974f1b5c386d6230c76260d5337a"),
+        (b"abcdef0123456789", 0x80, "19b65ee7afec6ac06a144f2d6134f08eeec185f1a890fe34e68f0e377b7d0312883c048d9b8a1d6ecc3b541cb4987c26f45e0c82691ea299b5e6889bbfe589153016d8131717ba26f07c3c14ffbef1f3eff9752e5b6183f43871a78219a75e7000fbac6a7072e2b83c790a3a5aecd9d14be79f9fd4fb180960a3772e08680495"),
+        (&q128, 0x80, "ca1b56861482b16eae0f4a26212112362fcc2d76dcc80c93c4182ed66c5113fe41733ed68be2942a3487394317f3379856f4822a611735e50528a60e7ade8ec8c71670fec6661e2c59a09ed36386513221688b35dc47e3c3111ee8c67ff49579089d661caa29db1ef10eb6eace575bf3dc9806e7c4016bd50f3c0e2a6481ee6d"),
+        (&a512, 0x80, "9d763a5ce58f65c91531b4100c7266d479a5d9777ba761693d052acd37d149e7ac91c796a10b919cd74a591a1e38719fb91b7203e2af31eac3bff7ead2c195af7d88b8bc0a8adf3d1e90ab9bed6ddc2b7f655dd86c730bdeaea884e73741097142c92f0e3fc1811b699ba593c7fbd81da288a29d423df831652e3a01a9374999"),
+    ];
+
+    run_expand_message_tests::<ExpandMsgXof<sha3::Shake128>>(DST, &tests);
 }
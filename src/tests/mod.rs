@@ -1,5 +1,18 @@
 pub mod curve;
+pub mod edwards;
 pub mod engine;
 pub mod field;
+#[cfg(feature = "std")]
 pub mod hash;
 pub mod repr;
+
+use rand_core::SeedableRng;
+use rand_xorshift::XorShiftRng;
+
+/// The seeded RNG shared by the tests.
+pub(crate) fn test_rng() -> XorShiftRng {
+    XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ])
+}
@@ -0,0 +1,510 @@
+/*!
+Threshold BLS signatures over BLS12-381.
+
+A secret key `Fr` is split into `n` Shamir shares, any `t` of which determine
+it: share `i` is `f(i)` for a random polynomial `f` of degree `t - 1` with
+`f(0)` equal to the secret. Since a BLS signature is linear in the key, the
+signature under the secret is the Lagrange interpolation at zero of any `t`
+partial signatures, and interpolating the share public keys the same way
+gives the group public key.
+
+Signatures live in either source group, see `SignatureGroup`: `G2` for
+public keys in `G1`, and `G1` for public keys in `G2`. Messages are hashed
+with `hash_to_curve`, using expand_message_xmd with SHA-256 and a DST chosen
+by the caller.
+
+Combining does not check that partial signatures are valid, nor that there
+are at least `t` of them; callers should run `verify_partial` on each one
+first, since a single bad partial signature spoils the combination.
+*/
+
+use crate::{
+    bls12_381::{Bls12, Fr, FrRepr, G1Affine, G2Affine, G1, G2},
+    hash_to_curve::HashToCurve,
+    hash_to_field::ExpandMsgXmd,
+    CurveAffine, CurveProjective, Engine,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::{Field, PrimeField};
+use rand_core::RngCore;
+use sha2::Sha256;
+use zeroize::Zeroize;
+
+/// An error from splitting a secret or combining shares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThresholdError {
+    /// The threshold is zero or larger than the number of shares.
+    InvalidThreshold { threshold: usize, shares: usize },
+    /// A share has index 0, the point at which the secret is stored.
+    ZeroIndex,
+    /// Two shares have the same index.
+    DuplicateIndex(u64),
+    /// There are no shares to combine.
+    NoShares,
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ThresholdError {}
+
+impl fmt::Display for ThresholdError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ThresholdError::InvalidThreshold { threshold, shares } => write!(
+                f,
+                "threshold {} is not between 1 and the number of shares {}",
+                threshold, shares
+            ),
+            ThresholdError::ZeroIndex => write!(f, "share index 0 is reserved for the secret"),
+            ThresholdError::DuplicateIndex(index) => write!(f, "duplicate share index {}", index),
+            ThresholdError::NoShares => write!(f, "no shares to combine"),
+        }
+    }
+}
+
+/// A source group that signatures live in. Public keys live in the other
+/// source group.
+pub trait SignatureGroup:
+    CurveProjective<Engine = Bls12, Scalar = Fr> + HashToCurve<ExpandMsgXmd<Sha256>>
+{
+    /// The group of the public keys.
+    type PublicKey: CurveProjective<Engine = Bls12, Scalar = Fr>;
+
+    /// The pairing of a point of the public key group with one of this
+    /// group.
+    fn pairing(pk: &Self::PublicKey, p: &Self) -> <Bls12 as Engine>::Fqk;
+
+    /// Checks that `e(pk, msg) == e(g, sig)`, with the arguments of each
+    /// pairing in the order of the source groups and `g` the generator of
+    /// the public key group. Returns false if `pk` or `sig` is the identity,
+    /// for which the check would hold for every message.
+    fn pairing_check(pk: &Self::PublicKey, msg: &Self, sig: &Self) -> bool;
+}
+
+impl SignatureGroup for G2 {
+    type PublicKey = G1;
+
+    fn pairing(pk: &G1, p: &G2) -> <Bls12 as Engine>::Fqk {
+        Bls12::pairing(*pk, *p)
+    }
+
+    fn pairing_check(pk: &G1, msg: &G2, sig: &G2) -> bool {
+        if pk.is_zero() || sig.is_zero() {
+            return false;
+        }
+        let mut g = G1Affine::one();
+        g.negate();
+        let pairs = [
+            (&pk.into_affine().prepare(), &msg.into_affine().prepare()),
+            (&g.prepare(), &sig.into_affine().prepare()),
+        ];
+        match Bls12::final_exponentiation(&Bls12::miller_loop(pairs.iter())) {
+            Some(res) => res == <Bls12 as Engine>::Fqk::one(),
+            None => false,
+        }
+    }
+}
+
+impl SignatureGroup for G1 {
+    type PublicKey = G2;
+
+    fn pairing(pk: &G2, p: &G1) -> <Bls12 as Engine>::Fqk {
+        Bls12::pairing(*p, *pk)
+    }
+
+    fn pairing_check(pk: &G2, msg: &G1, sig: &G1) -> bool {
+        if pk.is_zero() || sig.is_zero() {
+            return false;
+        }
+        let mut g = G2Affine::one();
+        g.negate();
+        let pairs = [
+            (&msg.into_affine().prepare(), &pk.into_affine().prepare()),
+            (&sig.into_affine().prepare(), &g.prepare()),
+        ];
+        match Bls12::final_exponentiation(&Bls12::miller_loop(pairs.iter())) {
+            Some(res) => res == <Bls12 as Engine>::Fqk::one(),
+            None => false,
+        }
+    }
+}
+
+/// A Shamir share `(i, f(i))` of a secret key.
+#[derive(Clone, Debug, PartialEq, Eq, Zeroize)]
+pub struct SecretShare {
+    pub index: u64,
+    pub value: Fr,
+}
+
+/// The public key `f(i) * g` of a share, for the generator `g` of `G`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PublicShare<G> {
+    pub index: u64,
+    pub key: G,
+}
+
+/// A signature under a single share.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PartialSignature<S> {
+    pub index: u64,
+    pub signature: S,
+}
+
+impl SecretShare {
+    /// The public key of this share in `G`.
+    pub fn public_key<G: CurveProjective<Scalar = Fr>>(&self) -> PublicShare<G> {
+        PublicShare {
+            index: self.index,
+            key: mul_generator(&self.value),
+        }
+    }
+
+    /// Signs `msg` under this share, hashing it to `S` with `dst`.
+    pub fn sign<S: SignatureGroup>(&self, msg: &[u8], dst: &[u8]) -> PartialSignature<S> {
+        PartialSignature {
+            index: self.index,
+            signature: sign(&self.value, msg, dst),
+        }
+    }
+}
+
+/// Splits `secret` into `shares` shares at indices `1..=shares`, any
+/// `threshold` of which recover it.
+pub fn split_secret<R: RngCore + ?Sized>(
+    secret: &Fr,
+    threshold: usize,
+    shares: usize,
+    rng: &mut R,
+) -> Result<Vec<SecretShare>, ThresholdError> {
+    if threshold == 0 || threshold > shares {
+        return Err(ThresholdError::InvalidThreshold { threshold, shares });
+    }
+
+    let mut coeffs = random_polynomial(secret, threshold, rng);
+    let res = (1..=shares as u64)
+        .map(|index| SecretShare {
+            index,
+            value: evaluate_polynomial(&coeffs, &fr_from_u64(index)),
+        })
+        .collect();
+    coeffs.zeroize();
+    Ok(res)
+}
+
+/// The coefficients of a random polynomial of degree `threshold - 1` with
+/// constant term `secret`.
+pub(crate) fn random_polynomial<R: RngCore + ?Sized>(
+    secret: &Fr,
+    threshold: usize,
+    rng: &mut R,
+) -> Vec<Fr> {
+    let mut coeffs = Vec::with_capacity(threshold);
+    coeffs.push(*secret);
+    for _ in 1..threshold {
+        coeffs.push(Fr::random(rng));
+    }
+    coeffs
+}
+
+/// Evaluates the polynomial with the given coefficients at `x` with
+/// Horner's rule.
+pub(crate) fn evaluate_polynomial(coeffs: &[Fr], x: &Fr) -> Fr {
+    let mut res = Fr::zero();
+    for c in coeffs.iter().rev() {
+        res.mul_assign(x);
+        res.add_assign(c);
+    }
+    res
+}
+
+/// Recovers the secret from at least `threshold` shares.
+pub fn recover_secret(shares: &[SecretShare]) -> Result<Fr, ThresholdError> {
+    let indices: Vec<u64> = shares.iter().map(|s| s.index).collect();
+    let lambdas = lagrange_coefficients_at_zero(&indices)?;
+    let mut res = Fr::zero();
+    for (share, lambda) in shares.iter().zip(lambdas.iter()) {
+        let mut t = share.value;
+        t.mul_assign(lambda);
+        res.add_assign(&t);
+    }
+    Ok(res)
+}
+
+/// Signs `msg` under the full secret key, hashing it to `S` with `dst`.
+pub fn sign<S: SignatureGroup>(sk: &Fr, msg: &[u8], dst: &[u8]) -> S {
+    let mut sig = <S as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(msg, dst);
+    sig.mul_assign(*sk);
+    sig
+}
+
+/// Verifies a signature on `msg` under the public key `pk`.
+pub fn verify<S: SignatureGroup>(pk: &S::PublicKey, msg: &[u8], dst: &[u8], sig: &S) -> bool {
+    let h = <S as HashToCurve<ExpandMsgXmd<Sha256>>>::hash_to_curve(msg, dst);
+    S::pairing_check(pk, &h, sig)
+}
+
+/// Verifies a partial signature on `msg` against the public key of the
+/// share with the same index.
+pub fn verify_partial<S: SignatureGroup>(
+    pk: &PublicShare<S::PublicKey>,
+    msg: &[u8],
+    dst: &[u8],
+    partial: &PartialSignature<S>,
+) -> bool {
+    pk.index == partial.index && verify(&pk.key, msg, dst, &partial.signature)
+}
+
+/// Combines at least `threshold` verified partial signatures into the
+/// signature under the secret key.
+pub fn combine_signatures<S: SignatureGroup>(
+    partials: &[PartialSignature<S>],
+) -> Result<S, ThresholdError> {
+    let indices: Vec<u64> = partials.iter().map(|p| p.index).collect();
+    let points: Vec<S> = partials.iter().map(|p| p.signature).collect();
+    interpolate_at_zero(&indices, points)
+}
+
+/// Combines at least `threshold` share public keys into the public key of
+/// the secret.
+pub fn combine_public_keys<G: CurveProjective<Scalar = Fr>>(
+    shares: &[PublicShare<G>],
+) -> Result<G, ThresholdError> {
+    let indices: Vec<u64> = shares.iter().map(|s| s.index).collect();
+    let points: Vec<G> = shares.iter().map(|s| s.key).collect();
+    interpolate_at_zero(&indices, points)
+}
+
+/// The Lagrange coefficients at zero for the given share indices, i.e.
+/// `l_i = prod_{j != i} x_j / (x_j - x_i)`. The denominators are inverted
+/// together with a single field inversion.
+pub fn lagrange_coefficients_at_zero(indices: &[u64]) -> Result<Vec<Fr>, ThresholdError> {
+    lagrange_coefficients(indices, 0)
+}
+
+/// The Lagrange coefficients at `x` for the given share indices, i.e.
+/// `l_i = prod_{j != i} (x - x_j) / (x_i - x_j)`.
+pub(crate) fn lagrange_coefficients(indices: &[u64], x: u64) -> Result<Vec<Fr>, ThresholdError> {
+    if indices.is_empty() {
+        return Err(ThresholdError::NoShares);
+    }
+    for (i, &x) in indices.iter().enumerate() {
+        if x == 0 {
+            return Err(ThresholdError::ZeroIndex);
+        }
+        if indices[..i].contains(&x) {
+            return Err(ThresholdError::DuplicateIndex(x));
+        }
+    }
+
+    let x = fr_from_u64(x);
+    let xs: Vec<Fr> = indices.iter().map(|&x| fr_from_u64(x)).collect();
+    let mut nums = Vec::with_capacity(xs.len());
+    let mut dens = Vec::with_capacity(xs.len());
+    for (i, xi) in xs.iter().enumerate() {
+        let mut num = Fr::one();
+        let mut den = Fr::one();
+        for (j, xj) in xs.iter().enumerate() {
+            if i != j {
+                let mut diff = x;
+                diff.sub_assign(xj);
+                num.mul_assign(&diff);
+                let mut diff = *xi;
+                diff.sub_assign(xj);
+                den.mul_assign(&diff);
+            }
+        }
+        nums.push(num);
+        dens.push(den);
+    }
+
+    // the indices are distinct and nonzero, so all denominators are nonzero
+    batch_invert(&mut dens);
+    for (num, den) in nums.iter_mut().zip(dens.iter()) {
+        num.mul_assign(den);
+    }
+    Ok(nums)
+}
+
+/// Computes `sum_i l_i * points[i]` with one multi-scalar multiplication.
+fn interpolate_at_zero<G: CurveProjective<Scalar = Fr>>(
+    indices: &[u64],
+    mut points: Vec<G>,
+) -> Result<G, ThresholdError> {
+    let lambdas = lagrange_coefficients_at_zero(indices)?;
+    G::batch_normalization(&mut points);
+    let bases: Vec<G::Affine> = points.iter().map(|p| p.into_affine()).collect();
+    Ok(sum_of_products(&bases, &lambdas))
+}
+
+/// Computes `sum_i scalars[i] * bases[i]` with `CurveAffine::sum_of_products`,
+/// which takes the scalars as canonical limbs.
+pub(crate) fn sum_of_products<A: CurveAffine<Scalar = Fr>>(
+    bases: &[A],
+    scalars: &[Fr],
+) -> A::Projective {
+    let reprs: Vec<FrRepr> = scalars.iter().map(|s| s.into_repr()).collect();
+    let limbs: Vec<&[u64; 4]> = reprs.iter().map(|r| &r.0).collect();
+    A::sum_of_products(bases, &limbs)
+}
+
+/// Inverts every element of `v` in place with Montgomery's trick.
+///
+/// Panics if an element is zero.
+fn batch_invert(v: &mut [Fr]) {
+    let mut prefix = Vec::with_capacity(v.len());
+    let mut acc = Fr::one();
+    for x in v.iter() {
+        prefix.push(acc);
+        acc.mul_assign(x);
+    }
+
+    let mut inv = acc.inverse().expect("batch_invert: zero element");
+    for (x, p) in v.iter_mut().zip(prefix.iter()).rev() {
+        let mut t = inv;
+        t.mul_assign(p);
+        inv.mul_assign(x);
+        *x = t;
+    }
+}
+
+pub(crate) fn mul_generator<G: CurveProjective<Scalar = Fr>>(s: &Fr) -> G {
+    let mut p = G::one();
+    p.mul_assign(*s);
+    p
+}
+
+pub(crate) fn fr_from_u64(x: u64) -> Fr {
+    // cannot fail: every u64 is less than the modulus
+    Fr::from_repr(FrRepr::from(x)).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_rng;
+
+    const DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+    #[test]
+    fn test_split_and_recover() {
+        let mut rng = test_rng();
+        let secret = Fr::random(&mut rng);
+        let shares = split_secret(&secret, 3, 5, &mut rng).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        assert_eq!(recover_secret(&shares[..3]).unwrap(), secret);
+        assert_eq!(recover_secret(&shares[2..]).unwrap(), secret);
+        let subset = [shares[4].clone(), shares[0].clone(), shares[2].clone()];
+        assert_eq!(recover_secret(&subset).unwrap(), secret);
+        assert_eq!(recover_secret(&shares).unwrap(), secret);
+        // too few shares give an unrelated value
+        assert_ne!(recover_secret(&shares[..2]).unwrap(), secret);
+
+        // threshold 1 hands out the secret itself
+        let shares = split_secret(&secret, 1, 2, &mut rng).unwrap();
+        assert!(shares.iter().all(|s| s.value == secret));
+
+        assert_eq!(
+            split_secret(&secret, 0, 3, &mut rng),
+            Err(ThresholdError::InvalidThreshold {
+                threshold: 0,
+                shares: 3
+            })
+        );
+        assert!(split_secret(&secret, 4, 3, &mut rng).is_err());
+    }
+
+    #[test]
+    fn test_lagrange_coefficients() {
+        let mut v: Vec<Fr> = (1..10).map(fr_from_u64).collect();
+        batch_invert(&mut v);
+        for (i, x) in v.iter().enumerate() {
+            assert_eq!(*x, fr_from_u64(i as u64 + 1).inverse().unwrap());
+        }
+
+        // the coefficients of any set sum to one: they interpolate f = 1
+        let lambdas = lagrange_coefficients_at_zero(&[2, 7, 3, 11]).unwrap();
+        let mut sum = Fr::zero();
+        for l in lambdas.iter() {
+            sum.add_assign(l);
+        }
+        assert_eq!(sum, Fr::one());
+
+        assert_eq!(
+            lagrange_coefficients_at_zero(&[]),
+            Err(ThresholdError::NoShares)
+        );
+        assert_eq!(
+            lagrange_coefficients_at_zero(&[1, 0]),
+            Err(ThresholdError::ZeroIndex)
+        );
+        assert_eq!(
+            lagrange_coefficients_at_zero(&[1, 2, 1]),
+            Err(ThresholdError::DuplicateIndex(1))
+        );
+    }
+
+    fn threshold_signing<S: SignatureGroup>() {
+        let mut rng = test_rng();
+        let secret = Fr::random(&mut rng);
+        let shares = split_secret(&secret, 3, 5, &mut rng).unwrap();
+        let pks: Vec<PublicShare<S::PublicKey>> = shares.iter().map(|s| s.public_key()).collect();
+
+        let pk = combine_public_keys(&pks[1..4]).unwrap();
+        assert_eq!(pk, mul_generator(&secret));
+
+        let msg = b"threshold";
+        let partials: Vec<PartialSignature<S>> = shares.iter().map(|s| s.sign(msg, DST)).collect();
+        for (pk, partial) in pks.iter().zip(partials.iter()) {
+            assert!(verify_partial(pk, msg, DST, partial));
+        }
+        // a partial signature does not verify under another share's key
+        assert!(!verify_partial(&pks[0], msg, DST, &partials[1]));
+        let mut relabeled = partials[1];
+        relabeled.index = pks[0].index;
+        assert!(!verify_partial(&pks[0], msg, DST, &relabeled));
+        assert!(!verify_partial(&pks[1], b"other", DST, &partials[1]));
+
+        let sig = combine_signatures(&[partials[4], partials[0], partials[2]]).unwrap();
+        assert_eq!(sig, sign::<S>(&secret, msg, DST));
+        assert!(verify(&pk, msg, DST, &sig));
+        assert!(!verify(&pk, b"other", DST, &sig));
+
+        // a bad partial signature spoils the combination
+        let mut bad = partials[2];
+        bad.signature.double();
+        let sig = combine_signatures(&[partials[0], partials[1], bad]).unwrap();
+        assert!(!verify(&pk, msg, DST, &sig));
+
+        assert_eq!(
+            combine_signatures(&[partials[0], partials[0]]),
+            Err(ThresholdError::DuplicateIndex(1))
+        );
+
+        // the identity key and signature would pass the pairing check for
+        // any message
+        let zero_pk = S::PublicKey::zero();
+        assert!(!verify(&zero_pk, msg, DST, &S::zero()));
+        assert!(!verify(&zero_pk, b"other", DST, &S::zero()));
+        assert!(!verify(&pk, msg, DST, &S::zero()));
+        let zero_share = PublicShare {
+            index: 1,
+            key: zero_pk,
+        };
+        let zero_partial = PartialSignature {
+            index: 1,
+            signature: S::zero(),
+        };
+        assert!(!verify_partial(&zero_share, msg, DST, &zero_partial));
+    }
+
+    #[test]
+    fn test_threshold_signing_g2() {
+        threshold_signing::<G2>();
+    }
+
+    #[test]
+    fn test_threshold_signing_g1() {
+        threshold_signing::<G1>();
+    }
+}
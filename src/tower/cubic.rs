@@ -0,0 +1,364 @@
+use core::fmt;
+use ff::Field;
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+/// Parameters of a cubic extension BaseField[X] / (X^3 - nonresidue).
+pub trait CubicExtParameters: 'static + Send + Sync + Sized {
+    /// The field being extended.
+    type BaseField: Field;
+
+    /// Name of the extension field, used by Debug and Display.
+    const NAME: &'static str;
+
+    /// Name of the generator X, used by Display.
+    const VAR: &'static str;
+
+    /// Multiply an element of the base field by the nonresidue X^3.
+    fn mul_base_field_by_nonresidue(fe: &mut Self::BaseField);
+
+    /// Multiply c1 and c2 by the Frobenius coefficients X^(p^power - 1) and
+    /// X^(2 (p^power - 1)), where p is the characteristic.
+    fn mul_by_frobenius_coeffs(c1: &mut Self::BaseField, c2: &mut Self::BaseField, power: usize);
+}
+
+/// An element of a cubic extension, represented by c0 + c1 * X + c2 * X^2.
+pub struct CubicExtension<P: CubicExtParameters> {
+    pub c0: P::BaseField,
+    pub c1: P::BaseField,
+    pub c2: P::BaseField,
+}
+
+impl<P: CubicExtParameters> Copy for CubicExtension<P> {}
+
+impl<P: CubicExtParameters> Clone for CubicExtension<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: CubicExtParameters> PartialEq for CubicExtension<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1 && self.c2 == other.c2
+    }
+}
+
+impl<P: CubicExtParameters> Eq for CubicExtension<P> {}
+
+impl<P: CubicExtParameters> fmt::Debug for CubicExtension<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct(P::NAME)
+            .field("c0", &self.c0)
+            .field("c1", &self.c1)
+            .field("c2", &self.c2)
+            .finish()
+    }
+}
+
+impl<P: CubicExtParameters> fmt::Display for CubicExtension<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}({} + {} * {}, {} * {}^2)",
+            P::NAME,
+            self.c0,
+            self.c1,
+            P::VAR,
+            self.c2,
+            P::VAR
+        )
+    }
+}
+
+impl<P: CubicExtParameters> Zeroize for CubicExtension<P>
+where
+    P::BaseField: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+        self.c2.zeroize();
+    }
+}
+
+impl<P: CubicExtParameters> CubicExtension<P> {
+    /// Multiply by the generator X.
+    pub fn mul_by_nonresidue(&mut self) {
+        use core::mem::swap;
+        swap(&mut self.c0, &mut self.c1);
+        swap(&mut self.c0, &mut self.c2);
+
+        P::mul_base_field_by_nonresidue(&mut self.c0);
+    }
+
+    /// Multiply by c1 * X.
+    pub fn mul_by_1(&mut self, c1: &P::BaseField) {
+        let mut b_b = self.c1;
+        b_b.mul_assign(c1);
+
+        let mut t1 = *c1;
+        {
+            let mut tmp = self.c1;
+            tmp.add_assign(&self.c2);
+
+            t1.mul_assign(&tmp);
+            t1.sub_assign(&b_b);
+            P::mul_base_field_by_nonresidue(&mut t1);
+        }
+
+        let mut t2 = *c1;
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c1);
+
+            t2.mul_assign(&tmp);
+            t2.sub_assign(&b_b);
+        }
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = b_b;
+    }
+
+    /// Multiply by c0 + c1 * X.
+    pub fn mul_by_01(&mut self, c0: &P::BaseField, c1: &P::BaseField) {
+        let mut a_a = self.c0;
+        let mut b_b = self.c1;
+        a_a.mul_assign(c0);
+        b_b.mul_assign(c1);
+
+        let mut t1 = *c1;
+        {
+            let mut tmp = self.c1;
+            tmp.add_assign(&self.c2);
+
+            t1.mul_assign(&tmp);
+            t1.sub_assign(&b_b);
+            P::mul_base_field_by_nonresidue(&mut t1);
+            t1.add_assign(&a_a);
+        }
+
+        let mut t3 = *c0;
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c2);
+
+            t3.mul_assign(&tmp);
+            t3.sub_assign(&a_a);
+            t3.add_assign(&b_b);
+        }
+
+        let mut t2 = *c0;
+        t2.add_assign(c1);
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c1);
+
+            t2.mul_assign(&tmp);
+            t2.sub_assign(&a_a);
+            t2.sub_assign(&b_b);
+        }
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = t3;
+    }
+}
+
+impl<P: CubicExtParameters> Field for CubicExtension<P> {
+    fn random<R: RngCore + ?core::marker::Sized>(rng: &mut R) -> Self {
+        CubicExtension {
+            c0: P::BaseField::random(rng),
+            c1: P::BaseField::random(rng),
+            c2: P::BaseField::random(rng),
+        }
+    }
+
+    fn zero() -> Self {
+        CubicExtension {
+            c0: P::BaseField::zero(),
+            c1: P::BaseField::zero(),
+            c2: P::BaseField::zero(),
+        }
+    }
+
+    fn one() -> Self {
+        CubicExtension {
+            c0: P::BaseField::one(),
+            c1: P::BaseField::zero(),
+            c2: P::BaseField::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero() && self.c2.is_zero()
+    }
+
+    fn double(&mut self) {
+        self.c0.double();
+        self.c1.double();
+        self.c2.double();
+    }
+
+    fn negate(&mut self) {
+        self.c0.negate();
+        self.c1.negate();
+        self.c2.negate();
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(&other.c0);
+        self.c1.add_assign(&other.c1);
+        self.c2.add_assign(&other.c2);
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+        self.c2.sub_assign(&other.c2);
+    }
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c0.frobenius_map(power);
+        self.c1.frobenius_map(power);
+        self.c2.frobenius_map(power);
+
+        P::mul_by_frobenius_coeffs(&mut self.c1, &mut self.c2, power);
+    }
+
+    fn square(&mut self) {
+        // CH-SQR2
+        let mut s0 = self.c0;
+        s0.square();
+        let mut ab = self.c0;
+        ab.mul_assign(&self.c1);
+        let mut s1 = ab;
+        s1.double();
+        let mut s2 = self.c0;
+        s2.sub_assign(&self.c1);
+        s2.add_assign(&self.c2);
+        s2.square();
+        let mut bc = self.c1;
+        bc.mul_assign(&self.c2);
+        let mut s3 = bc;
+        s3.double();
+        let mut s4 = self.c2;
+        s4.square();
+
+        self.c0 = s3;
+        P::mul_base_field_by_nonresidue(&mut self.c0);
+        self.c0.add_assign(&s0);
+
+        self.c1 = s4;
+        P::mul_base_field_by_nonresidue(&mut self.c1);
+        self.c1.add_assign(&s1);
+
+        self.c2 = s1;
+        self.c2.add_assign(&s2);
+        self.c2.add_assign(&s3);
+        self.c2.sub_assign(&s0);
+        self.c2.sub_assign(&s4);
+    }
+
+    fn mul_assign(&mut self, other: &Self) {
+        // Karatsuba
+        let mut a_a = self.c0;
+        let mut b_b = self.c1;
+        let mut c_c = self.c2;
+        a_a.mul_assign(&other.c0);
+        b_b.mul_assign(&other.c1);
+        c_c.mul_assign(&other.c2);
+
+        let mut t1 = other.c1;
+        t1.add_assign(&other.c2);
+        {
+            let mut tmp = self.c1;
+            tmp.add_assign(&self.c2);
+
+            t1.mul_assign(&tmp);
+            t1.sub_assign(&b_b);
+            t1.sub_assign(&c_c);
+            P::mul_base_field_by_nonresidue(&mut t1);
+            t1.add_assign(&a_a);
+        }
+
+        let mut t3 = other.c0;
+        t3.add_assign(&other.c2);
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c2);
+
+            t3.mul_assign(&tmp);
+            t3.sub_assign(&a_a);
+            t3.add_assign(&b_b);
+            t3.sub_assign(&c_c);
+        }
+
+        let mut t2 = other.c0;
+        t2.add_assign(&other.c1);
+        {
+            let mut tmp = self.c0;
+            tmp.add_assign(&self.c1);
+
+            t2.mul_assign(&tmp);
+            t2.sub_assign(&a_a);
+            t2.sub_assign(&b_b);
+            P::mul_base_field_by_nonresidue(&mut c_c);
+            t2.add_assign(&c_c);
+        }
+
+        self.c0 = t1;
+        self.c1 = t2;
+        self.c2 = t3;
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        let mut c0 = self.c2;
+        P::mul_base_field_by_nonresidue(&mut c0);
+        c0.mul_assign(&self.c1);
+        c0.negate();
+        {
+            let mut c0s = self.c0;
+            c0s.square();
+            c0.add_assign(&c0s);
+        }
+        let mut c1 = self.c2;
+        c1.square();
+        P::mul_base_field_by_nonresidue(&mut c1);
+        {
+            let mut c01 = self.c0;
+            c01.mul_assign(&self.c1);
+            c1.sub_assign(&c01);
+        }
+        let mut c2 = self.c1;
+        c2.square();
+        {
+            let mut c02 = self.c0;
+            c02.mul_assign(&self.c2);
+            c2.sub_assign(&c02);
+        }
+
+        let mut tmp1 = self.c2;
+        tmp1.mul_assign(&c1);
+        let mut tmp2 = self.c1;
+        tmp2.mul_assign(&c2);
+        tmp1.add_assign(&tmp2);
+        P::mul_base_field_by_nonresidue(&mut tmp1);
+        tmp2 = self.c0;
+        tmp2.mul_assign(&c0);
+        tmp1.add_assign(&tmp2);
+
+        tmp1.inverse().map(|t| {
+            let mut tmp: Self = CubicExtension {
+                c0: t,
+                c1: t,
+                c2: t,
+            };
+            tmp.c0.mul_assign(&c0);
+            tmp.c1.mul_assign(&c1);
+            tmp.c2.mul_assign(&c2);
+
+            tmp
+        })
+    }
+}
@@ -0,0 +1,25 @@
+/*!
+Generic extension fields for building the towers used by pairings.
+
+A tower level is a `QuadraticExtension<P>` or a `CubicExtension<P>`, where
+`P` is a zero-sized parameter type that names the base field and supplies
+the two things that differ between curves: multiplication of a base field
+element by the nonresidue that defines the extension, and the Frobenius
+coefficients. For example, the BLS12-381 tower is
+
+```text
+Fq2  = QuadraticExtension<Fq2Parameters>     Fq[u] / (u^2 + 1)
+Fq6  = CubicExtension<Fq6Parameters>         Fq2[v] / (v^3 - (u + 1))
+Fq12 = QuadraticExtension<Fq12Parameters>    Fq6[w] / (w^2 - v)
+```
+
+All arithmetic is monomorphized, so an instantiated tower is as fast as a
+hand-written one. Curve-specific functionality, such as square roots in
+Fq2 and hashing to Fq2, is implemented directly on the instantiated types.
+*/
+
+mod cubic;
+mod quadratic;
+
+pub use self::cubic::{CubicExtParameters, CubicExtension};
+pub use self::quadratic::{QuadraticExtParameters, QuadraticExtension};
@@ -0,0 +1,284 @@
+use super::{CubicExtParameters, CubicExtension};
+use crate::signum::{Sgn0Result, Signum0};
+use core::cmp::Ordering;
+use core::fmt;
+use ff::Field;
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+/// Parameters of a quadratic extension BaseField[X] / (X^2 - nonresidue).
+pub trait QuadraticExtParameters: 'static + Send + Sync + Sized {
+    /// The field being extended.
+    type BaseField: Field;
+
+    /// Name of the extension field, used by Debug and Display.
+    const NAME: &'static str;
+
+    /// Name of the generator X, used by Display.
+    const VAR: &'static str;
+
+    /// Multiply an element of the base field by the nonresidue X^2.
+    fn mul_base_field_by_nonresidue(fe: &mut Self::BaseField);
+
+    /// Compute acc += nonresidue * fe. Override this and sub_mul_by_nonresidue
+    /// when the nonresidue is small enough that this can skip a step.
+    #[inline(always)]
+    fn add_mul_by_nonresidue(acc: &mut Self::BaseField, fe: &Self::BaseField) {
+        let mut tmp = *fe;
+        Self::mul_base_field_by_nonresidue(&mut tmp);
+        acc.add_assign(&tmp);
+    }
+
+    /// Compute acc -= nonresidue * fe.
+    #[inline(always)]
+    fn sub_mul_by_nonresidue(acc: &mut Self::BaseField, fe: &Self::BaseField) {
+        let mut tmp = *fe;
+        Self::mul_base_field_by_nonresidue(&mut tmp);
+        acc.sub_assign(&tmp);
+    }
+
+    /// Multiply c1 by the Frobenius coefficient X^(p^power - 1), where p is
+    /// the characteristic.
+    fn mul_by_frobenius_coeff(c1: &mut Self::BaseField, power: usize);
+}
+
+/// An element of a quadratic extension, represented by c0 + c1 * X.
+pub struct QuadraticExtension<P: QuadraticExtParameters> {
+    pub c0: P::BaseField,
+    pub c1: P::BaseField,
+}
+
+impl<P: QuadraticExtParameters> Copy for QuadraticExtension<P> {}
+
+impl<P: QuadraticExtParameters> Clone for QuadraticExtension<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P: QuadraticExtParameters> PartialEq for QuadraticExtension<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.c0 == other.c0 && self.c1 == other.c1
+    }
+}
+
+impl<P: QuadraticExtParameters> Eq for QuadraticExtension<P> {}
+
+impl<P: QuadraticExtParameters> fmt::Debug for QuadraticExtension<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct(P::NAME)
+            .field("c0", &self.c0)
+            .field("c1", &self.c1)
+            .finish()
+    }
+}
+
+impl<P: QuadraticExtParameters> fmt::Display for QuadraticExtension<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}({} + {} * {})", P::NAME, self.c0, self.c1, P::VAR)
+    }
+}
+
+impl<P: QuadraticExtParameters> Zeroize for QuadraticExtension<P>
+where
+    P::BaseField: Zeroize,
+{
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+    }
+}
+
+/// Elements are ordered lexicographically, comparing c1 first.
+impl<P: QuadraticExtParameters> Ord for QuadraticExtension<P>
+where
+    P::BaseField: Ord,
+{
+    #[inline(always)]
+    fn cmp(&self, other: &Self) -> Ordering {
+        match self.c1.cmp(&other.c1) {
+            Ordering::Equal => self.c0.cmp(&other.c0),
+            ord => ord,
+        }
+    }
+}
+
+impl<P: QuadraticExtParameters> PartialOrd for QuadraticExtension<P>
+where
+    P::BaseField: Ord,
+{
+    #[inline(always)]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: QuadraticExtParameters> QuadraticExtension<P> {
+    /// Conjugation, c0 - c1 * X.
+    pub fn conjugate(&mut self) {
+        self.c1.negate();
+    }
+
+    /// Norm over the base field, c0^2 - nonresidue * c1^2.
+    pub fn norm(&self) -> P::BaseField {
+        let mut t0 = self.c0;
+        let mut t1 = self.c1;
+        t0.square();
+        t1.square();
+        P::sub_mul_by_nonresidue(&mut t0, &t1);
+
+        t0
+    }
+}
+
+impl<P: QuadraticExtParameters> Field for QuadraticExtension<P> {
+    fn random<R: RngCore + ?core::marker::Sized>(rng: &mut R) -> Self {
+        QuadraticExtension {
+            c0: P::BaseField::random(rng),
+            c1: P::BaseField::random(rng),
+        }
+    }
+
+    fn zero() -> Self {
+        QuadraticExtension {
+            c0: P::BaseField::zero(),
+            c1: P::BaseField::zero(),
+        }
+    }
+
+    fn one() -> Self {
+        QuadraticExtension {
+            c0: P::BaseField::one(),
+            c1: P::BaseField::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.c0.is_zero() && self.c1.is_zero()
+    }
+
+    fn square(&mut self) {
+        // (c0 + c1)(c0 + nr c1) - c0 c1 - nr c0 c1 = c0^2 + nr c1^2
+        let mut ab = self.c0;
+        ab.mul_assign(&self.c1);
+        let mut c0c1 = self.c0;
+        c0c1.add_assign(&self.c1);
+        let mut c0 = self.c0;
+        P::add_mul_by_nonresidue(&mut c0, &self.c1);
+        c0.mul_assign(&c0c1);
+        c0.sub_assign(&ab);
+        P::sub_mul_by_nonresidue(&mut c0, &ab);
+        self.c1 = ab;
+        self.c1.double();
+        self.c0 = c0;
+    }
+
+    fn double(&mut self) {
+        self.c0.double();
+        self.c1.double();
+    }
+
+    fn negate(&mut self) {
+        self.c0.negate();
+        self.c1.negate();
+    }
+
+    fn add_assign(&mut self, other: &Self) {
+        self.c0.add_assign(&other.c0);
+        self.c1.add_assign(&other.c1);
+    }
+
+    fn sub_assign(&mut self, other: &Self) {
+        self.c0.sub_assign(&other.c0);
+        self.c1.sub_assign(&other.c1);
+    }
+
+    fn mul_assign(&mut self, other: &Self) {
+        // Karatsuba
+        let mut aa = self.c0;
+        aa.mul_assign(&other.c0);
+        let mut bb = self.c1;
+        bb.mul_assign(&other.c1);
+        let mut o = other.c0;
+        o.add_assign(&other.c1);
+        self.c1.add_assign(&self.c0);
+        self.c1.mul_assign(&o);
+        self.c1.sub_assign(&aa);
+        self.c1.sub_assign(&bb);
+        self.c0 = aa;
+        P::add_mul_by_nonresidue(&mut self.c0, &bb);
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        self.norm().inverse().map(|t| {
+            let mut tmp = *self;
+            tmp.c0.mul_assign(&t);
+            tmp.c1.mul_assign(&t);
+            tmp.c1.negate();
+
+            tmp
+        })
+    }
+
+    fn frobenius_map(&mut self, power: usize) {
+        self.c0.frobenius_map(power);
+        self.c1.frobenius_map(power);
+        P::mul_by_frobenius_coeff(&mut self.c1, power);
+    }
+}
+
+/// sgn0 of c0, or of c1 if c0 is zero.
+impl<P: QuadraticExtParameters> Signum0 for QuadraticExtension<P>
+where
+    P::BaseField: Signum0,
+{
+    fn sgn0(&self) -> Sgn0Result {
+        if self.c0.is_zero() {
+            self.c1.sgn0()
+        } else {
+            self.c0.sgn0()
+        }
+    }
+}
+
+/// Sparse multiplications for the degree-12 and degree-6 pairing targets,
+/// which are quadratic extensions of a cubic extension.
+impl<P, Q> QuadraticExtension<P>
+where
+    P: QuadraticExtParameters<BaseField = CubicExtension<Q>>,
+    Q: CubicExtParameters,
+{
+    /// Multiply by c0 + c1 * v + c4 * v * w, the line shape of M-type twists.
+    pub fn mul_by_014(&mut self, c0: &Q::BaseField, c1: &Q::BaseField, c4: &Q::BaseField) {
+        let mut aa = self.c0;
+        aa.mul_by_01(c0, c1);
+        let mut bb = self.c1;
+        bb.mul_by_1(c4);
+        let mut o = *c1;
+        o.add_assign(c4);
+        self.c1.add_assign(&self.c0);
+        self.c1.mul_by_01(c0, &o);
+        self.c1.sub_assign(&aa);
+        self.c1.sub_assign(&bb);
+        self.c0 = aa;
+        P::add_mul_by_nonresidue(&mut self.c0, &bb);
+    }
+
+    /// Multiply by c0 + c3 * w + c4 * v * w, the line shape of D-type twists.
+    pub fn mul_by_034(&mut self, c0: &Q::BaseField, c3: &Q::BaseField, c4: &Q::BaseField) {
+        let mut aa = self.c0;
+        aa.c0.mul_assign(c0);
+        aa.c1.mul_assign(c0);
+        aa.c2.mul_assign(c0);
+        let mut bb = self.c1;
+        bb.mul_by_01(c3, c4);
+        let mut o = *c0;
+        o.add_assign(c3);
+        self.c1.add_assign(&self.c0);
+        self.c1.mul_by_01(&o, c4);
+        self.c1.sub_assign(&aa);
+        self.c1.sub_assign(&bb);
+        self.c0 = aa;
+        P::add_mul_by_nonresidue(&mut self.c0, &bb);
+    }
+}
@@ -0,0 +1,249 @@
+/*!
+A Fiat-Shamir transcript for interactive proofs made non-interactive.
+
+The prover and the verifier feed the same sequence of labelled messages into
+a `Transcript` and squeeze the same challenges out of it. The transcript is
+a SHAKE128 state. Every operation absorbs a frame
+
+```text
+op || I2OSP(len(label), 8) || label || I2OSP(len(data), 8) || data
+```
+
+with `op` 0 for an appended message and 1 for a challenge, so the messages
+can always be told apart. Scalars and points are appended with the `SerDes`
+encodings: big-endian for `Fr` and `Fq12`, and the compressed encodings for
+`G1Affine` and `G2Affine`.
+
+A challenge is `hash_to_field` into `Fr` with expand_message_xof over the
+SHAKE128 state, after absorbing the challenge frame, under the DST
+`TRANSCRIPT_DST`. The challenge frame stays in the state, so challenges
+squeezed one after another are independent.
+
+expand_message outputs at most 65535 bytes, i.e. 1365 elements of `Fr`. A
+request for more challenges is squeezed in blocks of 1365: block `i > 0` is
+hashed from a copy of the state that has also absorbed a frame with `op` 2
+and data `I2OSP(i, 8)`. Block 0 is hashed from the state itself, so smaller
+requests need a single call.
+*/
+
+use crate::{
+    bls12_381::{Fq12, Fr, G1Affine, G2Affine},
+    hash_to_field::{try_hash_to_field_with, ExpandMsg, ExpandMsgHasher, ExpandMsgXof, FromRO},
+};
+use alloc::vec::Vec;
+use core::fmt;
+use digest::{generic_array::typenum::Unsigned, Update};
+use sha3::Shake128;
+
+/// The DST under which challenges are hashed to `Fr`.
+pub const TRANSCRIPT_DST: &[u8] = b"PAIRING-PLUS-TRANSCRIPT-V01";
+
+const APPEND: u8 = 0;
+const CHALLENGE: u8 = 1;
+const BLOCK: u8 = 2;
+
+/// The most challenges one call of expand_message can produce.
+const BLOCK_LEN: usize = 65535 / <Fr as FromRO>::Length::USIZE;
+
+/// A Fiat-Shamir transcript over SHAKE128.
+#[derive(Clone)]
+pub struct Transcript {
+    hasher: ExpandMsgHasher<Shake128>,
+}
+
+impl Transcript {
+    /// Starts a transcript for the protocol named `label`.
+    pub fn new(label: &[u8]) -> Self {
+        let mut t = Transcript {
+            hasher: <ExpandMsgXof<Shake128> as ExpandMsg>::hasher(),
+        };
+        t.append_message(b"protocol", label);
+        t
+    }
+
+    /// Appends a message.
+    pub fn append_message(&mut self, label: &[u8], msg: &[u8]) {
+        self.absorb(APPEND, label, msg);
+    }
+
+    /// Appends an integer, as 8 big-endian bytes.
+    pub fn append_u64(&mut self, label: &[u8], x: u64) {
+        self.append_message(label, &x.to_be_bytes());
+    }
+
+    /// Appends a scalar.
+    pub fn append_scalar(&mut self, label: &[u8], x: &Fr) {
+        self.append_message(label, &x.to_bytes_be());
+    }
+
+    /// Appends a point of G1.
+    pub fn append_g1(&mut self, label: &[u8], p: &G1Affine) {
+        self.append_message(label, &p.to_compressed_bytes());
+    }
+
+    /// Appends a point of G2.
+    pub fn append_g2(&mut self, label: &[u8], p: &G2Affine) {
+        self.append_message(label, &p.to_compressed_bytes());
+    }
+
+    /// Appends an element of `Fq12`, e.g. a pairing output.
+    pub fn append_fq12(&mut self, label: &[u8], x: &Fq12) {
+        self.append_message(label, &x.to_bytes_be());
+    }
+
+    /// Squeezes a challenge.
+    pub fn challenge_scalar(&mut self, label: &[u8]) -> Fr {
+        self.challenge_scalars(label, 1)[0]
+    }
+
+    /// Squeezes `count` independent challenges.
+    pub fn challenge_scalars(&mut self, label: &[u8], count: usize) -> Vec<Fr> {
+        self.absorb(CHALLENGE, label, &(count as u64).to_be_bytes());
+        let mut res = Vec::with_capacity(count);
+        for block in 0..count.div_ceil(BLOCK_LEN) {
+            let mut hasher = self.hasher.clone();
+            if block > 0 {
+                Self::absorb_into(&mut hasher, BLOCK, b"", &(block as u64).to_be_bytes());
+            }
+            let len = BLOCK_LEN.min(count - res.len());
+            // BLOCK_LEN elements always fit in one expand_message output
+            res.extend(
+                try_hash_to_field_with::<Fr, ExpandMsgXof<Shake128>>(hasher, TRANSCRIPT_DST, len)
+                    .unwrap(),
+            );
+        }
+        res
+    }
+
+    fn absorb(&mut self, op: u8, label: &[u8], data: &[u8]) {
+        Self::absorb_into(&mut self.hasher, op, label, data);
+    }
+
+    fn absorb_into(hasher: &mut ExpandMsgHasher<Shake128>, op: u8, label: &[u8], data: &[u8]) {
+        hasher.update([op]);
+        hasher.update((label.len() as u64).to_be_bytes());
+        hasher.update(label);
+        hasher.update((data.len() as u64).to_be_bytes());
+        hasher.update(data);
+    }
+}
+
+impl fmt::Debug for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Transcript").finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bls12_381::{Bls12, G1, G2};
+    use crate::tests::test_rng;
+    use crate::{CurveAffine, CurveProjective, Engine};
+    use ff::Field;
+
+    #[test]
+    fn test_transcript_challenges() {
+        let mut a = Transcript::new(b"test");
+        let mut b = Transcript::new(b"test");
+        a.append_message(b"m", b"hello");
+        b.append_message(b"m", b"hello");
+        let ca = a.challenge_scalar(b"c");
+        assert_eq!(ca, b.challenge_scalar(b"c"));
+
+        // a second challenge is different
+        let ca2 = a.challenge_scalar(b"c");
+        assert_ne!(ca, ca2);
+        assert_eq!(ca2, b.challenge_scalar(b"c"));
+
+        let cs = a.challenge_scalars(b"cs", 3);
+        assert_eq!(cs.len(), 3);
+        assert_ne!(cs[0], cs[1]);
+        assert_eq!(cs, b.challenge_scalars(b"cs", 3));
+
+        // more challenges than one expand_message call can produce: the
+        // first block is hashed from the state itself, the second from a
+        // copy that has absorbed the block frame
+        let many = a.clone().challenge_scalars(b"cs", BLOCK_LEN + 2);
+        assert_eq!(many.len(), BLOCK_LEN + 2);
+        let mut c = a.clone();
+        c.absorb(CHALLENGE, b"cs", &((BLOCK_LEN + 2) as u64).to_be_bytes());
+        let first = try_hash_to_field_with::<Fr, ExpandMsgXof<Shake128>>(
+            c.hasher,
+            TRANSCRIPT_DST,
+            BLOCK_LEN,
+        )
+        .unwrap();
+        assert_eq!(many[..BLOCK_LEN], first[..]);
+        assert_ne!(many[BLOCK_LEN], many[0]);
+        assert_ne!(many[BLOCK_LEN], many[BLOCK_LEN + 1]);
+        assert_eq!(many, b.clone().challenge_scalars(b"cs", BLOCK_LEN + 2));
+
+        // the protocol, the labels, and the framing all matter
+        let challenge = |f: &dyn Fn(&mut Transcript)| {
+            let mut t = Transcript::new(b"test");
+            f(&mut t);
+            t.challenge_scalar(b"c")
+        };
+        let base = challenge(&|t| t.append_message(b"m", b"hello"));
+        let mut other = Transcript::new(b"other");
+        other.append_message(b"m", b"hello");
+        assert_ne!(base, other.challenge_scalar(b"c"));
+        assert_ne!(base, challenge(&|t| t.append_message(b"n", b"hello")));
+        assert_ne!(base, challenge(&|t| t.append_message(b"mh", b"ello")));
+        assert_ne!(
+            challenge(&|t| {
+                t.append_message(b"m", b"ab");
+                t.append_message(b"m", b"c");
+            }),
+            challenge(&|t| {
+                t.append_message(b"m", b"a");
+                t.append_message(b"m", b"bc");
+            })
+        );
+        assert_ne!(
+            challenge(&|t| t.append_message(b"c", b"")),
+            challenge(&|_| ())
+        );
+    }
+
+    #[test]
+    fn test_transcript_append() {
+        let mut rng = test_rng();
+        let x = Fr::random(&mut rng);
+        let p = G1::random(&mut rng).into_affine();
+        let q = G2::random(&mut rng).into_affine();
+        let gt = Bls12::pairing(p, q);
+
+        let mut a = Transcript::new(b"test");
+        a.append_u64(b"n", 7);
+        a.append_scalar(b"x", &x);
+        a.append_g1(b"p", &p);
+        a.append_g2(b"q", &q);
+        a.append_fq12(b"gt", &gt);
+
+        let mut b = Transcript::new(b"test");
+        b.append_message(b"n", &[0, 0, 0, 0, 0, 0, 0, 7]);
+        b.append_message(b"x", &x.to_bytes_be());
+        b.append_message(b"p", p.into_compressed().as_ref());
+        b.append_message(b"q", q.into_compressed().as_ref());
+        b.append_message(b"gt", &gt.to_bytes_be());
+        assert_eq!(a.challenge_scalar(b"c"), b.challenge_scalar(b"c"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_transcript_serdes_encodings() {
+        use crate::serdes::SerDes;
+
+        let mut rng = test_rng();
+        let p = G1::random(&mut rng).into_affine();
+        let q = G2::random(&mut rng).into_affine();
+        let mut buf = vec![];
+        p.serialize(&mut buf, true).unwrap();
+        assert_eq!(&buf[..], &p.to_compressed_bytes()[..]);
+        let mut buf = vec![];
+        q.serialize(&mut buf, true).unwrap();
+        assert_eq!(&buf[..], &q.to_compressed_bytes()[..]);
+    }
+}
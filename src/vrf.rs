@@ -0,0 +1,280 @@
+/*!
+Verifiable random functions over BLS12-381.
+
+A VRF maps an input `alpha` under a secret key to a pseudorandom output
+`beta`, together with a proof that lets anyone holding the public key check
+that `beta` is the unique output for `alpha`. Two constructions are
+provided, both with public keys `sk * g` in G1 and 32-byte SHA-256 outputs:
+
+* The BLS VRF. The proof is the BLS signature `sk * H(alpha)` in G2, where
+  `H` hashes to G2 under `BLS_VRF_APP_TAG`, and verification is a pairing
+  product check. Many proofs can be checked together with one multi-pairing.
+* The DLEQ VRF, after ECVRF (RFC 9381) but in G1. The proof is
+  `Gamma = sk * H(alpha)`, with `H` hashing to G1 under `DLEQ_VRF_APP_TAG`,
+  and a Chaum-Pedersen proof that `log_g(pk) = log_H(Gamma)`. Verification
+  takes four scalar multiplications and no pairing.
+
+The output is the hash of the proof point, separated by the suite string of
+the construction as in RFC 9381:
+`beta = SHA-256(suite || 0x03 || compressed point || 0x00)`.
+*/
+
+use crate::{
+    bls12_381::{Bls12, Fr, G1Affine, G1, G2},
+    ciphersuite::{Bls12381G1XmdSha256SswuRo, Bls12381G2XmdSha256SswuRo, HashToCurveSuite},
+    hash_to_field::ExpandMsgXmd,
+    hash_to_scalar::HashToScalar,
+    threshold::{mul_generator, sum_of_products},
+    CurveAffine, CurveProjective, Engine,
+};
+use alloc::vec::Vec;
+use ff::Field;
+use rand_core::RngCore;
+use sha2::{Digest, Sha256};
+
+/// The application tag of the DST under which the BLS VRF hashes inputs to
+/// G2.
+pub const BLS_VRF_APP_TAG: &[u8] = b"PAIRING-PLUS-BLS-VRF-V01";
+
+/// The application tag of the DST under which the DLEQ VRF hashes inputs to
+/// G1. The DSTs of its nonce and challenge append "-NONCE" and "-CHALLENGE".
+pub const DLEQ_VRF_APP_TAG: &[u8] = b"PAIRING-PLUS-DLEQ-VRF-V01";
+
+/// A BLS VRF proof: the signature on the input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BlsProof(pub G2);
+
+/// A DLEQ VRF proof `(Gamma, c, s)`, in the ECVRF layout. `nizk::DleqProof`
+/// is the plain Chaum-Pedersen proof, without a VRF output.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EcvrfProof {
+    pub gamma: G1,
+    pub c: Fr,
+    pub s: Fr,
+}
+
+/// Proves the BLS VRF output for `alpha` under `sk`.
+pub fn bls_prove(sk: &Fr, alpha: &[u8]) -> BlsProof {
+    let mut pi = Bls12381G2XmdSha256SswuRo::hash(alpha, BLS_VRF_APP_TAG);
+    pi.mul_assign(*sk);
+    BlsProof(pi)
+}
+
+impl BlsProof {
+    /// The VRF output of this proof. Only meaningful once the proof has been
+    /// verified.
+    pub fn output(&self) -> [u8; 32] {
+        output(BLS_VRF_APP_TAG, &self.0.into_affine().to_compressed_bytes())
+    }
+
+    /// Checks the proof for `alpha` under `pk` with
+    /// `e(pk, H(alpha)) * e(-g, pi) == 1`, and returns the output if it is
+    /// valid.
+    pub fn verify(&self, pk: &G1, alpha: &[u8]) -> Option<[u8; 32]> {
+        if pk.is_zero() {
+            return None;
+        }
+
+        let h = Bls12381G2XmdSha256SswuRo::hash(alpha, BLS_VRF_APP_TAG);
+        let mut g = G1Affine::one();
+        g.negate();
+        if Bls12::pairing_product(pk.into_affine(), h, g, self.0) == <Bls12 as Engine>::Fqk::one() {
+            Some(self.output())
+        } else {
+            None
+        }
+    }
+}
+
+/// Checks many BLS VRF proofs `(pk, alpha, proof)` at once, with a random
+/// linear combination of the pairing equations:
+/// `prod_i e(r_i * pk_i, H(alpha_i)) == e(g, sum_i r_i * pi_i)`.
+pub fn bls_batch_verify<R: RngCore + ?Sized>(items: &[(G1, &[u8], BlsProof)], rng: &mut R) -> bool {
+    if items.iter().any(|(pk, _, _)| pk.is_zero()) {
+        return false;
+    }
+
+    let mut ps = Vec::with_capacity(items.len() + 1);
+    let mut qs = Vec::with_capacity(items.len() + 1);
+    let mut pi = G2::zero();
+    for (pk, alpha, proof) in items {
+        let r = Fr::random(rng);
+        let mut p = *pk;
+        p.mul_assign(r);
+        ps.push(p.into_affine());
+        qs.push(Bls12381G2XmdSha256SswuRo::hash(alpha, BLS_VRF_APP_TAG).into_affine());
+        let mut t = proof.0;
+        t.mul_assign(r);
+        pi.add_assign(&t);
+    }
+    let mut g = G1Affine::one();
+    g.negate();
+    ps.push(g);
+    qs.push(pi.into_affine());
+    Bls12::pairing_multi_product(&ps, &qs) == <Bls12 as Engine>::Fqk::one()
+}
+
+/// Proves the DLEQ VRF output for `alpha` under `sk`. The nonce is derived
+/// deterministically from `sk` and the hashed input.
+pub fn dleq_prove(sk: &Fr, alpha: &[u8]) -> EcvrfProof {
+    let pk = mul_generator::<G1>(sk);
+    let h = Bls12381G1XmdSha256SswuRo::hash(alpha, DLEQ_VRF_APP_TAG);
+    let mut gamma = h;
+    gamma.mul_assign(*sk);
+
+    let mut nonce_input = sk.to_bytes_be().to_vec();
+    nonce_input.extend_from_slice(&h.into_affine().to_compressed_bytes());
+    let k = hash_to_fr(&nonce_input, b"-NONCE");
+    let u = mul_generator::<G1>(&k);
+    let mut v = h;
+    v.mul_assign(k);
+
+    let c = challenge(&pk, &h, &gamma, &u, &v);
+    let mut s = c;
+    s.mul_assign(sk);
+    s.add_assign(&k);
+    EcvrfProof { gamma, c, s }
+}
+
+impl EcvrfProof {
+    /// The VRF output of this proof. Only meaningful once the proof has been
+    /// verified.
+    pub fn output(&self) -> [u8; 32] {
+        output(
+            DLEQ_VRF_APP_TAG,
+            &self.gamma.into_affine().to_compressed_bytes(),
+        )
+    }
+
+    /// Checks the proof for `alpha` under `pk`, recomputing the commitments
+    /// `U = s * g - c * pk` and `V = s * H(alpha) - c * Gamma` and the
+    /// challenge, and returns the output if it is valid.
+    pub fn verify(&self, pk: &G1, alpha: &[u8]) -> Option<[u8; 32]> {
+        if pk.is_zero() {
+            return None;
+        }
+
+        let h = Bls12381G1XmdSha256SswuRo::hash(alpha, DLEQ_VRF_APP_TAG);
+        let mut neg_c = self.c;
+        neg_c.negate();
+        let scalars = [self.s, neg_c];
+        let u = sum_of_products(&[G1Affine::one(), pk.into_affine()], &scalars);
+        let v = sum_of_products(&[h.into_affine(), self.gamma.into_affine()], &scalars);
+        if challenge(pk, &h, &self.gamma, &u, &v) == self.c {
+            Some(self.output())
+        } else {
+            None
+        }
+    }
+}
+
+/// The DLEQ challenge: the hash of the statement and the commitments.
+fn challenge(pk: &G1, h: &G1, gamma: &G1, u: &G1, v: &G1) -> Fr {
+    let mut input = Vec::with_capacity(5 * 48);
+    for p in [pk, h, gamma, u, v].iter() {
+        input.extend_from_slice(&p.into_affine().to_compressed_bytes());
+    }
+    hash_to_fr(&input, b"-CHALLENGE")
+}
+
+/// Hashes to a scalar under `DLEQ_VRF_APP_TAG || suffix`.
+fn hash_to_fr(msg: &[u8], suffix: &[u8]) -> Fr {
+    let mut dst = DLEQ_VRF_APP_TAG.to_vec();
+    dst.extend_from_slice(suffix);
+    <Fr as HashToScalar<ExpandMsgXmd<Sha256>>>::hash_to_scalar(msg, dst)
+}
+
+/// `SHA-256(suite || 0x03 || point || 0x00)`
+fn output(suite: &[u8], point: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out.copy_from_slice(
+        &Sha256::new()
+            .chain(suite)
+            .chain([0x03])
+            .chain(point)
+            .chain([0x00])
+            .finalize(),
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_rng;
+
+    #[test]
+    fn test_bls_vrf() {
+        let mut rng = test_rng();
+        let sk = Fr::random(&mut rng);
+        let pk = mul_generator::<G1>(&sk);
+
+        let proof = bls_prove(&sk, b"slot 1");
+        assert_eq!(proof, bls_prove(&sk, b"slot 1"));
+        assert_eq!(proof.verify(&pk, b"slot 1"), Some(proof.output()));
+        assert_eq!(proof.verify(&pk, b"slot 2"), None);
+        assert_eq!(proof.verify(&G1::one(), b"slot 1"), None);
+        assert_eq!(proof.verify(&G1::zero(), b"slot 1"), None);
+        assert_ne!(proof.output(), bls_prove(&sk, b"slot 2").output());
+
+        // the proof is a BLS signature under the VRF DST
+        let dst = Bls12381G2XmdSha256SswuRo::dst(BLS_VRF_APP_TAG);
+        assert_eq!(proof.0, crate::threshold::sign::<G2>(&sk, b"slot 1", &dst));
+    }
+
+    #[test]
+    fn test_bls_vrf_batch() {
+        let mut rng = test_rng();
+        let alphas: [&[u8]; 4] = [b"a", b"b", b"c", b"a"];
+        let mut items = vec![];
+        for alpha in alphas.iter() {
+            let sk = Fr::random(&mut rng);
+            items.push((mul_generator::<G1>(&sk), *alpha, bls_prove(&sk, alpha)));
+        }
+        assert!(bls_batch_verify(&items, &mut rng));
+        assert!(bls_batch_verify(&items[..1], &mut rng));
+        assert!(bls_batch_verify(&[], &mut rng));
+
+        let mut bad = items.clone();
+        bad[2].1 = b"d";
+        assert!(!bls_batch_verify(&bad, &mut rng));
+        let mut bad = items.clone();
+        bad[0].2 = items[1].2;
+        assert!(!bls_batch_verify(&bad, &mut rng));
+        // swapping the proofs of two keys breaks both equations
+        let mut bad = items.clone();
+        bad[0].2 = items[3].2;
+        bad[3].2 = items[0].2;
+        assert!(!bls_batch_verify(&bad, &mut rng));
+        let mut bad = items;
+        bad[1].0 = G1::zero();
+        assert!(!bls_batch_verify(&bad, &mut rng));
+    }
+
+    #[test]
+    fn test_dleq_vrf() {
+        let mut rng = test_rng();
+        let sk = Fr::random(&mut rng);
+        let pk = mul_generator::<G1>(&sk);
+
+        let proof = dleq_prove(&sk, b"slot 1");
+        assert_eq!(proof, dleq_prove(&sk, b"slot 1"));
+        assert_eq!(proof.verify(&pk, b"slot 1"), Some(proof.output()));
+        assert_eq!(proof.verify(&pk, b"slot 2"), None);
+        assert_eq!(proof.verify(&G1::one(), b"slot 1"), None);
+        assert_eq!(proof.verify(&G1::zero(), b"slot 1"), None);
+        assert_ne!(proof.output(), dleq_prove(&sk, b"slot 2").output());
+        // the outputs of the two constructions are unrelated
+        assert_ne!(proof.output(), bls_prove(&sk, b"slot 1").output());
+
+        let mut bad = proof;
+        bad.s.add_assign(&Fr::one());
+        assert_eq!(bad.verify(&pk, b"slot 1"), None);
+        let mut bad = proof;
+        bad.c.add_assign(&Fr::one());
+        assert_eq!(bad.verify(&pk, b"slot 1"), None);
+        let mut bad = proof;
+        bad.gamma.double();
+        assert_eq!(bad.verify(&pk, b"slot 1"), None);
+    }
+}
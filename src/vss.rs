@@ -0,0 +1,402 @@
+/*!
+Feldman and Pedersen verifiable secret sharing over BLS12-381 G1.
+
+The dealer shares a secret as in `threshold::split_secret` and publishes a
+commitment to each coefficient of the sharing polynomial `f`. Anyone can then
+evaluate the committed polynomial at a share index in the exponent, with one
+multi-scalar multiplication, and compare the result with the share.
+
+* Feldman VSS commits to `a_k` as `a_k * g`. The constant term of the
+  commitment is the public key of the secret.
+* Pedersen VSS commits to `a_k` as `a_k * g + b_k * h`, where the `b_k` are
+  the coefficients of a second, blinding polynomial. The commitments reveal
+  nothing about the secret. The generator `h` is the output of
+  `hash_to_curve`, so nobody knows its discrete logarithm to base `g`.
+
+Batch verification checks a random linear combination of the share
+equations, which costs one multi-scalar multiplication of the size of the
+commitment however many shares are checked.
+*/
+
+use crate::{
+    bls12_381::{Fr, G1Affine, G1},
+    ciphersuite::{Bls12381G1XmdSha256SswuRo, HashToCurveSuite},
+    threshold::{
+        evaluate_polynomial, fr_from_u64, mul_generator, random_polynomial, sum_of_products,
+        SecretShare, ThresholdError,
+    },
+    CurveAffine, CurveProjective,
+};
+use alloc::vec::Vec;
+use ff::Field;
+use rand_core::RngCore;
+use zeroize::Zeroize;
+
+/// The application tag of the DST under which the Pedersen generator `h` is
+/// hashed to G1.
+pub const PEDERSEN_GENERATOR_APP_TAG: &[u8] = b"PAIRING-PLUS-PEDERSEN-VSS-V01";
+
+/// The second Pedersen generator `h`: the hash of "h" to G1 under
+/// `PEDERSEN_GENERATOR_APP_TAG`.
+pub fn pedersen_generator() -> G1 {
+    pedersen_generator_affine().into_projective()
+}
+
+/// `pedersen_generator()` in affine form. With `std` it is hashed once and
+/// cached; without, it is hashed on every call.
+fn pedersen_generator_affine() -> G1Affine {
+    #[cfg(feature = "std")]
+    {
+        static H: std::sync::OnceLock<G1Affine> = std::sync::OnceLock::new();
+        *H.get_or_init(hash_pedersen_generator)
+    }
+    #[cfg(not(feature = "std"))]
+    hash_pedersen_generator()
+}
+
+fn hash_pedersen_generator() -> G1Affine {
+    Bls12381G1XmdSha256SswuRo::hash(b"h", PEDERSEN_GENERATOR_APP_TAG).into_affine()
+}
+
+/// Commitments `a_k * g` to the coefficients of a sharing polynomial.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeldmanCommitment {
+    pub coeffs: Vec<G1Affine>,
+}
+
+/// A Pedersen share `(i, f(i), f'(i))`, with `f'` the blinding polynomial.
+#[derive(Clone, Debug, PartialEq, Eq, Zeroize)]
+pub struct PedersenShare {
+    pub index: u64,
+    pub value: Fr,
+    pub blinding: Fr,
+}
+
+/// Commitments `a_k * g + b_k * h` to the coefficients of a sharing
+/// polynomial and of its blinding polynomial.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PedersenCommitment {
+    pub coeffs: Vec<G1Affine>,
+}
+
+/// Splits `secret` as `threshold::split_secret` does, and commits to the
+/// sharing polynomial.
+pub fn feldman_share<R: RngCore + ?Sized>(
+    secret: &Fr,
+    threshold: usize,
+    shares: usize,
+    rng: &mut R,
+) -> Result<(FeldmanCommitment, Vec<SecretShare>), ThresholdError> {
+    if threshold == 0 || threshold > shares {
+        return Err(ThresholdError::InvalidThreshold { threshold, shares });
+    }
+
+    let mut poly = random_polynomial(secret, threshold, rng);
+    let commitment = commit_feldman(&poly);
+    let res = (1..=shares as u64)
+        .map(|index| SecretShare {
+            index,
+            value: evaluate_polynomial(&poly, &fr_from_u64(index)),
+        })
+        .collect();
+    poly.zeroize();
+    Ok((commitment, res))
+}
+
+/// Splits `secret` with a blinding polynomial, and commits to both
+/// polynomials with the generators `g` and `pedersen_generator()`.
+pub fn pedersen_share<R: RngCore + ?Sized>(
+    secret: &Fr,
+    threshold: usize,
+    shares: usize,
+    rng: &mut R,
+) -> Result<(PedersenCommitment, Vec<PedersenShare>), ThresholdError> {
+    if threshold == 0 || threshold > shares {
+        return Err(ThresholdError::InvalidThreshold { threshold, shares });
+    }
+
+    let mut poly = random_polynomial(secret, threshold, rng);
+    let mut blinding = random_polynomial(&Fr::random(rng), threshold, rng);
+    let commitment = commit_pedersen(&poly, &blinding);
+    let res = (1..=shares as u64)
+        .map(|index| {
+            let x = fr_from_u64(index);
+            PedersenShare {
+                index,
+                value: evaluate_polynomial(&poly, &x),
+                blinding: evaluate_polynomial(&blinding, &x),
+            }
+        })
+        .collect();
+    poly.zeroize();
+    blinding.zeroize();
+    Ok((commitment, res))
+}
+
+/// The Feldman commitment to the polynomial with coefficients `poly`.
+pub(crate) fn commit_feldman(poly: &[Fr]) -> FeldmanCommitment {
+    let mut coeffs: Vec<G1> = poly.iter().map(mul_generator).collect();
+    G1::batch_normalization(&mut coeffs);
+    FeldmanCommitment {
+        coeffs: coeffs.iter().map(|c| c.into_affine()).collect(),
+    }
+}
+
+/// The Pedersen commitment to the polynomial with coefficients `poly`,
+/// blinded by the one with coefficients `blinding`.
+pub(crate) fn commit_pedersen(poly: &[Fr], blinding: &[Fr]) -> PedersenCommitment {
+    let h = pedersen_generator_affine();
+    let mut coeffs: Vec<G1> = poly
+        .iter()
+        .zip(blinding.iter())
+        .map(|(a, b)| {
+            let mut c = mul_generator::<G1>(a);
+            c.add_assign(&h.mul(*b));
+            c
+        })
+        .collect();
+    G1::batch_normalization(&mut coeffs);
+    PedersenCommitment {
+        coeffs: coeffs.iter().map(|c| c.into_affine()).collect(),
+    }
+}
+
+impl FeldmanCommitment {
+    /// The threshold of the sharing, i.e. the number of coefficients.
+    pub fn threshold(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    /// The public key `f(0) * g` of the shared secret, or `None` if the
+    /// commitment is empty.
+    pub fn public_key(&self) -> Option<G1> {
+        self.coeffs.first().map(|c| c.into_projective())
+    }
+
+    /// The committed polynomial evaluated at `index` in the exponent, i.e.
+    /// the public key `f(index) * g` of that share.
+    pub fn evaluate(&self, index: u64) -> G1 {
+        sum_of_products(
+            &self.coeffs,
+            &powers(&fr_from_u64(index), self.coeffs.len()),
+        )
+    }
+
+    /// Checks a share against the commitment. An empty commitment commits
+    /// to nothing, and verifies no share.
+    pub fn verify(&self, share: &SecretShare) -> bool {
+        !self.coeffs.is_empty()
+            && share.index != 0
+            && self.evaluate(share.index) == mul_generator(&share.value)
+    }
+
+    /// Checks all `shares` against the commitment at once, with a random
+    /// linear combination of the share equations.
+    pub fn batch_verify<R: RngCore + ?Sized>(&self, shares: &[SecretShare], rng: &mut R) -> bool {
+        if self.coeffs.is_empty() || shares.iter().any(|s| s.index == 0) {
+            return false;
+        }
+
+        let mut scalars = vec![Fr::zero(); self.coeffs.len()];
+        let mut value = Fr::zero();
+        for share in shares {
+            let w = Fr::random(rng);
+            add_weighted_powers(&mut scalars, &w, share.index);
+            let mut t = share.value;
+            t.mul_assign(&w);
+            value.add_assign(&t);
+        }
+        sum_of_products(&self.coeffs, &scalars) == mul_generator(&value)
+    }
+}
+
+impl PedersenCommitment {
+    /// The threshold of the sharing, i.e. the number of coefficients.
+    pub fn threshold(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    /// Checks a share against the commitment: `f(i) * g + f'(i) * h` must
+    /// equal the committed polynomial evaluated at `i` in the exponent. An
+    /// empty commitment verifies no share.
+    pub fn verify(&self, share: &PedersenShare) -> bool {
+        if self.coeffs.is_empty() || share.index == 0 {
+            return false;
+        }
+
+        let mut scalars = powers(&fr_from_u64(share.index), self.coeffs.len());
+        let mut value = share.value;
+        let mut blinding = share.blinding;
+        value.negate();
+        blinding.negate();
+        scalars.push(value);
+        scalars.push(blinding);
+        sum_of_products(&self.bases(), &scalars).is_zero()
+    }
+
+    /// Checks all `shares` against the commitment at once, with a random
+    /// linear combination of the share equations.
+    pub fn batch_verify<R: RngCore + ?Sized>(&self, shares: &[PedersenShare], rng: &mut R) -> bool {
+        if self.coeffs.is_empty() || shares.iter().any(|s| s.index == 0) {
+            return false;
+        }
+
+        let mut scalars = vec![Fr::zero(); self.coeffs.len()];
+        let mut value = Fr::zero();
+        let mut blinding = Fr::zero();
+        for share in shares {
+            let w = Fr::random(rng);
+            add_weighted_powers(&mut scalars, &w, share.index);
+            let mut t = share.value;
+            t.mul_assign(&w);
+            value.add_assign(&t);
+            let mut t = share.blinding;
+            t.mul_assign(&w);
+            blinding.add_assign(&t);
+        }
+        value.negate();
+        blinding.negate();
+        scalars.push(value);
+        scalars.push(blinding);
+        sum_of_products(&self.bases(), &scalars).is_zero()
+    }
+
+    /// The commitments followed by the generators `g` and `h`.
+    fn bases(&self) -> Vec<G1Affine> {
+        let mut bases = self.coeffs.clone();
+        bases.push(G1Affine::one());
+        bases.push(pedersen_generator_affine());
+        bases
+    }
+}
+
+/// `[1, x, ..., x^(n-1)]`
+fn powers(x: &Fr, n: usize) -> Vec<Fr> {
+    let mut res = Vec::with_capacity(n);
+    let mut p = Fr::one();
+    for _ in 0..n {
+        res.push(p);
+        p.mul_assign(x);
+    }
+    res
+}
+
+/// Adds `w * index^k` to `scalars[k]` for each `k`.
+fn add_weighted_powers(scalars: &mut [Fr], w: &Fr, index: u64) {
+    let x = fr_from_u64(index);
+    let mut p = *w;
+    for s in scalars.iter_mut() {
+        s.add_assign(&p);
+        p.mul_assign(&x);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_rng;
+    use crate::threshold::recover_secret;
+
+    #[test]
+    fn test_feldman() {
+        let mut rng = test_rng();
+        let secret = Fr::random(&mut rng);
+        let (commitment, shares) = feldman_share(&secret, 3, 5, &mut rng).unwrap();
+        assert_eq!(commitment.threshold(), 3);
+        assert_eq!(commitment.public_key(), Some(mul_generator(&secret)));
+        assert_eq!(recover_secret(&shares[1..4]).unwrap(), secret);
+
+        for share in shares.iter() {
+            assert!(commitment.verify(share));
+            assert_eq!(
+                commitment.evaluate(share.index),
+                share.public_key::<G1>().key
+            );
+        }
+        assert!(commitment.batch_verify(&shares, &mut rng));
+
+        let mut bad = shares.clone();
+        bad[3].value.add_assign(&Fr::one());
+        assert!(!commitment.verify(&bad[3]));
+        assert!(!commitment.batch_verify(&bad, &mut rng));
+        bad[3].value = shares[2].value;
+        assert!(!commitment.batch_verify(&bad, &mut rng));
+
+        let zero = SecretShare {
+            index: 0,
+            value: secret,
+        };
+        assert!(!commitment.verify(&zero));
+        assert!(!commitment.batch_verify(&[zero], &mut rng));
+
+        assert!(feldman_share(&secret, 0, 5, &mut rng).is_err());
+
+        // an empty commitment has no public key and verifies nothing, not
+        // even a share of value zero
+        let empty = FeldmanCommitment { coeffs: vec![] };
+        assert_eq!(empty.public_key(), None);
+        let zero = SecretShare {
+            index: 1,
+            value: Fr::zero(),
+        };
+        assert!(!empty.verify(&zero));
+        assert!(!empty.batch_verify(&[zero], &mut rng));
+    }
+
+    #[test]
+    fn test_pedersen() {
+        let mut rng = test_rng();
+        let secret = Fr::random(&mut rng);
+        let (commitment, shares) = pedersen_share(&secret, 4, 7, &mut rng).unwrap();
+        assert_eq!(commitment.threshold(), 4);
+
+        for share in shares.iter() {
+            assert!(commitment.verify(share));
+        }
+        assert!(commitment.batch_verify(&shares, &mut rng));
+        assert!(commitment.batch_verify(&shares[2..5], &mut rng));
+
+        let values: Vec<SecretShare> = shares
+            .iter()
+            .map(|s| SecretShare {
+                index: s.index,
+                value: s.value,
+            })
+            .collect();
+        assert_eq!(recover_secret(&values[3..]).unwrap(), secret);
+
+        let mut bad = shares.clone();
+        bad[0].blinding.add_assign(&Fr::one());
+        assert!(!commitment.verify(&bad[0]));
+        assert!(!commitment.batch_verify(&bad, &mut rng));
+        bad[0] = shares[0].clone();
+        bad[5].index = 9;
+        assert!(!commitment.verify(&bad[5]));
+        assert!(!commitment.batch_verify(&bad, &mut rng));
+
+        // the commitments hide the secret: the constant term is blinded
+        assert_ne!(
+            commitment.coeffs[0],
+            mul_generator::<G1>(&secret).into_affine()
+        );
+
+        assert!(pedersen_share(&secret, 8, 7, &mut rng).is_err());
+
+        let empty = PedersenCommitment { coeffs: vec![] };
+        let zero = PedersenShare {
+            index: 1,
+            value: Fr::zero(),
+            blinding: Fr::zero(),
+        };
+        assert!(!empty.verify(&zero));
+        assert!(!empty.batch_verify(&[zero], &mut rng));
+    }
+
+    #[test]
+    fn test_pedersen_generator() {
+        let h = pedersen_generator();
+        assert!(!h.is_zero());
+        assert_ne!(h, G1::one());
+        assert_eq!(h, pedersen_generator());
+    }
+}